@@ -0,0 +1,59 @@
+use criterion::black_box;
+
+use winnow::binary::bits::{bits, take, take_packed};
+use winnow::combinator::repeat;
+use winnow::error::ContextError;
+use winnow::error::ErrMode;
+use winnow::prelude::*;
+
+type Stream<'i> = &'i [u8];
+
+fn naive(count: usize, width: usize) -> impl FnMut(&mut Stream<'_>) -> ModalResult<Vec<u32>> {
+    move |i: &mut Stream<'_>| {
+        bits::<_, _, ErrMode<ContextError>, _, _>(repeat(count, take::<_, u32, _, _>(width)))
+            .parse_next(i)
+    }
+}
+
+fn packed(count: usize, width: usize) -> impl FnMut(&mut Stream<'_>) -> ModalResult<Vec<u32>> {
+    move |i: &mut Stream<'_>| {
+        bits::<_, _, ErrMode<ContextError>, _, _>(take_packed::<_, u32, _, _, _>(count, width))
+            .parse_next(i)
+    }
+}
+
+fn packed_values(c: &mut criterion::Criterion) {
+    let data = (0..4096).map(|i| (i * 31 % 256) as u8).collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("packed");
+    group.throughput(criterion::Throughput::Bytes(data.len() as u64));
+    for width in [1, 7, 12, 24] {
+        let count = data.len() * 8 / width;
+        group.bench_with_input(
+            criterion::BenchmarkId::new("repeat_take", width),
+            &width,
+            |b, &width| {
+                b.iter(|| {
+                    naive(count, width)
+                        .parse_peek(black_box(&data[..]))
+                        .unwrap()
+                });
+            },
+        );
+        group.bench_with_input(
+            criterion::BenchmarkId::new("take_packed", width),
+            &width,
+            |b, &width| {
+                b.iter(|| {
+                    packed(count, width)
+                        .parse_peek(black_box(&data[..]))
+                        .unwrap()
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion::criterion_group!(benches, packed_values);
+criterion::criterion_main!(benches);
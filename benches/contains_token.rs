@@ -1,8 +1,16 @@
 use criterion::black_box;
 
+use winnow::ascii::take_while_ascii;
 use winnow::combinator::alt;
 use winnow::combinator::repeat;
+use winnow::error::ContextError;
+use winnow::error::ErrMode;
+use winnow::error::InputError;
+use winnow::error::ParserError;
 use winnow::prelude::*;
+use winnow::stream::ByteTable;
+use winnow::token::any;
+use winnow::token::one_of;
 use winnow::token::take_till;
 use winnow::token::take_while;
 
@@ -50,6 +58,123 @@ fn contains_token(c: &mut criterion::Criterion) {
     group.finish();
 }
 
+fn take_while_ascii_str(c: &mut criterion::Criterion) {
+    let indented = format!("{}größe", " \t\r\n".repeat(1024));
+    let data = [("indented", indented.as_str())];
+    let mut group = c.benchmark_group("take_while_ascii");
+    for (name, sample) in data {
+        let len = sample.len();
+        group.throughput(criterion::Throughput::Bytes(len as u64));
+
+        group.bench_with_input(criterion::BenchmarkId::new("char", name), &len, |b, _| {
+            b.iter(|| black_box(parser_blank_char.parse_peek(black_box(sample)).unwrap()));
+        });
+        group.bench_with_input(criterion::BenchmarkId::new("ascii", name), &len, |b, _| {
+            b.iter(|| black_box(parser_blank_ascii.parse_peek(black_box(sample)).unwrap()));
+        });
+    }
+    group.finish();
+}
+
+fn byte_table(c: &mut criterion::Criterion) {
+    let identifiers = "foo_bar + baz42 * (qux_1 - _quux) / CORGE_GRAULT;\n".repeat(256);
+    let data = [("identifiers", identifiers.as_bytes())];
+    let mut group = c.benchmark_group("byte_table");
+    for (name, sample) in data {
+        let len = sample.len();
+        group.throughput(criterion::Throughput::Bytes(len as u64));
+
+        group.bench_with_input(criterion::BenchmarkId::new("table", name), &len, |b, _| {
+            b.iter(|| black_box(parser_ident_table.parse_peek(black_box(sample)).unwrap()));
+        });
+        group.bench_with_input(criterion::BenchmarkId::new("tuple", name), &len, |b, _| {
+            b.iter(|| black_box(parser_ident_tuple.parse_peek(black_box(sample)).unwrap()));
+        });
+        group.bench_with_input(
+            criterion::BenchmarkId::new("closure", name),
+            &len,
+            |b, _| {
+                b.iter(|| black_box(parser_ident_closure.parse_peek(black_box(sample)).unwrap()));
+            },
+        );
+    }
+    group.finish();
+}
+
+fn one_of_error(c: &mut criterion::Criterion) {
+    let data = [("interleaved", INTERLEAVED), ("canada", CANADA)];
+    let mut group = c.benchmark_group("one_of_error");
+    for (name, sample) in data {
+        let len = sample.len();
+        group.throughput(criterion::Throughput::Bytes(len as u64));
+
+        group.bench_with_input(criterion::BenchmarkId::new("input", name), &len, |b, _| {
+            b.iter(|| {
+                black_box(
+                    parser_one_of::<InputError<&str>>
+                        .parse_peek(black_box(sample))
+                        .unwrap(),
+                )
+            });
+        });
+        group.bench_with_input(
+            criterion::BenchmarkId::new("context", name),
+            &len,
+            |b, _| {
+                b.iter(|| {
+                    black_box(
+                        parser_one_of::<ContextError>
+                            .parse_peek(black_box(sample))
+                            .unwrap(),
+                    )
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Fails `one_of` on every non-digit
+fn parser_one_of<'i, E: ParserError<&'i str>>(input: &mut &'i str) -> Result<usize, ErrMode<E>> {
+    let contains = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+    repeat(0.., alt((one_of(contains).void(), any.void()))).parse_next(input)
+}
+
+fn parser_blank_char<'i>(input: &mut &'i str) -> ModalResult<&'i str> {
+    take_while(0.., (' ', '\t', '\r', '\n')).parse_next(input)
+}
+
+fn parser_blank_ascii<'i>(input: &mut &'i str) -> ModalResult<&'i str> {
+    take_while_ascii(0.., (' ', '\t', '\r', '\n')).parse_next(input)
+}
+
+const IDENT: ByteTable = ByteTable::new(b"_")
+    .with_range(b'a'..=b'z')
+    .with_range(b'A'..=b'Z')
+    .with_range(b'0'..=b'9');
+
+fn parser_ident_table(input: &mut &[u8]) -> ModalResult<usize> {
+    repeat(0.., alt((take_while(1.., IDENT), take_till(1.., IDENT)))).parse_next(input)
+}
+
+fn parser_ident_tuple(input: &mut &[u8]) -> ModalResult<usize> {
+    let contains = (b'a'..=b'z', b'A'..=b'Z', b'0'..=b'9', b'_');
+    repeat(
+        0..,
+        alt((take_while(1.., contains.clone()), take_till(1.., contains))),
+    )
+    .parse_next(input)
+}
+
+fn parser_ident_closure(input: &mut &[u8]) -> ModalResult<usize> {
+    let contains = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    repeat(
+        0..,
+        alt((take_while(1.., contains), take_till(1.., contains))),
+    )
+    .parse_next(input)
+}
+
 fn parser_slice(input: &mut &str) -> ModalResult<usize> {
     let contains = &['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'][..];
     repeat(
@@ -110,5 +235,11 @@ const CONTIGUOUS: &str = "012345678901234567890123456789012345678901234567890123
 const INTERLEAVED: &str = "0123456789abc0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab0123456789ab";
 const CANADA: &str = include_str!("../third_party/nativejson-benchmark/data/canada.json");
 
-criterion::criterion_group!(benches, contains_token);
+criterion::criterion_group!(
+    benches,
+    contains_token,
+    take_while_ascii_str,
+    byte_table,
+    one_of_error
+);
 criterion::criterion_main!(benches);
@@ -4,7 +4,7 @@ extern crate criterion;
 use criterion::Criterion;
 
 use winnow::ascii::float;
-use winnow::binary::be_u64;
+use winnow::binary::{be_u16, be_u24, be_u32, be_u64, le_u32, le_u64};
 use winnow::error::InputError;
 use winnow::error::ParserError;
 use winnow::prelude::*;
@@ -27,6 +27,44 @@ fn number(c: &mut Criterion) {
     });
 }
 
+#[derive(Debug)]
+#[allow(dead_code)]
+struct Header {
+    magic: u32,
+    version: u16,
+    flags: u32,
+    length: u64,
+    checksum: u32,
+    offset: u64,
+}
+
+fn header(i: &mut Stream<'_>) -> ModalResult<Header> {
+    (be_u32, be_u16, be_u24, be_u64, le_u32, le_u64)
+        .map(|(magic, version, flags, length, checksum, offset)| Header {
+            magic,
+            version,
+            flags,
+            length,
+            checksum,
+            offset,
+        })
+        .parse_next(i)
+}
+
+fn integers(c: &mut Criterion) {
+    let data: Vec<u8> = (0..29 * 64).map(|b| b as u8).collect();
+
+    println!("integers result: {:?}", header.parse_peek(&data[..29]));
+    c.bench_function("integers", move |b| {
+        b.iter(|| {
+            let mut input = &data[..];
+            while !input.is_empty() {
+                criterion::black_box(header.parse_next(&mut input).unwrap());
+            }
+        });
+    });
+}
+
 fn float_bytes(c: &mut Criterion) {
     println!(
         "float_bytes result: {:?}",
@@ -64,5 +102,12 @@ fn std_float_bytes(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, number, float_bytes, std_float_bytes, float_str);
+criterion_group!(
+    benches,
+    number,
+    integers,
+    float_bytes,
+    std_float_bytes,
+    float_str
+);
 criterion_main!(benches);
@@ -0,0 +1,39 @@
+use criterion::black_box;
+
+use winnow::ascii::{alpha1, dec_uint};
+use winnow::prelude::*;
+
+fn record<'i>(i: &mut &'i str) -> ModalResult<(&'i str, u32)> {
+    (alpha1, '=', dec_uint)
+        .map(|(k, _, v)| (k, v))
+        .parse_next(i)
+}
+
+fn parse_iter(c: &mut criterion::Criterion) {
+    let data = (0..1_000_000)
+        .map(|i| format!("key={i}"))
+        .collect::<Vec<_>>();
+    let data = data.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("parse_iter");
+    group.sample_size(10);
+    group.bench_function("parse", |b| {
+        b.iter(|| {
+            data.iter()
+                .map(|line| record.parse(black_box(line)).unwrap().1)
+                .fold(0u32, u32::wrapping_add)
+        });
+    });
+    group.bench_function("parse_iter", |b| {
+        b.iter(|| {
+            record
+                .parse_iter(data.iter().map(|line| black_box(*line)))
+                .map(|r| r.unwrap().1)
+                .fold(0u32, u32::wrapping_add)
+        });
+    });
+    group.finish();
+}
+
+criterion::criterion_group!(benches, parse_iter);
+criterion::criterion_main!(benches);
@@ -0,0 +1,40 @@
+use criterion::black_box;
+
+use winnow::ascii::{multispace1, till_line_ending};
+use winnow::combinator::{alt, preceded, repeat};
+use winnow::prelude::*;
+
+fn comment<'i>(i: &mut &'i str) -> ModalResult<&'i str> {
+    preceded('#', till_line_ending).parse_next(i)
+}
+
+fn skip_owned(i: &mut &str) -> ModalResult<()> {
+    repeat(
+        0..,
+        alt((multispace1.map(String::from), comment.map(String::from))),
+    )
+    .parse_next(i)
+}
+
+fn skip_borrowed(i: &mut &str) -> ModalResult<()> {
+    repeat(0.., alt((multispace1, comment))).parse_next(i)
+}
+
+fn skip(c: &mut criterion::Criterion) {
+    let data = (0..10_000)
+        .map(|i| format!("# comment number {i}\n  \n"))
+        .collect::<String>();
+
+    let mut group = c.benchmark_group("skip");
+    group.throughput(criterion::Throughput::Bytes(data.len() as u64));
+    group.bench_function("owned", |b| {
+        b.iter(|| skip_owned.parse(black_box(data.as_str())).unwrap());
+    });
+    group.bench_function("borrowed", |b| {
+        b.iter(|| skip_borrowed.parse(black_box(data.as_str())).unwrap());
+    });
+    group.finish();
+}
+
+criterion::criterion_group!(benches, skip);
+criterion::criterion_main!(benches);
@@ -27,6 +27,13 @@ fn arithmetic(c: &mut criterion::Criterion) {
     c.bench_function("lexer", |b| {
         b.iter(|| parser_lexer::expr2.parse_peek(data).unwrap());
     });
+
+    let nested = format!("{}1{}", "(".repeat(100), ")".repeat(100));
+    let nested = nested.as_str();
+    assert_eq!(parser::expr.parse(nested), Ok(1));
+    c.bench_function("direct_nested", |b| {
+        b.iter(|| parser::expr.parse(nested).unwrap());
+    });
 }
 
 criterion::criterion_group!(benches, arithmetic);
@@ -9,7 +9,7 @@ use winnow::{
     ascii::{digit1 as digits, multispace0 as multispaces},
     combinator::alt,
     combinator::delimited,
-    combinator::repeat,
+    combinator::separated_foldl1,
     token::one_of,
 };
 
@@ -51,37 +51,25 @@ impl Display for Expr {
 }
 
 pub(crate) fn expr(i: &mut &str) -> Result<Expr> {
-    let init = term.parse_next(i)?;
-
-    repeat(0.., (one_of(['+', '-']), term))
-        .fold(
-            move || init.clone(),
-            |acc, (op, val): (char, Expr)| {
-                if op == '+' {
-                    Expr::Add(Box::new(acc), Box::new(val))
-                } else {
-                    Expr::Sub(Box::new(acc), Box::new(val))
-                }
-            },
-        )
-        .parse_next(i)
+    separated_foldl1(term, one_of(['+', '-']), |lhs, op, rhs| {
+        if op == '+' {
+            Expr::Add(Box::new(lhs), Box::new(rhs))
+        } else {
+            Expr::Sub(Box::new(lhs), Box::new(rhs))
+        }
+    })
+    .parse_next(i)
 }
 
 fn term(i: &mut &str) -> Result<Expr> {
-    let init = factor.parse_next(i)?;
-
-    repeat(0.., (one_of(['*', '/']), factor))
-        .fold(
-            move || init.clone(),
-            |acc, (op, val): (char, Expr)| {
-                if op == '*' {
-                    Expr::Mul(Box::new(acc), Box::new(val))
-                } else {
-                    Expr::Div(Box::new(acc), Box::new(val))
-                }
-            },
-        )
-        .parse_next(i)
+    separated_foldl1(factor, one_of(['*', '/']), |lhs, op, rhs| {
+        if op == '*' {
+            Expr::Mul(Box::new(lhs), Box::new(rhs))
+        } else {
+            Expr::Div(Box::new(lhs), Box::new(rhs))
+        }
+    })
+    .parse_next(i)
 }
 
 fn factor(i: &mut &str) -> Result<Expr> {
@@ -0,0 +1,144 @@
+//! This example parses a tiny expression language into an AST where every node carries the span
+//! of the input it came from, using [`LocatingSlice`][winnow::stream::LocatingSlice] and
+//! [`spanned`][winnow::combinator::spanned].
+//!
+//! Parsing is only the first stage.  The spans are what let the second stage, evaluation, point
+//! at the offending part of the input when it finds a problem the grammar can't express, like an
+//! undefined variable or a division by zero.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use winnow::prelude::*;
+use winnow::stream::LocatingSlice;
+
+mod parser;
+
+use parser::{Expr, ExprKind, Op};
+
+fn main() -> Result<(), lexopt::Error> {
+    let args = Args::parse()?;
+
+    let input = args.input.as_deref().unwrap_or("2 * (x + 1) / (y - 3)");
+    let vars = args
+        .vars
+        .iter()
+        .map(|(name, value)| (name.as_str(), *value))
+        .collect::<HashMap<_, _>>();
+
+    println!("{input} =");
+    match parser::expr.parse(LocatingSlice::new(input)) {
+        Ok(expr) => match eval(&expr, &vars) {
+            Ok(value) => println!("  {value}"),
+            Err(err) => println!("{}", err.render(input)),
+        },
+        Err(err) => println!("  {err}"),
+    }
+
+    Ok(())
+}
+
+/// An error found after parsing, pointing back into the input
+#[derive(Debug, PartialEq, Eq)]
+struct EvalError {
+    message: String,
+    span: Range<usize>,
+}
+
+impl EvalError {
+    fn render(&self, input: &str) -> String {
+        let indent = " ".repeat(self.span.start);
+        let underline = "^".repeat(self.span.len().max(1));
+        format!("{input}\n{indent}{underline}\n{}", self.message)
+    }
+}
+
+fn eval(expr: &Expr, vars: &HashMap<&str, i64>) -> Result<i64, EvalError> {
+    match &expr.value {
+        ExprKind::Value(value) => Ok(*value),
+        ExprKind::Var(name) => vars.get(name.as_str()).copied().ok_or_else(|| EvalError {
+            message: format!("undefined variable `{name}`"),
+            span: expr.span.clone(),
+        }),
+        ExprKind::Binary(lhs, op, rhs) => {
+            let lhs = eval(lhs, vars)?;
+            let rhs_value = eval(rhs, vars)?;
+            match op.value {
+                Op::Add => Ok(lhs + rhs_value),
+                Op::Sub => Ok(lhs - rhs_value),
+                Op::Mul => Ok(lhs * rhs_value),
+                Op::Div if rhs_value == 0 => Err(EvalError {
+                    message: "division by zero".to_owned(),
+                    span: rhs.span.clone(),
+                }),
+                Op::Div => Ok(lhs / rhs_value),
+            }
+        }
+        ExprKind::Paren(inner) => eval(inner, vars),
+    }
+}
+
+#[derive(Default)]
+struct Args {
+    input: Option<String>,
+    vars: Vec<(String, i64)>,
+}
+
+impl Args {
+    fn parse() -> Result<Self, lexopt::Error> {
+        use lexopt::prelude::*;
+
+        let mut res = Args::default();
+
+        let mut args = lexopt::Parser::from_env();
+        while let Some(arg) = args.next()? {
+            match arg {
+                Short('v') | Long("var") => {
+                    let var = args.value()?.string()?;
+                    let (name, value) = var
+                        .split_once('=')
+                        .ok_or_else(|| format!("expected `NAME=VALUE`, got `{var}`"))?;
+                    let value = value.parse().map_err(|e| format!("{e}"))?;
+                    res.vars.push((name.to_owned(), value));
+                }
+                Value(input) => {
+                    res.input = Some(input.string()?);
+                }
+                _ => return Err(arg.unexpected()),
+            }
+        }
+        Ok(res)
+    }
+}
+
+#[test]
+fn eval_with_vars() {
+    let input = "2 * (x + 1) / (y - 3)";
+    let expr = parser::expr.parse(LocatingSlice::new(input)).unwrap();
+    let vars = HashMap::from([("x", 4), ("y", 8)]);
+    assert_eq!(eval(&expr, &vars), Ok(2));
+}
+
+#[test]
+fn eval_errors_point_at_input() {
+    let input = "2 * (x + 1) / (y - 3)";
+    let expr = parser::expr.parse(LocatingSlice::new(input)).unwrap();
+
+    let err = eval(&expr, &HashMap::from([("x", 4)])).unwrap_err();
+    assert_eq!(
+        err.render(input),
+        "\
+2 * (x + 1) / (y - 3)
+               ^
+undefined variable `y`"
+    );
+
+    let err = eval(&expr, &HashMap::from([("x", 4), ("y", 3)])).unwrap_err();
+    assert_eq!(
+        err.render(input),
+        "\
+2 * (x + 1) / (y - 3)
+              ^^^^^^^
+division by zero"
+    );
+}
@@ -0,0 +1,152 @@
+use std::str::FromStr;
+
+use winnow::prelude::*;
+use winnow::Result;
+use winnow::{
+    ascii::{alpha1, digit1 as digits, multispace0 as multispaces},
+    combinator::alt,
+    combinator::delimited,
+    combinator::separated_foldl1,
+    combinator::{spanned, Spanned},
+    stream::LocatingSlice,
+    token::one_of,
+};
+
+/// Tracking the location within the original input, as opposed to the remaining input
+pub(crate) type Input<'i> = LocatingSlice<&'i str>;
+
+/// Every node carries the span of the input it was parsed from
+pub(crate) type Expr = Spanned<ExprKind>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ExprKind {
+    Value(i64),
+    Var(String),
+    Binary(Box<Expr>, Spanned<Op>, Box<Expr>),
+    Paren(Box<Expr>),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+pub(crate) fn expr(i: &mut Input<'_>) -> Result<Expr> {
+    separated_foldl1(term, op(['+', '-']), binary).parse_next(i)
+}
+
+fn term(i: &mut Input<'_>) -> Result<Expr> {
+    separated_foldl1(factor, op(['*', '/']), binary).parse_next(i)
+}
+
+/// The span of a binary expression covers both operands
+fn binary(lhs: Expr, op: Spanned<Op>, rhs: Expr) -> Expr {
+    let span = lhs.span.start..rhs.span.end;
+    Spanned::new(ExprKind::Binary(Box::new(lhs), op, Box::new(rhs)), span)
+}
+
+fn op<'i>(ops: [char; 2]) -> impl Parser<Input<'i>, Spanned<Op>, winnow::error::ContextError> {
+    spanned(one_of(ops).map(|c| match c {
+        '+' => Op::Add,
+        '-' => Op::Sub,
+        '*' => Op::Mul,
+        '/' => Op::Div,
+        _ => unreachable!("only operators are matched"),
+    }))
+}
+
+fn factor(i: &mut Input<'_>) -> Result<Expr> {
+    // Whitespace is consumed outside of `spanned` so it isn't included in the spans
+    delimited(
+        multispaces,
+        alt((
+            spanned(digits.try_map(FromStr::from_str).map(ExprKind::Value)),
+            spanned(alpha1.map(|name: &str| ExprKind::Var(name.to_owned()))),
+            parens,
+        )),
+        multispaces,
+    )
+    .parse_next(i)
+}
+
+fn parens(i: &mut Input<'_>) -> Result<Expr> {
+    spanned(delimited("(", expr, ")").map(|e| ExprKind::Paren(Box::new(e)))).parse_next(i)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(input: &str) -> Expr {
+        expr.parse(LocatingSlice::new(input)).unwrap()
+    }
+
+    /// Check that every child's span is within its parent's
+    fn assert_nested(expr: &Expr) {
+        let children: Vec<&Expr> = match &expr.value {
+            ExprKind::Value(_) | ExprKind::Var(_) => vec![],
+            ExprKind::Binary(lhs, op, rhs) => {
+                assert!(lhs.span.end <= op.span.start && op.span.end <= rhs.span.start);
+                vec![lhs, rhs]
+            }
+            ExprKind::Paren(inner) => vec![inner],
+        };
+        for child in children {
+            assert!(
+                expr.span.start <= child.span.start && child.span.end <= expr.span.end,
+                "{:?} is not within {:?}",
+                child.span,
+                expr.span
+            );
+            assert_nested(child);
+        }
+    }
+
+    #[test]
+    fn leaf_spans() {
+        assert_eq!(parse("  42 "), Spanned::new(ExprKind::Value(42), 2..4));
+        assert_eq!(
+            parse("abc"),
+            Spanned::new(ExprKind::Var("abc".to_owned()), 0..3)
+        );
+    }
+
+    #[test]
+    fn binary_spans() {
+        let input = " 1 + x * 3";
+        let expr = parse(input);
+        assert_eq!(&input[expr.span.clone()], "1 + x * 3");
+
+        let ExprKind::Binary(lhs, op, rhs) = &expr.value else {
+            panic!("expected binary expression, got {expr:?}");
+        };
+        assert_eq!(lhs.span, 1..2);
+        assert_eq!(op.span, 3..4);
+        assert_eq!(op.value, Op::Add);
+        assert_eq!(&input[rhs.span.clone()], "x * 3");
+        assert_nested(&expr);
+    }
+
+    #[test]
+    fn nested_spans() {
+        let input = "2 * ( (a - 1) / b ) + 3";
+        let expr = parse(input);
+        assert_eq!(expr.span, 0..input.len());
+        assert_nested(&expr);
+
+        let ExprKind::Binary(lhs, _, _) = &expr.value else {
+            panic!("expected binary expression, got {expr:?}");
+        };
+        let ExprKind::Binary(_, _, paren) = &lhs.value else {
+            panic!("expected binary expression, got {lhs:?}");
+        };
+        assert_eq!(&input[paren.span.clone()], "( (a - 1) / b )");
+        let ExprKind::Paren(inner) = &paren.value else {
+            panic!("expected parenthesized expression, got {paren:?}");
+        };
+        assert_eq!(&input[inner.span.clone()], "(a - 1) / b");
+    }
+}
@@ -0,0 +1,65 @@
+//! This example parses CSV, per [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180), including
+//! quoted fields that contain delimiters, line endings, or `""` for a quote.
+//!
+//! The parsers work with [`Partial`][winnow::stream::Partial] for input that arrives in pieces.
+
+use winnow::prelude::*;
+
+mod parser;
+
+fn main() -> Result<(), lexopt::Error> {
+    let args = Args::parse()?;
+
+    let input = args.input.as_deref().unwrap_or(
+        "name,quote\r\nAda,\"Says \"\"hello\"\", then leaves\"\r\nGrace,\"Two\r\nlines\"\r\n",
+    );
+
+    match parser::csv(args.delimiter).parse(input) {
+        Ok(records) => {
+            for record in records {
+                println!("{record:?}");
+            }
+        }
+        Err(err) => {
+            println!("{err}");
+        }
+    }
+
+    Ok(())
+}
+
+struct Args {
+    input: Option<String>,
+    delimiter: char,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Self {
+            input: None,
+            delimiter: ',',
+        }
+    }
+}
+
+impl Args {
+    fn parse() -> Result<Self, lexopt::Error> {
+        use lexopt::prelude::*;
+
+        let mut res = Args::default();
+
+        let mut args = lexopt::Parser::from_env();
+        while let Some(arg) = args.next()? {
+            match arg {
+                Long("delimiter") => {
+                    res.delimiter = args.value()?.parse()?;
+                }
+                Value(input) => {
+                    res.input = Some(input.string()?);
+                }
+                _ => return Err(arg.unexpected()),
+            }
+        }
+        Ok(res)
+    }
+}
@@ -0,0 +1,195 @@
+use std::borrow::Cow;
+
+use winnow::prelude::*;
+use winnow::{
+    ascii::line_ending,
+    combinator::{alt, cut_err, delimited, eof, repeat, repeat_till, separated, terminated},
+    error::{ContextError, ErrMode, StrContext},
+    stream::{Compare, Stream, StreamIsPartial},
+    token::take_till,
+};
+
+/// Text to parse, either `&str` when all of it is present or
+/// [`Partial<&str>`][winnow::stream::Partial] when it arrives in pieces
+///
+/// With `Partial`, running out of input reports `ErrMode::Incomplete` rather than an error, even in
+/// the middle of a quoted field.
+pub(crate) trait Input<'i>:
+    Stream<Token = char, Slice = &'i str> + StreamIsPartial + Compare<char> + Compare<&'static str>
+{
+}
+
+impl<'i, I> Input<'i> for I where
+    I: Stream<Token = char, Slice = &'i str>
+        + StreamIsPartial
+        + Compare<char>
+        + Compare<&'static str>
+{
+}
+
+/// A whole document, per [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180)
+pub(crate) fn csv<'i, I: Input<'i>>(
+    delimiter: char,
+) -> impl Parser<I, Vec<Vec<Cow<'i, str>>>, ErrMode<ContextError>> {
+    move |input: &mut I| {
+        repeat_till(0.., record(delimiter), eof)
+            .map(|(records, _)| records)
+            .parse_next(input)
+    }
+}
+
+/// One line of fields, consuming the line ending
+///
+/// A trailing delimiter means the record ends with an empty field.  The last record in the input
+/// may leave off the line ending.
+pub(crate) fn record<'i, I: Input<'i>>(
+    delimiter: char,
+) -> impl Parser<I, Vec<Cow<'i, str>>, ErrMode<ContextError>> {
+    move |input: &mut I| {
+        terminated(
+            separated(1.., field(delimiter), delimiter),
+            alt((line_ending, eof)),
+        )
+        .parse_next(input)
+    }
+}
+
+/// A single field, without the surrounding quotes
+///
+/// Quoted fields may contain the delimiter, line endings, and quotes written as `""`.  Only
+/// quoted fields with a `""` need to be copied.
+///
+/// A `"` is only special at the start of a field; elsewhere it is kept as-is.
+pub(crate) fn field<'i, I: Input<'i>>(
+    delimiter: char,
+) -> impl Parser<I, Cow<'i, str>, ErrMode<ContextError>> {
+    move |input: &mut I| {
+        alt((
+            quoted,
+            take_till(0.., (delimiter, '\r', '\n')).map(Cow::Borrowed),
+        ))
+        .parse_next(input)
+    }
+}
+
+fn quoted<'i, I: Input<'i>>(input: &mut I) -> ModalResult<Cow<'i, str>> {
+    let content = delimited(
+        '"',
+        // Only consume `""` together so a `"` at the end of a `Partial` buffer is `Incomplete`
+        cut_err(repeat::<_, _, (), _, _>(0.., alt((take_till(1.., '"'), "\"\""))).take()),
+        cut_err('"'),
+    )
+    .context(StrContext::Label("quoted field"))
+    .parse_next(input)?;
+    if content.contains("\"\"") {
+        Ok(Cow::Owned(content.replace("\"\"", "\"")))
+    } else {
+        Ok(Cow::Borrowed(content))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use winnow::stream::Partial;
+
+    #[test]
+    fn unquoted() {
+        assert_eq!(
+            record(',').parse_peek("a,b,c\r\nd"),
+            Ok(("d", vec!["a".into(), "b".into(), "c".into()]))
+        );
+    }
+
+    #[test]
+    fn empty_fields() {
+        assert_eq!(
+            record(',').parse_peek(",,\n"),
+            Ok(("", vec!["".into(), "".into(), "".into()]))
+        );
+        assert_eq!(record(',').parse_peek("\n"), Ok(("", vec!["".into()])));
+    }
+
+    #[test]
+    fn trailing_delimiter() {
+        assert_eq!(
+            record(';').parse_peek("a;b;\n"),
+            Ok(("", vec!["a".into(), "b".into(), "".into()]))
+        );
+    }
+
+    #[test]
+    fn quoted_fields() {
+        let (_, fields) = record(',')
+            .parse_peek("\"a,b\",\"say \"\"hi\"\"\",\"\"\n")
+            .unwrap();
+        assert_eq!(fields, vec!["a,b", "say \"hi\"", ""]);
+        assert!(matches!(fields[0], Cow::Borrowed(_)));
+        assert!(matches!(fields[1], Cow::Owned(_)));
+    }
+
+    #[test]
+    fn quote_at_field_start_only() {
+        assert_eq!(
+            record(',').parse_peek("a\"b,c\"\n"),
+            Ok(("", vec!["a\"b".into(), "c\"".into()]))
+        );
+        // Nothing may follow the closing quote
+        assert!(record(',').parse_peek("\"a\"b,c\n").is_err());
+    }
+
+    #[test]
+    fn crlf_in_quoted_field() {
+        assert_eq!(
+            record(',').parse_peek("\"line 1\r\nline 2\",x\r\nnext"),
+            Ok(("next", vec!["line 1\r\nline 2".into(), "x".into()]))
+        );
+    }
+
+    #[test]
+    fn last_record_without_line_ending() {
+        assert_eq!(
+            csv(',').parse("a,b\nc,d"),
+            Ok(vec![
+                vec!["a".into(), "b".into()],
+                vec!["c".into(), "d".into()]
+            ])
+        );
+        assert_eq!(csv(',').parse(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn unterminated_quote() {
+        let err = csv(',').parse("a,\"b\nc").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\
+parse error at line 2, column 2
+  |
+2 | c
+  |  ^
+invalid quoted field
+expected `\"`"
+        );
+    }
+
+    #[test]
+    fn partial() {
+        // Ending inside a quoted field, even on what might be the closing quote
+        for input in ["\"a,b", "\"a,b\r\n", "\"a\"", "\"a\"\""] {
+            let res = record(',').parse_peek(Partial::new(input));
+            assert!(
+                matches!(res, Err(ErrMode::Incomplete(_))),
+                "{input:?}: {res:?}"
+            );
+        }
+        // Without a line ending, there may be more fields
+        let res = record(',').parse_peek(Partial::new("a,b"));
+        assert!(matches!(res, Err(ErrMode::Incomplete(_))), "{res:?}");
+        assert_eq!(
+            record(',').parse_peek(Partial::new("\"a\"\"\",b\r\n")),
+            Ok((Partial::new(""), vec!["a\"".into(), "b".into()]))
+        );
+    }
+}
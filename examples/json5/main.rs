@@ -0,0 +1,75 @@
+//! This example parses [JSON5](https://json5.org/), a superset of JSON meant to be written by
+//! people, with comments, trailing commas, single-quoted strings, unquoted keys and more number
+//! formats.
+//!
+//! It shows the recommended way to report errors with [`ContextError`][winnow::error::ContextError]:
+//! [`cut_err`][winnow::combinator::cut_err] once the grammar is committed, with
+//! [`StrContext`][winnow::error::StrContext] describing what was expected, and
+//! [`Parser::parse`] to find where in the input the error happened.
+
+use winnow::prelude::*;
+
+mod parser;
+
+fn main() -> Result<(), lexopt::Error> {
+    let args = Args::parse()?;
+
+    let input = args.input.as_deref().unwrap_or(if args.invalid {
+        "{
+  // Comments are allowed
+  unquoted: 'and you can quote me on that',
+  trailing: [1, 2, 3,],
+  hex: 0xDECAF
+  positive: +1,
+}"
+    } else {
+        "{
+  // Comments are allowed
+  unquoted: 'and you can quote me on that',
+  trailing: [1, 2, 3,],
+  hex: 0xDECAF,
+  positive: +1,
+}"
+    });
+
+    match parser::json5.parse(input) {
+        Ok(json) => {
+            println!("{json:#?}");
+        }
+        Err(err) => {
+            // `ParseError`'s `Display` shows the line with a caret under `err.offset()`
+            println!("{err}");
+            println!("at byte offset {}", err.offset());
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct Args {
+    input: Option<String>,
+    invalid: bool,
+}
+
+impl Args {
+    fn parse() -> Result<Self, lexopt::Error> {
+        use lexopt::prelude::*;
+
+        let mut res = Args::default();
+
+        let mut args = lexopt::Parser::from_env();
+        while let Some(arg) = args.next()? {
+            match arg {
+                Long("invalid") => {
+                    res.invalid = true;
+                }
+                Value(input) => {
+                    res.input = Some(input.string()?);
+                }
+                _ => return Err(arg.unexpected()),
+            }
+        }
+        Ok(res)
+    }
+}
@@ -0,0 +1,395 @@
+use std::collections::HashMap;
+
+use winnow::prelude::*;
+use winnow::{
+    ascii::{float, hex_uint, multispace1, till_line_ending},
+    combinator::{alt, cut_err, delimited, dispatch, fail, opt, peek, preceded, repeat},
+    combinator::{separated, separated_pair, terminated},
+    error::{ContextError, ErrMode, StrContext, StrContextValue},
+    token::{any, none_of, one_of, take, take_until, take_while},
+};
+
+pub(crate) type Stream<'i> = &'i str;
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum Json5Value {
+    Null,
+    Boolean(bool),
+    Str(String),
+    Num(f64),
+    Array(Vec<Json5Value>),
+    Object(HashMap<String, Json5Value>),
+}
+
+/// The root element of a JSON5 document is any value, surrounded by whitespace and comments
+///
+/// Unlike the `json` example, this parser commits to the [`ContextError`][winnow::error::ContextError]
+/// error type.  Once a parser has seen enough of the input to know what it is looking at (e.g. an
+/// opening `[`), it wraps the rest in [`cut_err`] so the first error is reported rather than
+/// backtracking into an unrelated alternative.  Every cut is annotated with
+/// [`StrContext::Expected`] so the error can tell the user what would have been valid there.
+///
+/// [`Parser::parse`] records the offset the error happened at, so the caller can point at it.
+pub(crate) fn json5(input: &mut Stream<'_>) -> ModalResult<Json5Value> {
+    delimited(ws, json5_value, ws).parse_next(input)
+}
+
+fn json5_value(input: &mut Stream<'_>) -> ModalResult<Json5Value> {
+    dispatch!(peek(any);
+        'n' => keyword("null").value(Json5Value::Null),
+        't' => keyword("true").value(Json5Value::Boolean(true)),
+        'f' => keyword("false").value(Json5Value::Boolean(false)),
+        '"' | '\'' => string.map(Json5Value::Str),
+        '+' | '-' | '.' | '0'..='9' | 'I' | 'N' => number.map(Json5Value::Num),
+        '[' => array.map(Json5Value::Array),
+        '{' => object.map(Json5Value::Object),
+        _ => fail.context(StrContext::Expected(StrContextValue::Description("value"))),
+    )
+    .parse_next(input)
+}
+
+/// Once the first character picked a keyword, anything else is an error
+fn keyword<'i>(word: &'static str) -> impl Parser<Stream<'i>, &'i str, ErrMode<ContextError>> {
+    cut_err(word).context(StrContext::Expected(StrContextValue::StringLiteral(word)))
+}
+
+/// JSON5 numbers extend JSON with hexadecimal, leading or trailing decimal points, an explicit `+`
+/// sign, `Infinity` and `NaN`
+fn number(input: &mut Stream<'_>) -> ModalResult<f64> {
+    let sign = opt(one_of(['+', '-']))
+        .map(|s| if s == Some('-') { -1.0 } else { 1.0 })
+        .parse_next(input)?;
+    let magnitude = alt((
+        preceded(alt(("0x", "0X")), cut_err(hex_uint))
+            .map(|n: u64| n as f64)
+            .context(StrContext::Expected(StrContextValue::Description(
+                "hexadecimal digits",
+            ))),
+        "Infinity".value(f64::INFINITY),
+        "NaN".value(f64::NAN),
+        // `float` also accepts a sign and words like `inf` which JSON5 doesn't
+        preceded(peek(one_of(('0'..='9', '.'))), cut_err(float)),
+    ))
+    .context(StrContext::Expected(StrContextValue::Description("number")))
+    .parse_next(input)?;
+    Ok(sign * magnitude)
+}
+
+/// Strings may be quoted with either `"` or `'`, with the other quote allowed unescaped inside
+fn string(input: &mut Stream<'_>) -> ModalResult<String> {
+    let quote = one_of(['"', '\'']).parse_next(input)?;
+    cut_err(terminated(
+        repeat(0.., character(quote)).fold(String::new, |mut string, c| {
+            string.push(c);
+            string
+        }),
+        quote.context(StrContext::Expected(StrContextValue::CharLiteral(quote))),
+    ))
+    .context(StrContext::Label("string"))
+    .parse_next(input)
+}
+
+fn character<'i>(quote: char) -> impl Parser<Stream<'i>, char, ErrMode<ContextError>> {
+    move |input: &mut Stream<'i>| {
+        let c = none_of([quote, '\n']).parse_next(input)?;
+        if c == '\\' {
+            // Peek so an invalid escape is reported at the offending character
+            cut_err(dispatch!(peek(any);
+                '"' | '\'' | '\\' | '/' => any,
+                'b' => any.value('\x08'),
+                'f' => any.value('\x0C'),
+                'n' => any.value('\n'),
+                'r' => any.value('\r'),
+                't' => any.value('\t'),
+                '0' => any.value('\0'),
+                'u' => preceded(any, unicode_escape),
+                _ => fail,
+            ))
+            .context(StrContext::Label("escape sequence"))
+            .parse_next(input)
+        } else {
+            Ok(c)
+        }
+    }
+}
+
+fn unicode_escape(input: &mut Stream<'_>) -> ModalResult<char> {
+    take(4usize)
+        .verify_map(|s| u32::from_str_radix(s, 16).ok())
+        .verify_map(std::char::from_u32)
+        .context(StrContext::Expected(StrContextValue::Description(
+            "four hexadecimal digits",
+        )))
+        .parse_next(input)
+}
+
+/// Arrays and objects allow a trailing comma
+///
+/// [`separated`] leaves a final `,` unconsumed when no element follows it, so it can be picked up
+/// with [`opt`] before the closing delimiter.
+fn array(input: &mut Stream<'_>) -> ModalResult<Vec<Json5Value>> {
+    preceded(
+        ('[', ws),
+        cut_err(terminated(
+            separated(0.., terminated(json5_value, ws), (',', ws)),
+            (
+                opt((',', ws)),
+                ']'.context(StrContext::Expected(StrContextValue::CharLiteral(',')))
+                    .context(StrContext::Expected(StrContextValue::CharLiteral(']'))),
+            ),
+        )),
+    )
+    .context(StrContext::Label("array"))
+    .parse_next(input)
+}
+
+fn object(input: &mut Stream<'_>) -> ModalResult<HashMap<String, Json5Value>> {
+    preceded(
+        ('{', ws),
+        cut_err(terminated(
+            separated(0.., terminated(member, ws), (',', ws)),
+            (
+                opt((',', ws)),
+                '}'.context(StrContext::Expected(StrContextValue::CharLiteral(',')))
+                    .context(StrContext::Expected(StrContextValue::CharLiteral('}'))),
+            ),
+        )),
+    )
+    .context(StrContext::Label("object"))
+    .parse_next(input)
+}
+
+fn member(input: &mut Stream<'_>) -> ModalResult<(String, Json5Value)> {
+    separated_pair(
+        key,
+        cut_err((ws, ':', ws)).context(StrContext::Expected(StrContextValue::CharLiteral(':'))),
+        cut_err(json5_value),
+    )
+    .parse_next(input)
+}
+
+/// Keys may be strings or bare identifiers
+fn key(input: &mut Stream<'_>) -> ModalResult<String> {
+    alt((
+        string,
+        (
+            one_of(('a'..='z', 'A'..='Z', '_', '$')),
+            take_while(0.., ('a'..='z', 'A'..='Z', '0'..='9', '_', '$')),
+        )
+            .take()
+            .map(String::from),
+    ))
+    .context(StrContext::Label("key"))
+    .parse_next(input)
+}
+
+/// Whitespace and comments are skipped wherever whitespace is allowed
+fn ws(input: &mut Stream<'_>) -> ModalResult<()> {
+    repeat(0.., alt((multispace1.void(), line_comment, block_comment))).parse_next(input)
+}
+
+fn line_comment(input: &mut Stream<'_>) -> ModalResult<()> {
+    ("//", till_line_ending).void().parse_next(input)
+}
+
+fn block_comment(input: &mut Stream<'_>) -> ModalResult<()> {
+    (
+        "/*",
+        cut_err((take_until(0.., "*/"), "*/"))
+            .context(StrContext::Expected(StrContextValue::StringLiteral("*/"))),
+    )
+        .void()
+        .parse_next(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use snapbox::assert_data_eq;
+    use snapbox::str;
+    use snapbox::IntoData as _;
+
+    fn render(input: &str) -> String {
+        match json5.parse(input) {
+            Ok(value) => format!("{value:?}"),
+            Err(err) => format!("{err}"),
+        }
+    }
+
+    #[test]
+    fn json5_extras() {
+        use Json5Value::{Array, Boolean, Null, Num, Object, Str};
+
+        let input = r#"
+// leading comment
+{
+    unquoted: 'single "quoted"',
+    "trailing": [1, 2, /* inline */ 3,],
+    hex: 0xFF,
+    leading: .5,
+    trailing_point: 5.,
+    positive: +1,
+    infinite: -Infinity,
+    nested: { a: null, b: true, c: false, },
+}
+"#;
+
+        let expected = Object(
+            vec![
+                ("unquoted".to_owned(), Str("single \"quoted\"".to_owned())),
+                (
+                    "trailing".to_owned(),
+                    Array(vec![Num(1.0), Num(2.0), Num(3.0)]),
+                ),
+                ("hex".to_owned(), Num(255.0)),
+                ("leading".to_owned(), Num(0.5)),
+                ("trailing_point".to_owned(), Num(5.0)),
+                ("positive".to_owned(), Num(1.0)),
+                ("infinite".to_owned(), Num(f64::NEG_INFINITY)),
+                (
+                    "nested".to_owned(),
+                    Object(
+                        vec![
+                            ("a".to_owned(), Null),
+                            ("b".to_owned(), Boolean(true)),
+                            ("c".to_owned(), Boolean(false)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    ),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        assert_eq!(json5.parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn json5_nan() {
+        let value = json5.parse("NaN").unwrap();
+        assert!(matches!(value, Json5Value::Num(n) if n.is_nan()));
+    }
+
+    #[test]
+    fn missing_colon() {
+        assert_data_eq!(
+            render("{\n  a: 1,\n  b 2\n}"),
+            str![[r#"
+parse error at line 3, column 5
+  |
+3 |   b 2
+  |     ^
+invalid object
+expected `:`
+"#]]
+        );
+    }
+
+    #[test]
+    fn missing_close_bracket() {
+        assert_data_eq!(
+            render("[1, 2 3]"),
+            str![[r#"
+[1, 2 3]
+      ^
+invalid array
+expected `,`, `]`
+"#]]
+        );
+    }
+
+    #[test]
+    fn unterminated_string() {
+        assert_data_eq!(
+            render("{\n  a: 'abc\n}"),
+            str![[r#"
+parse error at line 2, column 10
+  |
+2 |   a: 'abc
+  |          ^
+invalid string
+expected `'`
+"#]]
+        );
+    }
+
+    #[test]
+    fn bad_keyword() {
+        assert_data_eq!(
+            render("[nul]"),
+            str![[r#"
+[nul]
+ ^
+invalid array
+expected `null`
+"#]]
+        );
+    }
+
+    #[test]
+    fn bad_escape() {
+        assert_data_eq!(
+            render(r#""a\qb""#),
+            str![[r#"
+"a\qb"
+   ^
+invalid escape sequence
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn bad_hex() {
+        assert_data_eq!(
+            render("[0xZZ]"),
+            str![[r#"
+[0xZZ]
+   ^
+invalid array
+expected hexadecimal digits, number
+"#]]
+        );
+    }
+
+    #[test]
+    fn unterminated_comment() {
+        assert_data_eq!(
+            render("{ a: 1 /* never closed }"),
+            str![[r#"
+{ a: 1 /* never closed }
+         ^
+invalid object
+expected `*/`
+"#]]
+        );
+    }
+
+    #[test]
+    fn unexpected_value() {
+        assert_data_eq!(
+            render("{ a: @ }"),
+            str![[r#"
+{ a: @ }
+     ^
+invalid object
+expected value
+"#]]
+        );
+    }
+
+    #[test]
+    fn trailing_input() {
+        assert_data_eq!(
+            render("[1] [2]"),
+            str![[r#"
+[1] [2]
+    ^
+unexpected trailing input (3 bytes): `[2]`
+"#]]
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! # CSV
+//!
+//! ```rust
+#![doc = include_str!("../../examples/csv/parser.rs")]
+//! ```
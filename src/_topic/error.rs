@@ -5,12 +5,24 @@
 //! - [`Parser::context`]
 //! - [`cut_err`]
 //!
-//! *(see [tutorial][chapter_7])*
+//! *(see [tutorial][chapter_7] and, for a complete grammar, the `json5` example)*
 //!
 //! Most other needs can likely be met by using a custom context type with [`ContextError`] instead
 //! of [`StrContext`].
 //! This will require implementing a custom renderer.
 //!
+//! ## Describing what went wrong
+//!
+//! Built-in parsers don't categorize their errors; the `ErrorKind` that served this purpose was
+//! removed as matching on it produced lossy messages.  Instead, describe failures where you have
+//! the most information:
+//! - [`StrContext::Label`] and [`StrContext::Expected`] through [`Parser::context`] for what was
+//!   being parsed and what would have been accepted
+//! - [`Parser::try_map`] with your own error type for validation and overflow, retrievable through
+//!   [`ContextError::cause`]
+//! - Built-in external errors, like [`LengthLimitError`] or [`RecursionLimitError`], for limits
+//!   reported by winnow, also retrievable through [`ContextError::cause`]
+//!
 //! ## `ParserError` Trait
 //!
 //! When needed, you can also create your own type that implements [`ParserError`].
@@ -41,4 +53,6 @@ use crate::Parser;
 use crate::_tutorial::chapter_7;
 use crate::error::AddContext;
 use crate::error::FromExternalError;
+use crate::error::LengthLimitError;
 use crate::error::ParserError;
+use crate::error::RecursionLimitError;
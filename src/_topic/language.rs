@@ -51,6 +51,8 @@
 //! &inner)`. You can use your own parser instead of `multispace0` if you want to skip a different set
 //! of lexemes.
 //!
+//! To skip comments along with whitespace, see [`ascii::whitespace`][crate::ascii::whitespace].
+//!
 //! ## Comments
 //!
 //! ### `// C++/EOL-style comments`
@@ -10,6 +10,7 @@
 //!   - [s-expression][s_expression]
 //!   - [json]
 //!   - [INI][ini]
+//!   - [CSV][csv]
 //!   - [HTTP][http]
 //! - Special Topics:
 //!   - [Implementing `FromStr`][fromstr]
@@ -26,6 +27,7 @@
 #![allow(clippy::std_instead_of_core)]
 
 pub mod arithmetic;
+pub mod csv;
 pub mod error;
 pub mod fromstr;
 pub mod http;
@@ -38,6 +38,17 @@
 //! 1. Resolve deprecation messages
 //! 1. Commit
 //!
+//! ### Mixing old and new parsers
+//!
+//! Functions in the older `Fn(I) -> IResult<I, O, E>` style (`IResult<I, O, E>` being
+//! `Result<(I, O), E>`) can be used as a [`Parser`][crate::Parser] with
+//! [`from_peek_fn`][crate::combinator::from_peek_fn].  This lets you migrate one function at a
+//! time while old and new style parsers are mixed in [`alt`],
+//! [`seq!`][crate::combinator::seq], etc.
+//!
+//! Going the other way, [`Parser::parse_peek`][crate::Parser::parse_peek] calls a new style parser
+//! from an old style function.
+//!
 //! ### Examples
 //!
 //! For example migrations, see
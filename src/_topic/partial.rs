@@ -18,6 +18,14 @@
 //! If the chunks are not homogeneous, a state machine will be needed to track what the expected
 //! parser is for the next chunk.
 //!
+//! When all of the data is available, [`Parser::parse`] marks the [`Partial`] stream complete,
+//! letting the same parsers be used without waiting for more input.
+//!
+//! To check for [`Incomplete`] without matching on [`ErrMode`], see [`ModalResultExt`] and
+//! [`ErrMode::needed`].  For tests, [`Needed::unwrap_size`] gets at the number of bytes needed.
+//! To check a parser gives the same result no matter where the input is split into chunks, see
+//! [`check_partial`].
+//!
 //! Caveats:
 //! - `winnow` takes the approach of re-parsing from scratch. Chunks should be relatively small to
 //!   prevent the re-parsing overhead from dominating.
@@ -40,7 +48,11 @@
 
 use crate::binary::length_and_then;
 use crate::combinator::repeat;
+use crate::error::ErrMode;
 use crate::error::ErrMode::Incomplete;
+use crate::error::ModalResultExt;
 use crate::error::Needed;
 use crate::stream::Partial;
 use crate::stream::StreamIsPartial;
+use crate::testing::check_partial;
+use crate::Parser;
@@ -14,6 +14,9 @@
 //!   falling back to escape support if it fails.
 //! - Watch for large return types. A surprising place these can show up is when chaining parsers
 //!   with a tuple.
+//! - When skipping input, like whitespace and comments, avoid building outputs that will be
+//!   thrown away. [`repeat`] into `()` and [`Parser::void`] discard outputs but the inner parser
+//!   still computes them, so leave off allocating [`Parser::map`]s from skip parsers.
 //!
 //! ## Build-time Performance
 //!
@@ -52,4 +55,6 @@
 #![allow(unused_imports)]
 use crate::combinator::alt;
 use crate::combinator::dispatch;
+use crate::combinator::repeat;
 use crate::stream::BStr;
+use crate::Parser;
@@ -6,10 +6,39 @@
 //! - [`Stateful`] for passing state through your parser, like tracking recursion
 //!   depth
 //! - [`LocatingSlice`] for looking up the absolute position of a token
+//! - [`Shared`] for parsing an `Rc<str>` or `Arc<[u8]>` without borrowing it
+//! - [`CaseInsensitive`] for matching every literal without regard to ASCII case
 //!
 //! But that won't always cut it for your parser. For example, you might lex `&str` into
 //! a series of tokens and then want to parse a `TokenStream`.
 //!
+//! ## Parsing lexed tokens
+//!
+//! When you lex into a `Vec<MyToken>` first, `&[MyToken]` (or [`TokenSlice`] to also track
+//! [`Location`]s) is a [`Stream`] when `MyToken: Clone + Debug`, giving you:
+//! - [`any`][crate::token::any] and [`take`][crate::token::take] for taking tokens
+//! - [`literal`][crate::token::literal] for a specific token or sequence of tokens (e.g.
+//!   `literal(MyToken::OpenParen)` or `literal([MyToken::Minus, MyToken::Minus])`) when
+//!   `MyToken: PartialEq`
+//! - [`one_of`][crate::token::one_of] with a predicate, like
+//!   `one_of(|t: MyToken| matches!(t, MyToken::Value(_)))`.  Implement [`ContainsToken`] for
+//!   `MyToken` (and arrays of it) to use the tokens themselves as sets.
+//!
+//! For example, see the `arithmetic` example's lexer-based parser below.
+//!
+//! ## Nesting wrappers
+//!
+//! [`Stateful`], [`LocatingSlice`], [`Partial`], and [`CaseInsensitive`] forward the [`Stream`]
+//! traits of the stream they wrap, so they can be nested in any order.  We recommend
+//! `Stateful<LocatingSlice<Partial<I>>, S>` (aliased as [`StatefulLocatingPartial`]):
+//! - [`Stateful`] goes on the outside so `input.state` can be modified directly
+//! - [`LocatingSlice`] goes around [`Partial`] so spans are relative to the start of the buffer
+//!
+//! [`LookBehind`] is only available when [`LocatingSlice`] directly wraps the slice or its
+//! [`Partial`].
+//!
+//! See also the [`LocatingPartial`] and [`StatefulLocating`] aliases.
+//!
 //! ## Implementing a custom stream
 //!
 //! Let's assume we have an input type we'll call `MyStream`.
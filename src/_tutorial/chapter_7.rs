@@ -361,8 +361,8 @@
 //!
 //! ## Error Cuts
 //!
-//! We still have the issue that we are falling-through when the radix is valid but the digits
-//! don't match it:
+//! [`alt`] reports the error from the case that got the furthest, so when the radix is valid but the
+//! digits don't match it, we point at the digits:
 //! ```rust
 //! # use winnow::prelude::*;
 //! # use winnow::Result;
@@ -448,13 +448,17 @@
 //!     let input = "0b5";
 //!     let error = "\
 //! 0b5
-//! ^
-//! invalid radix prefix
-//! expected `0b`, `0o`, `0d`, `0x`";
+//!   ^
+//! invalid digit
+//! expected binary";
 //!     assert_eq!(input.parse::<Hex>().unwrap_err(), error);
 //! }
 //! ```
 //!
+//! However, we are still falling-through: after `0b` matched, [`alt`] goes on to try every other
+//! case, and anything built on top of `parse_digits` is free to backtrack and try something else,
+//! losing this error.
+//!
 //! Winnow provides an error wrapper, [`ErrMode<ContextError>`], so different failure modes can affect parsing.
 //! [`ErrMode`] is an enum with [`Backtrack`] and [`Cut`] variants (ignore [`Incomplete`] as its only
 //! relevant for [streaming][_topic::stream]). By default, errors are [`Backtrack`], meaning that
@@ -468,7 +472,7 @@
 //! pub type ModalResult<O, E = ContextError> = Result<O, ErrMode<E>>;
 //! ```
 //!
-//! So we can get the correct `context` by changing to [`ModalResult`] and adding [`cut_err`]:
+//! So we can commit to the radix once it is matched by changing to [`ModalResult`] and adding [`cut_err`]:
 //! ```rust
 //! # use winnow::prelude::*;
 //! # use winnow::token::take_while;
@@ -716,6 +720,7 @@
 #![allow(unused_imports)]
 use super::chapter_1;
 use super::chapter_3;
+use crate::_topic;
 use crate::combinator::alt;
 use crate::combinator::cut_err;
 use crate::combinator::fail;
@@ -725,7 +730,6 @@ use crate::error::ErrMode::*;
 use crate::ModalResult;
 use crate::Parser;
 use crate::Result;
-use crate::_topic;
 
 pub use super::chapter_6 as previous;
 pub use super::chapter_8 as next;
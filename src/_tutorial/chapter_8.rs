@@ -31,6 +31,12 @@
 //! #     assert_eq!(output, "");
 //! # }
 //! ```
+//!
+//! Or with the [`Parser::trace`][crate::Parser::trace] method, which also accepts names built at
+//! runtime, like with [`format_args!`].
+//!
+//! For deeply recursive grammars, limit how many levels of nesting are shown with the
+//! `WINNOW_TRACE_DEPTH` environment variable.
 
 pub use super::chapter_7 as previous;
 pub use crate::_tutorial as table_of_contents;
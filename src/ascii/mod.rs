@@ -5,6 +5,9 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "unicode")]
+pub mod unicode;
+
 use crate::lib::std::ops::{Add, Shl};
 
 use crate::combinator::alt;
@@ -16,11 +19,14 @@ use crate::combinator::peek;
 use crate::combinator::trace;
 use crate::error::Needed;
 use crate::error::ParserError;
+use crate::error::{AddContext, ModalError, StrContext, StrContextValue};
 use crate::stream::FindSlice;
-use crate::stream::{AsBStr, AsChar, ParseSlice, Stream, StreamIsPartial};
-use crate::stream::{Compare, CompareResult};
+use crate::stream::{AsBStr, AsChar, LookBehind, ParseSlice, Range, Stream, StreamIsPartial};
+use crate::stream::{Compare, CompareResult, ContainsToken, SliceLen};
 use crate::token::any;
 use crate::token::one_of;
+use crate::token::sized_literal;
+use crate::token::take_till;
 use crate::token::take_until;
 use crate::token::take_while;
 use crate::Parser;
@@ -28,6 +34,10 @@ use crate::Result;
 
 /// Mark a value as case-insensitive for ASCII characters
 ///
+/// This works for literals as well as [`ContainsToken`] sets, like with [`one_of`] and
+/// [`take_while`].  Only ASCII letters are case-folded; all other characters, including
+/// non-ASCII bytes, are compared verbatim.  Unicode case folding is not supported.
+///
 /// # Example
 /// ```rust
 /// # use winnow::prelude::*;
@@ -43,6 +53,20 @@ use crate::Result;
 /// assert!(parser.parse_peek("Some").is_err());
 /// assert!(parser.parse_peek("").is_err());
 /// ```
+///
+/// As a set:
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::Caseless;
+/// use winnow::token::take_while;
+///
+/// fn roman<'s>(s: &mut &'s str) -> ModalResult<&'s str> {
+///   take_while(1.., Caseless(['i', 'v', 'x'])).parse_next(s)
+/// }
+///
+/// assert_eq!(roman.parse_peek("XiV."), Ok((".", "XiV")));
+/// assert!(roman.parse_peek("L").is_err());
+/// ```
 #[derive(Copy, Clone, Debug)]
 pub struct Caseless<T>(pub T);
 
@@ -92,7 +116,8 @@ impl Caseless<&str> {
 /// # use winnow::ascii::crlf;
 /// assert_eq!(crlf::<_, ErrMode<ContextError>>.parse_peek(Partial::new("\r\nc")), Ok((Partial::new("c"), "\r\n")));
 /// assert!(crlf::<_, ErrMode<ContextError>>.parse_peek(Partial::new("ab\r\nc")).is_err());
-/// assert_eq!(crlf::<_, ErrMode<ContextError>>.parse_peek(Partial::new("")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// assert_eq!(crlf::<_, ErrMode<ContextError>>.parse_peek(Partial::new("")), Err(ErrMode::Incomplete(Needed::new(2))));
+/// assert_eq!(crlf::<_, ErrMode<ContextError>>.parse_peek(Partial::new("\r")), Err(ErrMode::Incomplete(Needed::new(1))));
 /// ```
 #[inline(always)]
 pub fn crlf<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
@@ -190,7 +215,10 @@ where
         match comp {
             CompareResult::Ok(_) => {}
             CompareResult::Incomplete if PARTIAL && input.is_partial() => {
-                return Err(ParserError::incomplete(input, Needed::Unknown));
+                return Err(ParserError::incomplete(
+                    input,
+                    Needed::new("\r\n".len() - input.eof_offset()),
+                ));
             }
             CompareResult::Incomplete | CompareResult::Error => {
                 return Err(ParserError::from_input(input));
@@ -238,7 +266,7 @@ where
 /// # use winnow::ascii::line_ending;
 /// assert_eq!(line_ending::<_, ErrMode<ContextError>>.parse_peek(Partial::new("\r\nc")), Ok((Partial::new("c"), "\r\n")));
 /// assert!(line_ending::<_, ErrMode<ContextError>>.parse_peek(Partial::new("ab\r\nc")).is_err());
-/// assert_eq!(line_ending::<_, ErrMode<ContextError>>.parse_peek(Partial::new("")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// assert_eq!(line_ending::<_, ErrMode<ContextError>>.parse_peek(Partial::new("")), Err(ErrMode::Incomplete(Needed::new(1))));
 /// ```
 #[inline(always)]
 pub fn line_ending<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
@@ -249,6 +277,145 @@ where
     trace("line_ending", alt(("\n", "\r\n"))).parse_next(input)
 }
 
+/// Recognizes a line, consuming the line ending (`"\n"` or `"\r\n"`) without returning it
+///
+/// Unlike [`till_line_ending`], a `'\r'` that isn't followed by `'\n'` is part of the line.
+///
+/// To include the line ending in the result, see [`line_inclusive`].
+///
+/// *Complete version*: The final line may be missing its line ending.  Will return an error if
+/// there's no input left.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's no line ending, as more of the line could still arrive.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn line<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::ascii::line.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::line;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     line.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("ab\r\nc"), Ok(("c", "ab")));
+/// assert_eq!(parser.parse_peek("ab\nc"), Ok(("c", "ab")));
+/// assert_eq!(parser.parse_peek("\nc"), Ok(("c", "")));
+/// assert_eq!(parser.parse_peek("a\rb\nc"), Ok(("c", "a\rb")));
+/// assert_eq!(parser.parse_peek("abc"), Ok(("", "abc")));
+/// assert!(parser.parse_peek("").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// # use winnow::ascii::line;
+/// assert_eq!(line::<_, ErrMode<ContextError>>.parse_peek(Partial::new("ab\r\nc")), Ok((Partial::new("c"), "ab")));
+/// assert_eq!(line::<_, ErrMode<ContextError>>.parse_peek(Partial::new("abc")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// assert_eq!(line::<_, ErrMode<ContextError>>.parse_peek(Partial::new("ab\r")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// ```
+#[inline(always)]
+pub fn line<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream + FindSlice<(&'static str, &'static str)>,
+    Error: ParserError<Input>,
+{
+    trace("line", move |input: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            line_::<_, _, true>(input, false)
+        } else {
+            line_::<_, _, false>(input, false)
+        }
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a line, including its line ending (`"\n"` or `"\r\n"`)
+///
+/// This is like [`line()`] except the line ending is part of the result, like for checksumming
+/// the input.
+///
+/// *Complete version*: The final line may be missing its line ending.  Will return an error if
+/// there's no input left.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's no line ending, as more of the line could still arrive.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn line_inclusive<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::ascii::line_inclusive.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::line_inclusive;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     line_inclusive.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("ab\r\nc"), Ok(("c", "ab\r\n")));
+/// assert_eq!(parser.parse_peek("ab\nc"), Ok(("c", "ab\n")));
+/// assert_eq!(parser.parse_peek("abc"), Ok(("", "abc")));
+/// assert!(parser.parse_peek("").is_err());
+/// ```
+#[inline(always)]
+pub fn line_inclusive<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream + FindSlice<(&'static str, &'static str)>,
+    Error: ParserError<Input>,
+{
+    trace("line_inclusive", move |input: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            line_::<_, _, true>(input, true)
+        } else {
+            line_::<_, _, false>(input, true)
+        }
+    })
+    .parse_next(input)
+}
+
+fn line_<I, E: ParserError<I>, const PARTIAL: bool>(
+    input: &mut I,
+    inclusive: bool,
+) -> Result<<I as Stream>::Slice, E>
+where
+    I: StreamIsPartial,
+    I: Stream,
+    I: FindSlice<(&'static str, &'static str)>,
+{
+    match input.find_slice(("\r\n", "\n")) {
+        Some(range) if inclusive => Ok(input.next_slice(range.end)),
+        Some(range) => {
+            let line = input.next_slice(range.start);
+            let _ = input.next_slice(range.end - range.start);
+            Ok(line)
+        }
+        None if PARTIAL && input.is_partial() => {
+            Err(ParserError::incomplete(input, Needed::Unknown))
+        }
+        None if input.eof_offset() == 0 => Err(ParserError::from_input(input)),
+        None => Ok(input.finish()),
+    }
+}
+
 /// Matches a newline character `'\n'`.
 ///
 /// *Complete version*: Will return an error if there's not enough input data.
@@ -287,7 +454,7 @@ where
 /// # use winnow::ascii::newline;
 /// assert_eq!(newline::<_, ErrMode<ContextError>>.parse_peek(Partial::new("\nc")), Ok((Partial::new("c"), '\n')));
 /// assert!(newline::<_, ErrMode<ContextError>>.parse_peek(Partial::new("\r\nc")).is_err());
-/// assert_eq!(newline::<_, ErrMode<ContextError>>.parse_peek(Partial::new("")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// assert_eq!(newline::<_, ErrMode<ContextError>>.parse_peek(Partial::new("")), Err(ErrMode::Incomplete(Needed::new(1))));
 /// ```
 #[inline(always)]
 pub fn newline<I, Error: ParserError<I>>(input: &mut I) -> Result<char, Error>
@@ -337,7 +504,7 @@ where
 /// # use winnow::ascii::tab;
 /// assert_eq!(tab::<_, ErrMode<ContextError>>.parse_peek(Partial::new("\tc")), Ok((Partial::new("c"), '\t')));
 /// assert!(tab::<_, ErrMode<ContextError>>.parse_peek(Partial::new("\r\nc")).is_err());
-/// assert_eq!(tab::<_, ErrMode<ContextError>>.parse_peek(Partial::new("")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// assert_eq!(tab::<_, ErrMode<ContextError>>.parse_peek(Partial::new("")), Err(ErrMode::Incomplete(Needed::new(1))));
 /// ```
 #[inline(always)]
 pub fn tab<Input, Error>(input: &mut Input) -> Result<char, Error>
@@ -573,6 +740,63 @@ where
     trace("digit1", take_while(1.., AsChar::is_dec_digit)).parse_next(input)
 }
 
+/// Recognizes exactly `N` ASCII numerical characters: `'0'..='9'`
+///
+/// Unlike [`digit1`], this never consumes more than `N` characters, even if more digits follow,
+/// making it suitable for fixed-width fields like those in dates and times.
+///
+/// *Complete version*: Will return an error if there are fewer than `N` digits.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there are fewer than `N` digits
+/// before the end of input.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn digit_fixed<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::ascii::digit_fixed::<4, _, _>.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::digit_fixed;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     digit_fixed::<4, _, _>.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("2024c"), Ok(("c", "2024")));
+/// assert_eq!(parser.parse_peek("202401"), Ok(("01", "2024")));
+/// assert!(parser.parse_peek("202").is_err());
+/// assert!(parser.parse_peek("20c4").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// # use winnow::ascii::digit_fixed;
+/// assert_eq!(digit_fixed::<4, _, ErrMode<ContextError>>.parse_peek(Partial::new("202401")), Ok((Partial::new("01"), "2024")));
+/// assert!(digit_fixed::<4, _, ErrMode<ContextError>>.parse_peek(Partial::new("20c4")).is_err());
+/// assert_eq!(digit_fixed::<4, _, ErrMode<ContextError>>.parse_peek(Partial::new("20")), Err(ErrMode::Incomplete(Needed::new(2))));
+/// ```
+#[inline(always)]
+pub fn digit_fixed<const N: usize, Input, Error>(
+    input: &mut Input,
+) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("digit_fixed", take_while(N..=N, AsChar::is_dec_digit)).parse_next(input)
+}
+
 /// Recognizes zero or more ASCII hexadecimal numerical characters: `'0'..='9'`, `'A'..='F'`,
 /// `'a'..='f'`
 ///
@@ -1083,126 +1307,844 @@ where
     trace("multispace1", take_while(1.., (' ', '\t', '\r', '\n'))).parse_next(input)
 }
 
-/// Decode a decimal unsigned integer (e.g. [`u32`])
+/// Recognize the longest (m <= len <= n) input slice of ASCII bytes that match the [set of
+/// tokens][ContainsToken]
 ///
-/// *Complete version*: can parse until the end of input.
+/// This is [`take_while`] for sets that only contain ASCII, like whitespace or delimiters.  It
+/// scans the input byte-by-byte, rather than decoding it `char`-by-`char` for `&str`.  As ASCII
+/// bytes never appear inside a multi-byte UTF-8 sequence, non-ASCII bytes never match and the
+/// returned slice always ends on a `char` boundary.
 ///
-/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+/// The set is checked against `u8` tokens, so `char`s and ranges of `char`s work but closures
+/// need to accept a `u8`.
+///
+/// *Complete version*: Will return the whole input if no terminating token is found (a byte
+/// outside of the set).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a byte outside of the set).
 ///
 /// # Effective Signature
 ///
-/// Assuming you are parsing a `&str` [Stream] into a `u32`:
+/// Assuming you are parsing a `&str` [Stream] with `0..` or `1..` [ranges][Range]:
 /// ```rust
-/// # use winnow::prelude::*;;
-/// pub fn dec_uint(input: &mut &str) -> ModalResult<u32>
+/// # use std::ops::RangeFrom;
+/// # use winnow::prelude::*;
+/// # use winnow::stream::ContainsToken;
+/// # use winnow::error::ContextError;
+/// pub fn take_while_ascii<'i>(occurrences: RangeFrom<usize>, set: impl ContainsToken<u8>) -> impl Parser<&'i str, &'i str, ContextError>
 /// # {
-/// #     winnow::ascii::dec_uint.parse_next(input)
+/// #     winnow::ascii::take_while_ascii(occurrences, set)
 /// # }
 /// ```
-#[doc(alias = "u8")]
-#[doc(alias = "u16")]
-#[doc(alias = "u32")]
-#[doc(alias = "u64")]
-#[doc(alias = "u128")]
-pub fn dec_uint<Input, Output, Error>(input: &mut Input) -> Result<Output, Error>
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::take_while_ascii;
+///
+/// fn blank<'i>(s: &mut &'i str) -> ModalResult<&'i str> {
+///   take_while_ascii(0.., (' ', '\t')).parse_next(s)
+/// }
+///
+/// assert_eq!(blank.parse_peek(" \t é"), Ok(("é", " \t ")));
+/// assert_eq!(blank.parse_peek("é "), Ok(("é ", "")));
+/// assert_eq!(blank.parse_peek("  "), Ok(("", "  ")));
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::ascii::take_while_ascii;
+///
+/// fn hex<'i>(s: &mut Partial<&'i str>) -> ModalResult<&'i str> {
+///   take_while_ascii(1..=4, ('0'..='9', 'A'..='F')).parse_next(s)
+/// }
+///
+/// assert_eq!(hex.parse_peek(Partial::new("12 and voila")), Ok((Partial::new(" and voila"), "12")));
+/// assert_eq!(hex.parse_peek(Partial::new("BEEFED")), Ok((Partial::new("ED"), "BEEF")));
+/// assert_eq!(hex.parse_peek(Partial::new("D1")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// assert!(hex.parse_peek(Partial::new("é")).is_err());
+/// ```
+#[inline(always)]
+pub fn take_while_ascii<Set, Input, Error>(
+    occurrences: impl Into<Range>,
+    set: Set,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
 where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
-    <Input as Stream>::Token: AsChar + Clone,
-    Output: Uint,
+    Set: ContainsToken<u8>,
     Error: ParserError<Input>,
 {
-    trace("dec_uint", move |input: &mut Input| {
-        alt(((one_of('1'..='9'), digit0).void(), one_of('0').void()))
-            .take()
-            .verify_map(|s: <Input as Stream>::Slice| {
-                let s = s.as_bstr();
-                // SAFETY: Only 7-bit ASCII characters are parsed
-                let s = unsafe { crate::lib::std::str::from_utf8_unchecked(s) };
-                Output::try_from_dec_uint(s)
-            })
-            .parse_next(input)
+    let Range {
+        start_inclusive,
+        end_inclusive,
+    } = occurrences.into();
+    trace("take_while_ascii", move |input: &mut Input| {
+        let end = end_inclusive.unwrap_or(usize::MAX);
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            take_while_ascii_::<_, _, _, true>(input, start_inclusive, end, &set)
+        } else {
+            take_while_ascii_::<_, _, _, false>(input, start_inclusive, end, &set)
+        }
     })
-    .parse_next(input)
-}
-
-/// Metadata for parsing unsigned integers, see [`dec_uint`]
-pub trait Uint: Sized {
-    #[doc(hidden)]
-    fn try_from_dec_uint(slice: &str) -> Option<Self>;
-}
-
-impl Uint for u8 {
-    fn try_from_dec_uint(slice: &str) -> Option<Self> {
-        slice.parse().ok()
-    }
-}
-
-impl Uint for u16 {
-    fn try_from_dec_uint(slice: &str) -> Option<Self> {
-        slice.parse().ok()
-    }
-}
-
-impl Uint for u32 {
-    fn try_from_dec_uint(slice: &str) -> Option<Self> {
-        slice.parse().ok()
-    }
 }
 
-impl Uint for u64 {
-    fn try_from_dec_uint(slice: &str) -> Option<Self> {
-        slice.parse().ok()
-    }
-}
-
-impl Uint for u128 {
-    fn try_from_dec_uint(slice: &str) -> Option<Self> {
-        slice.parse().ok()
+fn take_while_ascii_<I, S, E, const PARTIAL: bool>(
+    input: &mut I,
+    m: usize,
+    n: usize,
+    set: &S,
+) -> Result<<I as Stream>::Slice, E>
+where
+    I: StreamIsPartial + Stream,
+    <I as Stream>::Slice: AsBStr,
+    S: ContainsToken<u8>,
+    E: ParserError<I>,
+{
+    if n < m {
+        return Err(ParserError::assert(
+            input,
+            "`occurrences` should be ascending, rather than descending",
+        ));
     }
-}
 
-impl Uint for usize {
-    fn try_from_dec_uint(slice: &str) -> Option<Self> {
-        slice.parse().ok()
+    // For `AsBStr` streams, offsets are in bytes
+    let remaining = input.peek_slice(input.eof_offset());
+    let bytes = remaining.as_bstr();
+    let matched = bytes[..n.min(bytes.len())]
+        .iter()
+        .position(|&b| !(b.is_ascii() && set.contains_token(b)));
+    let offset = match matched {
+        Some(offset) => offset,
+        None if n <= bytes.len() => n,
+        None if PARTIAL && input.is_partial() => {
+            let needed = if m > bytes.len() { m - bytes.len() } else { 1 };
+            return Err(ParserError::incomplete(input, Needed::new(needed)));
+        }
+        None => bytes.len(),
+    };
+    if offset < m {
+        return Err(ParserError::from_input(input));
     }
+    Ok(input.next_slice(offset))
 }
 
-/// Decode a decimal signed integer (e.g. [`i32`])
+/// Recognizes an ASCII identifier: `[A-Za-z_][A-Za-z0-9_]*`
 ///
-/// *Complete version*: can parse until the end of input.
+/// For other character classes, see [`identifier_with`].
 ///
-/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+/// *Complete version*: Will return the whole input if no terminating token is found (a non
+/// alphanumeric or `_` character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non alphanumeric or `_` character).
 ///
 /// # Effective Signature
 ///
-/// Assuming you are parsing a `&str` [Stream] into an `i32`:
+/// Assuming you are parsing a `&str` [Stream]:
 /// ```rust
 /// # use winnow::prelude::*;;
-/// pub fn dec_int(input: &mut &str) -> ModalResult<i32>
+/// pub fn identifier<'i>(input: &mut &'i str) -> ModalResult<&'i str>
 /// # {
-/// #     winnow::ascii::dec_int.parse_next(input)
+/// #     winnow::ascii::identifier.parse_next(input)
 /// # }
 /// ```
-#[doc(alias = "i8")]
-#[doc(alias = "i16")]
-#[doc(alias = "i32")]
-#[doc(alias = "i64")]
-#[doc(alias = "i128")]
-pub fn dec_int<Input, Output, Error>(input: &mut Input) -> Result<Output, Error>
-where
-    Input: StreamIsPartial + Stream,
-    <Input as Stream>::Slice: AsBStr,
-    <Input as Stream>::Token: AsChar + Clone,
-    Output: Int,
-    Error: ParserError<Input>,
-{
-    trace("dec_int", move |input: &mut Input| {
-        let sign = opt(dispatch! {any.map(AsChar::as_char);
-            '+' => empty.value(true),
-            '-' => empty.value(false),
-            _ => fail,
-        });
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::identifier;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     identifier.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("_foo1 = 2"), Ok((" = 2", "_foo1")));
+/// assert_eq!(parser.parse_peek("_"), Ok(("", "_")));
+/// assert!(parser.parse_peek("1foo").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// # use winnow::ascii::identifier;
+/// assert_eq!(identifier::<_, ErrMode<ContextError>>.parse_peek(Partial::new("foo ")), Ok((Partial::new(" "), "foo")));
+/// assert_eq!(identifier::<_, ErrMode<ContextError>>.parse_peek(Partial::new("foo")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// assert_eq!(identifier::<_, ErrMode<ContextError>>.parse_peek(Partial::new("")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+///
+/// ## Filtering keywords
+///
+/// You can use `identifier` in combination with [`Parser::verify`] to reject reserved words:
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::identifier;
+/// const KEYWORDS: &[&str] = &["if", "else", "fn"];
+///
+/// fn name<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     identifier.verify(|s: &str| !KEYWORDS.contains(&s)).parse_next(input)
+/// }
+///
+/// assert_eq!(name.parse_peek("iffy"), Ok(("", "iffy")));
+/// assert!(name.parse_peek("if x").is_err());
+/// ```
+#[inline(always)]
+pub fn identifier<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input>,
+{
+    trace(
+        "identifier",
+        identifier_with((AsChar::is_alpha, '_'), is_identifier_continue),
+    )
+    .parse_next(input)
+}
+
+/// Recognizes an identifier, starting with a token in `start` followed by zero or more tokens in
+/// `cont`
+///
+/// For ASCII identifiers, see [`identifier`].
+///
+/// *Complete version*: Will return the whole input if no terminating token is found (a token
+/// outside of `cont`).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a token outside of `cont`).
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::identifier_with;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     identifier_with(
+///         (char::is_alphabetic, '_'),
+///         (char::is_alphanumeric, '_'),
+///     ).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("größe = 2"), Ok((" = 2", "größe")));
+/// assert!(parser.parse_peek("2größe").is_err());
+/// ```
+#[inline(always)]
+pub fn identifier_with<Input, Start, Cont, Error>(
+    start: Start,
+    cont: Cont,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: Clone,
+    Start: ContainsToken<<Input as Stream>::Token>,
+    Cont: ContainsToken<<Input as Stream>::Token>,
+    Error: ParserError<Input>,
+{
+    trace("identifier_with", move |input: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            identifier_::<_, _, _, _, true>(input, &start, &cont)
+        } else {
+            identifier_::<_, _, _, _, false>(input, &start, &cont)
+        }
+    })
+}
+
+fn identifier_<I, S, C, E, const PARTIAL: bool>(
+    input: &mut I,
+    start: &S,
+    cont: &C,
+) -> Result<<I as Stream>::Slice, E>
+where
+    I: StreamIsPartial + Stream,
+    <I as Stream>::Token: Clone,
+    S: ContainsToken<<I as Stream>::Token>,
+    C: ContainsToken<<I as Stream>::Token>,
+    E: ParserError<I>,
+{
+    let mut offsets = input.iter_offsets();
+    match offsets.next() {
+        Some((_, token)) => {
+            if !start.contains_token(token) {
+                return Err(ParserError::from_input(input));
+            }
+        }
+        None if PARTIAL && input.is_partial() => {
+            return Err(ParserError::incomplete(input, Needed::new(1)));
+        }
+        None => return Err(ParserError::from_input(input)),
+    }
+    let offset = match offsets.find(|(_, token)| !cont.contains_token(token.clone())) {
+        Some((offset, _)) => offset,
+        None if PARTIAL && input.is_partial() => {
+            return Err(ParserError::incomplete(input, Needed::new(1)));
+        }
+        None => input.eof_offset(),
+    };
+    Ok(input.next_slice(offset))
+}
+
+/// Whether `token` can continue an [`identifier`] or end a [`keyword`]: `[A-Za-z0-9_]`
+fn is_identifier_continue<T: AsChar>(token: T) -> bool {
+    let c = token.as_char();
+    c.is_alphanum() || c == '_'
+}
+
+/// Recognizes the keyword `word`, when it isn't the start of a longer [`identifier`]
+///
+/// The token after `word` is checked, without being consumed, to not be `[A-Za-z0-9_]`, so
+/// `keyword("let")` matches `let x` and `let!` but not `letter` or `let_`.
+///
+/// For other character classes, see [`keyword_with`].
+///
+/// *Complete version*: Will treat the end of input as not continuing the keyword.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// including for the token after `word`.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// pub fn keyword<'i>(word: &'static str) -> impl Parser<&'i str, &'i str, ContextError>
+/// # {
+/// #     winnow::ascii::keyword(word)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::keyword;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     keyword("let").parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("let x"), Ok((" x", "let")));
+/// assert_eq!(parser.parse_peek("let!"), Ok(("!", "let")));
+/// assert_eq!(parser.parse_peek("let"), Ok(("", "let")));
+/// assert!(parser.parse_peek("letter").is_err());
+/// assert!(parser.parse_peek("let_").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// # use winnow::ascii::keyword;
+/// assert_eq!(keyword::<_, _, ErrMode<ContextError>>("let").parse_peek(Partial::new("let x")), Ok((Partial::new(" x"), "let")));
+/// assert_eq!(keyword::<_, _, ErrMode<ContextError>>("let").parse_peek(Partial::new("let")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// assert_eq!(keyword::<_, _, ErrMode<ContextError>>("let").parse_peek(Partial::new("le")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+pub fn keyword<Literal, Input, Error>(
+    word: Literal,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<Literal>,
+    <Input as Stream>::Token: AsChar + Clone,
+    Literal: SliceLen + Clone + crate::lib::std::fmt::Debug,
+    Error: ParserError<Input>,
+{
+    trace(
+        "keyword",
+        keyword_with(word, is_identifier_continue::<<Input as Stream>::Token>),
+    )
+}
+
+/// Recognizes the keyword `word`, when it isn't followed by a token in `cont`
+///
+/// For ASCII identifiers, see [`keyword`].
+///
+/// *Complete version*: Will treat the end of input as not continuing the keyword.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// including for the token after `word`.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::keyword_with;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     keyword_with("für", (char::is_alphanumeric, '_')).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("für x"), Ok((" x", "für")));
+/// assert!(parser.parse_peek("fürst").is_err());
+/// assert!(parser.parse_peek("füré").is_err());
+/// ```
+#[inline(always)]
+pub fn keyword_with<Literal, Input, Cont, Error>(
+    word: Literal,
+    cont: Cont,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<Literal>,
+    <Input as Stream>::Token: Clone,
+    Literal: SliceLen + Clone + crate::lib::std::fmt::Debug,
+    Cont: ContainsToken<<Input as Stream>::Token>,
+    Error: ParserError<Input>,
+{
+    let mut word = sized_literal(word);
+    trace("keyword_with", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let slice = word.parse_next(input)?;
+        match input.peek_token() {
+            Some(token) if cont.contains_token(token.clone()) => {
+                input.reset(&start);
+                Err(ParserError::from_input(input))
+            }
+            None if input.is_partial() => {
+                input.reset(&start);
+                Err(ParserError::incomplete(input, Needed::new(1)))
+            }
+            _ => Ok(slice),
+        }
+    })
+}
+
+/// Configuration for [`whitespace`]
+///
+/// By default, only spaces, tabs, carriage returns and line feeds are skipped.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Whitespace {
+    line_comment: Option<&'static str>,
+    block_comment: Option<(&'static str, &'static str)>,
+    nested: bool,
+}
+
+impl Whitespace {
+    /// Skip only spaces, tabs, carriage returns and line feeds
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            line_comment: None,
+            block_comment: None,
+            nested: false,
+        }
+    }
+
+    /// Also skip comments starting with `prefix` up to the end of the line
+    #[inline(always)]
+    pub const fn line_comment(mut self, prefix: &'static str) -> Self {
+        self.line_comment = Some(prefix);
+        self
+    }
+
+    /// Also skip comments delimited by `open` and `close`
+    #[inline(always)]
+    pub const fn block_comment(mut self, open: &'static str, close: &'static str) -> Self {
+        self.block_comment = Some((open, close));
+        self
+    }
+
+    /// Whether an `open` inside of a block comment starts a nested block comment
+    #[inline(always)]
+    pub const fn nested(mut self, yes: bool) -> Self {
+        self.nested = yes;
+        self
+    }
+}
+
+/// Recognizes whitespace and, if configured, comments
+///
+/// Spaces, tabs, carriage returns and line feeds are always skipped.  See [`Whitespace`] for
+/// enabling line and block comments.
+///
+/// *Complete version*: will return the whole input if no terminating token is found (a non
+/// space character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// including when inside of an unterminated comment.
+///
+/// # Errors
+///
+/// An unterminated block comment is reported as a [cut][crate::error::ErrMode::Cut] error,
+/// pointing at the opening delimiter.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::alpha1;
+/// use winnow::ascii::whitespace;
+/// use winnow::ascii::Whitespace;
+/// use winnow::combinator::delimited;
+///
+/// const WS: Whitespace = Whitespace::new()
+///     .line_comment("//")
+///     .block_comment("/*", "*/")
+///     .nested(true);
+///
+/// fn word<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     delimited(whitespace(WS), alpha1, whitespace(WS)).parse_next(input)
+/// }
+///
+/// assert_eq!(word.parse_peek(" /* a /* b */ c */ hello // world\nthere"), Ok(("there", "hello")));
+/// assert!(word.parse_peek("/* a").is_err());
+/// ```
+#[inline(always)]
+pub fn whitespace<Input, Error>(
+    config: Whitespace,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'static str>,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + ModalError + AddContext<Input, StrContext>,
+{
+    trace("whitespace", move |input: &mut Input| {
+        let start = input.checkpoint();
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            whitespace_::<_, _, true>(input, &config)?;
+        } else {
+            whitespace_::<_, _, false>(input, &config)?;
+        }
+        let offset = input.offset_from(&start);
+        input.reset(&start);
+        Ok(input.next_slice(offset))
+    })
+}
+
+fn whitespace_<I, E, const PARTIAL: bool>(input: &mut I, config: &Whitespace) -> Result<(), E>
+where
+    I: StreamIsPartial + Stream + Compare<&'static str>,
+    <I as Stream>::Token: AsChar + Clone,
+    E: ParserError<I> + ModalError + AddContext<I, StrContext>,
+{
+    'outer: loop {
+        take_while(0.., (' ', '\t', '\r', '\n'))
+            .void()
+            .parse_next(input)?;
+
+        if let Some(prefix) = config.line_comment {
+            if comment_start::<_, _, PARTIAL>(input, prefix)? {
+                take_till(0.., '\n').void().parse_next(input)?;
+                continue 'outer;
+            }
+        }
+
+        if let Some((open, close)) = config.block_comment {
+            let start = input.checkpoint();
+            if comment_start::<_, _, PARTIAL>(input, open)? {
+                let mut depth = 1_usize;
+                while depth != 0 {
+                    if comment_start::<_, _, PARTIAL>(input, close)? {
+                        depth -= 1;
+                    } else if config.nested && comment_start::<_, _, PARTIAL>(input, open)? {
+                        depth += 1;
+                    } else if input.next_token().is_none() {
+                        if PARTIAL && input.is_partial() {
+                            return Err(ParserError::incomplete(input, Needed::Unknown));
+                        }
+                        input.reset(&start);
+                        let err = E::from_input(input)
+                            .add_context(input, &start, StrContext::Label("block comment"))
+                            .add_context(
+                                input,
+                                &start,
+                                StrContext::Expected(StrContextValue::StringLiteral(close)),
+                            );
+                        return Err(err.cut());
+                    }
+                }
+                continue 'outer;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+/// Consume `delimiter`, returning whether it was present
+fn comment_start<I, E, const PARTIAL: bool>(
+    input: &mut I,
+    delimiter: &'static str,
+) -> Result<bool, E>
+where
+    I: StreamIsPartial + Stream + Compare<&'static str>,
+    E: ParserError<I>,
+{
+    match input.compare(delimiter) {
+        CompareResult::Ok(len) => {
+            input.next_slice(len);
+            Ok(true)
+        }
+        CompareResult::Incomplete if PARTIAL && input.is_partial() => Err(ParserError::incomplete(
+            input,
+            Needed::new(delimiter.len() - input.eof_offset()),
+        )),
+        CompareResult::Incomplete | CompareResult::Error => Ok(false),
+    }
+}
+
+/// Succeeds at a word boundary, without consuming input
+///
+/// A word boundary is between a word character (alphanumeric or `_`) and a non-word character,
+/// or the start or end of input.  The previous token is inspected with [`LookBehind`], so this
+/// requires a [`Stream`] that retains consumed input, like
+/// [`LocatingSlice`][crate::stream::LocatingSlice].
+///
+/// *Complete version*: Will treat the end of input as a non-word character.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `LocatingSlice<&str>` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::stream::LocatingSlice;
+/// pub fn word_boundary(input: &mut LocatingSlice<&str>) -> ModalResult<()>
+/// # {
+/// #     winnow::ascii::word_boundary.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::word_boundary;
+/// use winnow::combinator::{delimited, preceded};
+/// use winnow::stream::LocatingSlice;
+/// use winnow::token::take_till;
+///
+/// fn keyword_if<'i>(input: &mut LocatingSlice<&'i str>) -> ModalResult<&'i str> {
+///     delimited(word_boundary, "if", word_boundary).parse_next(input)
+/// }
+///
+/// fn parser<'i>(input: &mut LocatingSlice<&'i str>) -> ModalResult<&'i str> {
+///     preceded(take_till(0.., 'i'), keyword_if).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse(LocatingSlice::new("if")), Ok("if"));
+/// assert_eq!(parser.parse(LocatingSlice::new(") if")), Ok("if"));
+/// assert!(parser.parse(LocatingSlice::new("elif")).is_err());
+/// assert!(parser.parse(LocatingSlice::new("iff")).is_err());
+/// ```
+pub fn word_boundary<Input, Error>(input: &mut Input) -> Result<(), Error>
+where
+    Input: StreamIsPartial + LookBehind,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input>,
+{
+    trace("word_boundary", move |input: &mut Input| {
+        let is_word = |t: <Input as Stream>::Token| {
+            let c = t.as_char();
+            c.is_alphanumeric() || c == '_'
+        };
+        let previous = input.peek_previous_token().map(is_word).unwrap_or(false);
+        let next = match input.peek_token() {
+            Some(t) => is_word(t),
+            None if input.is_partial() => {
+                return Err(ParserError::incomplete(input, Needed::new(1)));
+            }
+            None => false,
+        };
+        if previous != next {
+            Ok(())
+        } else {
+            Err(ParserError::from_input(input))
+        }
+    })
+    .parse_next(input)
+}
+
+/// Decode a decimal unsigned integer (e.g. [`u32`])
+///
+/// *Complete version*: can parse until the end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] into a `u32`:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn dec_uint(input: &mut &str) -> ModalResult<u32>
+/// # {
+/// #     winnow::ascii::dec_uint.parse_next(input)
+/// # }
+/// ```
+#[doc(alias = "u8")]
+#[doc(alias = "u16")]
+#[doc(alias = "u32")]
+#[doc(alias = "u64")]
+#[doc(alias = "u128")]
+pub fn dec_uint<Input, Output, Error>(input: &mut Input) -> Result<Output, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Output: Uint,
+    Error: ParserError<Input>,
+{
+    trace("dec_uint", move |input: &mut Input| {
+        alt(((one_of('1'..='9'), digit0).void(), one_of('0').void()))
+            .take()
+            .verify_map(|s: <Input as Stream>::Slice| {
+                let s = s.as_bstr();
+                // SAFETY: Only 7-bit ASCII characters are parsed
+                let s = unsafe { crate::lib::std::str::from_utf8_unchecked(s) };
+                Output::try_from_dec_uint(s)
+            })
+            .parse_next(input)
+    })
+    .parse_next(input)
+}
+
+/// Decode exactly `N` decimal digits as an unsigned integer (e.g. [`u32`])
+///
+/// Leading zeros are accepted and never more than `N` digits are consumed, making this suitable
+/// for fixed-width fields like those in dates and times.
+///
+/// *Complete version*: Will return an error if there are fewer than `N` digits or the value does
+/// not fit in `Output`.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there are fewer than `N` digits
+/// before the end of input.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] into a `u16`:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn dec_uint_fixed(input: &mut &str) -> ModalResult<u16>
+/// # {
+/// #     winnow::ascii::dec_uint_fixed::<4, _, _, _>.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::dec_uint_fixed;
+/// fn date(input: &mut &str) -> ModalResult<(u16, u8, u8)> {
+///     (
+///         dec_uint_fixed::<4, _, _, _>,
+///         '-',
+///         dec_uint_fixed::<2, _, _, _>,
+///         '-',
+///         dec_uint_fixed::<2, _, _, _>,
+///     )
+///         .map(|(y, _, m, _, d)| (y, m, d))
+///         .parse_next(input)
+/// }
+///
+/// assert_eq!(date.parse_peek("2024-01-09"), Ok(("", (2024, 1, 9))));
+/// assert!(date.parse_peek("2024-1-09").is_err());
+/// ```
+#[inline(always)]
+pub fn dec_uint_fixed<const N: usize, Input, Output, Error>(
+    input: &mut Input,
+) -> Result<Output, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar,
+    Output: Uint,
+    Error: ParserError<Input>,
+{
+    trace("dec_uint_fixed", move |input: &mut Input| {
+        digit_fixed::<N, _, _>
+            .verify_map(|s: <Input as Stream>::Slice| {
+                let s = s.as_bstr();
+                // SAFETY: Only 7-bit ASCII characters are parsed
+                let s = unsafe { crate::lib::std::str::from_utf8_unchecked(s) };
+                Output::try_from_dec_uint(s)
+            })
+            .parse_next(input)
+    })
+    .parse_next(input)
+}
+
+/// Metadata for parsing unsigned integers, see [`dec_uint`]
+pub trait Uint: Sized {
+    #[doc(hidden)]
+    fn try_from_dec_uint(slice: &str) -> Option<Self>;
+}
+
+impl Uint for u8 {
+    fn try_from_dec_uint(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Uint for u16 {
+    fn try_from_dec_uint(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Uint for u32 {
+    fn try_from_dec_uint(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Uint for u64 {
+    fn try_from_dec_uint(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Uint for u128 {
+    fn try_from_dec_uint(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+impl Uint for usize {
+    fn try_from_dec_uint(slice: &str) -> Option<Self> {
+        slice.parse().ok()
+    }
+}
+
+/// Decode a decimal signed integer (e.g. [`i32`])
+///
+/// *Complete version*: can parse until the end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] into an `i32`:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn dec_int(input: &mut &str) -> ModalResult<i32>
+/// # {
+/// #     winnow::ascii::dec_int.parse_next(input)
+/// # }
+/// ```
+#[doc(alias = "i8")]
+#[doc(alias = "i16")]
+#[doc(alias = "i32")]
+#[doc(alias = "i64")]
+#[doc(alias = "i128")]
+pub fn dec_int<Input, Output, Error>(input: &mut Input) -> Result<Output, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Output: Int,
+    Error: ParserError<Input>,
+{
+    trace("dec_int", move |input: &mut Input| {
+        let sign = opt(dispatch! {any.map(AsChar::as_char);
+            '+' => empty.value(true),
+            '-' => empty.value(false),
+            _ => fail,
+        });
         alt(((sign, one_of('1'..='9'), digit0).void(), one_of('0').void()))
             .take()
             .verify_map(|s: <Input as Stream>::Slice| {
@@ -1258,6 +2200,156 @@ impl Int for isize {
     }
 }
 
+/// Decode a decimal unsigned integer (e.g. [`u32`]), accepting a sign according to [`SignPolicy`]
+///
+/// Unsigned integers never accept a `-`, so [`SignPolicy::Minus`] behaves like [`SignPolicy::None`]
+/// and [`SignPolicy::Any`] like [`SignPolicy::Plus`].
+///
+/// *Complete version*: can parse until the end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] into a `u32`:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::ascii::SignPolicy;
+/// # use winnow::error::ContextError;
+/// pub fn dec_uint_with<'i>(policy: SignPolicy) -> impl Parser<&'i str, u32, ContextError>
+/// # {
+/// #     winnow::ascii::dec_uint_with(policy)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{dec_uint_with, SignPolicy};
+///
+/// fn parser(input: &mut &str) -> ModalResult<u8> {
+///     dec_uint_with(SignPolicy::Plus).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("+255"), Ok(("", 255)));
+/// assert_eq!(parser.parse_peek("255"), Ok(("", 255)));
+/// assert!(parser.parse_peek("+256").is_err());
+/// assert!(parser.parse_peek("-0").is_err());
+/// ```
+#[inline(always)]
+pub fn dec_uint_with<Input, Output, Error>(policy: SignPolicy) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Output: Uint,
+    Error: ParserError<Input>,
+{
+    trace("dec_uint_with", move |input: &mut Input| {
+        let sign = one_of(move |c: <Input as Stream>::Token| {
+            let c = c.as_char();
+            c != '-' && policy.accepts(c)
+        });
+        (
+            opt(sign),
+            alt(((one_of('1'..='9'), digit0).void(), one_of('0').void())),
+        )
+            .take()
+            .verify_map(|s: <Input as Stream>::Slice| {
+                let s = s.as_bstr();
+                // SAFETY: Only 7-bit ASCII characters are parsed
+                let s = unsafe { crate::lib::std::str::from_utf8_unchecked(s) };
+                Output::try_from_dec_uint(s)
+            })
+            .parse_next(input)
+    })
+}
+
+/// Decode a decimal signed integer (e.g. [`i32`]), accepting a sign according to [`SignPolicy`]
+///
+/// Unlike [`dec_int`], a sign is accepted before `0`, with `-0` and `+0` both decoding to `0`.
+///
+/// *Complete version*: can parse until the end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] into an `i32`:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::ascii::SignPolicy;
+/// # use winnow::error::ContextError;
+/// pub fn dec_int_with<'i>(policy: SignPolicy) -> impl Parser<&'i str, i32, ContextError>
+/// # {
+/// #     winnow::ascii::dec_int_with(policy)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{dec_int_with, SignPolicy};
+///
+/// fn parser(input: &mut &str) -> ModalResult<i8> {
+///     dec_int_with(SignPolicy::Minus).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("-128"), Ok(("", -128)));
+/// assert_eq!(parser.parse_peek("-0"), Ok(("", 0)));
+/// assert_eq!(parser.parse_peek("127"), Ok(("", 127)));
+/// assert!(parser.parse_peek("+1").is_err());
+/// ```
+#[inline(always)]
+pub fn dec_int_with<Input, Output, Error>(policy: SignPolicy) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Output: Int,
+    Error: ParserError<Input>,
+{
+    trace("dec_int_with", move |input: &mut Input| {
+        let sign = one_of(move |c: <Input as Stream>::Token| policy.accepts(c.as_char()));
+        (
+            opt(sign),
+            alt(((one_of('1'..='9'), digit0).void(), one_of('0').void())),
+        )
+            .take()
+            .verify_map(|s: <Input as Stream>::Slice| {
+                let s = s.as_bstr();
+                // SAFETY: Only 7-bit ASCII characters are parsed
+                let s = unsafe { crate::lib::std::str::from_utf8_unchecked(s) };
+                Output::try_from_dec_int(s)
+            })
+            .parse_next(input)
+    })
+}
+
+/// Which signs to accept before a number, see [`dec_uint_with`] and [`dec_int_with`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SignPolicy {
+    /// Reject any sign
+    None,
+    /// Accept an optional `+`
+    Plus,
+    /// Accept an optional `-`
+    Minus,
+    /// Accept an optional `+` or `-`, like [`dec_int`]
+    Any,
+}
+
+impl SignPolicy {
+    fn accepts(self, c: char) -> bool {
+        matches!(
+            (self, c),
+            (SignPolicy::Plus | SignPolicy::Any, '+') | (SignPolicy::Minus | SignPolicy::Any, '-')
+        )
+    }
+}
+
 /// Decode a variable-width hexadecimal integer (e.g. [`u32`])
 ///
 /// *Complete version*: Will parse until the end of input if it has fewer characters than the type
@@ -1393,18 +2485,346 @@ impl HexUint for u32 {
     }
 }
 
-impl HexUint for u64 {
-    #[inline(always)]
-    fn max_nibbles(_: sealed::SealedMarker) -> usize {
-        16
+impl HexUint for u64 {
+    #[inline(always)]
+    fn max_nibbles(_: sealed::SealedMarker) -> usize {
+        16
+    }
+}
+
+impl HexUint for u128 {
+    #[inline(always)]
+    fn max_nibbles(_: sealed::SealedMarker) -> usize {
+        32
+    }
+}
+
+/// Decode pairs of hex digits into bytes, case-insensitively
+///
+/// `occurrences` is the number of decoded bytes.  Parsing stops at the first character that is
+/// not a hex digit, like whitespace.
+///
+/// When there is an odd number of digits, the error points at the final digit.
+///
+/// *Complete version*: Returns an error if there are too few bytes or a pair is cut off by the
+/// end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if the end of input
+/// is reached before `occurrences` is exhausted, including in the middle of a pair.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] with `1..`:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn hex_bytes(input: &mut &str) -> ModalResult<Vec<u8>>
+/// # {
+/// #     winnow::ascii::hex_bytes(1..).parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::hex_bytes;
+///
+/// fn parser(s: &mut &str) -> ModalResult<Vec<u8>> {
+///   hex_bytes(1..).parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek("deadBEEF;"), Ok((";", vec![0xde, 0xad, 0xbe, 0xef])));
+/// assert_eq!(parser.parse_peek("dead beef"), Ok((" beef", vec![0xde, 0xad])));
+/// assert!(parser.parse_peek("dea").is_err());
+/// assert!(parser.parse_peek(";").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::Partial;
+/// use winnow::ascii::hex_bytes;
+///
+/// fn parser(s: &mut Partial<&str>) -> ModalResult<Vec<u8>> {
+///   hex_bytes(1..).parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new("dead;")), Ok((Partial::new(";"), vec![0xde, 0xad])));
+/// assert_eq!(parser.parse_peek(Partial::new("dea")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+#[doc(alias = "hex_decode")]
+pub fn hex_bytes<Input, Error>(
+    occurrences: impl Into<Range>,
+) -> impl Parser<Input, crate::lib::std::vec::Vec<u8>, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    let Range {
+        start_inclusive,
+        end_inclusive,
+    } = occurrences.into();
+    trace("hex_bytes", move |input: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            hex_bytes_::<_, _, true>(input, start_inclusive, end_inclusive)
+        } else {
+            hex_bytes_::<_, _, false>(input, start_inclusive, end_inclusive)
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn hex_bytes_<I, E, const PARTIAL: bool>(
+    input: &mut I,
+    min: usize,
+    max: Option<usize>,
+) -> Result<crate::lib::std::vec::Vec<u8>, E>
+where
+    I: StreamIsPartial + Stream,
+    <I as Stream>::Token: AsChar,
+    E: ParserError<I>,
+{
+    let mut bytes = crate::lib::std::vec::Vec::new();
+    while max.map(|max| bytes.len() < max).unwrap_or(true) {
+        let start = input.checkpoint();
+        let high = match input.next_token() {
+            Some(c) => c.as_char().to_digit(16),
+            None if PARTIAL && input.is_partial() => {
+                return Err(ParserError::incomplete(input, Needed::new(1)));
+            }
+            None => None,
+        };
+        let Some(high) = high else {
+            input.reset(&start);
+            break;
+        };
+        match input.next_token().map(|c| c.as_char().to_digit(16)) {
+            Some(Some(low)) => bytes.push((high << 4 | low) as u8),
+            None if PARTIAL && input.is_partial() => {
+                return Err(ParserError::incomplete(input, Needed::new(1)));
+            }
+            _ => {
+                // Unpaired digit
+                input.reset(&start);
+                return Err(ParserError::from_input(input));
+            }
+        }
+    }
+    if bytes.len() < min {
+        return Err(ParserError::from_input(input));
+    }
+    Ok(bytes)
+}
+
+/// Decode [base64](https://www.rfc-editor.org/rfc/rfc4648#section-4) with the standard
+/// alphabet and padding
+///
+/// Parsing stops at the first character outside of the alphabet, between groups of 4, or after
+/// padding.  For base64 broken across lines, like in PEM, see [`base64_wrapped`].
+///
+/// On error, the input points at the offending character, like a group of 4 that is cut off.
+///
+/// *Complete version*: Returns an error if a group of 4 is cut off by the end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if the end of input
+/// is reached before padding, including in the middle of a group of 4.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn base64(input: &mut &str) -> ModalResult<Vec<u8>>
+/// # {
+/// #     winnow::ascii::base64.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::base64;
+///
+/// fn parser(s: &mut &str) -> ModalResult<Vec<u8>> {
+///   base64(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek("aGk=;"), Ok((";", b"hi".to_vec())));
+/// assert_eq!(parser.parse_peek("aGVsbG8h;"), Ok((";", b"hello!".to_vec())));
+/// assert_eq!(parser.parse_peek(";"), Ok((";", vec![])));
+/// assert!(parser.parse_peek("aGk;").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::Partial;
+/// use winnow::ascii::base64;
+///
+/// fn parser(s: &mut Partial<&str>) -> ModalResult<Vec<u8>> {
+///   base64(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new("aGk=;")), Ok((Partial::new(";"), b"hi".to_vec())));
+/// assert_eq!(parser.parse_peek(Partial::new("aG")), Err(ErrMode::Incomplete(Needed::new(2))));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn base64<Input, Error>(input: &mut Input) -> Result<crate::lib::std::vec::Vec<u8>, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("base64", move |input: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            base64_::<_, _, true>(input, false)
+        } else {
+            base64_::<_, _, false>(input, false)
+        }
+    })
+    .parse_next(input)
+}
+
+/// Decode [`base64`] that may be broken across lines
+///
+/// Line endings (`\n` and `\r\n`) are skipped anywhere in the base64, like in PEM.  A trailing line
+/// ending is not consumed.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn base64_wrapped(input: &mut &str) -> ModalResult<Vec<u8>>
+/// # {
+/// #     winnow::ascii::base64_wrapped.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::base64_wrapped;
+///
+/// fn parser(s: &mut &str) -> ModalResult<Vec<u8>> {
+///   base64_wrapped(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek("aGVs\nbG8h\n-----"), Ok(("\n-----", b"hello!".to_vec())));
+/// assert_eq!(parser.parse_peek("aG\r\nk=\n"), Ok(("\n", b"hi".to_vec())));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn base64_wrapped<Input, Error>(
+    input: &mut Input,
+) -> Result<crate::lib::std::vec::Vec<u8>, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("base64_wrapped", move |input: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            base64_::<_, _, true>(input, true)
+        } else {
+            base64_::<_, _, false>(input, true)
+        }
+    })
+    .parse_next(input)
+}
+
+#[cfg(feature = "alloc")]
+fn base64_<I, E, const PARTIAL: bool>(
+    input: &mut I,
+    wrapped: bool,
+) -> Result<crate::lib::std::vec::Vec<u8>, E>
+where
+    I: StreamIsPartial + Stream,
+    <I as Stream>::Token: AsChar,
+    E: ParserError<I>,
+{
+    let mut bytes = crate::lib::std::vec::Vec::new();
+    loop {
+        let group_start = input.checkpoint();
+        let mut group = [0u8; 4];
+        let mut len = 0;
+        let mut padding = 0;
+        while len < 4 {
+            let start = loop {
+                let start = input.checkpoint();
+                match input.next_token().map(|c| c.as_char()) {
+                    Some('\n') if wrapped => {}
+                    Some('\r') if wrapped => {
+                        if input.next_token().map(|c| c.as_char()) != Some('\n') {
+                            input.reset(&start);
+                            break start;
+                        }
+                    }
+                    Some(_) => {
+                        input.reset(&start);
+                        break start;
+                    }
+                    None => break start,
+                }
+            };
+            let c = match input.next_token() {
+                Some(c) => c.as_char(),
+                None if PARTIAL && input.is_partial() => {
+                    let needed = if len == 0 { 1 } else { 4 - len };
+                    return Err(ParserError::incomplete(input, Needed::new(needed)));
+                }
+                None if len == 0 => {
+                    input.reset(&group_start);
+                    return Ok(bytes);
+                }
+                None => return Err(ParserError::from_input(input)),
+            };
+            match base64_value(c) {
+                Some(value) if padding == 0 => {
+                    group[len] = value;
+                }
+                _ if c == '=' && 2 <= len => {
+                    padding += 1;
+                }
+                _ if len == 0 => {
+                    input.reset(&group_start);
+                    return Ok(bytes);
+                }
+                _ => {
+                    input.reset(&start);
+                    return Err(ParserError::from_input(input));
+                }
+            }
+            len += 1;
+        }
+        let group = u32::from(group[0]) << 18
+            | u32::from(group[1]) << 12
+            | u32::from(group[2]) << 6
+            | u32::from(group[3]);
+        bytes.extend_from_slice(&group.to_be_bytes()[1..4 - padding]);
+        if padding != 0 {
+            return Ok(bytes);
+        }
     }
 }
 
-impl HexUint for u128 {
-    #[inline(always)]
-    fn max_nibbles(_: sealed::SealedMarker) -> usize {
-        32
-    }
+#[cfg(feature = "alloc")]
+fn base64_value(c: char) -> Option<u8> {
+    let value = match c {
+        'A'..='Z' => c as u8 - b'A',
+        'a'..='z' => c as u8 - b'a' + 26,
+        '0'..='9' => c as u8 - b'0' + 52,
+        '+' => 62,
+        '/' => 63,
+        _ => return None,
+    };
+    Some(value)
 }
 
 /// Recognizes floating point number in text format and returns a [`f32`] or [`f64`].
@@ -1478,6 +2898,78 @@ where
     .parse_next(input)
 }
 
+/// Recognizes floating point number in text format, treating the end of a
+/// [partial][crate::_topic::partial] buffer as the end of the number when the caller knows it is.
+///
+/// [`float`] reports [`Incomplete`][crate::error::ErrMode::Incomplete] when a number runs up to
+/// the end of the buffer as more digits could follow.  When framing already guarantees that the
+/// next chunk cannot extend the number, like when a delimiter is known to follow, that is an
+/// unnecessary round-trip to buffer more data.
+///
+/// On `Incomplete`, `is_terminated` is called with the input reset to the start of the number.
+/// Returning `true` promises that the number ends with the buffer, parsing it as if the stream was
+/// complete.  Returning `false` reports `Incomplete` as usual.
+///
+/// <div class="warning">
+///
+/// **Note:** if `is_terminated` returns `true` and more of the number is in the next chunk, the
+/// number will be silently truncated, e.g. `12` from `12` + `3`.  Partial exponents are not
+/// parsed, e.g. `1e` is parsed as `1`, leaving `e`.
+///
+/// </div>
+///
+/// *Complete version*: Same as [`float`], `is_terminated` is never called.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::Partial;
+/// use winnow::ascii::float_terminated;
+///
+/// // Framing is by line, so a number at the end of a complete line can't continue
+/// fn value<'s>(s: &mut Partial<&'s str>, line_complete: bool) -> ModalResult<f64> {
+///   float_terminated(|_: &Partial<&'s str>| line_complete).parse_next(s)
+/// }
+///
+/// let mut input = Partial::new("11e-1");
+/// assert_eq!(value(&mut input, true), Ok(1.1));
+/// let mut input = Partial::new("11e-1");
+/// assert_eq!(value(&mut input, false), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+#[allow(clippy::trait_duplication_in_bounds)] // HACK: clippy 1.64.0 bug
+pub fn float_terminated<Input, Output, Error, Terminated>(
+    mut is_terminated: Terminated,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<Caseless<&'static str>> + Compare<char> + AsBStr,
+    <Input as Stream>::Slice: ParseSlice<Output>,
+    <Input as Stream>::Token: AsChar + Clone,
+    <Input as Stream>::IterOffsets: Clone,
+    Error: ParserError<Input>,
+    Terminated: FnMut(&Input) -> bool,
+{
+    trace("float_terminated", move |input: &mut Input| {
+        let start = input.checkpoint();
+        match float::<_, _, Error>(input) {
+            Err(e) if e.is_incomplete() => {
+                input.reset(&start);
+                if is_terminated(input) {
+                    let state = input.complete();
+                    let res = float(input);
+                    input.restore_partial(state);
+                    res
+                } else {
+                    Err(e)
+                }
+            }
+            res => res,
+        }
+    })
+}
+
 #[allow(clippy::trait_duplication_in_bounds)] // HACK: clippy 1.64.0 bug
 fn take_float_or_exceptions<I, E: ParserError<I>>(input: &mut I) -> Result<<I as Stream>::Slice, E>
 where
@@ -1534,17 +3026,69 @@ where
     .parse_next(input)
 }
 
+/// Parse a [`FromStr`][crate::lib::std::str::FromStr] type from the longest slice of tokens
+/// within `set`
+///
+/// This is like <code>[take_while](occurrences, set).[parse_to](Parser::parse_to)()</code>
+/// for types that need to be given exactly their own text, like `Ipv4Addr`.
+/// On failure, the error points to the start of the slice and has the name of `Output` attached
+/// as [`StrContext::Expected`].
+///
+/// *Complete version*: Will return an error if there's not enough input data,
+/// or if the slice doesn't parse.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;
+/// # use std::net::Ipv4Addr;
+/// use winnow::ascii::from_str_while;
+/// use winnow::stream::AsChar;
+///
+/// fn addr(input: &mut &str) -> ModalResult<Ipv4Addr> {
+///     from_str_while(1.., (AsChar::is_dec_digit, '.')).parse_next(input)
+/// }
+///
+/// assert_eq!(addr.parse_peek("127.0.0.1:80"), Ok((":80", Ipv4Addr::LOCALHOST)));
+/// assert!(addr.parse_peek("127.0.0.256:80").is_err());
+/// # }
+/// ```
+#[inline(always)]
+pub fn from_str_while<Output, Input, Set, Error>(
+    occurrences: impl Into<Range>,
+    set: Set,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: ParseSlice<Output>,
+    Set: ContainsToken<<Input as Stream>::Token>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    let occurrences = occurrences.into();
+    trace(
+        "from_str_while",
+        take_while(occurrences, set)
+            .parse_to()
+            .context(StrContext::Expected(StrContextValue::Description(
+                crate::lib::std::any::type_name::<Output>(),
+            ))),
+    )
+}
+
 /// Recognize the input slice with escaped characters.
 ///
 /// Arguments:
 /// - `normal`: unescapeable characters
 ///   - Must not include `control`
+///   - Must not accept empty input
 /// - `control_char`: e.g. `\` for strings in most languages
 /// - `escape`: parse and transform the escaped character
 ///
 /// Parsing ends when:
 /// - `alt(normal, control._char)` [`Backtrack`s][crate::error::ErrMode::Backtrack]
-/// - `normal` doesn't advance the input stream
 /// - *(complete)* input stream is exhausted
 ///
 /// See also [`escaped_transform`]
@@ -1695,18 +3239,18 @@ where
 /// Arguments:
 /// - `normal`: unescapeable characters
 ///   - Must not include `control`
+///   - Must not accept empty input
 /// - `control_char`: e.g. `\` for strings in most languages
 /// - `escape`: parse and transform the escaped character
 ///
 /// Parsing ends when:
 /// - `alt(normal, control._char)` [`Backtrack`s][crate::error::ErrMode::Backtrack]
-/// - `normal` doesn't advance the input stream
 /// - *(complete)* input stream is exhausted
 ///
 /// <div class="warning">
 ///
-/// **Warning:** If the `normal` parser passed to `escaped_transform` accepts empty inputs
-/// (like `alpha0` or `digit0`), `escaped_transform` will return an error,
+/// **Warning:** If the `normal` parser passed to `escaped` accepts empty inputs
+/// (like `alpha0` or `digit0`), `escaped` will return an error,
 /// to prevent going into an infinite loop.
 ///
 /// </div>
@@ -1798,6 +3342,250 @@ where
     })
 }
 
+/// Parse escaped characters, unescaping them, with limits on the length of the matched input
+///
+/// Like [`escaped`], except that the input consumed, as measured by
+/// [`Offset::offset_from`][crate::stream::Offset::offset_from] the start, must fall within
+/// `occurrences`, or else a [`Backtrack`][crate::error::ErrMode::Backtrack] is
+/// returned without consuming anything.  For example, `1..` rejects an empty match.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;
+/// use winnow::ascii::escaped_with_limits;
+/// use winnow::ascii::alpha1;
+/// use winnow::combinator::alt;
+///
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<String> {
+///   escaped_with_limits(
+///     1..,
+///     alpha1,
+///     '\\',
+///     alt((
+///       "\\".value("\\"),
+///       "\"".value("\""),
+///       "n".value("\n"),
+///     ))
+///   ).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("ab\\\"cd"), Ok(("", String::from("ab\"cd"))));
+/// assert!(parser.parse_peek("").is_err());
+/// assert!(parser.parse_peek(";").is_err());
+/// # }
+/// ```
+#[inline(always)]
+pub fn escaped_with_limits<Input, Error, Normal, NormalOutput, Escape, EscapeOutput, Output>(
+    occurrences: impl Into<Range>,
+    mut normal: Normal,
+    control_char: char,
+    mut escape: Escape,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<char>,
+    Normal: Parser<Input, NormalOutput, Error>,
+    Escape: Parser<Input, EscapeOutput, Error>,
+    Output: crate::stream::Accumulate<NormalOutput>,
+    Output: crate::stream::Accumulate<EscapeOutput>,
+    Error: ParserError<Input>,
+{
+    let Range {
+        start_inclusive,
+        end_inclusive,
+    } = occurrences.into();
+    trace("escaped_with_limits", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let res = if <Input as StreamIsPartial>::is_partial_supported() && input.is_partial() {
+            escaped_transform_internal::<_, _, _, _, _, _, _, true>(
+                input,
+                &mut normal,
+                control_char,
+                &mut escape,
+            )
+        } else {
+            escaped_transform_internal::<_, _, _, _, _, _, _, false>(
+                input,
+                &mut normal,
+                control_char,
+                &mut escape,
+            )
+        }?;
+
+        let len = input.offset_from(&start);
+        if len < start_inclusive || end_inclusive.map(|end| end < len).unwrap_or(false) {
+            input.reset(&start);
+            return Err(ParserError::from_input(input));
+        }
+        Ok(res)
+    })
+}
+
+/// Parse escaped characters, returning both the raw slice and the unescaped value in one pass
+///
+/// Like running [`take_escaped`] and [`escaped`] over the same input, without parsing it twice.
+/// The value borrows from the input when there are no escapes, only allocating once an escape is
+/// found.
+///
+/// Arguments:
+/// - `normal`: unescapeable characters
+///   - Must not include `control`
+///   - Must not accept empty input
+/// - `control_char`: e.g. `\` for strings in most languages
+/// - `escape`: parse and transform the escaped character
+///
+/// Parsing ends when:
+/// - `alt(normal, control._char)` [`Backtrack`s][crate::error::ErrMode::Backtrack]
+/// - *(complete)* input stream is exhausted
+///
+/// <div class="warning">
+///
+/// **Warning:** If the `normal` parser passed to `escaped_with_raw` accepts empty inputs
+/// (like `alpha0` or `digit0`), `escaped_with_raw` will return an error,
+/// to prevent going into an infinite loop.
+///
+/// </div>
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use std::borrow::Cow;
+/// use winnow::ascii::alpha1;
+/// use winnow::ascii::escaped_with_raw;
+/// use winnow::combinator::alt;
+///
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<(&'s str, Cow<'s, str>)> {
+///   escaped_with_raw(
+///     alpha1,
+///     '\\',
+///     alt((
+///       "\\".value("\\"),
+///       "\"".value("\""),
+///       "n".value("\n"),
+///     ))
+///   ).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("abcd;"), Ok((";", ("abcd", Cow::Borrowed("abcd")))));
+/// assert_eq!(
+///     parser.parse_peek("ab\\ncd;"),
+///     Ok((";", ("ab\\ncd", Cow::Owned(String::from("ab\ncd")))))
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn escaped_with_raw<'i, Input, Error, Normal, NormalOutput, Escape, EscapeOutput>(
+    mut normal: Normal,
+    control_char: char,
+    mut escape: Escape,
+) -> impl Parser<Input, (&'i str, crate::lib::std::borrow::Cow<'i, str>), Error>
+where
+    Input: StreamIsPartial + Stream<Slice = &'i str> + Compare<char>,
+    Normal: Parser<Input, NormalOutput, Error>,
+    Escape: Parser<Input, EscapeOutput, Error>,
+    crate::lib::std::string::String: crate::stream::Accumulate<EscapeOutput>,
+    Error: ParserError<Input>,
+{
+    trace("escaped_with_raw", move |input: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() && input.is_partial() {
+            escaped_with_raw_internal::<_, _, _, _, _, _, true>(
+                input,
+                &mut normal,
+                control_char,
+                &mut escape,
+            )
+        } else {
+            escaped_with_raw_internal::<_, _, _, _, _, _, false>(
+                input,
+                &mut normal,
+                control_char,
+                &mut escape,
+            )
+        }
+    })
+}
+
+#[cfg(feature = "alloc")]
+fn escaped_with_raw_internal<'i, I, Error, F, NormalOutput, G, EscapeOutput, const PARTIAL: bool>(
+    input: &mut I,
+    normal: &mut F,
+    control_char: char,
+    transform: &mut G,
+) -> Result<(&'i str, crate::lib::std::borrow::Cow<'i, str>), Error>
+where
+    I: StreamIsPartial,
+    I: Stream<Slice = &'i str>,
+    I: Compare<char>,
+    F: Parser<I, NormalOutput, Error>,
+    G: Parser<I, EscapeOutput, Error>,
+    crate::lib::std::string::String: crate::stream::Accumulate<EscapeOutput>,
+    Error: ParserError<I>,
+{
+    use crate::lib::std::borrow::Cow;
+    use crate::lib::std::string::String;
+    use crate::stream::{Accumulate, Offset};
+
+    let start = input.checkpoint();
+    // Only allocated once an escape is found
+    let mut value: Option<String> = None;
+
+    while input.eof_offset() > 0 {
+        let current = input.checkpoint();
+        let current_len = input.eof_offset();
+
+        match opt(normal.by_ref()).parse_next(input)? {
+            Some(_) => {
+                // infinite loop check: the parser must always consume
+                if input.eof_offset() == current_len {
+                    return Err(ParserError::assert(
+                        input,
+                        "`escaped_with_raw` parsers must always consume",
+                    ));
+                }
+                if let Some(value) = value.as_mut() {
+                    let len = input.offset_from(&current);
+                    input.reset(&current);
+                    value.push_str(input.next_slice(len));
+                }
+            }
+            None => {
+                if opt(control_char).parse_next(input)?.is_some() {
+                    let o = transform.parse_next(input)?;
+                    let value = value.get_or_insert_with(|| {
+                        let end = input.checkpoint();
+                        let len = current.offset_from(&start);
+                        input.reset(&start);
+                        let prefix = input.next_slice(len);
+                        input.reset(&end);
+                        let mut value =
+                            <String as Accumulate<&str>>::initial(Some(len + input.eof_offset()));
+                        value.push_str(prefix);
+                        value
+                    });
+                    value.accumulate(o);
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+
+    if PARTIAL && input.is_partial() && input.eof_offset() == 0 {
+        return Err(ParserError::incomplete(input, Needed::Unknown));
+    }
+
+    let len = input.offset_from(&start);
+    input.reset(&start);
+    let raw = input.next_slice(len);
+    let value = match value {
+        Some(value) => Cow::Owned(value),
+        None => Cow::Borrowed(raw),
+    };
+    Ok((raw, value))
+}
+
 fn escaped_transform_internal<
     I,
     Error,
@@ -1836,7 +3624,7 @@ where
                 if input.eof_offset() == current_len {
                     return Err(ParserError::assert(
                         input,
-                        "`escaped_transform` parsers must always consume",
+                        "`escaped` parsers must always consume",
                     ));
                 }
             }
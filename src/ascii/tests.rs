@@ -8,9 +8,11 @@ mod complete {
     use proptest::prelude::*;
 
     use crate::combinator::alt;
+    use crate::combinator::preceded;
     use crate::error::ErrMode;
     use crate::error::InputError;
     use crate::prelude::*;
+    use crate::stream::LocatingSlice;
     use crate::stream::ParseSlice;
     use crate::token::none_of;
     use crate::token::one_of;
@@ -1150,6 +1152,85 @@ Ok(
     ),
 )
 
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn line_tests() {
+        #[allow(clippy::type_complexity)]
+        fn lines<'i>(i: &mut &'i str) -> TestResult<&'i str, (&'i str, &'i str, &'i str, &'i str)> {
+            (line, line, line, line).parse_next(i)
+        }
+        assert_parse!(
+            lines.parse_peek("a\r\n\nb\rc\nd"),
+            str![[r#"
+Ok(
+    (
+        "",
+        (
+            "a",
+            "",
+            "b\rc",
+            "d",
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            line.parse_peek("ab\r"),
+            str![[r#"
+Ok(
+    (
+        "",
+        "ab\r",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            line::<_, ErrMode<InputError<_>>>.parse_peek(""),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            line_inclusive.parse_peek("a\r\nb"),
+            str![[r#"
+Ok(
+    (
+        "b",
+        "a\r\n",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            line_inclusive.parse_peek("\nb"),
+            str![[r#"
+Ok(
+    (
+        "b",
+        "\n",
+    ),
+)
+
 "#]]
             .raw()
         );
@@ -1603,7 +1684,6 @@ Err(
     Backtrack(
         InputError {
             input: [
-                45,
                 48,
                 59,
             ],
@@ -1685,21 +1765,70 @@ Err(
     }
 
     #[test]
-    fn hex_uint_tests() {
-        fn hex_u32<'i>(input: &mut &'i [u8]) -> TestResult<&'i [u8], u32> {
-            hex_uint.parse_next(input)
+    fn dec_sign_policy_tests() {
+        fn uint(policy: SignPolicy, input: &str) -> Option<u8> {
+            dec_uint_with::<_, u8, InputError<_>>(policy)
+                .parse(input)
+                .ok()
+        }
+        fn int(policy: SignPolicy, input: &str) -> Option<i8> {
+            dec_int_with::<_, i8, InputError<_>>(policy)
+                .parse(input)
+                .ok()
+        }
+
+        for policy in [SignPolicy::None, SignPolicy::Minus] {
+            assert_eq!(uint(policy, "255"), Some(u8::MAX), "{policy:?}");
+            assert_eq!(uint(policy, "+255"), None, "{policy:?}");
+            assert_eq!(uint(policy, "256"), None, "{policy:?}");
+            assert_eq!(uint(policy, "-0"), None, "{policy:?}");
+        }
+        for policy in [SignPolicy::Plus, SignPolicy::Any] {
+            assert_eq!(uint(policy, "255"), Some(u8::MAX), "{policy:?}");
+            assert_eq!(uint(policy, "+255"), Some(u8::MAX), "{policy:?}");
+            assert_eq!(uint(policy, "+256"), None, "{policy:?}");
+            assert_eq!(uint(policy, "+0"), Some(0), "{policy:?}");
+            assert_eq!(uint(policy, "-0"), None, "{policy:?}");
+            assert_eq!(uint(policy, "++1"), None, "{policy:?}");
+        }
+
+        assert_eq!(int(SignPolicy::None, "127"), Some(i8::MAX));
+        assert_eq!(int(SignPolicy::None, "128"), None);
+        assert_eq!(int(SignPolicy::None, "-128"), None);
+        assert_eq!(int(SignPolicy::None, "+1"), None);
+
+        assert_eq!(int(SignPolicy::Plus, "+127"), Some(i8::MAX));
+        assert_eq!(int(SignPolicy::Plus, "+128"), None);
+        assert_eq!(int(SignPolicy::Plus, "-128"), None);
+        assert_eq!(int(SignPolicy::Plus, "+0"), Some(0));
+
+        assert_eq!(int(SignPolicy::Minus, "-128"), Some(i8::MIN));
+        assert_eq!(int(SignPolicy::Minus, "-129"), None);
+        assert_eq!(int(SignPolicy::Minus, "-0"), Some(0));
+        assert_eq!(int(SignPolicy::Minus, "+127"), None);
+
+        assert_eq!(int(SignPolicy::Any, "-128"), Some(i8::MIN));
+        assert_eq!(int(SignPolicy::Any, "+127"), Some(i8::MAX));
+        assert_eq!(int(SignPolicy::Any, "+128"), None);
+        assert_eq!(int(SignPolicy::Any, "-0"), Some(0));
+        assert_eq!(int(SignPolicy::Any, "+0"), Some(0));
+        assert_eq!(int(SignPolicy::Any, "-+1"), None);
+        assert_eq!(int(SignPolicy::Any, "-01"), None);
+    }
+
+    #[test]
+    fn digit_fixed_tests() {
+        fn year<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            digit_fixed::<4, _, _>.parse_next(input)
         }
 
         assert_parse!(
-            hex_u32.parse_peek(&b";"[..]),
+            year.parse_peek("2024-"),
             str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: [
-                59,
-            ],
-        },
+Ok(
+    (
+        "-",
+        "2024",
     ),
 )
 
@@ -1707,14 +1836,12 @@ Err(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(&b"ff;"[..]),
+            year.parse_peek("202401"),
             str![[r#"
 Ok(
     (
-        [
-            59,
-        ],
-        255,
+        "01",
+        "2024",
     ),
 )
 
@@ -1722,14 +1849,13 @@ Ok(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(&b"1be2;"[..]),
+            year.parse_peek("202-"),
             str![[r#"
-Ok(
-    (
-        [
-            59,
-        ],
-        7138,
+Err(
+    Backtrack(
+        InputError {
+            input: "202-",
+        },
     ),
 )
 
@@ -1737,29 +1863,37 @@ Ok(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(&b"c5a31be2;"[..]),
+            year.parse_peek("20a4"),
             str![[r#"
-Ok(
-    (
-        [
-            59,
-        ],
-        3315801058,
+Err(
+    Backtrack(
+        InputError {
+            input: "20a4",
+        },
     ),
 )
 
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn dec_uint_fixed_tests() {
+        fn month<'i>(input: &mut &'i str) -> TestResult<&'i str, u8> {
+            dec_uint_fixed::<2, _, _, _>.parse_next(input)
+        }
+        fn byte<'i>(input: &mut &'i str) -> TestResult<&'i str, u8> {
+            dec_uint_fixed::<3, _, _, _>.parse_next(input)
+        }
+
         assert_parse!(
-            hex_u32.parse_peek(&b"C5A31be2;"[..]),
+            month.parse_peek("09-"),
             str![[r#"
 Ok(
     (
-        [
-            59,
-        ],
-        3315801058,
+        "-",
+        9,
     ),
 )
 
@@ -1767,25 +1901,12 @@ Ok(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(&b"00c5a31be2;"[..]), // overflow
+            month.parse_peek("123"),
             str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: [
-                48,
-                48,
-                99,
-                53,
-                97,
-                51,
-                49,
-                98,
-                101,
-                50,
-                59,
-            ],
-        },
+Ok(
+    (
+        "3",
+        12,
     ),
 )
 
@@ -1793,24 +1914,12 @@ Err(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(&b"c5a31be201;"[..]), // overflow
+            month.parse_peek("1-"),
             str![[r#"
 Err(
     Backtrack(
         InputError {
-            input: [
-                99,
-                53,
-                97,
-                51,
-                49,
-                98,
-                101,
-                50,
-                48,
-                49,
-                59,
-            ],
+            input: "1-",
         },
     ),
 )
@@ -1819,14 +1928,12 @@ Err(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(&b"ffffffff;"[..]),
+            byte.parse_peek("255"),
             str![[r#"
 Ok(
     (
-        [
-            59,
-        ],
-        4294967295,
+        "",
+        255,
     ),
 )
 
@@ -1834,30 +1941,12 @@ Ok(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(&b"ffffffffffffffff;"[..]), // overflow
+            byte.parse_peek("256"),
             str![[r#"
 Err(
     Backtrack(
         InputError {
-            input: [
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                59,
-            ],
+            input: "256",
         },
     ),
 )
@@ -1865,31 +1954,53 @@ Err(
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn from_str_while_tests() {
+        use std::net::Ipv4Addr;
+
+        fn addr(input: &mut &str) -> ModalResult<Ipv4Addr> {
+            from_str_while(1.., (AsChar::is_dec_digit, '.')).parse_next(input)
+        }
+
+        assert_eq!(
+            addr.parse_peek("127.0.0.1:80"),
+            Ok((":80", Ipv4Addr::LOCALHOST))
+        );
+
+        let err = addr.parse("127.0.0.256").unwrap_err();
+        assert_eq!(err.offset(), 0);
+        // `type_name` is not guaranteed to be stable across compiler versions
+        let context = err.inner().to_string();
+        assert!(context.starts_with("expected "), "{context}");
+        assert!(context.ends_with("::Ipv4Addr"), "{context}");
+
+        let err = addr.parse(":80").unwrap_err();
+        assert_eq!(err.offset(), 0);
+        // `type_name` is not guaranteed to be stable across compiler versions
+        let context = err.inner().to_string();
+        assert!(context.starts_with("expected "), "{context}");
+        assert!(context.ends_with("::Ipv4Addr"), "{context}");
+    }
+
+    #[test]
+    fn take_while_ascii_tests() {
+        fn blank<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            take_while_ascii(0.., (' ', '\t')).parse_next(input)
+        }
+        fn short_alpha<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            take_while_ascii(2..=4, AsChar::is_alpha).parse_next(input)
+        }
+
         assert_parse!(
-            hex_u32.parse_peek(&b"ffffffffffffffff"[..]), // overflow
+            blank.parse_peek(" \t é"),
             str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: [
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-                102,
-            ],
-        },
+Ok(
+    (
+        "é",
+        " \t ",
     ),
 )
 
@@ -1897,19 +2008,12 @@ Err(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(&b"0x1be2;"[..]),
+            blank.parse_peek("é "),
             str![[r#"
 Ok(
     (
-        [
-            120,
-            49,
-            98,
-            101,
-            50,
-            59,
-        ],
-        0,
+        "é ",
+        "",
     ),
 )
 
@@ -1917,88 +2021,64 @@ Ok(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(&b"12af"[..]),
+            blank.parse_peek("  \t"),
             str![[r#"
 Ok(
     (
-        [],
-        4783,
+        "",
+        "  \t",
     ),
 )
 
 "#]]
             .raw()
         );
-    }
+        assert_parse!(
+            blank.parse_peek(""),
+            str![[r#"
+Ok(
+    (
+        "",
+        "",
+    ),
+)
 
-    #[test]
-    #[cfg(feature = "std")]
-    fn float_test() {
-        let test_cases = [
-            "+3.14",
-            "3.14",
-            "-3.14",
-            "0",
-            "0.0",
-            "1.",
-            ".789",
-            "-.5",
-            "1e7",
-            "-1E-7",
-            ".3e-2",
-            "1.e4",
-            "1.2e4",
-            "12.34",
-            "-1.234E-12",
-            "-1.234e-12",
-            "0.00000000000000000087",
-            "inf",
-            "Inf",
-            "infinity",
-            "Infinity",
-            "-inf",
-            "-Inf",
-            "-infinity",
-            "-Infinity",
-            "+inf",
-            "+Inf",
-            "+infinity",
-            "+Infinity",
-        ];
-
-        for test in test_cases {
-            let expected32 = str::parse::<f32>(test).unwrap();
-            let expected64 = str::parse::<f64>(test).unwrap();
-
-            println!("now parsing: {test} -> {expected32}");
-
-            assert_eq!(
-                float::<_, _, InputError<_>>.parse_peek(test.as_bytes()),
-                Ok((&b""[..], expected32))
-            );
-            assert_eq!(
-                float::<_, _, InputError<_>>.parse_peek(test),
-                Ok(("", expected32))
-            );
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            short_alpha.parse_peek("abcdeé"),
+            str![[r#"
+Ok(
+    (
+        "eé",
+        "abcd",
+    ),
+)
 
-            assert_eq!(
-                float::<_, _, InputError<_>>.parse_peek(test.as_bytes()),
-                Ok((&b""[..], expected64))
-            );
-            assert_eq!(
-                float::<_, _, InputError<_>>.parse_peek(test),
-                Ok(("", expected64))
-            );
-        }
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            short_alpha.parse_peek("abé"),
+            str![[r#"
+Ok(
+    (
+        "é",
+        "ab",
+    ),
+)
 
-        let remaining_exponent = "-1.234E-";
+"#]]
+            .raw()
+        );
         assert_parse!(
-            float::<_, f64, _>.parse_peek(remaining_exponent),
+            short_alpha.parse_peek("aé"),
             str![[r#"
 Err(
     Backtrack(
         InputError {
-            input: "",
+            input: "aé",
         },
     ),
 )
@@ -2006,134 +2086,810 @@ Err(
 "#]]
             .raw()
         );
+        assert_parse!(
+            short_alpha.parse_peek("ab"),
+            str![[r#"
+Ok(
+    (
+        "",
+        "ab",
+    ),
+)
 
-        let nan_test_cases = ["nan", "NaN", "NAN"];
-
-        for test in nan_test_cases {
-            println!("now parsing: {test}");
-
-            let (remaining, parsed) = float::<_, f32, ()>.parse_peek(test.as_bytes()).unwrap();
-            assert!(parsed.is_nan());
-            assert!(remaining.is_empty());
-
-            let (remaining, parsed) = float::<_, f32, ()>.parse_peek(test).unwrap();
-            assert!(parsed.is_nan());
-            assert!(remaining.is_empty());
-
-            let (remaining, parsed) = float::<_, f64, ()>.parse_peek(test.as_bytes()).unwrap();
-            assert!(parsed.is_nan());
-            assert!(remaining.is_empty());
-
-            let (remaining, parsed) = float::<_, f64, ()>.parse_peek(test).unwrap();
-            assert!(parsed.is_nan());
-            assert!(remaining.is_empty());
-        }
+"#]]
+            .raw()
+        );
     }
 
     proptest! {
       #[test]
-      #[cfg(feature = "std")]
       #[cfg_attr(miri, ignore)]  // See https://github.com/AltSysrq/proptest/issues/253
-      fn floats(s in "\\PC*") {
-          println!("testing {s}");
-          let res1 = parse_f64.parse_peek(&s);
-          let res2 = float::<_, f64, ErrMode<()>>.parse_peek(s.as_str());
-          assert_eq!(res1, res2);
+      fn take_while_ascii_matches_take_while(s in "[ \t\na-zé\u{1F499}]{0,12}") {
+          let set = (' ', '\t', 'a'..='m');
+          let ascii = take_while_ascii::<_, _, InputError<_>>(1..=8, set.clone()).parse_peek(s.as_str());
+          let chars = take_while::<_, _, InputError<_>>(1..=8, set).parse_peek(s.as_str());
+          prop_assert_eq!(ascii, chars);
       }
     }
 
-    #[cfg(feature = "std")]
-    fn parse_f64(i: &mut &str) -> ModalResult<f64, ()> {
-        match take_float_or_exceptions.parse_next(i) {
-            Err(e) => Err(e),
-            Ok(s) => {
-                if s.is_empty() {
-                    return Err(ErrMode::Backtrack(()));
-                }
-                match s.parse_slice() {
-                    Some(n) => Ok(n),
-                    None => Err(ErrMode::Backtrack(())),
-                }
-            }
-        }
-    }
-
-    // issue #1336 "take_escaped hangs if normal parser accepts empty"
     #[test]
-    #[cfg_attr(debug_assertions, should_panic)]
-    fn complete_take_escaped_hang_1() {
-        // issue #1336 "take_escaped hangs if normal parser accepts empty"
-        fn escaped_string<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
-            use crate::ascii::alpha0;
-            use crate::token::one_of;
-            take_escaped(alpha0, '\\', one_of(['n'])).parse_next(input)
+    fn identifier_tests() {
+        fn ident<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            identifier.parse_next(input)
         }
-
-        let input = "7";
-        assert_parse!(escaped_string.parse_peek(input), str![]);
-    }
-
-    // issue #1336 "take_escaped hangs if normal parser accepts empty"
-    #[test]
-    #[cfg_attr(debug_assertions, should_panic)]
-    fn complete_take_escaped_hang_2() {
-        // issue #1336 "take_escaped hangs if normal parser accepts empty"
-        fn escaped_string<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
-            use crate::ascii::alpha0;
-            use crate::token::one_of;
-            take_escaped(alpha0, '\\', one_of(['n'])).parse_next(input)
+        fn unicode_ident<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            identifier_with((char::is_alphabetic, '_'), (char::is_alphanumeric, '_'))
+                .parse_next(input)
         }
 
-        let input = "a7";
-        assert_parse!(escaped_string.parse_peek(input), str![]);
-    }
+        assert_parse!(
+            ident.parse_peek("foo_1 bar"),
+            str![[r#"
+Ok(
+    (
+        " bar",
+        "foo_1",
+    ),
+)
 
-    #[test]
-    #[cfg_attr(debug_assertions, should_panic)]
-    fn complete_take_escaped_hang_1118() {
-        // issue ##1118 take_escaped does not work with empty string
-        fn unquote<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
-            use crate::combinator::delimited;
-            use crate::combinator::opt;
-            use crate::token::one_of;
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ident.parse_peek("_"),
+            str![[r#"
+Ok(
+    (
+        "",
+        "_",
+    ),
+)
 
-            delimited(
-                '"',
-                take_escaped(
-                    opt(none_of(['\\', '"'])),
-                    '\\',
-                    one_of(['\\', '"', 'r', 'n', 't']),
-                ),
-                '"',
-            )
-            .parse_next(input)
-        }
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ident.parse_peek("__"),
+            str![[r#"
+Ok(
+    (
+        "",
+        "__",
+    ),
+)
 
-        let input = r#""""#;
-        assert_parse!(unquote.parse_peek(input), str![]);
-    }
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ident.parse_peek("1foo"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "1foo",
+        },
+    ),
+)
 
-    #[cfg(feature = "alloc")]
-    #[allow(unused_variables)]
-    #[test]
-    fn complete_escaping() {
-        use crate::ascii::{alpha1 as alpha, digit1 as digit};
-        use crate::token::one_of;
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ident.parse_peek(""),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "",
+        },
+    ),
+)
 
-        fn esc<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
-            take_escaped(alpha, '\\', one_of(['\"', 'n', '\\'])).parse_next(i)
-        }
+"#]]
+            .raw()
+        );
         assert_parse!(
-            esc.parse_peek(&b"abcd;"[..]),
+            ident.parse_peek("é"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "é",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            unicode_ident.parse_peek("é1-"),
             str![[r#"
 Ok(
     (
-        [
-            59,
-        ],
-        [
-            97,
-            98,
+        "-",
+        "é1",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            unicode_ident.parse_peek("1é"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "1é",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn xid_identifier_tests() {
+        use crate::ascii::unicode::{xid_identifier, XidContinue, XidStart};
+
+        fn ident<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            xid_identifier.parse_next(input)
+        }
+        fn rust_ident<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            identifier_with((XidStart, '_'), XidContinue).parse_next(input)
+        }
+
+        assert_parse!(
+            ident.parse_peek("größe = 2"),
+            str![[r#"
+Ok(
+    (
+        " = 2",
+        "größe",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ident.parse_peek("東京1"),
+            str![[r#"
+Ok(
+    (
+        "",
+        "東京1",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ident.parse_peek("αβγ_δ"),
+            str![[r#"
+Ok(
+    (
+        "",
+        "αβγ_δ",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // combining marks continue, but can't start, an identifier
+        assert_parse!(
+            ident.parse_peek("e\u{301}t\u{301}e\u{301}"),
+            str![[r#"
+Ok(
+    (
+        "",
+        "e\u{301}t\u{301}e\u{301}",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ident.parse_peek("\u{301}e"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "\u{301}e",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // emoji are neither `XID_Start` nor `XID_Continue`
+        assert_parse!(
+            ident.parse_peek("🦀"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "🦀",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ident.parse_peek("crab🦀"),
+            str![[r#"
+Ok(
+    (
+        "🦀",
+        "crab",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ident.parse_peek("1foo"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "1foo",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ident.parse_peek("_foo"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "_foo",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ident.parse_peek(""),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            rust_ident.parse_peek("_größe"),
+            str![[r#"
+Ok(
+    (
+        "",
+        "_größe",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn hex_uint_tests() {
+        fn hex_u32<'i>(input: &mut &'i [u8]) -> TestResult<&'i [u8], u32> {
+            hex_uint.parse_next(input)
+        }
+
+        assert_parse!(
+            hex_u32.parse_peek(&b";"[..]),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                59,
+            ],
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex_u32.parse_peek(&b"ff;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        255,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex_u32.parse_peek(&b"1be2;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        7138,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex_u32.parse_peek(&b"c5a31be2;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        3315801058,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex_u32.parse_peek(&b"C5A31be2;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        3315801058,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex_u32.parse_peek(&b"00c5a31be2;"[..]), // overflow
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                48,
+                48,
+                99,
+                53,
+                97,
+                51,
+                49,
+                98,
+                101,
+                50,
+                59,
+            ],
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex_u32.parse_peek(&b"c5a31be201;"[..]), // overflow
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                99,
+                53,
+                97,
+                51,
+                49,
+                98,
+                101,
+                50,
+                48,
+                49,
+                59,
+            ],
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex_u32.parse_peek(&b"ffffffff;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        4294967295,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex_u32.parse_peek(&b"ffffffffffffffff;"[..]), // overflow
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                59,
+            ],
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex_u32.parse_peek(&b"ffffffffffffffff"[..]), // overflow
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+                102,
+            ],
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex_u32.parse_peek(&b"0x1be2;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            120,
+            49,
+            98,
+            101,
+            50,
+            59,
+        ],
+        0,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex_u32.parse_peek(&b"12af"[..]),
+            str![[r#"
+Ok(
+    (
+        [],
+        4783,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn float_test() {
+        let test_cases = [
+            "+3.14",
+            "3.14",
+            "-3.14",
+            "0",
+            "0.0",
+            "1.",
+            ".789",
+            "-.5",
+            "1e7",
+            "-1E-7",
+            ".3e-2",
+            "1.e4",
+            "1.2e4",
+            "12.34",
+            "-1.234E-12",
+            "-1.234e-12",
+            "0.00000000000000000087",
+            "inf",
+            "Inf",
+            "infinity",
+            "Infinity",
+            "-inf",
+            "-Inf",
+            "-infinity",
+            "-Infinity",
+            "+inf",
+            "+Inf",
+            "+infinity",
+            "+Infinity",
+        ];
+
+        for test in test_cases {
+            let expected32 = str::parse::<f32>(test).unwrap();
+            let expected64 = str::parse::<f64>(test).unwrap();
+
+            println!("now parsing: {test} -> {expected32}");
+
+            assert_eq!(
+                float::<_, _, InputError<_>>.parse_peek(test.as_bytes()),
+                Ok((&b""[..], expected32))
+            );
+            assert_eq!(
+                float::<_, _, InputError<_>>.parse_peek(test),
+                Ok(("", expected32))
+            );
+
+            assert_eq!(
+                float::<_, _, InputError<_>>.parse_peek(test.as_bytes()),
+                Ok((&b""[..], expected64))
+            );
+            assert_eq!(
+                float::<_, _, InputError<_>>.parse_peek(test),
+                Ok(("", expected64))
+            );
+        }
+
+        let remaining_exponent = "-1.234E-";
+        assert_parse!(
+            float::<_, f64, _>.parse_peek(remaining_exponent),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+
+        let nan_test_cases = ["nan", "NaN", "NAN"];
+
+        for test in nan_test_cases {
+            println!("now parsing: {test}");
+
+            let (remaining, parsed) = float::<_, f32, ()>.parse_peek(test.as_bytes()).unwrap();
+            assert!(parsed.is_nan());
+            assert!(remaining.is_empty());
+
+            let (remaining, parsed) = float::<_, f32, ()>.parse_peek(test).unwrap();
+            assert!(parsed.is_nan());
+            assert!(remaining.is_empty());
+
+            let (remaining, parsed) = float::<_, f64, ()>.parse_peek(test.as_bytes()).unwrap();
+            assert!(parsed.is_nan());
+            assert!(remaining.is_empty());
+
+            let (remaining, parsed) = float::<_, f64, ()>.parse_peek(test).unwrap();
+            assert!(parsed.is_nan());
+            assert!(remaining.is_empty());
+        }
+    }
+
+    proptest! {
+      #[test]
+      #[cfg(feature = "std")]
+      #[cfg_attr(miri, ignore)]  // See https://github.com/AltSysrq/proptest/issues/253
+      fn floats(s in "\\PC*") {
+          println!("testing {s}");
+          let res1 = parse_f64.parse_peek(&s);
+          let res2 = float::<_, f64, ErrMode<()>>.parse_peek(s.as_str());
+          assert_eq!(res1, res2);
+      }
+    }
+
+    #[cfg(feature = "std")]
+    fn parse_f64(i: &mut &str) -> ModalResult<f64, ()> {
+        match take_float_or_exceptions.parse_next(i) {
+            Err(e) => Err(e),
+            Ok(s) => {
+                if s.is_empty() {
+                    return Err(ErrMode::Backtrack(()));
+                }
+                match s.parse_slice() {
+                    Some(n) => Ok(n),
+                    None => Err(ErrMode::Backtrack(())),
+                }
+            }
+        }
+    }
+
+    // issue #1336 "take_escaped hangs if normal parser accepts empty"
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn complete_take_escaped_hang_1() {
+        // issue #1336 "take_escaped hangs if normal parser accepts empty"
+        fn escaped_string<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            use crate::ascii::alpha0;
+            use crate::token::one_of;
+            take_escaped(alpha0, '\\', one_of(['n'])).parse_next(input)
+        }
+
+        let input = "7";
+        assert_parse!(escaped_string.parse_peek(input), str![]);
+    }
+
+    // issue #1336 "take_escaped hangs if normal parser accepts empty"
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn complete_take_escaped_hang_2() {
+        // issue #1336 "take_escaped hangs if normal parser accepts empty"
+        fn escaped_string<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            use crate::ascii::alpha0;
+            use crate::token::one_of;
+            take_escaped(alpha0, '\\', one_of(['n'])).parse_next(input)
+        }
+
+        let input = "a7";
+        assert_parse!(escaped_string.parse_peek(input), str![]);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn complete_take_escaped_hang_1118() {
+        // issue ##1118 take_escaped does not work with empty string
+        fn unquote<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            use crate::combinator::delimited;
+            use crate::combinator::opt;
+            use crate::token::one_of;
+
+            delimited(
+                '"',
+                take_escaped(
+                    opt(none_of(['\\', '"'])),
+                    '\\',
+                    one_of(['\\', '"', 'r', 'n', 't']),
+                ),
+                '"',
+            )
+            .parse_next(input)
+        }
+
+        let input = r#""""#;
+        assert_parse!(unquote.parse_peek(input), str![]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[allow(unused_variables)]
+    #[test]
+    fn complete_escaping() {
+        use crate::ascii::{alpha1 as alpha, digit1 as digit};
+        use crate::token::one_of;
+
+        fn esc<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+            take_escaped(alpha, '\\', one_of(['\"', 'n', '\\'])).parse_next(i)
+        }
+        assert_parse!(
+            esc.parse_peek(&b"abcd;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        [
+            97,
+            98,
+            99,
+            100,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek(&b"ab\\\"cd;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        [
+            97,
+            98,
+            92,
+            34,
+            99,
+            100,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek(&b"\\\"abcd;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        [
+            92,
+            34,
+            97,
+            98,
             99,
             100,
         ],
@@ -2144,21 +2900,1650 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek(&b"ab\\\"cd;"[..]),
+            esc.parse_peek(&b"\\n;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        [
+            92,
+            110,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek(&b"ab\\\"12"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            49,
+            50,
+        ],
+        [
+            97,
+            98,
+            92,
+            34,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek(&b"AB\\"[..]),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [],
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek(&b"AB\\A"[..]),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                65,
+            ],
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+
+        fn esc2<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+            take_escaped(digit, '\\', one_of(['\"', 'n', '\\'])).parse_next(i)
+        }
+        assert_parse!(
+            esc2.parse_peek(&b"12\\nnn34"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            110,
+            110,
+            51,
+            52,
+        ],
+        [
+            49,
+            50,
+            92,
+            110,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn complete_escaping_str() {
+        use crate::ascii::{alpha1 as alpha, digit1 as digit};
+        use crate::token::one_of;
+
+        fn esc<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            take_escaped(alpha, '\\', one_of(['\"', 'n', '\\'])).parse_next(i)
+        }
+        assert_parse!(
+            esc.parse_peek("abcd;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        "abcd",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("ab\\\"cd;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        "ab\\\"cd",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("\\\"abcd;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        "\\\"abcd",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("\\n;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        "\\n",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("ab\\\"12"),
+            str![[r#"
+Ok(
+    (
+        "12",
+        "ab\\\"",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("AB\\"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("AB\\A"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "A",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+
+        fn esc2<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            take_escaped(digit, '\\', one_of(['\"', 'n', '\\'])).parse_next(i)
+        }
+        assert_parse!(
+            esc2.parse_peek("12\\nnn34"),
+            str![[r#"
+Ok(
+    (
+        "nn34",
+        "12\\n",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+
+        fn esc3<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            take_escaped(alpha, '\u{241b}', one_of(['\"', 'n'])).parse_next(i)
+        }
+        assert_parse!(
+            esc3.parse_peek("ab␛ncd;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        "ab␛ncd",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn test_take_escaped_error() {
+        fn esc<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            use crate::ascii::digit1;
+            take_escaped(digit1, '\\', one_of(['\"', 'n', '\\'])).parse_next(i)
+        }
+
+        assert_parse!(
+            esc.parse_peek("abcd"),
+            str![[r#"
+Ok(
+    (
+        "abcd",
+        "",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn complete_escaped_hang() {
+        fn escaped_string<'i>(input: &mut &'i str) -> TestResult<&'i str, ()> {
+            use crate::ascii::alpha0;
+            escaped(alpha0, '\\', "n").parse_next(input)
+        }
+
+        let input = "a\\n7";
+        assert_parse!(escaped_string.parse_peek(input), str![]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn complete_escaped_with_raw() {
+        use crate::ascii::alpha1 as alpha;
+        use crate::lib::std::borrow::Cow;
+
+        fn esc<'i>(i: &mut &'i str) -> TestResult<&'i str, (&'i str, Cow<'i, str>)> {
+            escaped_with_raw(alpha, '\\', alt(('\\', '"', "n".value('\n')))).parse_next(i)
+        }
+        fn raw<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            take_escaped(alpha, '\\', alt(('\\', '"', "n".value('\n')))).parse_next(i)
+        }
+        fn value<'i>(i: &mut &'i str) -> TestResult<&'i str, String> {
+            escaped(alpha, '\\', alt(('\\', '"', "n".value('\n')))).parse_next(i)
+        }
+
+        for input in [
+            "",
+            "abcd",
+            "abcd;",
+            "a\\nb;",
+            "\\\"",
+            "\\\\ab\\\"cd\\n",
+            "ab\\n\\n;x",
+        ] {
+            let (rest, (actual_raw, actual_value)) = esc.parse_peek(input).unwrap();
+            let (raw_rest, expected_raw) = raw.parse_peek(input).unwrap();
+            let (value_rest, expected_value) = value.parse_peek(input).unwrap();
+            assert_eq!(rest, raw_rest, "{input:?}");
+            assert_eq!(rest, value_rest, "{input:?}");
+            assert_eq!(actual_raw, expected_raw, "{input:?}");
+            assert_eq!(actual_value, expected_value, "{input:?}");
+            assert_eq!(
+                matches!(actual_value, Cow::Borrowed(_)),
+                !actual_raw.contains('\\'),
+                "{input:?}"
+            );
+        }
+
+        assert_eq!(
+            esc.parse_peek("abcd;"),
+            Ok((";", ("abcd", Cow::Borrowed("abcd"))))
+        );
+        assert!(esc.parse_peek("ab\\x").is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn complete_escaped_with_limits() {
+        use crate::ascii::alpha1 as alpha;
+
+        fn esc<'i>(i: &mut &'i str) -> TestResult<&'i str, String> {
+            escaped_with_limits(1..=4, alpha, '\\', alt(('\\', '"', "n".value('\n')))).parse_next(i)
+        }
+
+        assert_parse!(
+            esc.parse_peek("a\\nb;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        "a\nb",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek(""),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek(";"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: ";",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("\\"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("ab\\"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("abcde;"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "abcde;",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn complete_escape_transform() {
+        use crate::ascii::alpha1 as alpha;
+
+        #[cfg(feature = "alloc")]
+        fn to_s(i: Vec<u8>) -> String {
+            String::from_utf8_lossy(&i).into_owned()
+        }
+
+        fn esc<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], String> {
+            escaped(alpha, '\\', alt((b'\\', b'"', "n".value(b'\n'))))
+                .map(to_s)
+                .parse_next(i)
+        }
+
+        assert_parse!(
+            esc.parse_peek(&b"abcd;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        "abcd",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek(&b"ab\\\"cd;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        "ab\"cd",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek(&b"\\\"abcd;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        "\"abcd",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek(&b"\\n;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        "\n",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek(&b"ab\\\"12"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            49,
+            50,
+        ],
+        "ab\"",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek(&b"AB\\"[..]),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [],
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek(&b"AB\\A"[..]),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                65,
+            ],
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+
+        fn esc2<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], String> {
+            escaped(
+                alpha,
+                '&',
+                alt((
+                    "egrave;".value("è".as_bytes()),
+                    "agrave;".value("à".as_bytes()),
+                )),
+            )
+            .map(to_s)
+            .parse_next(i)
+        }
+        assert_parse!(
+            esc2.parse_peek(&b"ab&egrave;DEF;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        "abèDEF",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc2.parse_peek(&b"ab&egrave;D&agrave;EF;"[..]),
+            str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        "abèDàEF",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn complete_escape_transform_str() {
+        use crate::ascii::alpha1 as alpha;
+
+        fn esc<'i>(i: &mut &'i str) -> TestResult<&'i str, String> {
+            escaped(
+                alpha,
+                '\\',
+                alt((
+                    '\\',
+                    '"',
+                    "n".value('\n'),
+                    ("x", hex_uint).map(|(_, hex)| char::from_u32(hex).unwrap()),
+                )),
+            )
+            .parse_next(i)
+        }
+
+        assert_parse!(
+            esc.parse_peek("abcd;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        "abcd",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("ab\\\"cd;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        "ab\"cd",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("\\\"abcd;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        "\"abcd",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("\\n;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        "\n",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("ab\\\"12"),
+            str![[r#"
+Ok(
+    (
+        "12",
+        "ab\"",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("ab\\x20"),
+            str![[r#"
+Ok(
+    (
+        "",
+        "ab ",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("AB\\"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc.parse_peek("AB\\A"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "A",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+
+        fn esc2<'i>(i: &mut &'i str) -> TestResult<&'i str, String> {
+            escaped(
+                alpha,
+                '&',
+                alt(("egrave;".value("è"), "agrave;".value("à"))),
+            )
+            .parse_next(i)
+        }
+        assert_parse!(
+            esc2.parse_peek("ab&egrave;DEF;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        "abèDEF",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            esc2.parse_peek("ab&egrave;D&agrave;EF;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        "abèDàEF",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+
+        fn esc3<'i>(i: &mut &'i str) -> TestResult<&'i str, String> {
+            escaped(alpha, '␛', alt(("0".value("\0"), "n".value("\n")))).parse_next(i)
+        }
+        assert_parse!(
+            esc3.parse_peek("a␛0bc␛n"),
+            str![[r#"
+Ok(
+    (
+        "",
+        "a\0bc\n",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_escaped_error() {
+        fn esc_trans<'i>(i: &mut &'i str) -> TestResult<&'i str, String> {
+            use crate::ascii::digit1;
+            escaped(digit1, '\\', "n").parse_next(i)
+        }
+
+        assert_parse!(
+            esc_trans.parse_peek("abcd"),
+            str![[r#"
+Ok(
+    (
+        "abcd",
+        "",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+    #[test]
+    fn whitespace_nested_block_comment() {
+        const WS: Whitespace = Whitespace::new()
+            .line_comment(";")
+            .block_comment("(*", "*)")
+            .nested(true);
+
+        fn ws<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            whitespace(WS).parse_next(i)
+        }
+
+        assert_parse!(
+            ws.parse_peek(" (* a (* b *) c *) ; d\n\te"),
+            str![[r#"
+Ok(
+    (
+        "e",
+        " (* a (* b *) c *) ; d\n\t",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ws.parse_peek("(* a *) *)"),
+            str![[r#"
+Ok(
+    (
+        "*)",
+        "(* a *) ",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ws.parse_peek("; a"),
+            str![[r#"
+Ok(
+    (
+        "",
+        "; a",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn whitespace_unnested_block_comment() {
+        const WS: Whitespace = Whitespace::new().block_comment("/*", "*/");
+
+        fn ws<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            whitespace(WS).parse_next(i)
+        }
+
+        assert_parse!(
+            ws.parse_peek("/* a /* b */ c */"),
+            str![[r#"
+Ok(
+    (
+        "c */",
+        "/* a /* b */ ",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn whitespace_unterminated_block_comment() {
+        const WS: Whitespace = Whitespace::new().block_comment("/*", "*/").nested(true);
+
+        fn ws<'i>(i: &mut &'i str) -> ModalResult<&'i str> {
+            whitespace(WS).parse_next(i)
+        }
+
+        let err = ws.parse_peek("  /* a /* b */ *").unwrap_err();
+        assert!(matches!(err, ErrMode::Cut(_)));
+        snapbox::assert_data_eq!(
+            err.into_inner().unwrap().to_string(),
+            str![[r#"
+invalid block comment
+expected `*/`
+"#]]
+            .raw()
+        );
+    }
+    #[test]
+    fn keyword_tests() {
+        fn kw_let<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            keyword("let").parse_next(input)
+        }
+
+        assert_parse!(
+            kw_let.parse_peek("let x"),
+            str![[r#"
+Ok(
+    (
+        " x",
+        "let",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            kw_let.parse_peek("lets"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "lets",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            kw_let.parse_peek("let_"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "let_",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            kw_let.parse_peek("let!"),
+            str![[r#"
+Ok(
+    (
+        "!",
+        "let",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            kw_let.parse_peek("let"),
+            str![[r#"
+Ok(
+    (
+        "",
+        "let",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn word_boundary_test() {
+        fn keyword_if<'i>(
+            i: &mut LocatingSlice<&'i str>,
+        ) -> TestResult<LocatingSlice<&'i str>, &'i str> {
+            preceded(take_till(0.., 'i'), (word_boundary, "if").map(|(_, k)| k)).parse_next(i)
+        }
+
+        // start-of-input
+        assert_parse!(
+            keyword_if.parse_peek(LocatingSlice::new("if")),
+            str![[r#"
+Ok(
+    (
+        LocatingSlice {
+            initial: "if",
+            input: "",
+        },
+        "if",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // after punctuation
+        assert_parse!(
+            keyword_if.parse_peek(LocatingSlice::new("(if")),
+            str![[r#"
+Ok(
+    (
+        LocatingSlice {
+            initial: "(if",
+            input: "",
+        },
+        "if",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // after identifier
+        assert_parse!(
+            keyword_if.parse_peek(LocatingSlice::new("_if")),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: LocatingSlice {
+                initial: "_if",
+                input: "if",
+            },
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // end of word
+        assert_parse!(
+            (word_boundary, "if", word_boundary).parse_peek(LocatingSlice::new("iff")),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: LocatingSlice {
+                initial: "iff",
+                input: "f",
+            },
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn hex_bytes_tests() {
+        fn hex<'i>(input: &mut &'i str) -> TestResult<&'i str, Vec<u8>> {
+            hex_bytes(1..=3).parse_next(input)
+        }
+
+        // mixed case
+        assert_parse!(
+            hex.parse_peek("dEaDbe;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        [
+            222,
+            173,
+            190,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // stops at whitespace and at the end of the range
+        assert_parse!(
+            hex.parse_peek("de ad"),
+            str![[r#"
+Ok(
+    (
+        " ad",
+        [
+            222,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex.parse_peek("deadbeef"),
+            str![[r#"
+Ok(
+    (
+        "ef",
+        [
+            222,
+            173,
+            190,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // odd length, error at the final digit
+        assert_parse!(
+            hex.parse_peek("dea"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "a",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex.parse_peek("dea;"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "a;",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // invalid digit
+        assert_parse!(
+            hex.parse_peek("deaX"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "aX",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // too few
+        assert_parse!(
+            hex.parse_peek(";"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: ";",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex.parse_peek(""),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn base64_tests() {
+        fn b64<'i>(input: &mut &'i str) -> TestResult<&'i str, Vec<u8>> {
+            base64.parse_next(input)
+        }
+        fn b64_wrapped<'i>(input: &mut &'i str) -> TestResult<&'i str, Vec<u8>> {
+            base64_wrapped.parse_next(input)
+        }
+
+        assert_parse!(
+            b64.parse_peek("aGVsbG8+/w==;"),
+            str![[r#"
+Ok(
+    (
+        ";",
+        [
+            104,
+            101,
+            108,
+            108,
+            111,
+            62,
+            255,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            b64.parse_peek("aGk=aGk="),
+            str![[r#"
+Ok(
+    (
+        "aGk=",
+        [
+            104,
+            105,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            b64.parse_peek(""),
+            str![[r#"
+Ok(
+    (
+        "",
+        [],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // cut off group
+        assert_parse!(
+            b64.parse_peek("aGVsbG8"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            b64.parse_peek("aG;k"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: ";k",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // misplaced padding
+        assert_parse!(
+            b64.parse_peek("a===;"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "===;",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            b64.parse_peek("aG=k"),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "k",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // whitespace
+        assert_parse!(
+            b64.parse_peek("aGVs\nbG8h"),
+            str![[r#"
+Ok(
+    (
+        "\nbG8h",
+        [
+            104,
+            101,
+            108,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            b64_wrapped.parse_peek("aGVs\nbG8h\n"),
+            str![[r#"
+Ok(
+    (
+        "\n",
+        [
+            104,
+            101,
+            108,
+            108,
+            111,
+            33,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            b64_wrapped.parse_peek("aGVs\r\nbG8h\r\n-"),
+            str![[r#"
+Ok(
+    (
+        "\r\n-",
+        [
+            104,
+            101,
+            108,
+            108,
+            111,
+            33,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            b64_wrapped.parse_peek("aG\nk\r="),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "\r=",
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+}
+
+mod partial {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    use crate::error::InputError;
+    use crate::prelude::*;
+    use crate::stream::LocatingSlice;
+    use crate::Partial;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn escaped_with_raw_test() {
+        use crate::ascii::alpha1 as alpha;
+        use crate::error::ErrMode;
+        use crate::lib::std::borrow::Cow;
+
+        fn esc<'i>(
+            i: &mut Partial<&'i str>,
+        ) -> TestResult<Partial<&'i str>, (&'i str, Cow<'i, str>)> {
+            escaped_with_raw(alpha, '\\', alt(('\\', '"', "n".value('\n')))).parse_next(i)
+        }
+
+        assert_eq!(
+            esc.parse_peek(Partial::new("abcd;")),
+            Ok((Partial::new(";"), ("abcd", Cow::Borrowed("abcd"))))
+        );
+        assert_eq!(
+            esc.parse_peek(Partial::new("a\\nb;")),
+            Ok((Partial::new(";"), ("a\\nb", Cow::Owned("a\nb".into()))))
+        );
+        assert!(matches!(
+            esc.parse_peek(Partial::new("abcd")),
+            Err(ErrMode::Incomplete(_))
+        ));
+        assert!(matches!(
+            esc.parse_peek(Partial::new("ab\\")),
+            Err(ErrMode::Incomplete(_))
+        ));
+    }
+
+    #[test]
+    fn float_terminated_test() {
+        use crate::error::{ErrMode, Needed};
+
+        fn hinted<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, f64> {
+            float_terminated(|_: &Partial<&'i str>| true).parse_next(i)
+        }
+        fn unhinted<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, f64> {
+            float_terminated(|_: &Partial<&'i str>| false).parse_next(i)
+        }
+
+        // Chunk ends with the number
+        for chunk in ["123", "-1.5", "1e7", "inf"] {
+            let expected = chunk.parse::<f64>().unwrap();
+            assert_eq!(
+                hinted.parse_peek(Partial::new(chunk)),
+                Ok((Partial::new(""), expected)),
+                "{chunk}"
+            );
+            assert_eq!(
+                unhinted
+                    .parse_peek(Partial::new(chunk))
+                    .unwrap_err()
+                    .needed(),
+                float::<_, f64, ErrMode<InputError<_>>>
+                    .parse_peek(Partial::new(chunk))
+                    .unwrap_err()
+                    .needed(),
+                "{chunk}"
+            );
+        }
+
+        // Chunk ends after the number
+        assert_eq!(
+            hinted.parse_peek(Partial::new("123 ")),
+            Ok((Partial::new(" "), 123.0))
+        );
+        assert_eq!(
+            unhinted.parse_peek(Partial::new("123 ")),
+            Ok((Partial::new(" "), 123.0))
+        );
+
+        // Chunk ends before the number
+        assert!(matches!(
+            unhinted.parse_peek(Partial::new("")),
+            Err(ErrMode::Incomplete(Needed::Size(_)))
+        ));
+        assert!(matches!(
+            hinted.parse_peek(Partial::new("")),
+            Err(ErrMode::Backtrack(_))
+        ));
+
+        // The stream is still partial afterwards
+        let mut input = Partial::new("1");
+        assert_eq!(hinted.parse_next(&mut input), Ok(1.0));
+        assert!(input.is_partial());
+
+        // The hint is given the start of the number
+        let mut seen = None;
+        let mut input = Partial::new(" 12");
+        let _ = input.next_slice(1);
+        let _: TestResult<Partial<&str>, f64> = float_terminated(|i: &Partial<&str>| {
+            seen = Some(**i);
+            true
+        })
+        .parse_next(&mut input);
+        assert_eq!(seen, Some("12"));
+    }
+
+    #[test]
+    fn character() {
+        let a: &[u8] = b"abcd";
+        let b: &[u8] = b"1234";
+        let c: &[u8] = b"a123";
+        let d: &[u8] = "azé12".as_bytes();
+        let e: &[u8] = b" ";
+        let f: &[u8] = b" ;";
+        //assert_parse!(alpha1::<_, Error<_>>(a), Err(ErrMode::Incomplete(Needed::new(1))));
+        assert_parse!(
+            alpha1.parse_peek(Partial::new(a)),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            alpha1.parse_peek(Partial::new(b)),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    49,
+                    50,
+                    51,
+                    52,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            alpha1.parse_peek(Partial::new(c)),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                49,
+                50,
+                51,
+            ],
+            partial: true,
+        },
+        [
+            97,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            alpha1.parse_peek(Partial::new(d)),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                195,
+                169,
+                49,
+                50,
+            ],
+            partial: true,
+        },
+        [
+            97,
+            122,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            digit1.parse_peek(Partial::new(a)),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    97,
+                    98,
+                    99,
+                    100,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            digit1.parse_peek(Partial::new(b)),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            digit1.parse_peek(Partial::new(c)),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    97,
+                    49,
+                    50,
+                    51,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            digit1.parse_peek(Partial::new(d)),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    97,
+                    122,
+                    195,
+                    169,
+                    49,
+                    50,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            hex_digit1.parse_peek(Partial::new(a)),
             str![[r#"
-Ok(
-    (
-        [
-            59,
-        ],
-        [
-            97,
-            98,
-            92,
-            34,
-            99,
-            100,
-        ],
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -2166,21 +4551,13 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek(&b"\\\"abcd;"[..]),
+            hex_digit1.parse_peek(Partial::new(b)),
             str![[r#"
-Ok(
-    (
-        [
-            59,
-        ],
-        [
-            92,
-            34,
-            97,
-            98,
-            99,
-            100,
-        ],
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -2188,17 +4565,13 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek(&b"\\n;"[..]),
+            hex_digit1.parse_peek(Partial::new(c)),
             str![[r#"
-Ok(
-    (
-        [
-            59,
-        ],
-        [
-            92,
-            110,
-        ],
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -2206,19 +4579,22 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek(&b"ab\\\"12"[..]),
+            hex_digit1.parse_peek(Partial::new(d)),
             str![[r#"
 Ok(
     (
-        [
-            49,
-            50,
-        ],
+        Partial {
+            input: [
+                122,
+                195,
+                169,
+                49,
+                50,
+            ],
+            partial: true,
+        },
         [
             97,
-            98,
-            92,
-            34,
         ],
     ),
 )
@@ -2227,12 +4603,17 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek(&b"AB\\"[..]),
+            hex_digit1.parse_peek(Partial::new(e)),
             str![[r#"
 Err(
     Backtrack(
         InputError {
-            input: [],
+            input: Partial {
+                input: [
+                    32,
+                ],
+                partial: true,
+            },
         },
     ),
 )
@@ -2241,14 +4622,20 @@ Err(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek(&b"AB\\A"[..]),
+            oct_digit1.parse_peek(Partial::new(a)),
             str![[r#"
 Err(
     Backtrack(
         InputError {
-            input: [
-                65,
-            ],
+            input: Partial {
+                input: [
+                    97,
+                    98,
+                    99,
+                    100,
+                ],
+                partial: true,
+            },
         },
     ),
 )
@@ -2256,51 +4643,36 @@ Err(
 "#]]
             .raw()
         );
-
-        fn esc2<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
-            take_escaped(digit, '\\', one_of(['\"', 'n', '\\'])).parse_next(i)
-        }
         assert_parse!(
-            esc2.parse_peek(&b"12\\nnn34"[..]),
+            oct_digit1.parse_peek(Partial::new(b)),
             str![[r#"
-Ok(
-    (
-        [
-            110,
-            110,
-            51,
-            52,
-        ],
-        [
-            49,
-            50,
-            92,
-            110,
-        ],
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
             .raw()
         );
-    }
-
-    #[cfg(feature = "alloc")]
-    #[test]
-    fn complete_escaping_str() {
-        use crate::ascii::{alpha1 as alpha, digit1 as digit};
-        use crate::token::one_of;
-
-        fn esc<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
-            take_escaped(alpha, '\\', one_of(['\"', 'n', '\\'])).parse_next(i)
-        }
         assert_parse!(
-            esc.parse_peek("abcd;"),
+            oct_digit1.parse_peek(Partial::new(c)),
             str![[r#"
-Ok(
-    (
-        ";",
-        "abcd",
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    97,
+                    49,
+                    50,
+                    51,
+                ],
+                partial: true,
+            },
+        },
     ),
 )
 
@@ -2308,12 +4680,23 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek("ab\\\"cd;"),
+            oct_digit1.parse_peek(Partial::new(d)),
             str![[r#"
-Ok(
-    (
-        ";",
-        "ab\\\"cd",
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    97,
+                    122,
+                    195,
+                    169,
+                    49,
+                    50,
+                ],
+                partial: true,
+            },
+        },
     ),
 )
 
@@ -2321,25 +4704,28 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek("\\\"abcd;"),
+            alphanumeric1.parse_peek(Partial::new(a)),
             str![[r#"
-Ok(
-    (
-        ";",
-        "\\\"abcd",
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
             .raw()
         );
+        //assert_parse!(fix_error!(b,(), alphanumeric1), str![]);
         assert_parse!(
-            esc.parse_peek("\\n;"),
+            alphanumeric1.parse_peek(Partial::new(c)),
             str![[r#"
-Ok(
-    (
-        ";",
-        "\\n",
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -2347,74 +4733,57 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek("ab\\\"12"),
+            alphanumeric1.parse_peek(Partial::new(d)),
             str![[r#"
 Ok(
     (
-        "12",
-        "ab\\\"",
-    ),
-)
-
-"#]]
-            .raw()
-        );
-        assert_parse!(
-            esc.parse_peek("AB\\"),
-            str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: "",
-        },
-    ),
-)
-
-"#]]
-            .raw()
-        );
-        assert_parse!(
-            esc.parse_peek("AB\\A"),
-            str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: "A",
+        Partial {
+            input: [
+                195,
+                169,
+                49,
+                50,
+            ],
+            partial: true,
         },
+        [
+            97,
+            122,
+        ],
     ),
 )
 
 "#]]
             .raw()
         );
-
-        fn esc2<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
-            take_escaped(digit, '\\', one_of(['\"', 'n', '\\'])).parse_next(i)
-        }
         assert_parse!(
-            esc2.parse_peek("12\\nnn34"),
-            str![[r#"
-Ok(
-    (
-        "nn34",
-        "12\\n",
+            space1.parse_peek(Partial::new(e)),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
             .raw()
         );
-
-        fn esc3<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
-            take_escaped(alpha, '\u{241b}', one_of(['\"', 'n'])).parse_next(i)
-        }
         assert_parse!(
-            esc3.parse_peek("ab␛ncd;"),
+            space1.parse_peek(Partial::new(f)),
             str![[r#"
 Ok(
     (
-        ";",
-        "ab␛ncd",
+        Partial {
+            input: [
+                59,
+            ],
+            partial: true,
+        },
+        [
+            32,
+        ],
     ),
 )
 
@@ -2423,53 +4792,39 @@ Ok(
         );
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_take_escaped_error() {
-        fn esc<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
-            use crate::ascii::digit1;
-            take_escaped(digit1, '\\', one_of(['\"', 'n', '\\'])).parse_next(i)
-        }
-
+    fn character_s() {
+        let a = "abcd";
+        let b = "1234";
+        let c = "a123";
+        let d = "azé12";
+        let e = " ";
         assert_parse!(
-            esc.parse_peek("abcd"),
+            alpha1.parse_peek(Partial::new(a)),
             str![[r#"
-Ok(
-    (
-        "abcd",
-        "",
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
             .raw()
         );
-    }
-
-    #[cfg(feature = "alloc")]
-    #[test]
-    fn complete_escape_transform() {
-        use crate::ascii::alpha1 as alpha;
-
-        #[cfg(feature = "alloc")]
-        fn to_s(i: Vec<u8>) -> String {
-            String::from_utf8_lossy(&i).into_owned()
-        }
-
-        fn esc<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], String> {
-            escaped(alpha, '\\', alt((b'\\', b'"', "n".value(b'\n'))))
-                .map(to_s)
-                .parse_next(i)
-        }
-
         assert_parse!(
-            esc.parse_peek(&b"abcd;"[..]),
+            alpha1.parse_peek(Partial::new(b)),
             str![[r#"
-Ok(
-    (
-        [
-            59,
-        ],
-        "abcd",
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: "1234",
+                partial: true,
+            },
+        },
     ),
 )
 
@@ -2477,14 +4832,15 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek(&b"ab\\\"cd;"[..]),
+            alpha1.parse_peek(Partial::new(c)),
             str![[r#"
 Ok(
     (
-        [
-            59,
-        ],
-        "ab\"cd",
+        Partial {
+            input: "123",
+            partial: true,
+        },
+        "a",
     ),
 )
 
@@ -2492,14 +4848,15 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek(&b"\\\"abcd;"[..]),
+            alpha1.parse_peek(Partial::new(d)),
             str![[r#"
 Ok(
     (
-        [
-            59,
-        ],
-        "\"abcd",
+        Partial {
+            input: "é12",
+            partial: true,
+        },
+        "az",
     ),
 )
 
@@ -2507,14 +4864,16 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek(&b"\\n;"[..]),
+            digit1.parse_peek(Partial::new(a)),
             str![[r#"
-Ok(
-    (
-        [
-            59,
-        ],
-        "\n",
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: "abcd",
+                partial: true,
+            },
+        },
     ),
 )
 
@@ -2522,15 +4881,13 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek(&b"ab\\\"12"[..]),
+            digit1.parse_peek(Partial::new(b)),
             str![[r#"
-Ok(
-    (
-        [
-            49,
-            50,
-        ],
-        "ab\"",
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -2538,12 +4895,15 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek(&b"AB\\"[..]),
+            digit1.parse_peek(Partial::new(c)),
             str![[r#"
 Err(
     Backtrack(
         InputError {
-            input: [],
+            input: Partial {
+                input: "a123",
+                partial: true,
+            },
         },
     ),
 )
@@ -2552,14 +4912,15 @@ Err(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek(&b"AB\\A"[..]),
+            digit1.parse_peek(Partial::new(d)),
             str![[r#"
 Err(
     Backtrack(
         InputError {
-            input: [
-                65,
-            ],
+            input: Partial {
+                input: "azé12",
+                partial: true,
+            },
         },
     ),
 )
@@ -2567,28 +4928,14 @@ Err(
 "#]]
             .raw()
         );
-
-        fn esc2<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], String> {
-            escaped(
-                alpha,
-                '&',
-                alt((
-                    "egrave;".value("è".as_bytes()),
-                    "agrave;".value("à".as_bytes()),
-                )),
-            )
-            .map(to_s)
-            .parse_next(i)
-        }
         assert_parse!(
-            esc2.parse_peek(&b"ab&egrave;DEF;"[..]),
+            hex_digit1.parse_peek(Partial::new(a)),
             str![[r#"
-Ok(
-    (
-        [
-            59,
-        ],
-        "abèDEF",
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -2596,48 +4943,43 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc2.parse_peek(&b"ab&egrave;D&agrave;EF;"[..]),
+            hex_digit1.parse_peek(Partial::new(b)),
             str![[r#"
-Ok(
-    (
-        [
-            59,
-        ],
-        "abèDàEF",
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
             .raw()
         );
-    }
-
-    #[cfg(feature = "std")]
-    #[test]
-    fn complete_escape_transform_str() {
-        use crate::ascii::alpha1 as alpha;
-
-        fn esc<'i>(i: &mut &'i str) -> TestResult<&'i str, String> {
-            escaped(
-                alpha,
-                '\\',
-                alt((
-                    '\\',
-                    '"',
-                    "n".value('\n'),
-                    ("x", hex_uint).map(|(_, hex)| char::from_u32(hex).unwrap()),
-                )),
-            )
-            .parse_next(i)
-        }
+        assert_parse!(
+            hex_digit1.parse_peek(Partial::new(c)),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
 
+"#]]
+            .raw()
+        );
         assert_parse!(
-            esc.parse_peek("abcd;"),
+            hex_digit1.parse_peek(Partial::new(d)),
             str![[r#"
 Ok(
     (
-        ";",
-        "abcd",
+        Partial {
+            input: "zé12",
+            partial: true,
+        },
+        "a",
     ),
 )
 
@@ -2645,12 +4987,33 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek("ab\\\"cd;"),
+            hex_digit1.parse_peek(Partial::new(e)),
             str![[r#"
-Ok(
-    (
-        ";",
-        "ab\"cd",
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: " ",
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            oct_digit1.parse_peek(Partial::new(a)),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: "abcd",
+                partial: true,
+            },
+        },
     ),
 )
 
@@ -2658,12 +5021,13 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek("\\\"abcd;"),
+            oct_digit1.parse_peek(Partial::new(b)),
             str![[r#"
-Ok(
-    (
-        ";",
-        "\"abcd",
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -2671,12 +5035,16 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek("\\n;"),
+            oct_digit1.parse_peek(Partial::new(c)),
             str![[r#"
-Ok(
-    (
-        ";",
-        "\n",
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: "a123",
+                partial: true,
+            },
+        },
     ),
 )
 
@@ -2684,12 +5052,16 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek("ab\\\"12"),
+            oct_digit1.parse_peek(Partial::new(d)),
             str![[r#"
-Ok(
-    (
-        "12",
-        "ab\"",
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: "azé12",
+                partial: true,
+            },
+        },
     ),
 )
 
@@ -2697,26 +5069,28 @@ Ok(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek("ab\\x20"),
+            alphanumeric1.parse_peek(Partial::new(a)),
             str![[r#"
-Ok(
-    (
-        "",
-        "ab ",
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
             .raw()
         );
+        //assert_parse!(fix_error!(b,(), alphanumeric1), str![]);
         assert_parse!(
-            esc.parse_peek("AB\\"),
+            alphanumeric1.parse_peek(Partial::new(c)),
             str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: "",
-        },
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -2724,48 +5098,124 @@ Err(
             .raw()
         );
         assert_parse!(
-            esc.parse_peek("AB\\A"),
+            alphanumeric1.parse_peek(Partial::new(d)),
             str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: "A",
+Ok(
+    (
+        Partial {
+            input: "é12",
+            partial: true,
         },
+        "az",
     ),
 )
 
 "#]]
             .raw()
         );
-
-        fn esc2<'i>(i: &mut &'i str) -> TestResult<&'i str, String> {
-            escaped(
-                alpha,
-                '&',
-                alt(("egrave;".value("è"), "agrave;".value("à"))),
-            )
-            .parse_next(i)
-        }
         assert_parse!(
-            esc2.parse_peek("ab&egrave;DEF;"),
+            space1.parse_peek(Partial::new(e)),
             str![[r#"
-Ok(
-    (
-        ";",
-        "abèDEF",
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
             .raw()
         );
+    }
+
+    use crate::stream::Offset;
+    #[test]
+    fn offset() {
+        let a = &b"abcd;"[..];
+        let b = &b"1234;"[..];
+        let c = &b"a123;"[..];
+        let d = &b" \t;"[..];
+        let e = &b" \t\r\n;"[..];
+        let f = &b"123abcDEF;"[..];
+
+        match alpha1::<_, InputError<_>>.parse_peek(Partial::new(a)) {
+            Ok((i, _)) => {
+                let i = i.into_inner();
+                assert_eq!(i.offset_from(&a) + i.len(), a.len());
+            }
+            _ => panic!("wrong return type in offset test for alpha"),
+        }
+        match digit1::<_, InputError<_>>.parse_peek(Partial::new(b)) {
+            Ok((i, _)) => {
+                let i = i.into_inner();
+                assert_eq!(i.offset_from(&b) + i.len(), b.len());
+            }
+            _ => panic!("wrong return type in offset test for digit"),
+        }
+        match alphanumeric1::<_, InputError<_>>.parse_peek(Partial::new(c)) {
+            Ok((i, _)) => {
+                let i = i.into_inner();
+                assert_eq!(i.offset_from(&c) + i.len(), c.len());
+            }
+            _ => panic!("wrong return type in offset test for alphanumeric"),
+        }
+        match space1::<_, InputError<_>>.parse_peek(Partial::new(d)) {
+            Ok((i, _)) => {
+                let i = i.into_inner();
+                assert_eq!(i.offset_from(&d) + i.len(), d.len());
+            }
+            _ => panic!("wrong return type in offset test for space"),
+        }
+        match multispace1::<_, InputError<_>>.parse_peek(Partial::new(e)) {
+            Ok((i, _)) => {
+                let i = i.into_inner();
+                assert_eq!(i.offset_from(&e) + i.len(), e.len());
+            }
+            _ => panic!("wrong return type in offset test for multispace"),
+        }
+        match hex_digit1::<_, InputError<_>>.parse_peek(Partial::new(f)) {
+            Ok((i, _)) => {
+                let i = i.into_inner();
+                assert_eq!(i.offset_from(&f) + i.len(), f.len());
+            }
+            _ => panic!("wrong return type in offset test for hex_digit"),
+        }
+        match oct_digit1::<_, InputError<_>>.parse_peek(Partial::new(f)) {
+            Ok((i, _)) => {
+                let i = i.into_inner();
+                assert_eq!(i.offset_from(&f) + i.len(), f.len());
+            }
+            _ => panic!("wrong return type in offset test for oct_digit"),
+        }
+    }
+
+    #[test]
+    fn is_till_line_ending_bytes() {
+        let a: &[u8] = b"ab12cd\nefgh";
         assert_parse!(
-            esc2.parse_peek("ab&egrave;D&agrave;EF;"),
+            till_line_ending.parse_peek(Partial::new(a)),
             str![[r#"
 Ok(
     (
-        ";",
-        "abèDàEF",
+        Partial {
+            input: [
+                10,
+                101,
+                102,
+                103,
+                104,
+            ],
+            partial: true,
+        },
+        [
+            97,
+            98,
+            49,
+            50,
+            99,
+            100,
+        ],
     ),
 )
 
@@ -2773,91 +5223,105 @@ Ok(
             .raw()
         );
 
-        fn esc3<'i>(i: &mut &'i str) -> TestResult<&'i str, String> {
-            escaped(alpha, '␛', alt(("0".value("\0"), "n".value("\n")))).parse_next(i)
-        }
+        let b: &[u8] = b"ab12cd\nefgh\nijkl";
         assert_parse!(
-            esc3.parse_peek("a␛0bc␛n"),
+            till_line_ending.parse_peek(Partial::new(b)),
             str![[r#"
 Ok(
     (
-        "",
-        "a\0bc\n",
+        Partial {
+            input: [
+                10,
+                101,
+                102,
+                103,
+                104,
+                10,
+                105,
+                106,
+                107,
+                108,
+            ],
+            partial: true,
+        },
+        [
+            97,
+            98,
+            49,
+            50,
+            99,
+            100,
+        ],
     ),
 )
 
 "#]]
             .raw()
         );
-    }
-
-    #[test]
-    #[cfg(feature = "alloc")]
-    fn test_escaped_error() {
-        fn esc_trans<'i>(i: &mut &'i str) -> TestResult<&'i str, String> {
-            use crate::ascii::digit1;
-            escaped(digit1, '\\', "n").parse_next(i)
-        }
 
+        let c: &[u8] = b"ab12cd\r\nefgh\nijkl";
         assert_parse!(
-            esc_trans.parse_peek("abcd"),
+            till_line_ending.parse_peek(Partial::new(c)),
             str![[r#"
 Ok(
     (
-        "abcd",
-        "",
+        Partial {
+            input: [
+                13,
+                10,
+                101,
+                102,
+                103,
+                104,
+                10,
+                105,
+                106,
+                107,
+                108,
+            ],
+            partial: true,
+        },
+        [
+            97,
+            98,
+            49,
+            50,
+            99,
+            100,
+        ],
     ),
 )
 
 "#]]
             .raw()
         );
-    }
-}
-
-mod partial {
-    use super::*;
-
-    use crate::error::InputError;
-    use crate::prelude::*;
-    use crate::Partial;
 
-    #[test]
-    fn character() {
-        let a: &[u8] = b"abcd";
-        let b: &[u8] = b"1234";
-        let c: &[u8] = b"a123";
-        let d: &[u8] = "azé12".as_bytes();
-        let e: &[u8] = b" ";
-        let f: &[u8] = b" ;";
-        //assert_parse!(alpha1::<_, Error<_>>(a), Err(ErrMode::Incomplete(Needed::new(1))));
+        let d: &[u8] = b"ab12cd";
         assert_parse!(
-            alpha1.parse_peek(Partial::new(a)),
+            till_line_ending.parse_peek(Partial::new(d)),
             str![[r#"
 Err(
     Incomplete(
-        Size(
-            1,
-        ),
+        Unknown,
     ),
 )
 
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn is_till_line_ending_str() {
+        let f = "βèƒôřè\rÂßÇáƒƭèř";
         assert_parse!(
-            alpha1.parse_peek(Partial::new(b)),
+            till_line_ending.parse_peek(Partial::new(f)),
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
-                input: [
-                    49,
-                    50,
-                    51,
-                    52,
-                ],
+                input: "\rÂßÇáƒƭèř",
                 partial: true,
             },
         },
@@ -2867,45 +5331,59 @@ Err(
 "#]]
             .raw()
         );
+
+        let g2: &str = "ab12cd";
         assert_parse!(
-            alpha1.parse_peek(Partial::new(c)),
+            till_line_ending.parse_peek(Partial::new(g2)),
             str![[r#"
-Ok(
-    (
-        Partial {
-            input: [
-                49,
-                50,
-                51,
-            ],
-            partial: true,
-        },
-        [
-            97,
-        ],
+Err(
+    Incomplete(
+        Unknown,
     ),
 )
 
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn hex_digit_test() {
+        let i = &b"0123456789abcdefABCDEF;"[..];
         assert_parse!(
-            alpha1.parse_peek(Partial::new(d)),
+            hex_digit1.parse_peek(Partial::new(i)),
             str![[r#"
 Ok(
     (
         Partial {
             input: [
-                195,
-                169,
-                49,
-                50,
+                59,
             ],
             partial: true,
         },
         [
+            48,
+            49,
+            50,
+            51,
+            52,
+            53,
+            54,
+            55,
+            56,
+            57,
             97,
-            122,
+            98,
+            99,
+            100,
+            101,
+            102,
+            65,
+            66,
+            67,
+            68,
+            69,
+            70,
         ],
     ),
 )
@@ -2913,18 +5391,17 @@ Ok(
 "#]]
             .raw()
         );
+
+        let i = &b"g"[..];
         assert_parse!(
-            digit1.parse_peek(Partial::new(a)),
+            hex_digit1.parse_peek(Partial::new(i)),
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
                 input: [
-                    97,
-                    98,
-                    99,
-                    100,
+                    103,
                 ],
                 partial: true,
             },
@@ -2935,32 +5412,17 @@ Err(
 "#]]
             .raw()
         );
-        assert_parse!(
-            digit1.parse_peek(Partial::new(b)),
-            str![[r#"
-Err(
-    Incomplete(
-        Size(
-            1,
-        ),
-    ),
-)
 
-"#]]
-            .raw()
-        );
+        let i = &b"G"[..];
         assert_parse!(
-            digit1.parse_peek(Partial::new(c)),
+            hex_digit1.parse_peek(Partial::new(i)),
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
                 input: [
-                    97,
-                    49,
-                    50,
-                    51,
+                    71,
                 ],
                 partial: true,
             },
@@ -2971,20 +5433,62 @@ Err(
 "#]]
             .raw()
         );
+
+        assert!(AsChar::is_hex_digit(b'0'));
+        assert!(AsChar::is_hex_digit(b'9'));
+        assert!(AsChar::is_hex_digit(b'a'));
+        assert!(AsChar::is_hex_digit(b'f'));
+        assert!(AsChar::is_hex_digit(b'A'));
+        assert!(AsChar::is_hex_digit(b'F'));
+        assert!(!AsChar::is_hex_digit(b'g'));
+        assert!(!AsChar::is_hex_digit(b'G'));
+        assert!(!AsChar::is_hex_digit(b'/'));
+        assert!(!AsChar::is_hex_digit(b':'));
+        assert!(!AsChar::is_hex_digit(b'@'));
+        assert!(!AsChar::is_hex_digit(b'\x60'));
+    }
+
+    #[test]
+    fn oct_digit_test() {
+        let i = &b"01234567;"[..];
+        assert_parse!(
+            oct_digit1.parse_peek(Partial::new(i)),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                59,
+            ],
+            partial: true,
+        },
+        [
+            48,
+            49,
+            50,
+            51,
+            52,
+            53,
+            54,
+            55,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+
+        let i = &b"8"[..];
         assert_parse!(
-            digit1.parse_peek(Partial::new(d)),
+            oct_digit1.parse_peek(Partial::new(i)),
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
                 input: [
-                    97,
-                    122,
-                    195,
-                    169,
-                    49,
-                    50,
+                    56,
                 ],
                 partial: true,
             },
@@ -2995,13 +5499,85 @@ Err(
 "#]]
             .raw()
         );
+
+        assert!(AsChar::is_oct_digit(b'0'));
+        assert!(AsChar::is_oct_digit(b'7'));
+        assert!(!AsChar::is_oct_digit(b'8'));
+        assert!(!AsChar::is_oct_digit(b'9'));
+        assert!(!AsChar::is_oct_digit(b'a'));
+        assert!(!AsChar::is_oct_digit(b'A'));
+        assert!(!AsChar::is_oct_digit(b'/'));
+        assert!(!AsChar::is_oct_digit(b':'));
+        assert!(!AsChar::is_oct_digit(b'@'));
+        assert!(!AsChar::is_oct_digit(b'\x60'));
+    }
+
+    #[test]
+    fn full_line_windows() {
+        #[allow(clippy::type_complexity)]
+        fn take_full_line<'i>(
+            i: &mut Partial<&'i [u8]>,
+        ) -> TestResult<Partial<&'i [u8]>, (&'i [u8], &'i [u8])> {
+            (till_line_ending, line_ending).parse_next(i)
+        }
+        let input = b"abc\r\n";
+        let output = take_full_line.parse_peek(Partial::new(input));
+        assert_parse!(
+            output,
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        (
+            [
+                97,
+                98,
+                99,
+            ],
+            [
+                13,
+                10,
+            ],
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn full_line_unix() {
+        #[allow(clippy::type_complexity)]
+        fn take_full_line<'i>(
+            i: &mut Partial<&'i [u8]>,
+        ) -> TestResult<Partial<&'i [u8]>, (&'i [u8], &'i [u8])> {
+            (till_line_ending, line_ending).parse_next(i)
+        }
+        let input = b"abc\n";
+        let output = take_full_line.parse_peek(Partial::new(input));
         assert_parse!(
-            hex_digit1.parse_peek(Partial::new(a)),
+            output,
             str![[r#"
-Err(
-    Incomplete(
-        Size(
-            1,
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        (
+            [
+                97,
+                98,
+                99,
+            ],
+            [
+                10,
+            ],
         ),
     ),
 )
@@ -3009,14 +5585,17 @@ Err(
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn line_tests() {
+        // Fed one fragment at a time
         assert_parse!(
-            hex_digit1.parse_peek(Partial::new(b)),
+            line.parse_peek(Partial::new("ab")),
             str![[r#"
 Err(
     Incomplete(
-        Size(
-            1,
-        ),
+        Unknown,
     ),
 )
 
@@ -3024,13 +5603,11 @@ Err(
             .raw()
         );
         assert_parse!(
-            hex_digit1.parse_peek(Partial::new(c)),
+            line.parse_peek(Partial::new("ab\r")),
             str![[r#"
 Err(
     Incomplete(
-        Size(
-            1,
-        ),
+        Unknown,
     ),
 )
 
@@ -3038,23 +5615,15 @@ Err(
             .raw()
         );
         assert_parse!(
-            hex_digit1.parse_peek(Partial::new(d)),
+            line.parse_peek(Partial::new("ab\r\ncd")),
             str![[r#"
 Ok(
     (
         Partial {
-            input: [
-                122,
-                195,
-                169,
-                49,
-                50,
-            ],
+            input: "cd",
             partial: true,
         },
-        [
-            97,
-        ],
+        "ab",
     ),
 )
 
@@ -3062,18 +5631,15 @@ Ok(
             .raw()
         );
         assert_parse!(
-            hex_digit1.parse_peek(Partial::new(e)),
+            line.parse_peek(Partial::new("\n")),
             str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    32,
-                ],
-                partial: true,
-            },
+Ok(
+    (
+        Partial {
+            input: "",
+            partial: true,
         },
+        "",
     ),
 )
 
@@ -3081,21 +5647,15 @@ Err(
             .raw()
         );
         assert_parse!(
-            oct_digit1.parse_peek(Partial::new(a)),
+            line_inclusive.parse_peek(Partial::new("ab\r\ncd")),
             str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    97,
-                    98,
-                    99,
-                    100,
-                ],
-                partial: true,
-            },
+Ok(
+    (
+        Partial {
+            input: "cd",
+            partial: true,
         },
+        "ab\r\n",
     ),
 )
 
@@ -3103,111 +5663,84 @@ Err(
             .raw()
         );
         assert_parse!(
-            oct_digit1.parse_peek(Partial::new(b)),
+            line_inclusive.parse_peek(Partial::new("ab")),
             str![[r#"
 Err(
     Incomplete(
-        Size(
-            1,
-        ),
+        Unknown,
     ),
 )
 
 "#]]
             .raw()
         );
-        assert_parse!(
-            oct_digit1.parse_peek(Partial::new(c)),
-            str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    97,
-                    49,
-                    50,
-                    51,
-                ],
-                partial: true,
-            },
-        },
-    ),
-)
+    }
 
-"#]]
-            .raw()
-        );
+    #[test]
+    fn check_windows_lineending() {
+        let input = b"\r\n";
+        let output = line_ending.parse_peek(Partial::new(&input[..]));
         assert_parse!(
-            oct_digit1.parse_peek(Partial::new(d)),
+            output,
             str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    97,
-                    122,
-                    195,
-                    169,
-                    49,
-                    50,
-                ],
-                partial: true,
-            },
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
         },
+        [
+            13,
+            10,
+        ],
     ),
 )
 
 "#]]
             .raw()
         );
-        assert_parse!(
-            alphanumeric1.parse_peek(Partial::new(a)),
-            str![[r#"
-Err(
-    Incomplete(
-        Size(
-            1,
-        ),
-    ),
-)
+    }
 
-"#]]
-            .raw()
-        );
-        //assert_parse!(fix_error!(b,(), alphanumeric1), str![]);
+    #[test]
+    fn check_unix_lineending() {
+        let input = b"\n";
+        let output = line_ending.parse_peek(Partial::new(&input[..]));
         assert_parse!(
-            alphanumeric1.parse_peek(Partial::new(c)),
+            output,
             str![[r#"
-Err(
-    Incomplete(
-        Size(
-            1,
-        ),
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        [
+            10,
+        ],
     ),
 )
 
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn cr_lf() {
         assert_parse!(
-            alphanumeric1.parse_peek(Partial::new(d)),
+            crlf.parse_peek(Partial::new(&b"\r\na"[..])),
             str![[r#"
 Ok(
     (
         Partial {
             input: [
-                195,
-                169,
-                49,
-                50,
+                97,
             ],
             partial: true,
         },
         [
-            97,
-            122,
+            13,
+            10,
         ],
     ),
 )
@@ -3216,7 +5749,7 @@ Ok(
             .raw()
         );
         assert_parse!(
-            space1.parse_peek(Partial::new(e)),
+            crlf.parse_peek(Partial::new(&b"\r"[..])),
             str![[r#"
 Err(
     Incomplete(
@@ -3230,37 +5763,44 @@ Err(
             .raw()
         );
         assert_parse!(
-            space1.parse_peek(Partial::new(f)),
+            crlf.parse_peek(Partial::new(&b"\ra"[..])),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    13,
+                    97,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+
+        assert_parse!(
+            crlf.parse_peek(Partial::new("\r\na")),
             str![[r#"
 Ok(
     (
         Partial {
-            input: [
-                59,
-            ],
+            input: "a",
             partial: true,
         },
-        [
-            32,
-        ],
+        "\r\n",
     ),
 )
 
 "#]]
             .raw()
         );
-    }
-
-    #[cfg(feature = "alloc")]
-    #[test]
-    fn character_s() {
-        let a = "abcd";
-        let b = "1234";
-        let c = "a123";
-        let d = "azé12";
-        let e = " ";
         assert_parse!(
-            alpha1.parse_peek(Partial::new(a)),
+            crlf.parse_peek(Partial::new("\r")),
             str![[r#"
 Err(
     Incomplete(
@@ -3274,13 +5814,13 @@ Err(
             .raw()
         );
         assert_parse!(
-            alpha1.parse_peek(Partial::new(b)),
+            crlf.parse_peek(Partial::new("\ra")),
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
-                input: "1234",
+                input: "\ra",
                 partial: true,
             },
         },
@@ -3290,16 +5830,24 @@ Err(
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn end_of_line() {
         assert_parse!(
-            alpha1.parse_peek(Partial::new(c)),
+            line_ending.parse_peek(Partial::new(&b"\na"[..])),
             str![[r#"
 Ok(
     (
         Partial {
-            input: "123",
+            input: [
+                97,
+            ],
             partial: true,
         },
-        "a",
+        [
+            10,
+        ],
     ),
 )
 
@@ -3307,32 +5855,20 @@ Ok(
             .raw()
         );
         assert_parse!(
-            alpha1.parse_peek(Partial::new(d)),
+            line_ending.parse_peek(Partial::new(&b"\r\na"[..])),
             str![[r#"
 Ok(
     (
         Partial {
-            input: "é12",
-            partial: true,
-        },
-        "az",
-    ),
-)
-
-"#]]
-            .raw()
-        );
-        assert_parse!(
-            digit1.parse_peek(Partial::new(a)),
-            str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: "abcd",
-                partial: true,
-            },
+            input: [
+                97,
+            ],
+            partial: true,
         },
+        [
+            13,
+            10,
+        ],
     ),
 )
 
@@ -3340,7 +5876,7 @@ Err(
             .raw()
         );
         assert_parse!(
-            digit1.parse_peek(Partial::new(b)),
+            line_ending.parse_peek(Partial::new(&b"\r"[..])),
             str![[r#"
 Err(
     Incomplete(
@@ -3354,13 +5890,16 @@ Err(
             .raw()
         );
         assert_parse!(
-            digit1.parse_peek(Partial::new(c)),
+            line_ending.parse_peek(Partial::new(&b"\ra"[..])),
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
-                input: "a123",
+                input: [
+                    13,
+                    97,
+                ],
                 partial: true,
             },
         },
@@ -3370,17 +5909,17 @@ Err(
 "#]]
             .raw()
         );
+
         assert_parse!(
-            digit1.parse_peek(Partial::new(d)),
+            line_ending.parse_peek(Partial::new("\na")),
             str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: "azé12",
-                partial: true,
-            },
+Ok(
+    (
+        Partial {
+            input: "a",
+            partial: true,
         },
+        "\n",
     ),
 )
 
@@ -3388,7 +5927,23 @@ Err(
             .raw()
         );
         assert_parse!(
-            hex_digit1.parse_peek(Partial::new(a)),
+            line_ending.parse_peek(Partial::new("\r\na")),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: "a",
+            partial: true,
+        },
+        "\r\n",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            line_ending.parse_peek(Partial::new("\r")),
             str![[r#"
 Err(
     Incomplete(
@@ -3402,26 +5957,37 @@ Err(
             .raw()
         );
         assert_parse!(
-            hex_digit1.parse_peek(Partial::new(b)),
+            line_ending.parse_peek(Partial::new("\ra")),
             str![[r#"
 Err(
-    Incomplete(
-        Size(
-            1,
-        ),
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: "\ra",
+                partial: true,
+            },
+        },
     ),
 )
 
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn dec_uint_fixed_tests() {
+        fn year<'i>(input: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, u16> {
+            dec_uint_fixed::<4, _, _, _>.parse_next(input)
+        }
+
         assert_parse!(
-            hex_digit1.parse_peek(Partial::new(c)),
+            year.parse_peek(Partial::new("20")),
             str![[r#"
 Err(
     Incomplete(
         Size(
-            1,
+            2,
         ),
     ),
 )
@@ -3430,15 +5996,15 @@ Err(
             .raw()
         );
         assert_parse!(
-            hex_digit1.parse_peek(Partial::new(d)),
+            year.parse_peek(Partial::new("2024")),
             str![[r#"
 Ok(
     (
         Partial {
-            input: "zé12",
+            input: "",
             partial: true,
         },
-        "a",
+        2024,
     ),
 )
 
@@ -3446,16 +6012,15 @@ Ok(
             .raw()
         );
         assert_parse!(
-            hex_digit1.parse_peek(Partial::new(e)),
+            year.parse_peek(Partial::new("202401")),
             str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: " ",
-                partial: true,
-            },
+Ok(
+    (
+        Partial {
+            input: "01",
+            partial: true,
         },
+        2024,
     ),
 )
 
@@ -3463,13 +6028,13 @@ Err(
             .raw()
         );
         assert_parse!(
-            oct_digit1.parse_peek(Partial::new(a)),
+            year.parse_peek(Partial::new("20a")),
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
-                input: "abcd",
+                input: "20a",
                 partial: true,
             },
         },
@@ -3479,14 +6044,28 @@ Err(
 "#]]
             .raw()
         );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn escaped_with_limits_tests() {
+        use crate::ascii::alpha1 as alpha;
+        use crate::combinator::alt;
+
+        fn esc<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, String> {
+            escaped_with_limits(1.., alpha, '\\', alt(('\\', '"', "n".value('\n')))).parse_next(i)
+        }
+
         assert_parse!(
-            oct_digit1.parse_peek(Partial::new(b)),
+            esc.parse_peek(Partial::new("a\\nb;")),
             str![[r#"
-Err(
-    Incomplete(
-        Size(
-            1,
-        ),
+Ok(
+    (
+        Partial {
+            input: ";",
+            partial: true,
+        },
+        "a\nb",
     ),
 )
 
@@ -3494,16 +6073,11 @@ Err(
             .raw()
         );
         assert_parse!(
-            oct_digit1.parse_peek(Partial::new(c)),
+            esc.parse_peek(Partial::new("")),
             str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: "a123",
-                partial: true,
-            },
-        },
+    Incomplete(
+        Unknown,
     ),
 )
 
@@ -3511,13 +6085,13 @@ Err(
             .raw()
         );
         assert_parse!(
-            oct_digit1.parse_peek(Partial::new(d)),
+            esc.parse_peek(Partial::new(";")),
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
-                input: "azé12",
+                input: ";",
                 partial: true,
             },
         },
@@ -3528,22 +6102,7 @@ Err(
             .raw()
         );
         assert_parse!(
-            alphanumeric1.parse_peek(Partial::new(a)),
-            str![[r#"
-Err(
-    Incomplete(
-        Size(
-            1,
-        ),
-    ),
-)
-
-"#]]
-            .raw()
-        );
-        //assert_parse!(fix_error!(b,(), alphanumeric1), str![]);
-        assert_parse!(
-            alphanumeric1.parse_peek(Partial::new(c)),
+            esc.parse_peek(Partial::new("\\")),
             str![[r#"
 Err(
     Incomplete(
@@ -3557,23 +6116,7 @@ Err(
             .raw()
         );
         assert_parse!(
-            alphanumeric1.parse_peek(Partial::new(d)),
-            str![[r#"
-Ok(
-    (
-        Partial {
-            input: "é12",
-            partial: true,
-        },
-        "az",
-    ),
-)
-
-"#]]
-            .raw()
-        );
-        assert_parse!(
-            space1.parse_peek(Partial::new(e)),
+            esc.parse_peek(Partial::new("ab\\")),
             str![[r#"
 Err(
     Incomplete(
@@ -3588,199 +6131,85 @@ Err(
         );
     }
 
-    use crate::stream::Offset;
     #[test]
-    fn offset() {
-        let a = &b"abcd;"[..];
-        let b = &b"1234;"[..];
-        let c = &b"a123;"[..];
-        let d = &b" \t;"[..];
-        let e = &b" \t\r\n;"[..];
-        let f = &b"123abcDEF;"[..];
-
-        match alpha1::<_, InputError<_>>.parse_peek(Partial::new(a)) {
-            Ok((i, _)) => {
-                let i = i.into_inner();
-                assert_eq!(i.offset_from(&a) + i.len(), a.len());
-            }
-            _ => panic!("wrong return type in offset test for alpha"),
-        }
-        match digit1::<_, InputError<_>>.parse_peek(Partial::new(b)) {
-            Ok((i, _)) => {
-                let i = i.into_inner();
-                assert_eq!(i.offset_from(&b) + i.len(), b.len());
-            }
-            _ => panic!("wrong return type in offset test for digit"),
-        }
-        match alphanumeric1::<_, InputError<_>>.parse_peek(Partial::new(c)) {
-            Ok((i, _)) => {
-                let i = i.into_inner();
-                assert_eq!(i.offset_from(&c) + i.len(), c.len());
-            }
-            _ => panic!("wrong return type in offset test for alphanumeric"),
-        }
-        match space1::<_, InputError<_>>.parse_peek(Partial::new(d)) {
-            Ok((i, _)) => {
-                let i = i.into_inner();
-                assert_eq!(i.offset_from(&d) + i.len(), d.len());
-            }
-            _ => panic!("wrong return type in offset test for space"),
-        }
-        match multispace1::<_, InputError<_>>.parse_peek(Partial::new(e)) {
-            Ok((i, _)) => {
-                let i = i.into_inner();
-                assert_eq!(i.offset_from(&e) + i.len(), e.len());
-            }
-            _ => panic!("wrong return type in offset test for multispace"),
-        }
-        match hex_digit1::<_, InputError<_>>.parse_peek(Partial::new(f)) {
-            Ok((i, _)) => {
-                let i = i.into_inner();
-                assert_eq!(i.offset_from(&f) + i.len(), f.len());
-            }
-            _ => panic!("wrong return type in offset test for hex_digit"),
+    fn take_while_ascii_tests() {
+        fn blank<'i>(input: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, &'i str> {
+            take_while_ascii(0.., (' ', '\t')).parse_next(input)
         }
-        match oct_digit1::<_, InputError<_>>.parse_peek(Partial::new(f)) {
-            Ok((i, _)) => {
-                let i = i.into_inner();
-                assert_eq!(i.offset_from(&f) + i.len(), f.len());
-            }
-            _ => panic!("wrong return type in offset test for oct_digit"),
+        fn short_alpha<'i>(input: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, &'i str> {
+            take_while_ascii(2..=4, AsChar::is_alpha).parse_next(input)
         }
-    }
 
-    #[test]
-    fn is_till_line_ending_bytes() {
-        let a: &[u8] = b"ab12cd\nefgh";
         assert_parse!(
-            till_line_ending.parse_peek(Partial::new(a)),
+            blank.parse_peek(Partial::new(" \t é")),
             str![[r#"
 Ok(
     (
         Partial {
-            input: [
-                10,
-                101,
-                102,
-                103,
-                104,
-            ],
+            input: "é",
             partial: true,
         },
-        [
-            97,
-            98,
-            49,
-            50,
-            99,
-            100,
-        ],
+        " \t ",
     ),
 )
 
 "#]]
             .raw()
         );
-
-        let b: &[u8] = b"ab12cd\nefgh\nijkl";
         assert_parse!(
-            till_line_ending.parse_peek(Partial::new(b)),
+            blank.parse_peek(Partial::new("  \t")),
             str![[r#"
-Ok(
-    (
-        Partial {
-            input: [
-                10,
-                101,
-                102,
-                103,
-                104,
-                10,
-                105,
-                106,
-                107,
-                108,
-            ],
-            partial: true,
-        },
-        [
-            97,
-            98,
-            49,
-            50,
-            99,
-            100,
-        ],
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
             .raw()
         );
-
-        let c: &[u8] = b"ab12cd\r\nefgh\nijkl";
         assert_parse!(
-            till_line_ending.parse_peek(Partial::new(c)),
+            short_alpha.parse_peek(Partial::new("abcdeé")),
             str![[r#"
 Ok(
     (
         Partial {
-            input: [
-                13,
-                10,
-                101,
-                102,
-                103,
-                104,
-                10,
-                105,
-                106,
-                107,
-                108,
-            ],
+            input: "eé",
             partial: true,
         },
-        [
-            97,
-            98,
-            49,
-            50,
-            99,
-            100,
-        ],
+        "abcd",
     ),
 )
 
 "#]]
             .raw()
         );
-
-        let d: &[u8] = b"ab12cd";
         assert_parse!(
-            till_line_ending.parse_peek(Partial::new(d)),
+            short_alpha.parse_peek(Partial::new("abé")),
             str![[r#"
-Err(
-    Incomplete(
-        Unknown,
+Ok(
+    (
+        Partial {
+            input: "é",
+            partial: true,
+        },
+        "ab",
     ),
 )
 
 "#]]
             .raw()
         );
-    }
-
-    #[test]
-    fn is_till_line_ending_str() {
-        let f = "βèƒôřè\rÂßÇáƒƭèř";
         assert_parse!(
-            till_line_ending.parse_peek(Partial::new(f)),
+            short_alpha.parse_peek(Partial::new("aé")),
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
-                input: "\rÂßÇáƒƭèř",
+                input: "aé",
                 partial: true,
             },
         },
@@ -3790,14 +6219,30 @@ Err(
 "#]]
             .raw()
         );
-
-        let g2: &str = "ab12cd";
         assert_parse!(
-            till_line_ending.parse_peek(Partial::new(g2)),
+            short_alpha.parse_peek(Partial::new("a")),
             str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            short_alpha.parse_peek(Partial::new("abcd")),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: "",
+            partial: true,
+        },
+        "abcd",
     ),
 )
 
@@ -3807,42 +6252,28 @@ Err(
     }
 
     #[test]
-    fn hex_digit_test() {
-        let i = &b"0123456789abcdefABCDEF;"[..];
+    fn identifier_tests() {
+        fn ident<'i>(input: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+            identifier.parse_next(input)
+        }
+
         assert_parse!(
-            hex_digit1.parse_peek(Partial::new(i)),
+            ident.parse_peek(Partial::new(&b"foo_1 "[..])),
             str![[r#"
 Ok(
     (
         Partial {
             input: [
-                59,
+                32,
             ],
             partial: true,
         },
         [
-            48,
-            49,
-            50,
-            51,
-            52,
-            53,
-            54,
-            55,
-            56,
-            57,
-            97,
-            98,
-            99,
-            100,
-            101,
             102,
-            65,
-            66,
-            67,
-            68,
-            69,
-            70,
+            111,
+            111,
+            95,
+            49,
         ],
     ),
 )
@@ -3850,104 +6281,60 @@ Ok(
 "#]]
             .raw()
         );
-
-        let i = &b"g"[..];
         assert_parse!(
-            hex_digit1.parse_peek(Partial::new(i)),
+            ident.parse_peek(Partial::new(&b"foo_1"[..])),
             str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    103,
-                ],
-                partial: true,
-            },
-        },
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
             .raw()
         );
-
-        let i = &b"G"[..];
         assert_parse!(
-            hex_digit1.parse_peek(Partial::new(i)),
+            ident.parse_peek(Partial::new(&b"_"[..])),
             str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    71,
-                ],
-                partial: true,
-            },
-        },
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
             .raw()
         );
-
-        assert!(AsChar::is_hex_digit(b'0'));
-        assert!(AsChar::is_hex_digit(b'9'));
-        assert!(AsChar::is_hex_digit(b'a'));
-        assert!(AsChar::is_hex_digit(b'f'));
-        assert!(AsChar::is_hex_digit(b'A'));
-        assert!(AsChar::is_hex_digit(b'F'));
-        assert!(!AsChar::is_hex_digit(b'g'));
-        assert!(!AsChar::is_hex_digit(b'G'));
-        assert!(!AsChar::is_hex_digit(b'/'));
-        assert!(!AsChar::is_hex_digit(b':'));
-        assert!(!AsChar::is_hex_digit(b'@'));
-        assert!(!AsChar::is_hex_digit(b'\x60'));
-    }
-
-    #[test]
-    fn oct_digit_test() {
-        let i = &b"01234567;"[..];
         assert_parse!(
-            oct_digit1.parse_peek(Partial::new(i)),
+            ident.parse_peek(Partial::new(&b""[..])),
             str![[r#"
-Ok(
-    (
-        Partial {
-            input: [
-                59,
-            ],
-            partial: true,
-        },
-        [
-            48,
-            49,
-            50,
-            51,
-            52,
-            53,
-            54,
-            55,
-        ],
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
             .raw()
         );
-
-        let i = &b"8"[..];
         assert_parse!(
-            oct_digit1.parse_peek(Partial::new(i)),
+            ident.parse_peek(Partial::new(&b"1foo"[..])),
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
                 input: [
-                    56,
+                    49,
+                    102,
+                    111,
+                    111,
                 ],
                 partial: true,
             },
@@ -3958,160 +6345,99 @@ Err(
 "#]]
             .raw()
         );
-
-        assert!(AsChar::is_oct_digit(b'0'));
-        assert!(AsChar::is_oct_digit(b'7'));
-        assert!(!AsChar::is_oct_digit(b'8'));
-        assert!(!AsChar::is_oct_digit(b'9'));
-        assert!(!AsChar::is_oct_digit(b'a'));
-        assert!(!AsChar::is_oct_digit(b'A'));
-        assert!(!AsChar::is_oct_digit(b'/'));
-        assert!(!AsChar::is_oct_digit(b':'));
-        assert!(!AsChar::is_oct_digit(b'@'));
-        assert!(!AsChar::is_oct_digit(b'\x60'));
     }
 
     #[test]
-    fn full_line_windows() {
-        #[allow(clippy::type_complexity)]
-        fn take_full_line<'i>(
-            i: &mut Partial<&'i [u8]>,
-        ) -> TestResult<Partial<&'i [u8]>, (&'i [u8], &'i [u8])> {
-            (till_line_ending, line_ending).parse_next(i)
+    fn hex_uint_tests() {
+        fn hex_u32<'i>(input: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, u32> {
+            hex_uint.parse_next(input)
         }
-        let input = b"abc\r\n";
-        let output = take_full_line.parse_peek(Partial::new(input));
+
         assert_parse!(
-            output,
+            hex_u32.parse_peek(Partial::new(&b";"[..])),
             str![[r#"
-Ok(
-    (
-        Partial {
-            input: [],
-            partial: true,
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    59,
+                ],
+                partial: true,
+            },
         },
-        (
-            [
-                97,
-                98,
-                99,
-            ],
-            [
-                13,
-                10,
-            ],
-        ),
     ),
 )
 
 "#]]
             .raw()
         );
-    }
-
-    #[test]
-    fn full_line_unix() {
-        #[allow(clippy::type_complexity)]
-        fn take_full_line<'i>(
-            i: &mut Partial<&'i [u8]>,
-        ) -> TestResult<Partial<&'i [u8]>, (&'i [u8], &'i [u8])> {
-            (till_line_ending, line_ending).parse_next(i)
-        }
-        let input = b"abc\n";
-        let output = take_full_line.parse_peek(Partial::new(input));
         assert_parse!(
-            output,
-            str![[r#"
-Ok(
-    (
-        Partial {
-            input: [],
-            partial: true,
-        },
-        (
-            [
-                97,
-                98,
-                99,
-            ],
-            [
-                10,
+            hex_u32.parse_peek(Partial::new(&b"ff;"[..])),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                59,
             ],
-        ),
+            partial: true,
+        },
+        255,
     ),
 )
 
 "#]]
             .raw()
         );
-    }
-
-    #[test]
-    fn check_windows_lineending() {
-        let input = b"\r\n";
-        let output = line_ending.parse_peek(Partial::new(&input[..]));
         assert_parse!(
-            output,
+            hex_u32.parse_peek(Partial::new(&b"1be2;"[..])),
             str![[r#"
 Ok(
     (
         Partial {
-            input: [],
+            input: [
+                59,
+            ],
             partial: true,
         },
-        [
-            13,
-            10,
-        ],
+        7138,
     ),
 )
 
 "#]]
             .raw()
         );
-    }
-
-    #[test]
-    fn check_unix_lineending() {
-        let input = b"\n";
-        let output = line_ending.parse_peek(Partial::new(&input[..]));
         assert_parse!(
-            output,
+            hex_u32.parse_peek(Partial::new(&b"c5a31be2;"[..])),
             str![[r#"
 Ok(
     (
         Partial {
-            input: [],
+            input: [
+                59,
+            ],
             partial: true,
         },
-        [
-            10,
-        ],
+        3315801058,
     ),
 )
 
 "#]]
             .raw()
         );
-    }
-
-    #[test]
-    fn cr_lf() {
         assert_parse!(
-            crlf.parse_peek(Partial::new(&b"\r\na"[..])),
+            hex_u32.parse_peek(Partial::new(&b"C5A31be2;"[..])),
             str![[r#"
 Ok(
     (
         Partial {
             input: [
-                97,
+                59,
             ],
             partial: true,
         },
-        [
-            13,
-            10,
-        ],
+        3315801058,
     ),
 )
 
@@ -4119,11 +6445,28 @@ Ok(
             .raw()
         );
         assert_parse!(
-            crlf.parse_peek(Partial::new(&b"\r"[..])),
+            hex_u32.parse_peek(Partial::new(&b"00c5a31be2;"[..])), // overflow
             str![[r#"
 Err(
-    Incomplete(
-        Unknown,
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    48,
+                    48,
+                    99,
+                    53,
+                    97,
+                    51,
+                    49,
+                    98,
+                    101,
+                    50,
+                    59,
+                ],
+                partial: true,
+            },
+        },
     ),
 )
 
@@ -4131,15 +6474,24 @@ Err(
             .raw()
         );
         assert_parse!(
-            crlf.parse_peek(Partial::new(&b"\ra"[..])),
+            hex_u32.parse_peek(Partial::new(&b"c5a31be201;"[..])), // overflow
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
                 input: [
-                    13,
+                    99,
+                    53,
                     97,
+                    51,
+                    49,
+                    98,
+                    101,
+                    50,
+                    48,
+                    49,
+                    59,
                 ],
                 partial: true,
             },
@@ -4150,17 +6502,18 @@ Err(
 "#]]
             .raw()
         );
-
         assert_parse!(
-            crlf.parse_peek(Partial::new("\r\na")),
+            hex_u32.parse_peek(Partial::new(&b"ffffffff;"[..])),
             str![[r#"
 Ok(
     (
         Partial {
-            input: "a",
+            input: [
+                59,
+            ],
             partial: true,
         },
-        "\r\n",
+        4294967295,
     ),
 )
 
@@ -4168,11 +6521,34 @@ Ok(
             .raw()
         );
         assert_parse!(
-            crlf.parse_peek(Partial::new("\r")),
+            hex_u32.parse_peek(Partial::new(&b"ffffffffffffffff;"[..])), // overflow
             str![[r#"
 Err(
-    Incomplete(
-        Unknown,
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    59,
+                ],
+                partial: true,
+            },
+        },
     ),
 )
 
@@ -4180,13 +6556,30 @@ Err(
             .raw()
         );
         assert_parse!(
-            crlf.parse_peek(Partial::new("\ra")),
+            hex_u32.parse_peek(Partial::new(&b"ffffffffffffffff"[..])), // overflow
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
-                input: "\ra",
+                input: [
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                    102,
+                ],
                 partial: true,
             },
         },
@@ -4196,24 +6589,23 @@ Err(
 "#]]
             .raw()
         );
-    }
-
-    #[test]
-    fn end_of_line() {
         assert_parse!(
-            line_ending.parse_peek(Partial::new(&b"\na"[..])),
+            hex_u32.parse_peek(Partial::new(&b"0x1be2;"[..])),
             str![[r#"
 Ok(
     (
         Partial {
             input: [
-                97,
+                120,
+                49,
+                98,
+                101,
+                50,
+                59,
             ],
             partial: true,
         },
-        [
-            10,
-        ],
+        0,
     ),
 )
 
@@ -4221,20 +6613,42 @@ Ok(
             .raw()
         );
         assert_parse!(
-            line_ending.parse_peek(Partial::new(&b"\r\na"[..])),
+            hex_u32.parse_peek(Partial::new(&b"12af"[..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn whitespace_block_comment() {
+        const WS: Whitespace = Whitespace::new()
+            .line_comment("//")
+            .block_comment("/*", "*/")
+            .nested(true);
+
+        fn ws<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, &'i str> {
+            whitespace(WS).parse_next(i)
+        }
+
+        assert_parse!(
+            ws.parse_peek(Partial::new(" /* a /* b */ */x")),
             str![[r#"
 Ok(
     (
         Partial {
-            input: [
-                97,
-            ],
+            input: "x",
             partial: true,
         },
-        [
-            13,
-            10,
-        ],
+        " /* a /* b */ */",
     ),
 )
 
@@ -4242,11 +6656,13 @@ Ok(
             .raw()
         );
         assert_parse!(
-            line_ending.parse_peek(Partial::new(&b"\r"[..])),
+            ws.parse_peek(Partial::new(" /* a /* b */ *")),
             str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -4254,16 +6670,65 @@ Err(
             .raw()
         );
         assert_parse!(
-            line_ending.parse_peek(Partial::new(&b"\ra"[..])),
+            ws.parse_peek(Partial::new(" // a")),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            ws.parse_peek(Partial::new(" /")),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn keyword_tests() {
+        fn kw_let<'i>(input: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, &'i str> {
+            keyword("let").parse_next(input)
+        }
+
+        assert_parse!(
+            kw_let.parse_peek(Partial::new("let x")),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: " x",
+            partial: true,
+        },
+        "let",
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            kw_let.parse_peek(Partial::new("lets")),
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
-                input: [
-                    13,
-                    97,
-                ],
+                input: "lets",
                 partial: true,
             },
         },
@@ -4273,17 +6738,17 @@ Err(
 "#]]
             .raw()
         );
-
         assert_parse!(
-            line_ending.parse_peek(Partial::new("\na")),
+            kw_let.parse_peek(Partial::new("let_")),
             str![[r#"
-Ok(
-    (
-        Partial {
-            input: "a",
-            partial: true,
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: "let_",
+                partial: true,
+            },
         },
-        "\n",
     ),
 )
 
@@ -4291,15 +6756,15 @@ Ok(
             .raw()
         );
         assert_parse!(
-            line_ending.parse_peek(Partial::new("\r\na")),
+            kw_let.parse_peek(Partial::new("let!")),
             str![[r#"
 Ok(
     (
         Partial {
-            input: "a",
+            input: "!",
             partial: true,
         },
-        "\r\n",
+        "let",
     ),
 )
 
@@ -4307,11 +6772,13 @@ Ok(
             .raw()
         );
         assert_parse!(
-            line_ending.parse_peek(Partial::new("\r")),
+            kw_let.parse_peek(Partial::new("let")),
             str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -4319,16 +6786,13 @@ Err(
             .raw()
         );
         assert_parse!(
-            line_ending.parse_peek(Partial::new("\ra")),
+            kw_let.parse_peek(Partial::new("le")),
             str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: "\ra",
-                partial: true,
-            },
-        },
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -4338,24 +6802,21 @@ Err(
     }
 
     #[test]
-    fn hex_uint_tests() {
-        fn hex_u32<'i>(input: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, u32> {
-            hex_uint.parse_next(input)
+    fn word_boundary_test() {
+        fn keyword_if<'i>(
+            i: &mut Partial<LocatingSlice<&'i str>>,
+        ) -> TestResult<Partial<LocatingSlice<&'i str>>, &'i str> {
+            ("if", word_boundary).map(|(k, _)| k).parse_next(i)
         }
 
         assert_parse!(
-            hex_u32.parse_peek(Partial::new(&b";"[..])),
+            keyword_if.parse_peek(Partial::new(LocatingSlice::new("if"))),
             str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    59,
-                ],
-                partial: true,
-            },
-        },
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -4363,35 +6824,48 @@ Err(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(Partial::new(&b"ff;"[..])),
+            keyword_if.parse_peek(Partial::new(LocatingSlice::new("if "))),
             str![[r#"
 Ok(
     (
         Partial {
-            input: [
-                59,
-            ],
+            input: LocatingSlice {
+                initial: "if ",
+                input: " ",
+            },
             partial: true,
         },
-        255,
+        "if",
     ),
 )
 
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn hex_bytes_tests() {
+        use crate::lib::std::vec::Vec;
+
+        fn hex<'i>(input: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, Vec<u8>> {
+            hex_bytes(1..=3).parse_next(input)
+        }
+
         assert_parse!(
-            hex_u32.parse_peek(Partial::new(&b"1be2;"[..])),
+            hex.parse_peek(Partial::new("dEaD;")),
             str![[r#"
 Ok(
     (
         Partial {
-            input: [
-                59,
-            ],
+            input: ";",
             partial: true,
         },
-        7138,
+        [
+            222,
+            173,
+        ],
     ),
 )
 
@@ -4399,17 +6873,19 @@ Ok(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(Partial::new(&b"c5a31be2;"[..])),
+            hex.parse_peek(Partial::new("deadbeef")),
             str![[r#"
 Ok(
     (
         Partial {
-            input: [
-                59,
-            ],
+            input: "ef",
             partial: true,
         },
-        3315801058,
+        [
+            222,
+            173,
+            190,
+        ],
     ),
 )
 
@@ -4417,17 +6893,13 @@ Ok(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(Partial::new(&b"C5A31be2;"[..])),
+            hex.parse_peek(Partial::new("dead")),
             str![[r#"
-Ok(
-    (
-        Partial {
-            input: [
-                59,
-            ],
-            partial: true,
-        },
-        3315801058,
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -4435,28 +6907,13 @@ Ok(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(Partial::new(&b"00c5a31be2;"[..])), // overflow
+            hex.parse_peek(Partial::new("dea")),
             str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    48,
-                    48,
-                    99,
-                    53,
-                    97,
-                    51,
-                    49,
-                    98,
-                    101,
-                    50,
-                    59,
-                ],
-                partial: true,
-            },
-        },
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -4464,25 +6921,13 @@ Err(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(Partial::new(&b"c5a31be201;"[..])), // overflow
+            hex.parse_peek(Partial::new("deaX")),
             str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
-                input: [
-                    99,
-                    53,
-                    97,
-                    51,
-                    49,
-                    98,
-                    101,
-                    50,
-                    48,
-                    49,
-                    59,
-                ],
+                input: "aX",
                 partial: true,
             },
         },
@@ -4492,18 +6937,33 @@ Err(
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn base64_tests() {
+        use crate::lib::std::vec::Vec;
+
+        fn b64<'i>(input: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, Vec<u8>> {
+            base64.parse_next(input)
+        }
+        fn b64_wrapped<'i>(input: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, Vec<u8>> {
+            base64_wrapped.parse_next(input)
+        }
+
         assert_parse!(
-            hex_u32.parse_peek(Partial::new(&b"ffffffff;"[..])),
+            b64.parse_peek(Partial::new("aGk=;")),
             str![[r#"
 Ok(
     (
         Partial {
-            input: [
-                59,
-            ],
+            input: ";",
             partial: true,
         },
-        4294967295,
+        [
+            104,
+            105,
+        ],
     ),
 )
 
@@ -4511,34 +6971,13 @@ Ok(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(Partial::new(&b"ffffffffffffffff;"[..])), // overflow
+            b64.parse_peek(Partial::new("aGVs")),
             str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    59,
-                ],
-                partial: true,
-            },
-        },
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -4546,33 +6985,13 @@ Err(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(Partial::new(&b"ffffffffffffffff"[..])), // overflow
+            b64.parse_peek(Partial::new("aGV")),
             str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                    102,
-                ],
-                partial: true,
-            },
-        },
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -4580,22 +6999,13 @@ Err(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(Partial::new(&b"0x1be2;"[..])),
+            b64.parse_peek(Partial::new("aG=")),
             str![[r#"
-Ok(
-    (
-        Partial {
-            input: [
-                120,
-                49,
-                98,
-                101,
-                50,
-                59,
-            ],
-            partial: true,
-        },
-        0,
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -4603,7 +7013,7 @@ Ok(
             .raw()
         );
         assert_parse!(
-            hex_u32.parse_peek(Partial::new(&b"12af"[..])),
+            b64_wrapped.parse_peek(Partial::new("aGVs\n")),
             str![[r#"
 Err(
     Incomplete(
@@ -4616,5 +7026,71 @@ Err(
 "#]]
             .raw()
         );
+        assert_parse!(
+            b64_wrapped.parse_peek(Partial::new("aGVs\nbG8h\n-")),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: "\n-",
+            partial: true,
+        },
+        [
+            104,
+            101,
+            108,
+            108,
+            111,
+            33,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn needed() {
+        use crate::error::{ContextError, ErrMode};
+        use crate::testing::check_needed;
+
+        let _ = check_needed(crlf::<_, ErrMode<ContextError>>, "\r\n");
+        let _ = check_needed(newline::<_, ErrMode<ContextError>>, "\n");
+        let _ = check_needed(tab::<_, ErrMode<ContextError>>, "\t");
+        let _ = check_needed(till_line_ending::<_, ErrMode<ContextError>>, "ab\r\n");
+        let _ = check_needed(till_line_ending::<_, ErrMode<ContextError>>, "ab\rc");
+
+        // Everything that is known to be missing is needed at once
+        assert_eq!(
+            crlf::<_, ErrMode<ContextError>>.parse_peek(Partial::new("")),
+            Err(ErrMode::Incomplete(Needed::new(2)))
+        );
+        assert_eq!(
+            till_line_ending::<_, ErrMode<ContextError>>.parse_peek(Partial::new("ab\r")),
+            Err(ErrMode::Incomplete(Needed::new(1)))
+        );
+    }
+
+    proptest! {
+      #[test]
+      #[cfg(feature = "std")]
+      #[cfg_attr(miri, ignore)]  // See https://github.com/AltSysrq/proptest/issues/253
+      fn chunked(s in "[0-9a-z +\\-.\r\n]{0,12}") {
+          use crate::error::{ContextError, ErrMode};
+          use crate::testing::{check_invariants, check_needed, check_partial};
+
+          let _ = check_partial(alpha1::<_, ErrMode<ContextError>>, s.as_str());
+          let _ = check_partial(digit1::<_, ErrMode<ContextError>>, s.as_str());
+          let _ = check_partial(space0::<_, ErrMode<ContextError>>, s.as_str());
+          let _ = check_partial(line_ending::<_, ErrMode<ContextError>>, s.as_str());
+          let _ = check_partial(dec_int::<_, i32, ErrMode<ContextError>>, s.as_str());
+          let _ = check_partial(dec_uint::<_, u8, ErrMode<ContextError>>, s.as_str());
+          let _ = check_invariants(dec_int::<_, i32, ErrMode<ContextError>>, s.as_str());
+          let _ = check_invariants(dec_uint::<_, u64, ErrMode<ContextError>>, s.as_str());
+          let _ = check_needed(crlf::<_, ErrMode<ContextError>>, s.as_str());
+      }
     }
 }
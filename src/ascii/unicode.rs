@@ -0,0 +1,181 @@
+//! Unicode identifier parsers, backed by [`unicode-ident`](https://docs.rs/unicode-ident)
+//!
+//! These work on [`char`] tokens, like `&str`.  Byte streams, like `&[u8]`, are not supported as
+//! UTF-8 can't be decoded one byte at a time; using these with a byte stream is a compile error
+//! as [`XidStart`] and [`XidContinue`] don't implement [`ContainsToken<u8>`].
+
+use crate::ascii::identifier_with;
+use crate::combinator::trace;
+use crate::error::{ParserError, StrContextValue};
+use crate::stream::{ContainsToken, Stream, StreamIsPartial};
+use crate::Parser;
+use crate::Result;
+
+/// Recognizes a [UAX #31](https://www.unicode.org/reports/tr31/) default identifier:
+/// `XID_Start XID_Continue*`
+///
+/// `_` is not `XID_Start`; to allow identifiers to start with it, like Rust does, see
+/// [`identifier_with`]:
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::identifier_with;
+/// # use winnow::ascii::unicode::{XidStart, XidContinue};
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     identifier_with((XidStart, '_'), XidContinue).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("_größe = 2"), Ok((" = 2", "_größe")));
+/// ```
+///
+/// *Complete version*: Will return the whole input if no terminating token is found (a token
+/// outside of `XID_Continue`).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a token outside of `XID_Continue`).
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn xid_identifier<'i>(input: &mut &'i str) -> ModalResult<&'i str>
+/// # {
+/// #     winnow::ascii::unicode::xid_identifier.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::unicode::xid_identifier;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     xid_identifier.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("größe = 2"), Ok((" = 2", "größe")));
+/// assert_eq!(parser.parse_peek("東京1"), Ok(("", "東京1")));
+/// assert!(parser.parse_peek("1foo").is_err());
+/// assert!(parser.parse_peek("🦀").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// # use winnow::ascii::unicode::xid_identifier;
+/// assert_eq!(xid_identifier::<_, ErrMode<ContextError>>.parse_peek(Partial::new("größe ")), Ok((Partial::new(" "), "größe")));
+/// assert_eq!(xid_identifier::<_, ErrMode<ContextError>>.parse_peek(Partial::new("größe")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// assert_eq!(xid_identifier::<_, ErrMode<ContextError>>.parse_peek(Partial::new("")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+pub fn xid_identifier<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: Clone,
+    XidStart: ContainsToken<<Input as Stream>::Token>,
+    XidContinue: ContainsToken<<Input as Stream>::Token>,
+    Error: ParserError<Input>,
+{
+    trace("xid_identifier", identifier_with(XidStart, XidContinue)).parse_next(input)
+}
+
+/// The set of [`char`]s with the `XID_Start` property, for use with
+/// [`identifier_with`], [`one_of`][crate::token::one_of], etc
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::unicode::XidStart;
+/// # use winnow::token::one_of;
+/// fn parser(input: &mut &str) -> ModalResult<char> {
+///     one_of(XidStart).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("ö"), Ok(("", 'ö')));
+/// assert!(parser.parse_peek("_").is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct XidStart;
+
+impl ContainsToken<char> for XidStart {
+    #[inline(always)]
+    fn contains_token(&self, token: char) -> bool {
+        unicode_ident::is_xid_start(token)
+    }
+
+    #[inline(always)]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        f(init, StrContextValue::Description("XID_Start"))
+    }
+}
+
+impl ContainsToken<&char> for XidStart {
+    #[inline(always)]
+    fn contains_token(&self, token: &char) -> bool {
+        self.contains_token(*token)
+    }
+
+    #[inline(always)]
+    fn fold_expected<A, F>(&self, init: A, f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        <Self as ContainsToken<char>>::fold_expected(self, init, f)
+    }
+}
+
+/// The set of [`char`]s with the `XID_Continue` property, for use with
+/// [`identifier_with`], [`take_while`][crate::token::take_while], etc
+///
+/// This includes `XID_Start`, digits, `_`, and combining marks.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::unicode::XidContinue;
+/// # use winnow::token::take_while;
+/// fn parser<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     take_while(1.., XidContinue).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("e\u{301}_1 "), Ok((" ", "e\u{301}_1")));
+/// assert!(parser.parse_peek("🦀").is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct XidContinue;
+
+impl ContainsToken<char> for XidContinue {
+    #[inline(always)]
+    fn contains_token(&self, token: char) -> bool {
+        unicode_ident::is_xid_continue(token)
+    }
+
+    #[inline(always)]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        f(init, StrContextValue::Description("XID_Continue"))
+    }
+}
+
+impl ContainsToken<&char> for XidContinue {
+    #[inline(always)]
+    fn contains_token(&self, token: &char) -> bool {
+        self.contains_token(*token)
+    }
+
+    #[inline(always)]
+    fn fold_expected<A, F>(&self, init: A, f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        <Self as ContainsToken<char>>::fold_expected(self, init, f)
+    }
+}
@@ -7,7 +7,7 @@ mod tests;
 use crate::combinator::trace;
 use crate::error::{ErrorConvert, Needed, ParserError};
 use crate::lib::std::ops::{AddAssign, Div, Shl, Shr};
-use crate::stream::{Stream, StreamIsPartial, ToUsize};
+use crate::stream::{Accumulate, Stream, StreamIsPartial, ToUsize};
 use crate::{Parser, Result};
 
 /// Number of bits in a byte
@@ -15,7 +15,7 @@ const BYTE: usize = u8::BITS as usize;
 
 /// Converts a byte-level input to a bit-level input
 ///
-/// See [`bytes`] to convert it back.
+/// See [`bytes()`] to convert it back.
 ///
 /// # Example
 /// ```rust
@@ -46,6 +46,32 @@ const BYTE: usize = u8::BITS as usize;
 /// assert_eq!(parsed.0, 0x01);
 /// assert_eq!(parsed.1, 0x23);
 /// ```
+///
+/// [`ContextError`][crate::error::ContextError] doesn't hold on to the input, so it can be used
+/// inside and outside of `bits` without a custom [`ErrorConvert`], keeping any context:
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;
+/// # use winnow::Bytes;
+/// use winnow::binary::bits::{bits, take};
+/// use winnow::error::{ContextError, ErrMode, StrContext};
+///
+/// fn header(input: &mut &Bytes) -> ModalResult<(u8, u8)> {
+///     bits::<_, _, ErrMode<ContextError>, _, _>((
+///         take(4usize)
+///             .verify(|version: &u8| *version == 4)
+///             .context(StrContext::Label("version")),
+///         take(4usize).context(StrContext::Label("header length")),
+///     ))
+///     .parse_next(input)
+/// }
+///
+/// assert_eq!(header.parse_peek(Bytes::new(&[0x45])), Ok((Bytes::new(&[]), (4, 5))));
+///
+/// let err = header.parse(Bytes::new(&[0x65])).unwrap_err();
+/// assert_eq!(err.inner().to_string(), "invalid version");
+/// # }
+/// ```
 pub fn bits<Input, Output, BitError, ByteError, ParseNext>(
     mut parser: ParseNext,
 ) -> impl Parser<Input, Output, ByteError>
@@ -263,6 +289,169 @@ where
     }
 }
 
+/// Parse `count` values of `bits` bits each, packed back-to-back
+///
+/// This is equivalent to [`repeat(count, take(bits))`][crate::combinator::repeat] but reads the
+/// values in a single pass over the bytes, rather than checking and updating the bit offset for
+/// each value.
+///
+/// Like [`take`], this leaves the stream mid-byte when `count * bits` isn't a multiple of 8.
+/// [`bits`] skips the rest of that byte when returning to the byte stream.  For formats that
+/// require the padding to be zero, follow this with [`pattern`].
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// pub fn take_packed<'i>(count: usize, bits: usize) -> impl Parser<(&'i [u8], usize), Vec<u16>, ContextError>
+/// # {
+/// #     winnow::binary::bits::take_packed::<_, u16, _, _, _>(count, bits)
+/// # }
+/// # }
+/// ```
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;
+/// # use winnow::Bytes;
+/// # use winnow::error::ContextError;
+/// # use winnow::error::ErrMode;
+/// use winnow::binary::bits::{bits, take_packed};
+///
+/// type Stream<'i> = &'i Bytes;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Bytes::new(b)
+/// }
+///
+/// // Three 7-bit values followed by 3 bits of padding
+/// fn parse(input: &mut Stream<'_>) -> ModalResult<Vec<u8>> {
+///     bits::<_, _, ErrMode<ContextError>, _, _>(take_packed::<_, u8, _, _, _>(3usize, 7usize))
+///         .parse_next(input)
+/// }
+///
+/// let input = stream(&[0b0000001_0, 0b000010_00, 0b00011_000, 0xff]);
+/// assert_eq!(parse.parse_peek(input), Ok((stream(&[0xff]), vec![1, 2, 3])));
+/// # }
+/// ```
+#[inline(always)]
+#[doc(alias = "packed_array")]
+#[doc(alias = "take_n_packed")]
+pub fn take_packed<Input, Output, Accumulator, Count, Error>(
+    count: Count,
+    bits: Count,
+) -> impl Parser<(Input, usize), Accumulator, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Output: From<u8> + AddAssign + Shl<usize, Output = Output> + Shr<usize, Output = Output>,
+    Accumulator: Accumulate<Output>,
+    Count: ToUsize,
+    Error: ParserError<(Input, usize)>,
+{
+    let count = count.to_usize();
+    let bits = bits.to_usize();
+    trace("take_packed", move |input: &mut (Input, usize)| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            take_packed_::<_, _, _, _, true>(input, count, bits)
+        } else {
+            take_packed_::<_, _, _, _, false>(input, count, bits)
+        }
+    })
+}
+
+fn take_packed_<I, O, C, E: ParserError<(I, usize)>, const PARTIAL: bool>(
+    bit_input: &mut (I, usize),
+    count: usize,
+    bits: usize,
+) -> Result<C, E>
+where
+    I: StreamIsPartial,
+    I: Stream<Token = u8> + Clone,
+    O: From<u8> + AddAssign + Shl<usize, Output = O> + Shr<usize, Output = O>,
+    C: Accumulate<O>,
+{
+    let mut acc = C::initial(Some(count));
+    let total = count.saturating_mul(bits);
+    if total == 0 {
+        for _ in 0..count {
+            acc.accumulate(0u8.into());
+        }
+        return Ok(acc);
+    }
+
+    let (mut input, bit_offset) = bit_input.clone();
+    let available = input.eof_offset().saturating_mul(BYTE);
+    let end = total.saturating_add(bit_offset);
+    if available < end {
+        return if PARTIAL && input.is_partial() {
+            Err(ParserError::incomplete(
+                bit_input,
+                Needed::new(end - available),
+            ))
+        } else {
+            Err(ParserError::from_input(bit_input))
+        };
+    }
+
+    if PACKED_BUFFER - BYTE < bits {
+        // Values wider than the buffer are rare enough to not be worth the complexity
+        for _ in 0..count {
+            acc.accumulate(take_::<_, _, _, PARTIAL>(bit_input, bits)?);
+        }
+        return Ok(acc);
+    }
+
+    let mut bytes = input.iter_offsets().map(|(_, byte)| u64::from(byte));
+    if BYTE <= bit_offset {
+        let _ = bytes.nth(bit_offset / BYTE - 1);
+    }
+    // Unconsumed bits are the lowest `buffered` bits of `buffer`
+    let mut buffer: u64 = 0;
+    let mut buffered: usize = 0;
+    if bit_offset % BYTE != 0 {
+        buffer = bytes.next().unwrap_or_default();
+        buffered = BYTE - bit_offset % BYTE;
+    }
+    let mask = u64::MAX >> (PACKED_BUFFER - bits);
+    for _ in 0..count {
+        if buffered < bits {
+            for byte in bytes.by_ref().take((PACKED_BUFFER - buffered) / BYTE) {
+                buffer = (buffer << BYTE) | byte;
+                buffered += BYTE;
+            }
+        }
+        buffered -= bits;
+        acc.accumulate(from_bits((buffer >> buffered) & mask, bits));
+    }
+
+    let _ = input.next_slice(end / BYTE);
+    *bit_input = (input, end % BYTE);
+    Ok(acc)
+}
+
+/// Number of bits buffered by [`take_packed`]
+const PACKED_BUFFER: usize = u64::BITS as usize;
+
+/// Convert the lowest `bits` bits of `value`
+#[inline(always)]
+fn from_bits<O>(value: u64, bits: usize) -> O
+where
+    O: From<u8> + AddAssign + Shl<usize, Output = O>,
+{
+    let mut shift = (bits - 1) / BYTE * BYTE;
+    let mut output: O = ((value >> shift) as u8).into();
+    while shift != 0 {
+        shift -= BYTE;
+        output = output << BYTE;
+        output += ((value >> shift) as u8).into();
+    }
+    output
+}
+
 /// Parse taking `count` bits and comparing them to `pattern`
 ///
 /// # Effective Signature
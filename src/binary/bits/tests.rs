@@ -202,3 +202,83 @@ fn test_bool_eof_partial() {
         Err(crate::error::ErrMode::Incomplete(Needed::new(1)))
     );
 }
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_take_packed_across_bytes() {
+    let input = &[0b1010_1100, 0b0101_0011, 0b1111_0000][..];
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), Vec<u8>), InputError<_>> =
+        take_packed::<_, u8, _, _, _>(3usize, 5usize).parse_peek((input, 0));
+
+    assert_eq!(
+        result,
+        Ok(((&input[1..], 7), vec![0b10101, 0b10001, 0b01001]))
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_take_packed_matches_repeat_take() {
+    use crate::combinator::repeat;
+
+    let input = &[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x0f][..];
+    for bits in 1..=32usize {
+        for offset in 0..BYTE {
+            let count = (input.len() * BYTE - offset) / bits;
+            for count in [0, 1, count / 2, count] {
+                let naive: ModalResult<_, InputError<_>> =
+                    repeat::<_, u32, Vec<u32>, _, _>(count, take(bits)).parse_peek((input, offset));
+                let packed: ModalResult<_, InputError<_>> =
+                    take_packed::<_, u32, Vec<u32>, _, _>(count, bits).parse_peek((input, offset));
+                assert_eq!(packed, naive, "bits={bits} offset={offset} count={count}");
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_take_packed_complete_eof() {
+    let input = &[0xff, 0xff][..];
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), Vec<u8>), InputError<_>> =
+        take_packed::<_, u8, _, _, _>(3usize, 5usize).parse_peek((input, 2));
+
+    assert_eq!(
+        result,
+        Err(crate::error::ErrMode::Backtrack(InputError::at((input, 2))))
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_take_packed_partial_eof() {
+    let input = Partial::new(&[0xff, 0xff][..]);
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((Partial<&[u8]>, usize), Vec<u8>), InputError<_>> =
+        take_packed::<_, u8, _, _, _>(3usize, 5usize).parse_peek((input, 2));
+
+    assert_eq!(
+        result,
+        Err(crate::error::ErrMode::Incomplete(Needed::new(1)))
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_take_packed_bits_scope() {
+    let input = &[0b0000_0011, 0b1100_0000, 0xaa][..];
+
+    // Two 5-bit values, then 6 bits of padding that `bits` skips
+    let result: ModalResult<(&[u8], Vec<u8>), InputError<_>> =
+        bits::<_, _, ErrMode<InputError<(&[u8], usize)>>, _, _>(take_packed::<_, u8, _, _, _>(
+            2usize, 5usize,
+        ))
+        .parse_peek(input);
+
+    assert_eq!(result, Ok((&input[2..], vec![0b00000, 0b01111])));
+}
@@ -9,9 +9,12 @@ mod tests;
 
 use crate::combinator::repeat;
 use crate::combinator::trace;
+use crate::error::FromExternalError;
 use crate::error::Needed;
 use crate::error::ParserError;
-use crate::lib::std::ops::{Add, Shl};
+use crate::error::RestLengthError;
+use crate::error::UnknownFlagsError;
+use crate::lib::std::ops::{Add, BitAnd, Not, Shl};
 use crate::stream::Accumulate;
 use crate::stream::{Stream, StreamIsPartial};
 use crate::stream::{ToUsize, UpdateSlice};
@@ -297,7 +300,7 @@ where
 fn be_uint<Input, Uint, Error>(input: &mut Input, bound: usize) -> Result<Uint, Error>
 where
     Input: StreamIsPartial + Stream<Token = u8>,
-    Uint: Default + Shl<u8, Output = Uint> + Add<Uint, Output = Uint> + From<u8>,
+    Uint: FromBytes,
     Error: ParserError<Input>,
 {
     debug_assert_ne!(bound, 1, "to_be_uint needs extra work to avoid overflow");
@@ -317,12 +320,17 @@ where
 #[inline]
 fn to_be_uint<Input, Uint>(number: &Input, offset: usize) -> Uint
 where
-    Input: Stream,
-    Uint: Default
-        + Shl<u8, Output = Uint>
-        + Add<Uint, Output = Uint>
-        + From<<Input as Stream>::Token>,
+    Input: Stream<Token = u8>,
+    Uint: FromBytes,
 {
+    let mut bytes = Uint::Bytes::default();
+    if offset == bytes.as_mut().len() {
+        for (slot, (_, byte)) in bytes.as_mut().iter_mut().zip(number.iter_offsets()) {
+            *slot = byte;
+        }
+        return Uint::from_be_bytes(bytes);
+    }
+
     let mut res = Uint::default();
     for (_, byte) in number.iter_offsets().take(offset) {
         res = (res << 8) + byte.into();
@@ -886,7 +894,7 @@ where
 fn le_uint<Input, Uint, Error>(input: &mut Input, bound: usize) -> Result<Uint, Error>
 where
     Input: StreamIsPartial + Stream<Token = u8>,
-    Uint: Default + Shl<u8, Output = Uint> + Add<Uint, Output = Uint> + From<u8>,
+    Uint: FromBytes,
     Error: ParserError<Input>,
 {
     match input.offset_at(bound) {
@@ -905,12 +913,17 @@ where
 #[inline]
 fn to_le_uint<Input, Uint>(number: &Input, offset: usize) -> Uint
 where
-    Input: Stream,
-    Uint: Default
-        + Shl<u8, Output = Uint>
-        + Add<Uint, Output = Uint>
-        + From<<Input as Stream>::Token>,
+    Input: Stream<Token = u8>,
+    Uint: FromBytes,
 {
+    let mut bytes = Uint::Bytes::default();
+    if offset == bytes.as_mut().len() {
+        for (slot, (_, byte)) in bytes.as_mut().iter_mut().zip(number.iter_offsets()) {
+            *slot = byte;
+        }
+        return Uint::from_le_bytes(bytes);
+    }
+
     let mut res = Uint::default();
     for (index, byte) in number.iter_offsets().take(offset) {
         res = res + (Uint::from(byte) << (8 * index as u8));
@@ -919,6 +932,40 @@ where
     res
 }
 
+/// Unsigned integers that can be read from a fixed number of bytes
+///
+/// When reading exactly `size_of::<Self>()` bytes, this lets the compiler use a single load rather
+/// than assembling the integer a byte at a time.  Other widths, like `u24`, fall back to the loop.
+trait FromBytes: Default + Shl<u8, Output = Self> + Add<Self, Output = Self> + From<u8> {
+    type Bytes: Default + AsMut<[u8]>;
+
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+}
+
+macro_rules! from_bytes_impl {
+    ($($uint:ty),*) => {
+        $(
+            impl FromBytes for $uint {
+                type Bytes = [u8; crate::lib::std::mem::size_of::<$uint>()];
+
+                #[inline(always)]
+                fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                    <$uint>::from_be_bytes(bytes)
+                }
+
+                #[inline(always)]
+                fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                    <$uint>::from_le_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+
+from_bytes_impl!(u16, u32, u64, u128);
+
 /// Recognizes a signed 1 byte integer.
 ///
 /// *Complete version*: Returns an error if there is not enough input data.
@@ -2378,11 +2425,9 @@ where
     }(input)
 }
 
-/// Get a length-prefixed slice ([TLV](https://en.wikipedia.org/wiki/Type-length-value))
-///
-/// To apply a parser to the returned slice, see [`length_and_then`].
+/// Recognizes a big endian 2 bytes IEEE 754 half-precision (binary16) floating point number.
 ///
-/// If the count is for something besides tokens, see [`length_repeat`].
+/// The value is widened to an `f32`, which represents every f16 value exactly.
 ///
 /// *Complete version*: Returns an error if there is not enough input data.
 ///
@@ -2391,42 +2436,47 @@ where
 /// # Example
 ///
 /// ```rust
-/// # use winnow::{error::ErrMode, error::Needed, stream::Partial};
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
 /// # use winnow::prelude::*;
-/// use winnow::Bytes;
-/// use winnow::binary::be_u16;
-/// use winnow::binary::length_take;
-///
-/// type Stream<'i> = Partial<&'i Bytes>;
+/// # use winnow::error::Needed::Size;
+/// use winnow::binary::be_f16;
 ///
-/// fn stream(b: &[u8]) -> Stream<'_> {
-///     Partial::new(Bytes::new(b))
+/// fn parser(s: &mut &[u8]) -> ModalResult<f32> {
+///       be_f16.parse_next(s)
 /// }
 ///
-/// fn parser<'i>(s: &mut Stream<'i>) -> ModalResult<&'i [u8]> {
-///   length_take(be_u16).parse_next(s)
+/// assert_eq!(parser.parse_peek(&[0x3c, 0x00][..]), Ok((&b""[..], 1.0)));
+/// assert!(parser.parse_peek(&b"a"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::be_f16;
+///
+/// fn parser(s: &mut Partial<&[u8]>) -> ModalResult<f32> {
+///       be_f16.parse_next(s)
 /// }
 ///
-/// assert_eq!(parser.parse_peek(stream(b"\x00\x03abcefg")), Ok((stream(&b"efg"[..]), &b"abc"[..])));
-/// assert_eq!(parser.parse_peek(stream(b"\x00\x03a")), Err(ErrMode::Incomplete(Needed::new(2))));
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x3c, 0x00][..])), Ok((Partial::new(&b""[..]), 1.0)));
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x01][..])), Err(ErrMode::Incomplete(Needed::new(1))));
 /// ```
-pub fn length_take<Input, Count, Error, CountParser>(
-    mut count: CountParser,
-) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+#[inline(always)]
+pub fn be_f16<Input, Error>(input: &mut Input) -> Result<f32, Error>
 where
-    Input: StreamIsPartial + Stream,
-    Count: ToUsize,
-    CountParser: Parser<Input, Count, Error>,
+    Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    trace("length_take", move |i: &mut Input| {
-        let length = count.parse_next(i)?;
-
-        crate::token::take(length).parse_next(i)
+    trace("be_f16", move |input: &mut Input| {
+        be_uint::<_, u16, _>(input, 2).map(f16_to_f32)
     })
+    .parse_next(input)
 }
 
-/// Parse a length-prefixed slice ([TLV](https://en.wikipedia.org/wiki/Type-length-value))
+/// Recognizes a little endian 2 bytes IEEE 754 half-precision (binary16) floating point number.
+///
+/// The value is widened to an `f32`, which represents every f16 value exactly.
 ///
 /// *Complete version*: Returns an error if there is not enough input data.
 ///
@@ -2435,99 +2485,1041 @@ where
 /// # Example
 ///
 /// ```rust
-/// # use winnow::{error::ErrMode, error::InputError, error::Needed, stream::{Partial, StreamIsPartial}};
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
 /// # use winnow::prelude::*;
-/// use winnow::Bytes;
-/// use winnow::binary::be_u16;
-/// use winnow::binary::length_and_then;
-///
-/// type Stream<'i> = Partial<&'i Bytes>;
+/// # use winnow::error::Needed::Size;
+/// use winnow::binary::le_f16;
 ///
-/// fn stream(b: &[u8]) -> Stream<'_> {
-///     Partial::new(Bytes::new(b))
+/// fn parser(s: &mut &[u8]) -> ModalResult<f32> {
+///       le_f16.parse_next(s)
 /// }
 ///
-/// fn complete_stream(b: &[u8]) -> Stream<'_> {
-///     let mut p = Partial::new(Bytes::new(b));
-///     let _ = p.complete();
-///     p
-/// }
+/// assert_eq!(parser.parse_peek(&[0x00, 0x3c][..]), Ok((&b""[..], 1.0)));
+/// assert!(parser.parse_peek(&b"a"[..]).is_err());
+/// ```
 ///
-/// fn parser<'i>(s: &mut Stream<'i>) -> ModalResult<&'i [u8]> {
-///   length_and_then(be_u16, "abc").parse_next(s)
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::le_f16;
+///
+/// fn parser(s: &mut Partial<&[u8]>) -> ModalResult<f32> {
+///       le_f16.parse_next(s)
 /// }
 ///
-/// assert_eq!(parser.parse_peek(stream(b"\x00\x03abcefg")), Ok((stream(&b"efg"[..]), &b"abc"[..])));
-/// assert!(parser.parse_peek(stream(b"\x00\x03123123")).is_err());
-/// assert_eq!(parser.parse_peek(stream(b"\x00\x03a")), Err(ErrMode::Incomplete(Needed::new(2))));
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x00, 0x3c][..])), Ok((Partial::new(&b""[..]), 1.0)));
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x01][..])), Err(ErrMode::Incomplete(Needed::new(1))));
 /// ```
-pub fn length_and_then<Input, Output, Count, Error, CountParser, ParseNext>(
-    mut count: CountParser,
-    mut parser: ParseNext,
-) -> impl Parser<Input, Output, Error>
+#[inline(always)]
+pub fn le_f16<Input, Error>(input: &mut Input) -> Result<f32, Error>
 where
-    Input: StreamIsPartial + Stream + UpdateSlice + Clone,
-    Count: ToUsize,
-    CountParser: Parser<Input, Count, Error>,
-    ParseNext: Parser<Input, Output, Error>,
+    Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    trace("length_and_then", move |i: &mut Input| {
-        let data = length_take(count.by_ref()).parse_next(i)?;
-        let mut data = Input::update_slice(i.clone(), data);
-        let _ = data.complete();
-        let o = parser.by_ref().complete_err().parse_next(&mut data)?;
-        Ok(o)
+    trace("le_f16", move |input: &mut Input| {
+        le_uint::<_, u16, _>(input, 2).map(f16_to_f32)
     })
+    .parse_next(input)
 }
 
-/// [`Accumulate`] a length-prefixed sequence of values ([TLV](https://en.wikipedia.org/wiki/Type-length-value))
+/// Recognizes a 2 byte IEEE 754 half-precision (binary16) floating point number
 ///
-/// If the length represents token counts, see instead [`length_take`]
+/// If the parameter is `winnow::binary::Endianness::Big`, parse a big endian f16,
+/// otherwise if `winnow::binary::Endianness::Little` parse a little endian f16.
+///
+/// The value is widened to an `f32`, which represents every f16 value exactly.
+///
+/// *Complete version*: returns an error if there is not enough input data
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
 ///
 /// # Example
 ///
 /// ```rust
-/// # #[cfg(feature = "std")] {
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
 /// # use winnow::prelude::*;
+/// use winnow::binary::f16;
+///
+/// fn be_f16(input: &mut &[u8]) -> ModalResult<f32> {
+///     f16(winnow::binary::Endianness::Big).parse_next(input)
+/// };
+///
+/// assert_eq!(be_f16.parse_peek(&[0x3c, 0x00][..]), Ok((&b""[..], 1.0)));
+/// assert!(be_f16.parse_peek(&b"a"[..]).is_err());
+///
+/// fn le_f16(input: &mut &[u8]) -> ModalResult<f32> {
+///     f16(winnow::binary::Endianness::Little).parse_next(input)
+/// };
+///
+/// assert_eq!(le_f16.parse_peek(&[0x00, 0x3c][..]), Ok((&b""[..], 1.0)));
+/// assert!(le_f16.parse_peek(&b"a"[..]).is_err());
+/// ```
+///
+/// ```rust
 /// # use winnow::{error::ErrMode, error::InputError, error::Needed};
 /// # use winnow::prelude::*;
-/// use winnow::Bytes;
-/// use winnow::binary::u8;
-/// use winnow::binary::length_repeat;
+/// # use winnow::Partial;
+/// use winnow::binary::f16;
 ///
-/// type Stream<'i> = &'i Bytes;
+/// fn be_f16(input: &mut Partial<&[u8]>) -> ModalResult<f32> {
+///     f16(winnow::binary::Endianness::Big).parse_next(input)
+/// };
 ///
-/// fn stream(b: &[u8]) -> Stream<'_> {
-///     Bytes::new(b)
+/// assert_eq!(be_f16.parse_peek(Partial::new(&[0x3c, 0x00][..])), Ok((Partial::new(&b""[..]), 1.0)));
+/// assert_eq!(be_f16.parse_peek(Partial::new(&b"a"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+pub fn f16<Input, Error>(endian: Endianness) -> impl Parser<Input, f32, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    move |input: &mut Input| {
+        match endian {
+            Endianness::Big => be_f16,
+            Endianness::Little => le_f16,
+            #[cfg(target_endian = "big")]
+            Endianness::Native => be_f16,
+            #[cfg(target_endian = "little")]
+            Endianness::Native => le_f16,
+        }
+    }(input)
+}
+
+/// Recognizes a big endian 2 bytes bfloat16 floating point number.
+///
+/// The value is widened to an `f32`, which represents every bf16 value exactly.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::error::Needed::Size;
+/// use winnow::binary::be_bf16;
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<f32> {
+///       be_bf16.parse_next(s)
 /// }
 ///
-/// fn parser<'i>(s: &mut Stream<'i>) -> ModalResult<Vec<&'i [u8]>> {
-///   length_repeat(u8.map(|i| {
-///      println!("got number: {}", i);
-///      i
-///   }), "abc").parse_next(s)
+/// assert_eq!(parser.parse_peek(&[0x41, 0x48][..]), Ok((&b""[..], 12.5)));
+/// assert!(parser.parse_peek(&b"a"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::be_bf16;
+///
+/// fn parser(s: &mut Partial<&[u8]>) -> ModalResult<f32> {
+///       be_bf16.parse_next(s)
 /// }
 ///
-/// assert_eq!(parser.parse_peek(stream(b"\x02abcabcabc")), Ok((stream(b"abc"), vec![&b"abc"[..], &b"abc"[..]])));
-/// assert!(parser.parse_peek(stream(b"\x03123123123")).is_err());
-/// # }
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x41, 0x48][..])), Ok((Partial::new(&b""[..]), 12.5)));
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x01][..])), Err(ErrMode::Incomplete(Needed::new(1))));
 /// ```
-pub fn length_repeat<Input, Output, Accumulator, Count, Error, CountParser, ParseNext>(
-    mut count: CountParser,
-    mut parser: ParseNext,
-) -> impl Parser<Input, Accumulator, Error>
+#[inline(always)]
+pub fn be_bf16<Input, Error>(input: &mut Input) -> Result<f32, Error>
 where
-    Input: Stream,
-    Count: ToUsize,
-    Accumulator: Accumulate<Output>,
-    CountParser: Parser<Input, Count, Error>,
-    ParseNext: Parser<Input, Output, Error>,
+    Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    trace("length_repeat", move |i: &mut Input| {
-        let n = count.parse_next(i)?;
-        let n = n.to_usize();
-        repeat(n, parser.by_ref()).parse_next(i)
+    trace("be_bf16", move |input: &mut Input| {
+        be_uint::<_, u16, _>(input, 2).map(bf16_to_f32)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a little endian 2 bytes bfloat16 floating point number.
+///
+/// The value is widened to an `f32`, which represents every bf16 value exactly.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::error::Needed::Size;
+/// use winnow::binary::le_bf16;
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<f32> {
+///       le_bf16.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&[0x48, 0x41][..]), Ok((&b""[..], 12.5)));
+/// assert!(parser.parse_peek(&b"a"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::le_bf16;
+///
+/// fn parser(s: &mut Partial<&[u8]>) -> ModalResult<f32> {
+///       le_bf16.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x48, 0x41][..])), Ok((Partial::new(&b""[..]), 12.5)));
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x01][..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+pub fn le_bf16<Input, Error>(input: &mut Input) -> Result<f32, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("le_bf16", move |input: &mut Input| {
+        le_uint::<_, u16, _>(input, 2).map(bf16_to_f32)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a 2 byte bfloat16 floating point number
+///
+/// If the parameter is `winnow::binary::Endianness::Big`, parse a big endian bf16,
+/// otherwise if `winnow::binary::Endianness::Little` parse a little endian bf16.
+///
+/// The value is widened to an `f32`, which represents every bf16 value exactly.
+///
+/// *Complete version*: returns an error if there is not enough input data
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::bf16;
+///
+/// fn be_bf16(input: &mut &[u8]) -> ModalResult<f32> {
+///     bf16(winnow::binary::Endianness::Big).parse_next(input)
+/// };
+///
+/// assert_eq!(be_bf16.parse_peek(&[0x41, 0x48][..]), Ok((&b""[..], 12.5)));
+/// assert!(be_bf16.parse_peek(&b"a"[..]).is_err());
+///
+/// fn le_bf16(input: &mut &[u8]) -> ModalResult<f32> {
+///     bf16(winnow::binary::Endianness::Little).parse_next(input)
+/// };
+///
+/// assert_eq!(le_bf16.parse_peek(&[0x48, 0x41][..]), Ok((&b""[..], 12.5)));
+/// assert!(le_bf16.parse_peek(&b"a"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::bf16;
+///
+/// fn be_bf16(input: &mut Partial<&[u8]>) -> ModalResult<f32> {
+///     bf16(winnow::binary::Endianness::Big).parse_next(input)
+/// };
+///
+/// assert_eq!(be_bf16.parse_peek(Partial::new(&[0x41, 0x48][..])), Ok((Partial::new(&b""[..]), 12.5)));
+/// assert_eq!(be_bf16.parse_peek(Partial::new(&b"a"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+pub fn bf16<Input, Error>(endian: Endianness) -> impl Parser<Input, f32, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    move |input: &mut Input| {
+        match endian {
+            Endianness::Big => be_bf16,
+            Endianness::Little => le_bf16,
+            #[cfg(target_endian = "big")]
+            Endianness::Native => be_bf16,
+            #[cfg(target_endian = "little")]
+            Endianness::Native => le_bf16,
+        }
+    }(input)
+}
+
+/// Widen the bits of an IEEE 754 half-precision float to an [`f32`]
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = u32::from(bits & 0x8000) << 16;
+    let exponent = u32::from((bits >> 10) & 0x1f);
+    let mantissa = u32::from(bits & 0x03ff);
+    let bits = match (exponent, mantissa) {
+        // Signed zero
+        (0, 0) => sign,
+        // Subnormal: normalize as f32 has the range to represent it
+        (0, mut mantissa) => {
+            let mut exponent = 127 - 15 + 1;
+            while mantissa & 0x0400 == 0 {
+                mantissa <<= 1;
+                exponent -= 1;
+            }
+            sign | (exponent << 23) | ((mantissa & 0x03ff) << 13)
+        }
+        // Infinity and NaN, keeping the NaN payload
+        (0x1f, mantissa) => sign | 0x7f80_0000 | (mantissa << 13),
+        (exponent, mantissa) => sign | ((exponent + 127 - 15) << 23) | (mantissa << 13),
+    };
+    f32::from_bits(bits)
+}
+
+/// Widen the bits of a bfloat16 to an [`f32`]
+fn bf16_to_f32(bits: u16) -> f32 {
+    f32::from_bits(u32::from(bits) << 16)
+}
+
+/// Get a null-terminated (C-style) slice, excluding the terminator
+///
+/// The terminating `0` is consumed.
+///
+/// For untrusted input, see [`c_str_bounded`].  To decode the slice as UTF-8, see [`c_str_utf8`].
+///
+/// *Complete version*: Returns an error if there is no terminator.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if no terminator has been seen yet.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::c_str;
+///
+/// fn parser<'i>(s: &mut &'i [u8]) -> ModalResult<&'i [u8]> {
+///     c_str.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&b"abc\x00efg"[..]), Ok((&b"efg"[..], &b"abc"[..])));
+/// assert_eq!(parser.parse_peek(&b"\x00"[..]), Ok((&b""[..], &b""[..])));
+/// assert!(parser.parse_peek(&b"abc"[..]).is_err());
+///
+/// fn partial_parser<'i>(s: &mut Partial<&'i [u8]>) -> ModalResult<&'i [u8]> {
+///     c_str.parse_next(s)
+/// }
+///
+/// assert_eq!(partial_parser.parse_peek(Partial::new(&b"abc"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[doc(alias = "null_terminated")]
+#[doc(alias = "take_terminated0")]
+#[inline(always)]
+pub fn c_str<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("c_str", move |input: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            c_str_::<_, _, true>(input, usize::MAX)
+        } else {
+            c_str_::<_, _, false>(input, usize::MAX)
+        }
+    })
+    .parse_next(input)
+}
+
+/// Get a null-terminated (C-style) slice, excluding the terminator, that must end within `max`
+/// bytes
+///
+/// `max` includes the terminator, so the returned slice is at most `max - 1` bytes long.
+/// The terminating `0` is consumed.
+///
+/// *Complete version*: Returns an error if there is no terminator within `max` bytes.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if no terminator has been seen yet
+/// and fewer than `max` bytes are available.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::c_str_bounded;
+///
+/// fn parser<'i>(s: &mut Partial<&'i [u8]>) -> ModalResult<&'i [u8]> {
+///     c_str_bounded(4).parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&b"abc\x00efg"[..])), Ok((Partial::new(&b"efg"[..]), &b"abc"[..])));
+/// assert_eq!(parser.parse_peek(Partial::new(&b"abc"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// assert!(parser.parse_peek(Partial::new(&b"abcd"[..])).is_err());
+/// ```
+#[inline(always)]
+pub fn c_str_bounded<Input, Error>(
+    max: usize,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("c_str_bounded", move |input: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            c_str_::<_, _, true>(input, max)
+        } else {
+            c_str_::<_, _, false>(input, max)
+        }
+    })
+}
+
+fn c_str_<I, E, const PARTIAL: bool>(input: &mut I, max: usize) -> Result<<I as Stream>::Slice, E>
+where
+    I: StreamIsPartial + Stream<Token = u8>,
+    E: ParserError<I>,
+{
+    let terminator = input
+        .iter_offsets()
+        .take(max)
+        .find(|(_, b)| *b == 0)
+        .map(|(offset, _)| offset);
+    match terminator {
+        Some(offset) => {
+            let slice = input.next_slice(offset);
+            let _ = input.next_token();
+            Ok(slice)
+        }
+        None if PARTIAL && input.is_partial() && input.eof_offset() < max => {
+            Err(ParserError::incomplete(input, Needed::new(1)))
+        }
+        None => Err(ParserError::from_input(input)),
+    }
+}
+
+/// Get a null-terminated (C-style) string, excluding the terminator, that must be valid UTF-8
+///
+/// See [`c_str`] for more details.
+///
+/// For a bounded version, combine [`c_str_bounded`] with [`Parser::try_map`] and
+/// [`core::str::from_utf8`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::c_str_utf8;
+///
+/// fn parser<'i>(s: &mut &'i [u8]) -> ModalResult<&'i str> {
+///     c_str_utf8.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&b"abc\x00efg"[..]), Ok((&b"efg"[..], "abc")));
+/// assert!(parser.parse_peek(&b"\xff\x00"[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn c_str_utf8<'i, Input, Error>(input: &mut Input) -> Result<&'i str, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8, Slice = &'i [u8]>,
+    Error: ParserError<Input> + FromExternalError<Input, crate::lib::std::str::Utf8Error>,
+{
+    trace("c_str_utf8", c_str.try_map(crate::lib::std::str::from_utf8)).parse_next(input)
+}
+
+/// Take the remaining input, requiring it to be exactly `N` bytes
+///
+/// This is intended for trailers, like a checksum at the end of a message.  Nothing is consumed on
+/// error; the error is built from a [`RestLengthError`] holding the expected and actual number of
+/// remaining bytes, and points to the start of the remaining input.
+///
+/// *Complete version*: Returns an error if fewer or more than `N` bytes remain.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` with the number of
+/// missing bytes if fewer than `N` bytes are available.  Surplus bytes are an error as soon as they
+/// are seen.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::take_rest_exact;
+///
+/// fn checksum(s: &mut &[u8]) -> ModalResult<[u8; 4]> {
+///     take_rest_exact.parse_next(s)
+/// }
+///
+/// assert_eq!(checksum.parse_peek(&b"\x01\x02\x03\x04"[..]), Ok((&b""[..], [1, 2, 3, 4])));
+/// assert!(checksum.parse_peek(&b"\x01\x02\x03"[..]).is_err());
+/// assert!(checksum.parse_peek(&b"\x01\x02\x03\x04\x05"[..]).is_err());
+///
+/// fn partial_checksum(s: &mut Partial<&[u8]>) -> ModalResult<[u8; 4]> {
+///     take_rest_exact.parse_next(s)
+/// }
+///
+/// assert_eq!(partial_checksum.parse_peek(Partial::new(&b"\x01"[..])), Err(ErrMode::Incomplete(Needed::new(3))));
+/// ```
+#[inline(always)]
+pub fn take_rest_exact<const N: usize, Input, Error>(input: &mut Input) -> Result<[u8; N], Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input> + FromExternalError<Input, RestLengthError>,
+{
+    trace("take_rest_exact", move |input: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            take_rest_exact_::<N, _, _, true>(input)
+        } else {
+            take_rest_exact_::<N, _, _, false>(input)
+        }
+    })
+    .parse_next(input)
+}
+
+fn take_rest_exact_<const N: usize, I, E, const PARTIAL: bool>(input: &mut I) -> Result<[u8; N], E>
+where
+    I: StreamIsPartial + Stream<Token = u8>,
+    E: ParserError<I> + FromExternalError<I, RestLengthError>,
+{
+    let remaining = input.eof_offset();
+    if PARTIAL && input.is_partial() && remaining < N {
+        return Err(ParserError::incomplete(input, Needed::new(N - remaining)));
+    }
+    if remaining != N {
+        return Err(E::from_external_error(
+            input,
+            RestLengthError::new(N, remaining),
+        ));
+    }
+
+    let mut bytes = [0; N];
+    for (byte, (_, token)) in bytes.iter_mut().zip(input.iter_offsets()) {
+        *byte = token;
+    }
+    let _ = input.finish();
+    Ok(bytes)
+}
+
+/// Apply a parser, then skip padding so the bytes consumed are a multiple of `align`
+///
+/// The alignment is relative to where `parser` started, so this doesn't need a
+/// [`LocatingSlice`][crate::stream::LocatingSlice].  No padding is skipped when `parser` consumed
+/// an aligned number of bytes.
+///
+/// The padding may be any value; to require it to be zero, see [`padded_zeroed`].
+///
+/// *Complete version*: Returns an error if there is not enough input data for the padding.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` with the number of
+/// missing padding bytes if there is not enough data.
+///
+/// # Panics
+///
+/// If `align` is `0`
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::{c_str, padded};
+///
+/// fn name<'i>(s: &mut &'i [u8]) -> ModalResult<&'i [u8]> {
+///     padded(c_str, 4).parse_next(s)
+/// }
+///
+/// assert_eq!(name.parse_peek(&b"abc\x00rest"[..]), Ok((&b"rest"[..], &b"abc"[..])));
+/// assert_eq!(name.parse_peek(&b"ab\x00\xffrest"[..]), Ok((&b"rest"[..], &b"ab"[..])));
+/// assert_eq!(name.parse_peek(&b"abcd\x00\x00\x00\x00rest"[..]), Ok((&b"rest"[..], &b"abcd"[..])));
+/// assert!(name.parse_peek(&b"abcd\x00"[..]).is_err());
+///
+/// fn partial_name<'i>(s: &mut Partial<&'i [u8]>) -> ModalResult<&'i [u8]> {
+///     padded(c_str, 4).parse_next(s)
+/// }
+///
+/// assert_eq!(partial_name.parse_peek(Partial::new(&b"a\x00"[..])), Err(ErrMode::Incomplete(Needed::new(2))));
+/// ```
+#[doc(alias = "align")]
+#[inline(always)]
+pub fn padded<Input, Output, Error, ParseNext>(
+    parser: ParseNext,
+    align: usize,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    ParseNext: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+{
+    trace("padded", padded_::<_, _, _, _, false>(parser, align))
+}
+
+/// Apply a parser, then skip zeroed padding so the bytes consumed are a multiple of `align`
+///
+/// See [`padded`] for more details.
+///
+/// *Complete version*: Returns an error if there is not enough input data for the padding, or at
+/// the first non-zero padding byte.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` with the number of
+/// missing padding bytes if there is not enough data.
+///
+/// # Panics
+///
+/// If `align` is `0`
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::{c_str, padded_zeroed};
+///
+/// fn name<'i>(s: &mut &'i [u8]) -> ModalResult<&'i [u8]> {
+///     padded_zeroed(c_str, 4).parse_next(s)
+/// }
+///
+/// assert_eq!(name.parse_peek(&b"a\x00\x00\x00rest"[..]), Ok((&b"rest"[..], &b"a"[..])));
+/// assert!(name.parse_peek(&b"a\x00\x00\xffrest"[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn padded_zeroed<Input, Output, Error, ParseNext>(
+    parser: ParseNext,
+    align: usize,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    ParseNext: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+{
+    trace("padded_zeroed", padded_::<_, _, _, _, true>(parser, align))
+}
+
+fn padded_<I, O, E, P, const ZEROED: bool>(mut parser: P, align: usize) -> impl Parser<I, O, E>
+where
+    I: StreamIsPartial + Stream<Token = u8>,
+    P: Parser<I, O, E>,
+    E: ParserError<I>,
+{
+    assert!(align != 0, "`align` must be non-zero");
+    move |input: &mut I| {
+        let start = input.checkpoint();
+        let output = parser.parse_next(input)?;
+        let consumed = input.offset_from(&start);
+        let padding = (align - consumed % align) % align;
+
+        let remaining = input.eof_offset();
+        if remaining < padding {
+            if <I as StreamIsPartial>::is_partial_supported() && input.is_partial() {
+                return Err(ParserError::incomplete(
+                    input,
+                    Needed::new(padding - remaining),
+                ));
+            } else {
+                return Err(ParserError::from_input(input));
+            }
+        }
+        if ZEROED {
+            if let Some((offset, _)) = input.iter_offsets().take(padding).find(|(_, b)| *b != 0) {
+                let _ = input.next_slice(offset);
+                return Err(ParserError::from_input(input));
+            }
+        }
+        let _ = input.next_slice(padding);
+        Ok(output)
+    }
+}
+
+/// Parse a set of bit-flags, failing if any bit outside of `known` is set
+///
+/// The error is built from an [`UnknownFlagsError`] holding the offending bits, and points to the
+/// start of the flags.
+///
+/// To convert the bits to your own flags type, see [`Parser::map`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::be_u16;
+/// use winnow::binary::flags;
+///
+/// const COMPRESSED: u16 = 0x0001;
+/// const ENCRYPTED: u16 = 0x0002;
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<u16> {
+///     flags(be_u16, COMPRESSED | ENCRYPTED).parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&b"\x00\x03rest"[..]), Ok((&b"rest"[..], 0x0003)));
+/// assert!(parser.parse_peek(&b"\x80\x01rest"[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn flags<Input, Output, Error, ParseNext>(
+    parser: ParseNext,
+    known: Output,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream,
+    Output: Copy + Default + PartialEq + BitAnd<Output = Output> + Not<Output = Output>,
+    ParseNext: Parser<Input, Output, Error>,
+    Error: ParserError<Input> + FromExternalError<Input, UnknownFlagsError<Output>>,
+{
+    trace(
+        "flags",
+        parser.try_map(move |bits: Output| {
+            let unknown = bits & !known;
+            if unknown == Output::default() {
+                Ok(bits)
+            } else {
+                Err(UnknownFlagsError::new(unknown))
+            }
+        }),
+    )
+}
+
+/// Get a length-prefixed slice ([TLV](https://en.wikipedia.org/wiki/Type-length-value))
+///
+/// To apply a parser to the returned slice, see [`length_and_then`].
+///
+/// If the count is for something besides tokens, see [`length_repeat`].
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::Needed, stream::Partial};
+/// # use winnow::prelude::*;
+/// use winnow::Bytes;
+/// use winnow::binary::be_u16;
+/// use winnow::binary::length_take;
+///
+/// type Stream<'i> = Partial<&'i Bytes>;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Partial::new(Bytes::new(b))
+/// }
+///
+/// fn parser<'i>(s: &mut Stream<'i>) -> ModalResult<&'i [u8]> {
+///   length_take(be_u16).parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(stream(b"\x00\x03abcefg")), Ok((stream(&b"efg"[..]), &b"abc"[..])));
+/// assert_eq!(parser.parse_peek(stream(b"\x00\x03a")), Err(ErrMode::Incomplete(Needed::new(2))));
+/// ```
+pub fn length_take<Input, Count, Error, CountParser>(
+    mut count: CountParser,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    Count: ToUsize,
+    CountParser: Parser<Input, Count, Error>,
+    Error: ParserError<Input>,
+{
+    trace("length_take", move |i: &mut Input| {
+        let length = count.parse_next(i)?;
+
+        crate::token::take(length).parse_next(i)
+    })
+}
+
+/// Parse a length-prefixed slice ([TLV](https://en.wikipedia.org/wiki/Type-length-value))
+///
+/// To require the slice to be consumed, with errors reported at their position within the
+/// slice, see [`subparse`][crate::combinator::subparse].
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed, stream::{Partial, StreamIsPartial}};
+/// # use winnow::prelude::*;
+/// use winnow::Bytes;
+/// use winnow::binary::be_u16;
+/// use winnow::binary::length_and_then;
+///
+/// type Stream<'i> = Partial<&'i Bytes>;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Partial::new(Bytes::new(b))
+/// }
+///
+/// fn complete_stream(b: &[u8]) -> Stream<'_> {
+///     let mut p = Partial::new(Bytes::new(b));
+///     let _ = p.complete();
+///     p
+/// }
+///
+/// fn parser<'i>(s: &mut Stream<'i>) -> ModalResult<&'i [u8]> {
+///   length_and_then(be_u16, "abc").parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(stream(b"\x00\x03abcefg")), Ok((stream(&b"efg"[..]), &b"abc"[..])));
+/// assert!(parser.parse_peek(stream(b"\x00\x03123123")).is_err());
+/// assert_eq!(parser.parse_peek(stream(b"\x00\x03a")), Err(ErrMode::Incomplete(Needed::new(2))));
+/// ```
+pub fn length_and_then<Input, Output, Count, Error, CountParser, ParseNext>(
+    mut count: CountParser,
+    mut parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream + UpdateSlice + Clone,
+    Count: ToUsize,
+    CountParser: Parser<Input, Count, Error>,
+    ParseNext: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+{
+    trace("length_and_then", move |i: &mut Input| {
+        let data = length_take(count.by_ref()).parse_next(i)?;
+        let mut data = Input::update_slice(i.clone(), data);
+        let _ = data.complete();
+        let o = parser.by_ref().complete_err().parse_next(&mut data)?;
+        Ok(o)
+    })
+}
+
+/// Get a length-prefixed slice ([TLV](https://en.wikipedia.org/wiki/Type-length-value)), along
+/// with the declared length
+///
+/// Like [`length_take`] but the parsed count is returned alongside the slice.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::Needed, stream::Partial};
+/// # use winnow::prelude::*;
+/// use winnow::Bytes;
+/// use winnow::binary::be_u16;
+/// use winnow::binary::length_take_with_len;
+///
+/// type Stream<'i> = Partial<&'i Bytes>;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Partial::new(Bytes::new(b))
+/// }
+///
+/// fn parser<'i>(s: &mut Stream<'i>) -> ModalResult<(u16, &'i [u8])> {
+///   length_take_with_len(be_u16).parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(stream(b"\x00\x03abcefg")), Ok((stream(&b"efg"[..]), (3, &b"abc"[..]))));
+/// assert_eq!(parser.parse_peek(stream(b"\x00\x03a")), Err(ErrMode::Incomplete(Needed::new(2))));
+/// ```
+pub fn length_take_with_len<Input, Count, Error, CountParser>(
+    mut count: CountParser,
+) -> impl Parser<Input, (Count, <Input as Stream>::Slice), Error>
+where
+    Input: StreamIsPartial + Stream,
+    Count: ToUsize,
+    CountParser: Parser<Input, Count, Error>,
+    Error: ParserError<Input>,
+{
+    trace("length_take_with_len", move |i: &mut Input| {
+        let length = count.parse_next(i)?;
+
+        let data = crate::token::take(length.to_usize()).parse_next(i)?;
+        Ok((length, data))
+    })
+}
+
+/// Parse a length-prefixed slice ([TLV](https://en.wikipedia.org/wiki/Type-length-value)), along
+/// with the declared length
+///
+/// Like [`length_and_then`] but the parsed count is returned alongside the value.  Any part of
+/// the slice that `parser` does not consume, like padding, is skipped.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::Needed, stream::Partial};
+/// # use winnow::prelude::*;
+/// use winnow::Bytes;
+/// use winnow::binary::be_u16;
+/// use winnow::binary::u8;
+/// use winnow::binary::length_and_then_with_len;
+///
+/// type Stream<'i> = Partial<&'i Bytes>;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Partial::new(Bytes::new(b))
+/// }
+///
+/// fn parser<'i>(s: &mut Stream<'i>) -> ModalResult<(u8, u16)> {
+///   length_and_then_with_len(u8, be_u16).parse_next(s)
+/// }
+///
+/// // The declared length includes 2 bytes of padding
+/// assert_eq!(parser.parse_peek(stream(b"\x04\x00\x01\x00\x00efg")), Ok((stream(&b"efg"[..]), (4, 1))));
+/// assert!(parser.parse_peek(stream(b"\x01\x00\x01")).is_err());
+/// assert_eq!(parser.parse_peek(stream(b"\x04\x00")), Err(ErrMode::Incomplete(Needed::new(3))));
+/// ```
+pub fn length_and_then_with_len<Input, Output, Count, Error, CountParser, ParseNext>(
+    mut count: CountParser,
+    mut parser: ParseNext,
+) -> impl Parser<Input, (Count, Output), Error>
+where
+    Input: StreamIsPartial + Stream + UpdateSlice + Clone,
+    Count: ToUsize,
+    CountParser: Parser<Input, Count, Error>,
+    ParseNext: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+{
+    trace("length_and_then_with_len", move |i: &mut Input| {
+        let (length, data) = length_take_with_len(count.by_ref()).parse_next(i)?;
+        let mut data = Input::update_slice(i.clone(), data);
+        let _ = data.complete();
+        let o = parser.by_ref().complete_err().parse_next(&mut data)?;
+        Ok((length, o))
+    })
+}
+
+/// [`Accumulate`] a length-prefixed sequence of values ([TLV](https://en.wikipedia.org/wiki/Type-length-value))
+///
+/// If the length represents token counts, see instead [`length_take`]
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::Bytes;
+/// use winnow::binary::u8;
+/// use winnow::binary::length_repeat;
+///
+/// type Stream<'i> = &'i Bytes;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Bytes::new(b)
+/// }
+///
+/// fn parser<'i>(s: &mut Stream<'i>) -> ModalResult<Vec<&'i [u8]>> {
+///   length_repeat(u8.map(|i| {
+///      println!("got number: {}", i);
+///      i
+///   }), "abc").parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(stream(b"\x02abcabcabc")), Ok((stream(b"abc"), vec![&b"abc"[..], &b"abc"[..]])));
+/// assert!(parser.parse_peek(stream(b"\x03123123123")).is_err());
+/// # }
+/// ```
+pub fn length_repeat<Input, Output, Accumulator, Count, Error, CountParser, ParseNext>(
+    mut count: CountParser,
+    mut parser: ParseNext,
+) -> impl Parser<Input, Accumulator, Error>
+where
+    Input: Stream,
+    Count: ToUsize,
+    Accumulator: Accumulate<Output>,
+    CountParser: Parser<Input, Count, Error>,
+    ParseNext: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+{
+    trace("length_repeat", move |i: &mut Input| {
+        let n = count.parse_next(i)?;
+        let n = n.to_usize();
+        repeat(n, parser.by_ref()).parse_next(i)
+    })
+}
+
+/// [`Accumulate`] the values in a length-prefixed region ([TLV](https://en.wikipedia.org/wiki/Type-length-value))
+///
+/// Unlike [`length_repeat`], the length is the number of tokens in the region, rather than the
+/// number of values.  Values are parsed until the region is fully consumed.
+///
+/// A value that is truncated by the end of the region is an error.  On error, the input is left
+/// where the value parser failed within the region, rather than at the end of the region, so
+/// error positions (like [`ParseError::offset`][crate::error::ParseError::offset]) point into
+/// the region.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data for the region.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;
+/// use winnow::Bytes;
+/// use winnow::binary::be_u16;
+/// use winnow::binary::u8;
+/// use winnow::binary::length_delimited;
+///
+/// type Stream<'i> = &'i Bytes;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Bytes::new(b)
+/// }
+///
+/// fn parser<'i>(s: &mut Stream<'i>) -> ModalResult<Vec<u16>> {
+///   length_delimited(u8, be_u16).parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(stream(b"\x04\x00\x01\x00\x02abc")), Ok((stream(b"abc"), vec![1, 2])));
+/// assert_eq!(parser.parse_peek(stream(b"\x00abc")), Ok((stream(b"abc"), vec![])));
+/// // The second value is truncated by the length of the region
+/// assert!(parser.parse_peek(stream(b"\x03\x00\x01\x00\x02abc")).is_err());
+///
+/// let err = parser.parse(stream(b"\x03\x00\x01\x00")).unwrap_err();
+/// assert_eq!(err.offset(), 3);
+/// # }
+/// ```
+pub fn length_delimited<Input, Output, Accumulator, Count, Error, CountParser, ParseNext>(
+    mut count: CountParser,
+    mut parser: ParseNext,
+) -> impl Parser<Input, Accumulator, Error>
+where
+    Input: StreamIsPartial + Stream + UpdateSlice + Clone,
+    Count: ToUsize,
+    Accumulator: Accumulate<Output>,
+    CountParser: Parser<Input, Count, Error>,
+    ParseNext: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+{
+    trace("length_delimited", move |i: &mut Input| {
+        let length = count.parse_next(i)?;
+        let start = i.checkpoint();
+        let data = crate::token::take(length).parse_next(i)?;
+        let mut data = Input::update_slice(i.clone(), data);
+        let _ = data.complete();
+        let data_start = data.checkpoint();
+
+        let mut acc = Accumulator::initial(None);
+        while data.eof_offset() != 0 {
+            let len = data.eof_offset();
+            match parser.by_ref().complete_err().parse_next(&mut data) {
+                Ok(o) => {
+                    // infinite loop check: the parser must always consume
+                    if data.eof_offset() == len {
+                        return Err(ParserError::assert(
+                            &data,
+                            "`repeat` parsers must always consume",
+                        ));
+                    }
+                    acc.accumulate(o);
+                }
+                Err(e) => {
+                    let consumed = data.offset_from(&data_start);
+                    i.reset(&start);
+                    let _ = i.next_slice(consumed);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(acc)
     })
 }
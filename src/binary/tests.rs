@@ -956,6 +956,80 @@ Ok(
         );
     }
 
+    #[test]
+    fn f16_tests() {
+        use crate::error::InputError;
+
+        let cases: &[(u16, u32)] = &[
+            // zeros
+            (0x0000, 0x0000_0000),
+            (0x8000, 0x8000_0000),
+            // normals
+            (0x3c00, 0x3f80_0000),
+            (0xc000, 0xc000_0000),
+            (0x7bff, 0x477f_e000),
+            (0x0400, 0x3880_0000),
+            // subnormals
+            (0x0001, 0x3380_0000),
+            (0x03ff, 0x387f_c000),
+            (0x8001, 0xb380_0000),
+            // infinities
+            (0x7c00, 0x7f80_0000),
+            (0xfc00, 0xff80_0000),
+            // NaNs, preserving the payload
+            (0x7e00, 0x7fc0_0000),
+            (0x7c01, 0x7f80_2000),
+        ];
+        for &(input, expected) in cases {
+            let be = input.to_be_bytes();
+            let le = input.to_le_bytes();
+            let (_, be_res) = be_f16::<_, InputError<_>>.parse_peek(&be[..]).unwrap();
+            let (_, le_res) = le_f16::<_, InputError<_>>.parse_peek(&le[..]).unwrap();
+            assert_eq!(be_res.to_bits(), expected, "be_f16 {input:#06x}");
+            assert_eq!(le_res.to_bits(), expected, "le_f16 {input:#06x}");
+            let (_, res) = f16::<_, InputError<_>>(Endianness::Big)
+                .parse_peek(&be[..])
+                .unwrap();
+            assert_eq!(res.to_bits(), expected, "f16(Big) {input:#06x}");
+            let (_, res) = f16::<_, InputError<_>>(Endianness::Little)
+                .parse_peek(&le[..])
+                .unwrap();
+            assert_eq!(res.to_bits(), expected, "f16(Little) {input:#06x}");
+        }
+    }
+
+    #[test]
+    fn bf16_tests() {
+        use crate::error::InputError;
+
+        let cases: &[(u16, u32)] = &[
+            (0x0000, 0x0000_0000),
+            (0x8000, 0x8000_0000),
+            (0x3f80, 0x3f80_0000),
+            (0x4148, 0x4148_0000),
+            (0x0001, 0x0001_0000),
+            (0x7f80, 0x7f80_0000),
+            (0xff80, 0xff80_0000),
+            (0x7fc1, 0x7fc1_0000),
+        ];
+        for &(input, expected) in cases {
+            let be = input.to_be_bytes();
+            let le = input.to_le_bytes();
+            let (_, be_res) = be_bf16::<_, InputError<_>>.parse_peek(&be[..]).unwrap();
+            let (_, le_res) = le_bf16::<_, InputError<_>>.parse_peek(&le[..]).unwrap();
+            assert_eq!(be_res.to_bits(), expected, "be_bf16 {input:#06x}");
+            assert_eq!(le_res.to_bits(), expected, "le_bf16 {input:#06x}");
+            let (_, res) = bf16::<_, InputError<_>>(Endianness::Big)
+                .parse_peek(&be[..])
+                .unwrap();
+            assert_eq!(res.to_bits(), expected, "bf16(Big) {input:#06x}");
+            let (_, res) = bf16::<_, InputError<_>>(Endianness::Little)
+                .parse_peek(&le[..])
+                .unwrap();
+            assert_eq!(res.to_bits(), expected, "bf16(Little) {input:#06x}");
+        }
+    }
+
     #[test]
     fn configurable_endianness() {
         use crate::binary::Endianness;
@@ -1158,94 +1232,89 @@ Ok(
             .raw()
         );
     }
-}
-
-mod partial {
-    use super::*;
-
-    #[cfg(feature = "alloc")]
-    use crate::lib::std::vec::Vec;
-    use crate::Partial;
-    use crate::{
-        ascii::digit1 as digit,
-        binary::{be_u16, be_u8},
-        lib::std::str::{self, FromStr},
-    };
-
     #[test]
-    fn i8_tests() {
-        assert_parse!(
-            be_i8.parse_peek(Partial::new(&[0x00][..])),
-            str![[r#"
-Ok(
-    (
-        Partial {
-            input: [],
-            partial: true,
-        },
-        0,
-    ),
-)
+    fn c_str_test() {
+        fn parser<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+            c_str.parse_next(i)
+        }
 
-"#]]
-            .raw()
-        );
+        // empty string
         assert_parse!(
-            be_i8.parse_peek(Partial::new(&[0x7f][..])),
+            parser.parse_peek(&b"\x00abc"[..]),
             str![[r#"
 Ok(
     (
-        Partial {
-            input: [],
-            partial: true,
-        },
-        127,
+        [
+            97,
+            98,
+            99,
+        ],
+        [],
     ),
 )
 
 "#]]
             .raw()
         );
+        // missing terminator at EOF
         assert_parse!(
-            be_i8.parse_peek(Partial::new(&[0xff][..])),
+            parser.parse_peek(&b"abc"[..]),
             str![[r#"
-Ok(
-    (
-        Partial {
-            input: [],
-            partial: true,
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                97,
+                98,
+                99,
+            ],
         },
-        -1,
     ),
 )
 
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn c_str_bounded_test() {
+        fn parser<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+            c_str_bounded(3).parse_next(i)
+        }
+
         assert_parse!(
-            be_i8.parse_peek(Partial::new(&[0x80][..])),
+            parser.parse_peek(&b"ab\x00c"[..]),
             str![[r#"
 Ok(
     (
-        Partial {
-            input: [],
-            partial: true,
-        },
-        -128,
+        [
+            99,
+        ],
+        [
+            97,
+            98,
+        ],
     ),
 )
 
 "#]]
             .raw()
         );
+        // bound violation
         assert_parse!(
-            be_i8.parse_peek(Partial::new(&[][..])),
+            parser.parse_peek(&b"abc\x00"[..]),
             str![[r#"
 Err(
-    Incomplete(
-        Size(
-            1,
-        ),
+    Backtrack(
+        InputError {
+            input: [
+                97,
+                98,
+                99,
+                0,
+            ],
+        },
     ),
 )
 
@@ -1255,17 +1324,20 @@ Err(
     }
 
     #[test]
-    fn i16_tests() {
+    fn c_str_utf8_test() {
+        fn parser<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i str> {
+            c_str_utf8.parse_next(i)
+        }
+
         assert_parse!(
-            be_i16.parse_peek(Partial::new(&[0x00, 0x00][..])),
+            parser.parse_peek("é\0x".as_bytes()),
             str![[r#"
 Ok(
     (
-        Partial {
-            input: [],
-            partial: true,
-        },
-        0,
+        [
+            120,
+        ],
+        "é",
     ),
 )
 
@@ -1273,31 +1345,40 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i16.parse_peek(Partial::new(&[0x7f, 0xff][..])),
+            parser.parse_peek(&b"a\xff\x00"[..]),
             str![[r#"
-Ok(
-    (
-        Partial {
-            input: [],
-            partial: true,
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                97,
+                255,
+                0,
+            ],
         },
-        32767,
     ),
 )
 
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn flags_test() {
+        fn parser<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], u16> {
+            flags(be_u16, 0x8003).parse_next(i)
+        }
+
         assert_parse!(
-            be_i16.parse_peek(Partial::new(&[0xff, 0xff][..])),
+            parser.parse_peek(&[0x80, 0x03, 0xff][..]),
             str![[r#"
 Ok(
     (
-        Partial {
-            input: [],
-            partial: true,
-        },
-        -1,
+        [
+            255,
+        ],
+        32771,
     ),
 )
 
@@ -1305,15 +1386,14 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i16.parse_peek(Partial::new(&[0x80, 0x00][..])),
+            parser.parse_peek(&[0x00, 0x00, 0xff][..]),
             str![[r#"
 Ok(
     (
-        Partial {
-            input: [],
-            partial: true,
-        },
-        -32768,
+        [
+            255,
+        ],
+        0,
     ),
 )
 
@@ -1321,47 +1401,84 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i16.parse_peek(Partial::new(&[][..])),
+            parser.parse_peek(&[0x40, 0x07, 0xff][..]),
             str![[r#"
 Err(
-    Incomplete(
-        Size(
-            2,
-        ),
+    Backtrack(
+        InputError {
+            input: [
+                64,
+                7,
+                255,
+            ],
+        },
     ),
 )
 
 "#]]
             .raw()
         );
-        assert_parse!(
-            be_i16.parse_peek(Partial::new(&[0x00][..])),
-            str![[r#"
-Err(
-    Incomplete(
-        Size(
-            1,
-        ),
-    ),
-)
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn flags_error_test() {
+        use crate::error::ContextError;
+        use crate::error::UnknownFlagsError;
 
+        let err = flags::<_, _, ContextError, _>(be_u16, 0x8003)
+            .parse(&[0x40, 0x07][..])
+            .unwrap_err();
+        assert_eq!(err.offset(), 0);
+        let cause = err.inner().cause().unwrap();
+        assert_eq!(
+            cause
+                .downcast_ref::<UnknownFlagsError<u16>>()
+                .unwrap()
+                .bits(),
+            &0x4004
+        );
+        snapbox::assert_data_eq!(
+            cause.to_string(),
+            str![[r#"
+unknown flags set (0x4004)
 "#]]
-            .raw()
         );
     }
 
     #[test]
-    fn u24_tests() {
+    fn flags_seq_test() {
+        use crate::combinator::seq;
+
+        #[derive(Debug, PartialEq)]
+        struct Header {
+            version: u8,
+            flags: u16,
+            length: u32,
+        }
+
+        fn header<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], Header> {
+            seq! {
+                Header {
+                    version: be_u8,
+                    flags: flags(be_u16, 0x0003),
+                    length: be_u32,
+                }
+            }
+            .parse_next(i)
+        }
+
         assert_parse!(
-            be_u24.parse_peek(Partial::new(&[0x00, 0x00, 0x00][..])),
+            header.parse_peek(&[0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x10][..]),
             str![[r#"
 Ok(
     (
-        Partial {
-            input: [],
-            partial: true,
+        [],
+        Header {
+            version: 1,
+            flags: 2,
+            length: 16,
         },
-        0,
     ),
 )
 
@@ -1369,31 +1486,44 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_u24.parse_peek(Partial::new(&[0x00, 0xFF, 0xFF][..])),
+            header.parse_peek(&[0x01, 0x01, 0x02, 0x00, 0x00, 0x00, 0x10][..]),
             str![[r#"
-Ok(
-    (
-        Partial {
-            input: [],
-            partial: true,
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                1,
+                2,
+                0,
+                0,
+                0,
+                16,
+            ],
         },
-        65535,
     ),
 )
 
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn take_rest_exact_test() {
+        fn parser<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], [u8; 2]> {
+            take_rest_exact.parse_next(i)
+        }
+
         assert_parse!(
-            be_u24.parse_peek(Partial::new(&[0x12, 0x34, 0x56][..])),
+            parser.parse_peek(&[0x01, 0x02][..]),
             str![[r#"
 Ok(
     (
-        Partial {
-            input: [],
-            partial: true,
-        },
-        1193046,
+        [],
+        [
+            1,
+            2,
+        ],
     ),
 )
 
@@ -1401,13 +1531,15 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_u24.parse_peek(Partial::new(&[][..])),
+            parser.parse_peek(&[0x01][..]),
             str![[r#"
 Err(
-    Incomplete(
-        Size(
-            3,
-        ),
+    Backtrack(
+        InputError {
+            input: [
+                1,
+            ],
+        },
     ),
 )
 
@@ -1415,47 +1547,68 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_u24.parse_peek(Partial::new(&[0x00][..])),
+            parser.parse_peek(&[0x01, 0x02, 0x03][..]),
             str![[r#"
 Err(
-    Incomplete(
-        Size(
-            2,
-        ),
+    Backtrack(
+        InputError {
+            input: [
+                1,
+                2,
+                3,
+            ],
+        },
     ),
 )
 
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn padded_test() {
+        fn parser<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+            padded(length_take(be_u8), 4).parse_next(i)
+        }
+
+        // Already aligned, so no padding is consumed
         assert_parse!(
-            be_u24.parse_peek(Partial::new(&[0x00, 0x00][..])),
+            parser.parse_peek(&b"\x03abcrest"[..]),
             str![[r#"
-Err(
-    Incomplete(
-        Size(
-            1,
-        ),
+Ok(
+    (
+        [
+            114,
+            101,
+            115,
+            116,
+        ],
+        [
+            97,
+            98,
+            99,
+        ],
     ),
 )
 
 "#]]
             .raw()
         );
-    }
-
-    #[test]
-    fn i24_tests() {
         assert_parse!(
-            be_i24.parse_peek(Partial::new(&[0xFF, 0xFF, 0xFF][..])),
+            parser.parse_peek(&b"\x01a\xff\xffrest"[..]),
             str![[r#"
 Ok(
     (
-        Partial {
-            input: [],
-            partial: true,
-        },
-        -1,
+        [
+            114,
+            101,
+            115,
+            116,
+        ],
+        [
+            97,
+        ],
     ),
 )
 
@@ -1463,45 +1616,62 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i24.parse_peek(Partial::new(&[0xFF, 0x00, 0x00][..])),
+            parser.parse_peek(&b"\x00\x00\x00\x00rest"[..]),
             str![[r#"
 Ok(
     (
-        Partial {
-            input: [],
-            partial: true,
-        },
-        -65536,
+        [
+            114,
+            101,
+            115,
+            116,
+        ],
+        [],
     ),
 )
 
 "#]]
             .raw()
         );
+        // Not enough padding
         assert_parse!(
-            be_i24.parse_peek(Partial::new(&[0xED, 0xCB, 0xAA][..])),
+            parser.parse_peek(&b"\x01a\x00"[..]),
             str![[r#"
-Ok(
-    (
-        Partial {
-            input: [],
-            partial: true,
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                0,
+            ],
         },
-        -1193046,
     ),
 )
 
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn padded_zeroed_test() {
+        fn parser<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+            padded_zeroed(length_take(be_u8), 4).parse_next(i)
+        }
+
         assert_parse!(
-            be_i24.parse_peek(Partial::new(&[][..])),
+            parser.parse_peek(&b"\x01a\x00\x00rest"[..]),
             str![[r#"
-Err(
-    Incomplete(
-        Size(
-            3,
-        ),
+Ok(
+    (
+        [
+            114,
+            101,
+            115,
+            116,
+        ],
+        [
+            97,
+        ],
     ),
 )
 
@@ -1509,27 +1679,42 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i24.parse_peek(Partial::new(&[0x00][..])),
+            parser.parse_peek(&b"\x03abcrest"[..]),
             str![[r#"
-Err(
-    Incomplete(
-        Size(
-            2,
-        ),
+Ok(
+    (
+        [
+            114,
+            101,
+            115,
+            116,
+        ],
+        [
+            97,
+            98,
+            99,
+        ],
     ),
 )
 
 "#]]
             .raw()
         );
+        // Fails at the first non-zero padding byte
         assert_parse!(
-            be_i24.parse_peek(Partial::new(&[0x00, 0x00][..])),
+            parser.parse_peek(&b"\x01a\x00\xffrest"[..]),
             str![[r#"
 Err(
-    Incomplete(
-        Size(
-            1,
-        ),
+    Backtrack(
+        InputError {
+            input: [
+                255,
+                114,
+                101,
+                115,
+                116,
+            ],
+        },
     ),
 )
 
@@ -1539,9 +1724,47 @@ Err(
     }
 
     #[test]
-    fn i32_tests() {
+    #[cfg(feature = "std")]
+    fn take_rest_exact_error_test() {
+        use crate::error::ContextError;
+        use crate::error::RestLengthError;
+
+        let err = take_rest_exact::<2, _, ContextError>
+            .parse(&[0x01, 0x02, 0x03][..])
+            .unwrap_err();
+        assert_eq!(err.offset(), 0);
+        let cause = err.inner().cause().unwrap();
+        assert_eq!(
+            cause.downcast_ref::<RestLengthError>(),
+            Some(&RestLengthError::new(2, 3))
+        );
+        snapbox::assert_data_eq!(
+            cause.to_string(),
+            str![[r#"
+expected 2 bytes remaining, found 3
+"#]]
+        );
+    }
+}
+
+mod partial {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    #[cfg(feature = "alloc")]
+    use crate::lib::std::vec::Vec;
+    use crate::Partial;
+    use crate::{
+        ascii::digit1 as digit,
+        binary::{be_u16, be_u8},
+        lib::std::str::{self, FromStr},
+    };
+
+    #[test]
+    fn i8_tests() {
         assert_parse!(
-            be_i32.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00][..])),
+            be_i8.parse_peek(Partial::new(&[0x00][..])),
             str![[r#"
 Ok(
     (
@@ -1557,7 +1780,7 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i32.parse_peek(Partial::new(&[0x7f, 0xff, 0xff, 0xff][..])),
+            be_i8.parse_peek(Partial::new(&[0x7f][..])),
             str![[r#"
 Ok(
     (
@@ -1565,7 +1788,7 @@ Ok(
             input: [],
             partial: true,
         },
-        2147483647,
+        127,
     ),
 )
 
@@ -1573,7 +1796,7 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i32.parse_peek(Partial::new(&[0xff, 0xff, 0xff, 0xff][..])),
+            be_i8.parse_peek(Partial::new(&[0xff][..])),
             str![[r#"
 Ok(
     (
@@ -1589,7 +1812,7 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i32.parse_peek(Partial::new(&[0x80, 0x00, 0x00, 0x00][..])),
+            be_i8.parse_peek(Partial::new(&[0x80][..])),
             str![[r#"
 Ok(
     (
@@ -1597,49 +1820,7 @@ Ok(
             input: [],
             partial: true,
         },
-        -2147483648,
-    ),
-)
-
-"#]]
-            .raw()
-        );
-        assert_parse!(
-            be_i32.parse_peek(Partial::new(&[][..])),
-            str![[r#"
-Err(
-    Incomplete(
-        Size(
-            4,
-        ),
-    ),
-)
-
-"#]]
-            .raw()
-        );
-        assert_parse!(
-            be_i32.parse_peek(Partial::new(&[0x00][..])),
-            str![[r#"
-Err(
-    Incomplete(
-        Size(
-            3,
-        ),
-    ),
-)
-
-"#]]
-            .raw()
-        );
-        assert_parse!(
-            be_i32.parse_peek(Partial::new(&[0x00, 0x00][..])),
-            str![[r#"
-Err(
-    Incomplete(
-        Size(
-            2,
-        ),
+        -128,
     ),
 )
 
@@ -1647,7 +1828,7 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i32.parse_peek(Partial::new(&[0x00, 0x00, 0x00][..])),
+            be_i8.parse_peek(Partial::new(&[][..])),
             str![[r#"
 Err(
     Incomplete(
@@ -1663,11 +1844,9 @@ Err(
     }
 
     #[test]
-    fn i64_tests() {
+    fn i16_tests() {
         assert_parse!(
-            be_i64.parse_peek(Partial::new(
-                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
-            )),
+            be_i16.parse_peek(Partial::new(&[0x00, 0x00][..])),
             str![[r#"
 Ok(
     (
@@ -1683,9 +1862,7 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i64.parse_peek(Partial::new(
-                &[0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff][..]
-            )),
+            be_i16.parse_peek(Partial::new(&[0x7f, 0xff][..])),
             str![[r#"
 Ok(
     (
@@ -1693,7 +1870,7 @@ Ok(
             input: [],
             partial: true,
         },
-        9223372036854775807,
+        32767,
     ),
 )
 
@@ -1701,9 +1878,7 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i64.parse_peek(Partial::new(
-                &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff][..]
-            )),
+            be_i16.parse_peek(Partial::new(&[0xff, 0xff][..])),
             str![[r#"
 Ok(
     (
@@ -1719,9 +1894,7 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i64.parse_peek(Partial::new(
-                &[0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
-            )),
+            be_i16.parse_peek(Partial::new(&[0x80, 0x00][..])),
             str![[r#"
 Ok(
     (
@@ -1729,7 +1902,7 @@ Ok(
             input: [],
             partial: true,
         },
-        -9223372036854775808,
+        -32768,
     ),
 )
 
@@ -1737,12 +1910,12 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i64.parse_peek(Partial::new(&[][..])),
+            be_i16.parse_peek(Partial::new(&[][..])),
             str![[r#"
 Err(
     Incomplete(
         Size(
-            8,
+            2,
         ),
     ),
 )
@@ -1751,12 +1924,12 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i64.parse_peek(Partial::new(&[0x00][..])),
+            be_i16.parse_peek(Partial::new(&[0x00][..])),
             str![[r#"
 Err(
     Incomplete(
         Size(
-            7,
+            1,
         ),
     ),
 )
@@ -1764,14 +1937,20 @@ Err(
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn u24_tests() {
         assert_parse!(
-            be_i64.parse_peek(Partial::new(&[0x00, 0x00][..])),
+            be_u24.parse_peek(Partial::new(&[0x00, 0x00, 0x00][..])),
             str![[r#"
-Err(
-    Incomplete(
-        Size(
-            6,
-        ),
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        0,
     ),
 )
 
@@ -1779,13 +1958,15 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i64.parse_peek(Partial::new(&[0x00, 0x00, 0x00][..])),
+            be_u24.parse_peek(Partial::new(&[0x00, 0xFF, 0xFF][..])),
             str![[r#"
-Err(
-    Incomplete(
-        Size(
-            5,
-        ),
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        65535,
     ),
 )
 
@@ -1793,13 +1974,15 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i64.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00][..])),
+            be_u24.parse_peek(Partial::new(&[0x12, 0x34, 0x56][..])),
             str![[r#"
-Err(
-    Incomplete(
-        Size(
-            4,
-        ),
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        1193046,
     ),
 )
 
@@ -1807,7 +1990,7 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i64.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00, 0x00][..])),
+            be_u24.parse_peek(Partial::new(&[][..])),
             str![[r#"
 Err(
     Incomplete(
@@ -1821,7 +2004,7 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i64.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..])),
+            be_u24.parse_peek(Partial::new(&[0x00][..])),
             str![[r#"
 Err(
     Incomplete(
@@ -1835,9 +2018,7 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i64.parse_peek(Partial::new(
-                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
-            )),
+            be_u24.parse_peek(Partial::new(&[0x00, 0x00][..])),
             str![[r#"
 Err(
     Incomplete(
@@ -1853,14 +2034,9 @@ Err(
     }
 
     #[test]
-    fn i128_tests() {
+    fn i24_tests() {
         assert_parse!(
-            be_i128.parse_peek(Partial::new(
-                &[
-                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                    0x00, 0x00, 0x00
-                ][..]
-            )),
+            be_i24.parse_peek(Partial::new(&[0xFF, 0xFF, 0xFF][..])),
             str![[r#"
 Ok(
     (
@@ -1868,7 +2044,7 @@ Ok(
             input: [],
             partial: true,
         },
-        0,
+        -1,
     ),
 )
 
@@ -1876,12 +2052,7 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(
-                &[
-                    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-                    0xff, 0xff, 0xff
-                ][..]
-            )),
+            be_i24.parse_peek(Partial::new(&[0xFF, 0x00, 0x00][..])),
             str![[r#"
 Ok(
     (
@@ -1889,7 +2060,7 @@ Ok(
             input: [],
             partial: true,
         },
-        170141183460469231731687303715884105727,
+        -65536,
     ),
 )
 
@@ -1897,12 +2068,7 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(
-                &[
-                    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-                    0xff, 0xff, 0xff
-                ][..]
-            )),
+            be_i24.parse_peek(Partial::new(&[0xED, 0xCB, 0xAA][..])),
             str![[r#"
 Ok(
     (
@@ -1910,7 +2076,7 @@ Ok(
             input: [],
             partial: true,
         },
-        -1,
+        -1193046,
     ),
 )
 
@@ -1918,20 +2084,13 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(
-                &[
-                    0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                    0x00, 0x00, 0x00
-                ][..]
-            )),
+            be_i24.parse_peek(Partial::new(&[][..])),
             str![[r#"
-Ok(
-    (
-        Partial {
-            input: [],
-            partial: true,
-        },
-        -170141183460469231731687303715884105728,
+Err(
+    Incomplete(
+        Size(
+            3,
+        ),
     ),
 )
 
@@ -1939,12 +2098,12 @@ Ok(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(&[][..])),
+            be_i24.parse_peek(Partial::new(&[0x00][..])),
             str![[r#"
 Err(
     Incomplete(
         Size(
-            16,
+            2,
         ),
     ),
 )
@@ -1953,12 +2112,12 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(&[0x00][..])),
+            be_i24.parse_peek(Partial::new(&[0x00, 0x00][..])),
             str![[r#"
 Err(
     Incomplete(
         Size(
-            15,
+            1,
         ),
     ),
 )
@@ -1966,14 +2125,20 @@ Err(
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn i32_tests() {
         assert_parse!(
-            be_i128.parse_peek(Partial::new(&[0x00, 0x00][..])),
+            be_i32.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00][..])),
             str![[r#"
-Err(
-    Incomplete(
-        Size(
-            14,
-        ),
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        0,
     ),
 )
 
@@ -1981,13 +2146,15 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(&[0x00, 0x00, 0x00][..])),
+            be_i32.parse_peek(Partial::new(&[0x7f, 0xff, 0xff, 0xff][..])),
             str![[r#"
-Err(
-    Incomplete(
-        Size(
-            13,
-        ),
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        2147483647,
     ),
 )
 
@@ -1995,12 +2162,44 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00][..])),
+            be_i32.parse_peek(Partial::new(&[0xff, 0xff, 0xff, 0xff][..])),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        -1,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i32.parse_peek(Partial::new(&[0x80, 0x00, 0x00, 0x00][..])),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        -2147483648,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i32.parse_peek(Partial::new(&[][..])),
             str![[r#"
 Err(
     Incomplete(
         Size(
-            12,
+            4,
         ),
     ),
 )
@@ -2009,12 +2208,12 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00, 0x00][..])),
+            be_i32.parse_peek(Partial::new(&[0x00][..])),
             str![[r#"
 Err(
     Incomplete(
         Size(
-            11,
+            3,
         ),
     ),
 )
@@ -2023,12 +2222,12 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..])),
+            be_i32.parse_peek(Partial::new(&[0x00, 0x00][..])),
             str![[r#"
 Err(
     Incomplete(
         Size(
-            10,
+            2,
         ),
     ),
 )
@@ -2037,14 +2236,12 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(
-                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
-            )),
+            be_i32.parse_peek(Partial::new(&[0x00, 0x00, 0x00][..])),
             str![[r#"
 Err(
     Incomplete(
         Size(
-            9,
+            1,
         ),
     ),
 )
@@ -2052,11 +2249,85 @@ Err(
 "#]]
             .raw()
         );
+    }
+
+    #[test]
+    fn i64_tests() {
         assert_parse!(
-            be_i128.parse_peek(Partial::new(
+            be_i64.parse_peek(Partial::new(
                 &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
             )),
             str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        0,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i64.parse_peek(Partial::new(
+                &[0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff][..]
+            )),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        9223372036854775807,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i64.parse_peek(Partial::new(
+                &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff][..]
+            )),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        -1,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i64.parse_peek(Partial::new(
+                &[0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
+            )),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        -9223372036854775808,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i64.parse_peek(Partial::new(&[][..])),
+            str![[r#"
 Err(
     Incomplete(
         Size(
@@ -2069,9 +2340,7 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(
-                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
-            )),
+            be_i64.parse_peek(Partial::new(&[0x00][..])),
             str![[r#"
 Err(
     Incomplete(
@@ -2085,9 +2354,7 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(
-                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
-            )),
+            be_i64.parse_peek(Partial::new(&[0x00, 0x00][..])),
             str![[r#"
 Err(
     Incomplete(
@@ -2101,9 +2368,7 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(
-                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
-            )),
+            be_i64.parse_peek(Partial::new(&[0x00, 0x00, 0x00][..])),
             str![[r#"
 Err(
     Incomplete(
@@ -2117,9 +2382,7 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(
-                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
-            )),
+            be_i64.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00][..])),
             str![[r#"
 Err(
     Incomplete(
@@ -2133,9 +2396,7 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(
-                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
-            )),
+            be_i64.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00, 0x00][..])),
             str![[r#"
 Err(
     Incomplete(
@@ -2149,12 +2410,7 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(
-                &[
-                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                    0x00
-                ][..]
-            )),
+            be_i64.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..])),
             str![[r#"
 Err(
     Incomplete(
@@ -2168,11 +2424,8 @@ Err(
             .raw()
         );
         assert_parse!(
-            be_i128.parse_peek(Partial::new(
-                &[
-                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                    0x00, 0x00
-                ][..]
+            be_i64.parse_peek(Partial::new(
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
             )),
             str![[r#"
 Err(
@@ -2189,9 +2442,14 @@ Err(
     }
 
     #[test]
-    fn le_u16_tests() {
+    fn i128_tests() {
         assert_parse!(
-            le_u16.parse_peek(Partial::new(&[0x00, 0x03][..])),
+            be_i128.parse_peek(Partial::new(
+                &[
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00
+                ][..]
+            )),
             str![[r#"
 Ok(
     (
@@ -2199,7 +2457,7 @@ Ok(
             input: [],
             partial: true,
         },
-        768,
+        0,
     ),
 )
 
@@ -2207,7 +2465,12 @@ Ok(
             .raw()
         );
         assert_parse!(
-            le_u16.parse_peek(Partial::new(&[b'a', b'b'][..])),
+            be_i128.parse_peek(Partial::new(
+                &[
+                    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+                    0xff, 0xff, 0xff
+                ][..]
+            )),
             str![[r#"
 Ok(
     (
@@ -2215,7 +2478,7 @@ Ok(
             input: [],
             partial: true,
         },
-        25185,
+        170141183460469231731687303715884105727,
     ),
 )
 
@@ -2223,12 +2486,338 @@ Ok(
             .raw()
         );
         assert_parse!(
-            le_u16.parse_peek(Partial::new(&[0x01][..])),
-            str![[r#"
-Err(
-    Incomplete(
-        Size(
-            1,
+            be_i128.parse_peek(Partial::new(
+                &[
+                    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+                    0xff, 0xff, 0xff
+                ][..]
+            )),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        -1,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(
+                &[
+                    0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00
+                ][..]
+            )),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        -170141183460469231731687303715884105728,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(&[][..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            16,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(&[0x00][..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            15,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(&[0x00, 0x00][..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            14,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(&[0x00, 0x00, 0x00][..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            13,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00][..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            12,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00, 0x00][..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            11,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            10,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
+            )),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            9,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
+            )),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            8,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
+            )),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            7,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
+            )),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            6,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
+            )),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            5,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
+            )),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            4,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
+            )),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            3,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(
+                &[
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00
+                ][..]
+            )),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            2,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_i128.parse_peek(Partial::new(
+                &[
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00
+                ][..]
+            )),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn le_u16_tests() {
+        assert_parse!(
+            le_u16.parse_peek(Partial::new(&[0x00, 0x03][..])),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        768,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            le_u16.parse_peek(Partial::new(&[b'a', b'b'][..])),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        25185,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            le_u16.parse_peek(Partial::new(&[0x01][..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
         ),
     ),
 )
@@ -2907,6 +3496,72 @@ Ok(
     ),
 )
 
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn f16_tests() {
+        assert_parse!(
+            be_f16.parse_peek(Partial::new(&[0x3c][..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            le_f16.parse_peek(Partial::new(&[0x00, 0x3c][..])),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        1.0,
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            be_bf16.parse_peek(Partial::new(&[0x41][..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            le_bf16.parse_peek(Partial::new(&[0x48, 0x41, 0x00][..])),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                0,
+            ],
+            partial: true,
+        },
+        12.5,
+    ),
+)
+
 "#]]
             .raw()
         );
@@ -3212,7 +3867,9 @@ Ok(
             str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -3224,7 +3881,9 @@ Err(
             str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -3526,6 +4185,118 @@ Ok(
     ),
 )
 
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn length_take_with_len_test() {
+        fn take<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, (u8, &'i [u8])> {
+            length_take_with_len(be_u8).parse_next(i)
+        }
+
+        assert_parse!(
+            take.parse_peek(Partial::new(&b"\x03abcef"[..])),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                101,
+                102,
+            ],
+            partial: true,
+        },
+        (
+            3,
+            [
+                97,
+                98,
+                99,
+            ],
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            take.parse_peek(Partial::new(&b"\x03ab"[..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn length_and_then_with_len_test() {
+        fn padded<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, (u8, u16)> {
+            length_and_then_with_len(be_u8, be_u16).parse_next(i)
+        }
+
+        // The declared length includes padding the inner parser doesn't consume
+        assert_parse!(
+            padded.parse_peek(Partial::new(&b"\x04\x00\x01\xff\xffef"[..])),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                101,
+                102,
+            ],
+            partial: true,
+        },
+        (
+            4,
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            padded.parse_peek(Partial::new(&b"\x04\x00\x01\xff"[..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            padded.parse_peek(Partial::new(&b"\x01\x00\x01"[..])),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    0,
+                ],
+                partial: false,
+            },
+        },
+    ),
+)
+
 "#]]
             .raw()
         );
@@ -3715,4 +4486,345 @@ Ok(
             .raw()
         );
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn length_delimited_test() {
+        fn length_delimited_1<'i>(
+            i: &mut Partial<&'i [u8]>,
+        ) -> TestResult<Partial<&'i [u8]>, Vec<u16>> {
+            length_delimited(be_u8, be_u16).parse_next(i)
+        }
+
+        let i1 = [4, 0, 1, 0, 2, 9];
+        assert_parse!(
+            length_delimited_1.parse_peek(Partial::new(&i1)),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                9,
+            ],
+            partial: true,
+        },
+        [
+            1,
+            2,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+
+        let i2 = [3, 0, 1, 0, 2, 9];
+        assert_parse!(
+            length_delimited_1.parse_peek(Partial::new(&i2)),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    0,
+                ],
+                partial: false,
+            },
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+
+        let i3 = [4, 0, 1, 0];
+        assert_parse!(
+            length_delimited_1.parse_peek(Partial::new(&i3)),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn length_delimited_offset_test() {
+        let i = [0, 3, 0, 1, 0, 2];
+        let err = length_delimited::<_, _, Vec<u16>, _, crate::error::InputError<_>, _, _>(
+            be_u16, be_u16,
+        )
+        .parse(&i[..])
+        .unwrap_err();
+        assert_eq!(err.offset(), 4);
+    }
+
+    #[test]
+    fn c_str_test() {
+        fn parser<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+            c_str.parse_next(i)
+        }
+
+        assert_parse!(
+            parser.parse_peek(Partial::new(&b"\x00"[..])),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        [],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            parser.parse_peek(Partial::new(&b"abc"[..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn c_str_bounded_test() {
+        fn parser<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+            c_str_bounded(3).parse_next(i)
+        }
+
+        // within the bound, no terminator yet
+        assert_parse!(
+            parser.parse_peek(Partial::new(&b"ab"[..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // bound violation
+        assert_parse!(
+            parser.parse_peek(Partial::new(&b"abc"[..])),
+            str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    97,
+                    98,
+                    99,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn take_rest_exact_test() {
+        use crate::error::{ErrMode, InputError};
+        use crate::stream::Bytes;
+
+        fn parser<'i>(i: &mut Partial<&'i Bytes>) -> TestResult<Partial<&'i Bytes>, [u8; 2]> {
+            take_rest_exact.parse_next(i)
+        }
+
+        assert_parse!(
+            parser.parse_peek(Partial::new(Bytes::new(&[0x01, 0x02]))),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: ,
+            partial: true,
+        },
+        [
+            1,
+            2,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            parser.parse_peek(Partial::new(Bytes::new(&[0x01]))),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // `Bytes`' debug output depends on alignment, so compare directly
+        let input = Partial::new(Bytes::new(&[0x01, 0x02, 0x03]));
+        assert_eq!(
+            parser.parse_peek(input),
+            Err(ErrMode::Backtrack(InputError::at(input)))
+        );
+    }
+
+    #[test]
+    fn padded_test() {
+        fn parser<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+            padded_zeroed(length_take(be_u8), 4).parse_next(i)
+        }
+
+        assert_parse!(
+            parser.parse_peek(Partial::new(&b"\x01a\x00\x00"[..])),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        [
+            97,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            parser.parse_peek(Partial::new(&b"\x01a"[..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            2,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            parser.parse_peek(Partial::new(&b"\x01a\x00"[..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // Incomplete padding is reported before checking it
+        assert_parse!(
+            parser.parse_peek(Partial::new(&b"\x01a\xff"[..])),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            parser.parse_peek(Partial::new(&b"\x03abc"[..])),
+            str![[r#"
+Ok(
+    (
+        Partial {
+            input: [],
+            partial: true,
+        },
+        [
+            97,
+            98,
+            99,
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn length_take_needed() {
+        use crate::error::{ContextError, ErrMode};
+        use crate::testing::check_needed;
+
+        // Both the length prefix and the payload report exactly what is missing
+        let input = &b"\x00\x05hello"[..];
+        assert_eq!(
+            check_needed(length_take(be_u16::<_, ErrMode<ContextError>>), input),
+            Ok((&b""[..], &b"hello"[..]))
+        );
+        assert_eq!(
+            length_take(be_u16::<_, ErrMode<ContextError>>).parse_peek(Partial::new(&input[..3])),
+            Err(ErrMode::Incomplete(Needed::new(4)))
+        );
+    }
+
+    proptest! {
+      #[test]
+      #[cfg(feature = "std")]
+      #[cfg_attr(miri, ignore)]  // See https://github.com/AltSysrq/proptest/issues/253
+      fn chunked(bytes in proptest::collection::vec(any::<u8>(), 0..12)) {
+          use crate::error::{ContextError, ErrMode};
+          use crate::testing::{check_invariants, check_partial};
+
+          let input = &bytes[..];
+          let _ = check_partial(be_u16::<_, ErrMode<ContextError>>, input);
+          let _ = check_partial(le_i32::<_, ErrMode<ContextError>>, input);
+          let _ = check_partial(be_u64::<_, ErrMode<ContextError>>, input);
+          let _ = check_partial(length_take(be_u8::<_, ErrMode<ContextError>>), input);
+          let _ = check_invariants(be_u16::<_, ErrMode<ContextError>>, input);
+          let _ = check_invariants(length_take(be_u8::<_, ErrMode<ContextError>>), input);
+          let _ = check_partial(padded_zeroed(length_take(be_u8::<_, ErrMode<ContextError>>), 4), input);
+      }
+    }
 }
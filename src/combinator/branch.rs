@@ -1,3 +1,4 @@
+use crate::combinator::core::take_step;
 use crate::combinator::trace;
 use crate::error::ParserError;
 use crate::stream::Stream;
@@ -8,7 +9,9 @@ pub use crate::dispatch;
 
 /// Helper trait for the [`alt()`] combinator.
 ///
-/// This trait is implemented for tuples of up to 21 elements
+/// This trait is implemented for tuples of up to 21 elements.  For alternatives that are only
+/// known at runtime, it is also implemented for arrays, slices, and `Vec`s of parsers, like
+/// `Vec<Box<dyn Parser<I, O, E>>>`.
 pub trait Alt<I, O, E> {
     /// Tests each parser in the tuple and returns the result of the first one that succeeds
     fn choice(&mut self, input: &mut I) -> Result<O, E>;
@@ -19,9 +22,16 @@ pub trait Alt<I, O, E> {
 /// To stop on an error, rather than trying further cases, see
 /// [`cut_err`][crate::combinator::cut_err] ([example][crate::_tutorial::chapter_7]).
 ///
-/// For tight control over the error when no match is found, add a final case using [`fail`][crate::combinator::fail].
+/// When every case fails, the error is from the case that got the furthest into the input, with
+/// the input left where that error happened.
+/// Errors from cases that failed at the same position are combined with [`ParserError::or`],
+/// e.g. [`ContextError`][crate::error::ContextError] reports what each expected while
+/// [`InputError`][crate::error::InputError] keeps the last one.
+///
+/// For tight control over the error when no case gets past where `alt` started, add a final case
+/// using [`fail`][crate::combinator::fail].
 /// Alternatively, with a [custom error type][crate::_topic::error], it is possible to track all
-/// errors or return the error of the parser that went the farthest in the input data.
+/// errors.
 ///
 /// When the alternative cases have unique prefixes, [`dispatch`] can offer better performance.
 ///
@@ -43,7 +53,7 @@ pub trait Alt<I, O, E> {
 /// // the first parser returns an error, so alt tries the second one
 /// assert_eq!(parser.parse_peek("123456"), Ok(("", "123456")));
 ///
-/// // both parsers failed, and with the default error type, alt will return the last error
+/// // both parsers failed at the same position, so their errors are combined
 /// assert!(parser.parse_peek(" ").is_err());
 /// # }
 /// ```
@@ -56,7 +66,92 @@ where
     Alternatives: Alt<Input, Output, Error>,
     Error: ParserError<Input>,
 {
-    trace("alt", move |i: &mut Input| alternatives.choice(i))
+    trace("alt", move |i: &mut Input| {
+        if !take_step() {
+            return Err(ParserError::from_input(i));
+        }
+        alternatives.choice(i)
+    })
+}
+
+/// Helper trait for the [`longest()`] combinator.
+///
+/// This trait is implemented for tuples of up to 21 elements.  For alternatives that are only
+/// known at runtime, it is also implemented for arrays, slices, and `Vec`s of parsers.
+pub trait Longest<I, O, E> {
+    /// Tests each parser in the tuple and returns the result of the one that consumed the most
+    fn longest(&mut self, input: &mut I) -> Result<O, E>;
+}
+
+/// Pick the successful parser that consumed the most input
+///
+/// This is "maximal munch", as tokenizers need for operators like `>`, `>=`, and `>>=`, without
+/// having to carefully order the cases for [`alt`].  When several cases consume the same amount,
+/// the first one wins.
+///
+/// Unlike [`alt`], every case is run, each from where `longest` started, and the output of each
+/// successful case is built before the winner is known.  Prefer [`alt`] or [`dispatch`] when
+/// the order of the cases can resolve the ambiguity, and keep outputs cheap, like slices or
+/// [`Parser::value`]s.
+///
+/// Errors other than [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack] are returned as
+/// soon as a case reports them, even if an earlier case succeeded:
+/// - [`cut_err`][crate::combinator::cut_err] commits to that case
+/// - [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete] means a longer match may need
+///   more input
+///
+/// When every case fails, the error is reported like with [`alt`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::combinator::longest;
+///
+/// fn operator<'i>(input: &mut &'i str) -> ModalResult<&'i str> {
+///   longest((">", ">=", ">>", ">>=", "=", "==")).parse_next(input)
+/// };
+///
+/// assert_eq!(operator.parse_peek(">>= 1"), Ok((" 1", ">>=")));
+/// assert_eq!(operator.parse_peek(">= 1"), Ok((" 1", ">=")));
+/// assert_eq!(operator.parse_peek("> 1"), Ok((" 1", ">")));
+/// assert_eq!(operator.parse_peek("=== 1"), Ok(("= 1", "==")));
+/// assert!(operator.parse_peek("< 1").is_err());
+/// ```
+///
+/// Ties go to the first case, like for keywords and identifiers:
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::alpha1;
+/// use winnow::combinator::longest;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Token<'i> {
+///     If,
+///     Ident(&'i str),
+/// }
+///
+/// fn token<'i>(input: &mut &'i str) -> ModalResult<Token<'i>> {
+///   longest(("if".value(Token::If), alpha1.map(Token::Ident))).parse_next(input)
+/// };
+///
+/// assert_eq!(token.parse_peek("if x"), Ok((" x", Token::If)));
+/// assert_eq!(token.parse_peek("iffy x"), Ok((" x", Token::Ident("iffy"))));
+/// ```
+#[inline(always)]
+pub fn longest<Input: Stream, Output, Error, Alternatives>(
+    mut alternatives: Alternatives,
+) -> impl Parser<Input, Output, Error>
+where
+    Alternatives: Longest<Input, Output, Error>,
+    Error: ParserError<Input>,
+{
+    trace("longest", move |i: &mut Input| {
+        if !take_step() {
+            return Err(ParserError::from_input(i));
+        }
+        alternatives.longest(i)
+    })
 }
 
 /// Helper trait for the [`permutation()`] combinator.
@@ -72,6 +167,7 @@ pub trait Permutation<I, O, E> {
 /// Permutation will succeed if all of the child parsers succeeded.
 /// It takes as argument a tuple of parsers, and returns a
 /// tuple of the parser results.
+/// If any child parsers never succeeded, the error is from the first of them.
 ///
 /// To stop on an error, rather than trying further permutations, see
 /// [`cut_err`][crate::combinator::cut_err] ([example][crate::_tutorial::chapter_7]).
@@ -99,6 +195,24 @@ pub trait Permutation<I, O, E> {
 /// # }
 /// ```
 ///
+/// A parser that succeeds without consuming input, like [`opt`][crate::combinator::opt], does
+/// not claim its place until none of the other parsers can make progress.
+/// This allows optional members:
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::combinator::{opt, permutation};
+///
+/// fn attrs<'i>(input: &mut &'i str) -> ModalResult<(Option<&'i str>, &'i str, Option<&'i str>)> {
+///   permutation((opt("a;"), "b;", opt("c;"))).parse_next(input)
+/// }
+///
+/// assert_eq!(attrs.parse_peek("c;b;"), Ok(("", (None, "b;", Some("c;")))));
+/// // The second `c;` is left for the caller
+/// assert_eq!(attrs.parse_peek("c;b;c;"), Ok(("c;", (None, "b;", Some("c;")))));
+/// // A required member is missing
+/// assert!(attrs.parse_peek("a;c;").is_err());
+/// ```
+///
 /// The parsers are applied greedily: if there are multiple unapplied parsers
 /// that could parse the next slice of input, the first one is used.
 /// ```rust
@@ -128,7 +242,7 @@ pub fn permutation<I: Stream, O, E: ParserError<I>, List: Permutation<I, O, E>>(
 
 impl<const N: usize, I: Stream, O, E: ParserError<I>, P: Parser<I, O, E>> Alt<I, O, E> for [P; N] {
     fn choice(&mut self, input: &mut I) -> Result<O, E> {
-        let mut error: Option<E> = None;
+        let mut error: Option<FurthestError<E>> = None;
 
         let start = input.checkpoint();
         for branch in self {
@@ -136,8 +250,8 @@ impl<const N: usize, I: Stream, O, E: ParserError<I>, P: Parser<I, O, E>> Alt<I,
             match branch.parse_next(input) {
                 Err(e) if e.is_backtrack() => {
                     error = match error {
-                        Some(error) => Some(error.or(e)),
-                        None => Some(e),
+                        Some(error) => Some(error.or(input, &start, e)),
+                        None => Some(FurthestError::new(input, &start, e)),
                     };
                 }
                 res => return res,
@@ -145,7 +259,7 @@ impl<const N: usize, I: Stream, O, E: ParserError<I>, P: Parser<I, O, E>> Alt<I,
         }
 
         match error {
-            Some(e) => Err(e.append(input, &start)),
+            Some(e) => e.finish(input, &start),
             None => Err(ParserError::assert(
                 input,
                 "`alt` needs at least one parser",
@@ -156,7 +270,7 @@ impl<const N: usize, I: Stream, O, E: ParserError<I>, P: Parser<I, O, E>> Alt<I,
 
 impl<I: Stream, O, E: ParserError<I>, P: Parser<I, O, E>> Alt<I, O, E> for &mut [P] {
     fn choice(&mut self, input: &mut I) -> Result<O, E> {
-        let mut error: Option<E> = None;
+        let mut error: Option<FurthestError<E>> = None;
 
         let start = input.checkpoint();
         for branch in self.iter_mut() {
@@ -164,8 +278,8 @@ impl<I: Stream, O, E: ParserError<I>, P: Parser<I, O, E>> Alt<I, O, E> for &mut
             match branch.parse_next(input) {
                 Err(e) if e.is_backtrack() => {
                     error = match error {
-                        Some(error) => Some(error.or(e)),
-                        None => Some(e),
+                        Some(error) => Some(error.or(input, &start, e)),
+                        None => Some(FurthestError::new(input, &start, e)),
                     };
                 }
                 res => return res,
@@ -173,7 +287,7 @@ impl<I: Stream, O, E: ParserError<I>, P: Parser<I, O, E>> Alt<I, O, E> for &mut
         }
 
         match error {
-            Some(e) => Err(e.append(input, &start)),
+            Some(e) => e.finish(input, &start),
             None => Err(ParserError::assert(
                 input,
                 "`alt` needs at least one parser",
@@ -182,6 +296,62 @@ impl<I: Stream, O, E: ParserError<I>, P: Parser<I, O, E>> Alt<I, O, E> for &mut
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<I: Stream, O, E: ParserError<I>, P: Parser<I, O, E>> Alt<I, O, E>
+    for crate::lib::std::vec::Vec<P>
+{
+    #[inline(always)]
+    fn choice(&mut self, input: &mut I) -> Result<O, E> {
+        self.as_mut_slice().choice(input)
+    }
+}
+
+/// Tracks the error from the [`alt`] branch that got the furthest into the input
+///
+/// Errors from branches that failed at the same offset are combined with [`ParserError::or`].
+/// Only the offset is kept, rather than a checkpoint, as the input can be moved back there from
+/// where `alt` started.
+struct FurthestError<E> {
+    error: E,
+    offset: usize,
+}
+
+impl<E> FurthestError<E> {
+    #[inline(always)]
+    fn new<I: Stream>(input: &I, start: &<I as Stream>::Checkpoint, error: E) -> Self {
+        let offset = input.offset_from(start);
+        Self { error, offset }
+    }
+
+    #[inline(always)]
+    fn or<I: Stream>(self, input: &I, start: &<I as Stream>::Checkpoint, error: E) -> Self
+    where
+        E: ParserError<I>,
+    {
+        let offset = input.offset_from(start);
+        if offset < self.offset {
+            self
+        } else if offset == self.offset {
+            Self {
+                error: self.error.or(error),
+                ..self
+            }
+        } else {
+            Self { error, offset }
+        }
+    }
+
+    #[inline(always)]
+    fn finish<I: Stream, O>(self, input: &mut I, start: &<I as Stream>::Checkpoint) -> Result<O, E>
+    where
+        E: ParserError<I>,
+    {
+        input.reset(start);
+        let _ = input.next_slice(self.offset);
+        Err(self.error.append(input, start))
+    }
+}
+
 macro_rules! alt_trait(
   ($first:ident $second:ident $($id: ident)+) => (
     alt_trait!(__impl $first $second; $($id)+);
@@ -207,7 +377,10 @@ macro_rules! alt_trait_impl(
       fn choice(&mut self, input: &mut I) -> Result<Output, Error> {
         let start = input.checkpoint();
         match self.0.parse_next(input) {
-          Err(e) if e.is_backtrack() => alt_trait_inner!(1, self, input, start, e, $($id)+),
+          Err(e) if e.is_backtrack() => {
+            let error = FurthestError::new(input, &start, e);
+            alt_trait_inner!(1, self, input, start, error, $($id)+)
+          }
           res => res,
         }
       }
@@ -244,14 +417,14 @@ macro_rules! alt_trait_inner(
     $input.reset(&$start);
     match $self.$it.parse_next($input) {
       Err(e) if e.is_backtrack() => {
-        let err = $err.or(e);
+        let err = $err.or($input, &$start, e);
         succ!($it, alt_trait_inner!($self, $input, $start, err, $($id)+))
       }
       res => res,
     }
   });
   ($it:tt, $self:expr, $input:expr, $start:ident, $err:expr, $head:ident) => ({
-    Err($err.append($input, &$start))
+    $err.finish($input, &$start)
   });
 );
 
@@ -264,6 +437,140 @@ impl<I: Stream, O, E: ParserError<I>, A: Parser<I, O, E>> Alt<I, O, E> for (A,)
     }
 }
 
+impl<const N: usize, I: Stream, O, E: ParserError<I>, P: Parser<I, O, E>> Longest<I, O, E>
+    for [P; N]
+{
+    fn longest(&mut self, input: &mut I) -> Result<O, E> {
+        self.as_mut_slice().longest(input)
+    }
+}
+
+impl<I: Stream, O, E: ParserError<I>, P: Parser<I, O, E>> Longest<I, O, E> for &mut [P] {
+    fn longest(&mut self, input: &mut I) -> Result<O, E> {
+        let start = input.checkpoint();
+        let mut state = LongestMatch::new();
+        for branch in self.iter_mut() {
+            input.reset(&start);
+            let result = branch.parse_next(input);
+            state.branch(input, &start, result)?;
+        }
+        state.finish(input, &start)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Stream, O, E: ParserError<I>, P: Parser<I, O, E>> Longest<I, O, E>
+    for crate::lib::std::vec::Vec<P>
+{
+    #[inline(always)]
+    fn longest(&mut self, input: &mut I) -> Result<O, E> {
+        self.as_mut_slice().longest(input)
+    }
+}
+
+/// Tracks the [`longest`] branch that has succeeded so far, falling back to the error from
+/// the branch that got the furthest
+struct LongestMatch<I: Stream, O, E> {
+    /// The output, how much was consumed, and where the input was left
+    best: Option<(O, usize, <I as Stream>::Checkpoint)>,
+    error: Option<FurthestError<E>>,
+}
+
+impl<I: Stream, O, E: ParserError<I>> LongestMatch<I, O, E> {
+    #[inline(always)]
+    fn new() -> Self {
+        Self {
+            best: None,
+            error: None,
+        }
+    }
+
+    /// Record a branch's result, returning errors that must stop the search
+    #[inline(always)]
+    fn branch(
+        &mut self,
+        input: &I,
+        start: &<I as Stream>::Checkpoint,
+        result: Result<O, E>,
+    ) -> Result<(), E> {
+        match result {
+            Ok(o) => {
+                let offset = input.offset_from(start);
+                let longer = match &self.best {
+                    Some((_, best, _)) => *best < offset,
+                    None => true,
+                };
+                if longer {
+                    self.best = Some((o, offset, input.checkpoint()));
+                }
+                Ok(())
+            }
+            Err(e) if e.is_backtrack() => {
+                self.error = match self.error.take() {
+                    Some(error) => Some(error.or(input, start, e)),
+                    None => Some(FurthestError::new(input, start, e)),
+                };
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    #[inline(always)]
+    fn finish(self, input: &mut I, start: &<I as Stream>::Checkpoint) -> Result<O, E> {
+        match (self.best, self.error) {
+            (Some((o, _, checkpoint)), _) => {
+                input.reset(&checkpoint);
+                Ok(o)
+            }
+            (None, Some(e)) => e.finish(input, start),
+            (None, None) => Err(ParserError::assert(
+                input,
+                "`longest` needs at least one parser",
+            )),
+        }
+    }
+}
+
+macro_rules! longest_trait_impl(
+  ($($index:tt $id:ident),+) => (
+    impl<
+      I: Stream, Output, Error: ParserError<I>,
+      $($id: Parser<I, Output, Error>),+
+    > Longest<I, Output, Error> for ( $($id),+, ) {
+
+      fn longest(&mut self, input: &mut I) -> Result<Output, Error> {
+        let start = input.checkpoint();
+        let mut state = LongestMatch::new();
+        $(
+          input.reset(&start);
+          let result = self.$index.parse_next(input);
+          state.branch(input, &start, result)?;
+        )+
+        state.finish(input, &start)
+      }
+    }
+  );
+);
+
+macro_rules! longest_trait(
+  ($index1:tt $id1:ident, $($index:tt $id:ident),+) => (
+    longest_trait!(__impl $index1 $id1; $($index $id),+);
+  );
+  (__impl $($index:tt $id:ident),+; $index1:tt $id1:ident $(,$index2:tt $id2:ident)*) => (
+    longest_trait_impl!($($index $id),+);
+    longest_trait!(__impl $($index $id),+, $index1 $id1; $($index2 $id2),*);
+  );
+  (__impl $($index:tt $id:ident),+;) => (
+    longest_trait_impl!($($index $id),+);
+  );
+);
+
+longest_trait!(
+  0 L0, 1 L1, 2 L2, 3 L3, 4 L4, 5 L5, 6 L6, 7 L7, 8 L8, 9 L9, 10 L10,
+  11 L11, 12 L12, 13 L13, 14 L14, 15 L15, 16 L16, 17 L17, 18 L18, 19 L19, 20 L20
+);
+
 macro_rules! permutation_trait(
   (
     $name1:ident $ty1:ident $item1:ident
@@ -293,17 +600,20 @@ macro_rules! permutation_trait_impl(
 
       fn permutation(&mut self, input: &mut I) -> Result<( $($ty),+ ), Error> {
         let mut res = ($(Option::<$ty>::None),+);
+        // Whether the parser consumed input, rather than being open to being re-applied
+        let mut done = ($({ let $item = false; $item }),+);
 
         loop {
           let mut err: Option<Error> = None;
           let start = input.checkpoint();
-          permutation_trait_inner!(0, self, input, start, res, err, $($name)+);
+          let start_len = input.eof_offset();
+          permutation_trait_inner!(0, self, input, start, start_len, res, done, err, $($name)+);
 
           // If we reach here, every iterator has either been applied before,
           // or errored on the remaining input
+          input.reset(&start);
           if let Some(err) = err {
             // There are remaining parsers, and all errored on the remaining input
-            input.reset(&start);
             return Err(err.append(input, &start));
           }
 
@@ -319,26 +629,29 @@ macro_rules! permutation_trait_impl(
 );
 
 macro_rules! permutation_trait_inner(
-  ($it:tt, $self:expr, $input:ident, $start:ident, $res:expr, $err:expr, $head:ident $($id:ident)*) => (
-    if $res.$it.is_none() {
+  ($it:tt, $self:expr, $input:ident, $start:ident, $start_len:ident, $res:expr, $done:expr, $err:expr, $head:ident $($id:ident)*) => (
+    if !$done.$it {
       $input.reset(&$start);
       match $self.$it.parse_next($input) {
         Ok(o) => {
           $res.$it = Some(o);
-          continue;
+          if $input.eof_offset() != $start_len {
+            $done.$it = true;
+            continue;
+          }
         }
         Err(e) if e.is_backtrack() => {
-          $err = Some(match $err {
-            Some(err) => err.or(e),
-            None => e,
-          });
+          // Report the first required member that is missing
+          if $res.$it.is_none() && $err.is_none() {
+            $err = Some(e);
+          }
         }
         Err(e) => return Err(e),
       };
     }
-    succ!($it, permutation_trait_inner!($self, $input, $start, $res, $err, $($id)*));
+    succ!($it, permutation_trait_inner!($self, $input, $start, $start_len, $res, $done, $err, $($id)*));
   );
-  ($it:tt, $self:expr, $input:ident, $start:ident, $res:expr, $err:expr,) => ();
+  ($it:tt, $self:expr, $input:ident, $start:ident, $start_len:ident, $res:expr, $done:expr, $err:expr,) => ();
 );
 
 permutation_trait!(
@@ -1,11 +1,26 @@
+use crate::combinator::impls::EmptyHint;
 use crate::combinator::trace;
-use crate::error::{ModalError, ParserError};
-use crate::stream::Stream;
+#[cfg(feature = "std")]
+use crate::error::StepLimitError;
+use crate::error::{
+    AddContext, FromExternalError, LengthLimitError, ModalError, Needed, ParserError,
+    RecursionLimitError, StrContext, StrContextValue, TodoError,
+};
+#[cfg(feature = "alloc")]
+use crate::lib::std::boxed::Box;
+use crate::lib::std::ops::Range;
+use crate::stream::{
+    ContainsToken, Location, LookBehind, RecursionDepth, Stateful, Stream, StreamIsPartial,
+    UpdateSlice,
+};
 use crate::*;
 
 /// Apply a [`Parser`], producing `None` on [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack].
 ///
-/// To chain an error up, see [`cut_err`].
+/// [`ErrMode::Cut`][crate::error::ErrMode::Cut] and
+/// [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete] are returned as-is, so once `parser`
+/// has [committed with `cut_err`][cut_err], its error is reported rather than becoming `None`.
+/// To produce `None` for a `Cut` as well, see [`opt_all`].
 ///
 /// # Example
 ///
@@ -23,6 +38,25 @@ use crate::*;
 /// assert_eq!(parser.parse_peek("123;"), Ok(("123;", None)));
 /// # }
 /// ```
+///
+/// With a [`cut_err`] inside:
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::ErrMode;
+/// # use winnow::error::ContextError;
+/// use winnow::combinator::{cut_err, opt, preceded};
+/// use winnow::ascii::digit1;
+/// # fn main() {
+///
+/// fn exponent<'i>(i: &mut &'i str) -> ModalResult<Option<&'i str>> {
+///   opt(preceded('e', cut_err(digit1))).parse_next(i)
+/// }
+///
+/// assert_eq!(exponent.parse_peek("e10;"), Ok((";", Some("10"))));
+/// assert_eq!(exponent.parse_peek(";"), Ok((";", None)));
+/// assert_eq!(exponent.parse_peek("e;"), Err(ErrMode::Cut(ContextError::new())));
+/// # }
+/// ```
 pub fn opt<Input: Stream, Output, Error, ParseNext>(
     mut parser: ParseNext,
 ) -> impl Parser<Input, Option<Output>, Error>
@@ -30,7 +64,10 @@ where
     ParseNext: Parser<Input, Output, Error>,
     Error: ParserError<Input>,
 {
-    trace("opt", move |input: &mut Input| {
+    let traced = trace("opt", move |input: &mut Input| {
+        if !take_step() {
+            return Err(ParserError::from_input(input));
+        }
         let start = input.checkpoint();
         match parser.parse_next(input) {
             Ok(o) => Ok(Some(o)),
@@ -40,7 +77,56 @@ where
             }
             Err(e) => Err(e),
         }
-    })
+    });
+    EmptyHint::new(traced, true)
+}
+
+/// Apply a [`Parser`], producing `None` on [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack]
+/// or [`ErrMode::Cut`][crate::error::ErrMode::Cut].
+///
+/// Unlike [`opt`], this treats the whole of `parser` as speculative, including any part of it
+/// wrapped in [`cut_err`].  The input is reset to where `parser` started.
+///
+/// [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete] is still returned as-is as more input
+/// could change the result.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::combinator::{cut_err, opt_all, preceded};
+/// use winnow::ascii::digit1;
+/// # fn main() {
+///
+/// fn exponent<'i>(i: &mut &'i str) -> ModalResult<Option<&'i str>> {
+///   opt_all(preceded('e', cut_err(digit1))).parse_next(i)
+/// }
+///
+/// assert_eq!(exponent.parse_peek("e10;"), Ok((";", Some("10"))));
+/// assert_eq!(exponent.parse_peek(";"), Ok((";", None)));
+/// assert_eq!(exponent.parse_peek("e;"), Ok(("e;", None)));
+/// # }
+/// ```
+#[doc(alias = "opt_or_cut")]
+pub fn opt_all<Input: Stream, Output, Error, ParseNext>(
+    mut parser: ParseNext,
+) -> impl Parser<Input, Option<Output>, Error>
+where
+    ParseNext: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+{
+    let traced = trace("opt_all", move |input: &mut Input| {
+        let start = input.checkpoint();
+        match parser.parse_next(input) {
+            Ok(o) => Ok(Some(o)),
+            Err(e) if e.is_incomplete() => Err(e),
+            Err(_) => {
+                input.reset(&start);
+                Ok(None)
+            }
+        }
+    });
+    EmptyHint::new(traced, true)
 }
 
 /// Calls the parser if the condition is met.
@@ -84,10 +170,59 @@ where
     })
 }
 
+/// Calls the first parser if the condition is met, otherwise the second.
+///
+/// Unlike [`alt`][crate::combinator::alt], the branch is selected up-front from an already-known
+/// value (e.g. a version field parsed earlier) and the other parser is never attempted, even if
+/// the selected one fails.
+///
+/// To run a parser only if the condition is met, see [`cond`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::combinator::cond_else;
+/// use winnow::binary::{be_u16, be_u32, u8};
+/// # fn main() {
+///
+/// fn parser(i: &mut &[u8]) -> ModalResult<u32> {
+///   let version = u8.parse_next(i)?;
+///   cond_else(2 <= version, be_u32, be_u16.map(u32::from)).parse_next(i)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&[2, 0, 0, 1, 0][..]), Ok((&[][..], 256)));
+/// assert_eq!(parser.parse_peek(&[1, 1, 0][..]), Ok((&[][..], 256)));
+/// assert!(parser.parse_peek(&[2, 1, 0][..]).is_err());
+/// # }
+/// ```
+pub fn cond_else<Input, Output, Error, IfTrue, IfFalse>(
+    cond: bool,
+    mut if_true: IfTrue,
+    mut if_false: IfFalse,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream,
+    IfTrue: Parser<Input, Output, Error>,
+    IfFalse: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+{
+    trace("cond_else", move |input: &mut Input| {
+        if cond {
+            if_true.parse_next(input)
+        } else {
+            if_false.parse_next(input)
+        }
+    })
+}
+
 /// Apply the parser without advancing the input.
 ///
 /// To lookahead and only advance on success, see [`opt`].
 ///
+/// Only the input's position is restored; changes to [`Stateful::state`] are kept.  To also undo
+/// those, see [`peek_with_state_rollback`].
+///
 /// # Example
 ///
 /// ```rust
@@ -122,6 +257,65 @@ where
     })
 }
 
+/// Apply the parser without advancing the input or changing the [`Stateful::state`].
+///
+/// [`peek`] only restores the input's position, so a parser that interns strings or increments
+/// counters in its state would leave those changes behind.  This instead clones the state before
+/// applying the parser and puts it back afterwards, whether or not the parser succeeded.
+///
+/// This costs a clone of the state on every call, so prefer [`peek`] for parsers that don't
+/// modify the state.  Changes made through shared references, like a `&Cell<_>` or an
+/// `&mut _` held in the state, can't be rolled back.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::alpha1;
+/// use winnow::combinator::peek_with_state_rollback;
+/// use winnow::stream::Stateful;
+///
+/// type Input<'i> = Stateful<&'i str, Vec<&'i str>>;
+///
+/// fn intern<'i>(input: &mut Input<'i>) -> ModalResult<&'i str> {
+///     let word = alpha1.parse_next(input)?;
+///     input.state.push(word);
+///     Ok(word)
+/// }
+///
+/// fn parser<'i>(input: &mut Input<'i>) -> ModalResult<&'i str> {
+///     peek_with_state_rollback(intern).parse_next(input)
+/// }
+///
+/// let mut input = Input { input: "abcd;", state: vec![] };
+/// assert_eq!(parser.parse_next(&mut input), Ok("abcd"));
+/// assert_eq!(input.input, "abcd;");
+/// assert!(input.state.is_empty());
+/// ```
+#[doc(alias = "look_ahead")]
+#[doc(alias = "rewind")]
+pub fn peek_with_state_rollback<Input, State, Output, Error, ParseNext>(
+    mut parser: ParseNext,
+) -> impl Parser<Stateful<Input, State>, Output, Error>
+where
+    Input: Stream,
+    State: Clone + crate::lib::std::fmt::Debug,
+    Error: ParserError<Stateful<Input, State>>,
+    ParseNext: Parser<Stateful<Input, State>, Output, Error>,
+{
+    trace(
+        "peek_with_state_rollback",
+        move |input: &mut Stateful<Input, State>| {
+            let start = input.checkpoint();
+            let state = input.state.clone();
+            let res = parser.parse_next(input);
+            input.reset(&start);
+            input.state = state;
+            res
+        },
+    )
+}
+
 /// Match the end of the [`Stream`]
 ///
 /// Otherwise, it will error.
@@ -150,6 +344,19 @@ where
 /// assert!(parser.parse_peek("abc").is_err());
 /// assert_eq!(parser.parse_peek(""), Ok(("", "")));
 /// ```
+///
+/// The error reports how much input was left, for error types that record what was
+/// [expected][crate::error::ParserError::add_expected]:
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::combinator::eof;
+/// # use winnow::error::ContextError;
+/// # use winnow::prelude::*;
+/// let err = eof::<_, ContextError>.parse_peek("abc").unwrap_err();
+/// assert_eq!(err.to_string(), "expected end of input (3 bytes left)");
+/// # }
+/// ```
 #[doc(alias = "end")]
 #[doc(alias = "eoi")]
 pub fn eof<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
@@ -158,10 +365,11 @@ where
     Error: ParserError<Input>,
 {
     trace("eof", move |input: &mut Input| {
-        if input.eof_offset() == 0 {
+        let remaining = input.eof_offset();
+        if remaining == 0 {
             Ok(input.next_slice(0))
         } else {
-            Err(ParserError::from_input(input))
+            Err(Error::from_input(input).add_expected(input, StrContextValue::Eof(remaining)))
         }
     })
     .parse_next(input)
@@ -209,6 +417,53 @@ where
     })
 }
 
+/// Succeeds if the previously consumed token is not in `set`, including at the start of input
+///
+/// This requires a [`Stream`] that retains consumed input, like
+/// [`LocatingSlice`][crate::stream::LocatingSlice].  See [`LookBehind`] for limitations.
+///
+/// <div class="warning">
+///
+/// **Note:** This does not advance the [`Stream`]
+///
+/// </div>
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::combinator::not_preceded_by;
+/// use winnow::combinator::preceded;
+/// use winnow::stream::AsChar;
+/// use winnow::stream::LocatingSlice;
+/// use winnow::ascii::{alpha0, digit1};
+///
+/// fn parser<'i>(input: &mut LocatingSlice<&'i str>) -> ModalResult<&'i str> {
+///     preceded(alpha0, preceded(not_preceded_by(AsChar::is_alpha), digit1)).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse(LocatingSlice::new("10")), Ok("10"));
+/// assert!(parser.parse(LocatingSlice::new("x10")).is_err());
+/// ```
+pub fn not_preceded_by<Input, Set, Error>(set: Set) -> impl Parser<Input, (), Error>
+where
+    Input: LookBehind,
+    Set: ContainsToken<<Input as Stream>::Token>,
+    Error: ParserError<Input>,
+{
+    trace("not_preceded_by", move |input: &mut Input| {
+        if input
+            .peek_previous_token()
+            .map(|token| set.contains_token(token))
+            .unwrap_or(false)
+        {
+            Err(ParserError::from_input(input))
+        } else {
+            Ok(())
+        }
+    })
+}
+
 /// Transforms an [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack] (recoverable) to [`ErrMode::Cut`][crate::error::ErrMode::Cut] (unrecoverable)
 ///
 /// This commits the parse result, preventing alternative branch paths like with
@@ -295,10 +550,438 @@ where
     })
 }
 
+/// Report [`Needed`][crate::error::Needed] as the total input length required from where `parser`
+/// started
+///
+/// By default, [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete] reports how much
+/// input is missing past the end of the current buffer.  This instead reports the length of input,
+/// measured from the start of this parser, that must be available to make progress, including
+/// any input already consumed by `parser`.  This is helpful for sizing a single read call when
+/// buffering [`Partial`] input.
+///
+/// On `Incomplete`, the input is reset to where `parser` started.
+/// [`Needed::Unknown`][crate::error::Needed::Unknown] is left as-is.
+///
+/// To otherwise adjust `Needed`, see [`ErrMode::map_incomplete`][crate::error::ErrMode::map_incomplete].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::{ErrMode, ContextError, Needed};
+/// # use winnow::Partial;
+/// use winnow::combinator::with_needed_offset;
+/// use winnow::token::take;
+///
+/// fn record<'i>(i: &mut Partial<&'i [u8]>) -> ModalResult<&'i [u8]> {
+///     with_needed_offset((take(6usize), take(1000usize)).map(|(_, body)| body)).parse_next(i)
+/// }
+///
+/// let buffer = Partial::new(&[0; 10][..]);
+/// assert_eq!(record.parse_peek(buffer), Err(ErrMode::Incomplete(Needed::new(1006))));
+/// ```
+pub fn with_needed_offset<Input, Output, Error, ParseNext>(
+    mut parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream,
+    Error: ParserError<Input>,
+    ParseNext: Parser<Input, Output, Error>,
+{
+    trace("with_needed_offset", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let len = input.eof_offset();
+        parser.parse_next(input).map_err(|e| match e.needed() {
+            Some(needed) => {
+                input.reset(&start);
+                Error::incomplete(input, needed.map(|n| len + n.get()))
+            }
+            None => e,
+        })
+    })
+}
+
+/// Treat the input as complete while running `parser`
+///
+/// This is for when the framing of [`Partial`] input already guarantees some input is complete,
+/// like the final field of a message that runs until the end of the message.  Without it,
+/// parsers like [`take_while`][crate::token::take_while] report
+/// [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete] at the end of the buffer, as more
+/// matching input could still arrive.
+///
+/// The input is restored to its prior partial state when `parser` returns, whether it succeeded
+/// or not, rather than manually pairing [`StreamIsPartial::complete`] with
+/// [`StreamIsPartial::restore_partial`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::{ErrMode, Needed};
+/// # use winnow::Partial;
+/// use winnow::combinator::complete_scope;
+/// use winnow::token::take_while;
+///
+/// fn trailer<'i>(i: &mut Partial<&'i str>) -> ModalResult<&'i str> {
+///     complete_scope(take_while(0.., |c: char| c.is_alphanumeric())).parse_next(i)
+/// }
+///
+/// assert_eq!(trailer.parse_peek(Partial::new("abc")), Ok((Partial::new(""), "abc")));
+/// assert_eq!(
+///     take_while::<_, _, ErrMode<()>>(0.., |c: char| c.is_alphanumeric()).parse_peek(Partial::new("abc")),
+///     Err(ErrMode::Incomplete(Needed::new(1)))
+/// );
+/// ```
+pub fn complete_scope<Input, Output, Error, ParseNext>(
+    mut parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream,
+    Error: ParserError<Input>,
+    ParseNext: Parser<Input, Output, Error>,
+{
+    trace("complete_scope", move |input: &mut Input| {
+        let state = input.complete();
+        let res = parser.parse_next(input);
+        input.restore_partial(state);
+        res
+    })
+}
+
+/// Run `parser` against the slice from `slice`, requiring all of it to be consumed
+///
+/// This is for nested containers, like a length-prefixed body with its own grammar.  Unlike
+/// [`Parser::and_then`], errors are reported in the outer stream's coordinates: when `parser`
+/// fails, the outer input is left at the position within the slice where it failed, so the offset
+/// from [`Parser::parse`] points into the slice.  With [`LocatingSlice`], the locations seen by
+/// `parser` are also in the outer stream's coordinates.
+///
+/// The slice is parsed as [complete][StreamIsPartial::complete], with an
+/// [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete] from `parser` reported as a
+/// backtrack error.
+///
+/// <div class="warning">
+///
+/// **Note:** error types that capture the input, like [`InputError`][crate::error::InputError],
+/// capture the slice's stream, not the outer stream.
+///
+/// </div>
+///
+/// See also [`length_and_then`][crate::binary::length_and_then].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{alpha1, digit1};
+/// use winnow::binary::length_take;
+/// use winnow::combinator::{separated_pair, subparse};
+///
+/// fn record<'i>(input: &mut &'i str) -> ModalResult<(&'i str, &'i str)> {
+///     subparse(
+///         length_take(digit1.parse_to::<usize>()),
+///         separated_pair(alpha1, ',', alpha1),
+///     )
+///     .parse_next(input)
+/// }
+///
+/// assert_eq!(record.parse_peek("5ab,cdef"), Ok(("ef", ("ab", "cd"))));
+///
+/// // The error is at the `1` within the slice
+/// let err = record.parse("5ab,1d").unwrap_err();
+/// assert_eq!(err.offset(), 4);
+/// ```
+pub fn subparse<Input, Output, Error, SliceParser, ParseNext>(
+    mut slice: SliceParser,
+    mut parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream + UpdateSlice + Clone,
+    Error: ParserError<Input>,
+    SliceParser: Parser<Input, <Input as Stream>::Slice, Error>,
+    ParseNext: Parser<Input, Output, Error>,
+{
+    trace("subparse", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let data = slice.parse_next(input)?;
+        let mut data = Input::update_slice(input.clone(), data);
+        let _ = data.complete();
+        let data_start = data.checkpoint();
+        let res = (parser.by_ref().complete_err(), eof)
+            .map(|(o, _)| o)
+            .parse_next(&mut data);
+        if res.is_err() {
+            // Move the input to where the error happened within the slice
+            let data_end = data.offset_from(&data_start);
+            data.reset(&data_start);
+            let data_len = data.eof_offset();
+            let data_offset = input.offset_from(&start) - data_len;
+            input.reset(&start);
+            let _ = input.next_slice(data_offset + data_end);
+        }
+        res
+    })
+}
+
+/// Limit how deeply a recursive [`Parser`] may nest
+///
+/// Each `depth_guard` entered increments the [`RecursionDepth`] of the [`Stream`], decrementing
+/// it when `parser` returns, whether it succeeded or not.  Once `max` guards are entered, the next
+/// one reports an [`ErrMode::Cut`][crate::error::ErrMode::Cut] of [`RecursionLimitError`]
+/// without running `parser`, protecting against stack overflows from maliciously nested input.
+///
+/// The depth is commonly tracked in the `state` of a [`Stateful`][crate::stream::Stateful].
+/// If `parser` panics, the depth is left incremented, so don't reuse the input after catching
+/// the panic, or reset its depth first.
+///
+/// As the guard counts as entered even if `parser` goes on to fail, wrap the recursive call
+/// rather than a parser that may not match.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::combinator::{delimited, depth_guard, opt};
+/// use winnow::stream::Stateful;
+///
+/// type Input<'i> = Stateful<&'i str, usize>;
+///
+/// fn nested(input: &mut Input<'_>) -> ModalResult<usize> {
+///     delimited('[', depth_guard(3, opt(nested)), ']')
+///         .map(|inner| inner.unwrap_or(0) + 1)
+///         .parse_next(input)
+/// }
+///
+/// let input = |s| Input { input: s, state: 0 };
+/// assert_eq!(nested.parse(input("[[[]]]")).unwrap(), 3);
+/// assert!(nested.parse(input("[[[[]]]]")).is_err());
+/// ```
+pub fn depth_guard<Input, Output, Error, ParseNext>(
+    max: usize,
+    mut parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream + RecursionDepth,
+    Error: ParserError<Input> + ModalError + FromExternalError<Input, RecursionLimitError>,
+    ParseNext: Parser<Input, Output, Error>,
+{
+    trace("depth_guard", move |input: &mut Input| {
+        if max <= *input.recursion_depth() {
+            return Err(Error::from_external_error(input, RecursionLimitError::new(max)).cut());
+        }
+
+        *input.recursion_depth() += 1;
+        let res = parser.parse_next(input);
+        *input.recursion_depth() -= 1;
+        res
+    })
+}
+
+/// Limit how much input a [`Parser`] may consume
+///
+/// If `parser` consumes more than `max` tokens, this reports an
+/// [`ErrMode::Cut`][crate::error::ErrMode::Cut] of [`LengthLimitError`] at the start of `parser`,
+/// protecting against pathological input where there is no length prefix to check, see instead
+/// [`length_and_then`][crate::binary::length_and_then].
+///
+/// *[Partial version][crate::_topic::partial]*: `Incomplete` is passed through while the input
+/// `parser` needs fits within `max`.  Once it would need more than `max`, this reports the
+/// error instead, so the caller doesn't keep buffering input that can never be accepted.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{alpha1, line_ending};
+/// use winnow::combinator::{limited, repeat, terminated};
+///
+/// fn header<'i>(input: &mut &'i str) -> ModalResult<Vec<&'i str>> {
+///     limited(12, repeat(0.., terminated(alpha1, line_ending))).parse_next(input)
+/// }
+///
+/// assert_eq!(header.parse_peek("Host\nAccept\nbody"), Ok(("body", vec!["Host", "Accept"])));
+/// assert!(header.parse_peek("Host\nAccept\nConnection\n").is_err());
+/// # }
+/// ```
+pub fn limited<Input, Output, Error, ParseNext>(
+    max: usize,
+    mut parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream,
+    Error: ParserError<Input> + ModalError + FromExternalError<Input, LengthLimitError>,
+    ParseNext: Parser<Input, Output, Error>,
+{
+    trace("limited", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let available = input.eof_offset();
+        match parser.parse_next(input) {
+            Ok(o) if input.offset_from(&start) <= max => return Ok(o),
+            Ok(_) => {}
+            Err(e) => {
+                let required = match e.needed() {
+                    Some(Needed::Size(n)) => available.saturating_add(n.get()),
+                    Some(Needed::Unknown) => available.saturating_add(1),
+                    None => return Err(e),
+                };
+                if required <= max {
+                    return Err(e);
+                }
+            }
+        }
+        input.reset(&start);
+        Err(Error::from_external_error(input, LengthLimitError::new(max)).cut())
+    })
+}
+
+/// Limit how many steps a [`Parser`] may take
+///
+/// Each [`alt`][crate::combinator::alt] and [`opt`] run by `parser` takes a step.  Once `max`
+/// steps are taken, the remaining ones fail, unwinding quickly, and this reports an
+/// [`ErrMode::Cut`][crate::error::ErrMode::Cut] of [`StepLimitError`] at the start of `parser`.
+/// This protects against grammars that backtrack exponentially on crafted input.
+///
+/// The budget is tracked per-thread, rather than in the [`Stream`].  Nested `step_limit`s take
+/// the minimum of their budget and what is left of the enclosing one, and steps they take count
+/// towards the enclosing one.  Outside of a `step_limit`, steps are not counted.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::combinator::{alt, delimited, step_limit};
+///
+/// // Each nested `(` is parsed twice, taking exponential time
+/// fn expr(input: &mut &str) -> ModalResult<()> {
+///     alt(((term, '+', expr).void(), term)).parse_next(input)
+/// }
+///
+/// fn term(input: &mut &str) -> ModalResult<()> {
+///     alt((delimited('(', expr, ')'), 'x'.void())).parse_next(input)
+/// }
+///
+/// fn parser(input: &mut &str) -> ModalResult<()> {
+///     step_limit(1_000, expr).parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse("(x+x)+x"), Ok(()));
+/// let input = "(".repeat(40);
+/// let err = parser.parse(&input).unwrap_err();
+/// assert_eq!(err.inner().to_string(), "step budget exceeded (max steps 1000)");
+/// ```
+#[cfg(feature = "std")]
+pub fn step_limit<Input, Output, Error, ParseNext>(
+    max: usize,
+    mut parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream,
+    Error: ParserError<Input> + ModalError + FromExternalError<Input, StepLimitError>,
+    ParseNext: Parser<Input, Output, Error>,
+{
+    trace("step_limit", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let guard = StepBudgetGuard::new(max);
+        let res = parser.parse_next(input);
+        let exceeded = STEP_BUDGET.with(|budget| budget.get().1);
+        drop(guard);
+
+        if exceeded {
+            input.reset(&start);
+            Err(Error::from_external_error(input, StepLimitError::new(max)).cut())
+        } else {
+            res
+        }
+    })
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// Steps left for the innermost [`step_limit`] and whether it ran out
+    ///
+    /// `usize::MAX` steps is unlimited.
+    static STEP_BUDGET: core::cell::Cell<(usize, bool)> =
+        const { core::cell::Cell::new((usize::MAX, false)) };
+}
+
+/// How many [`step_limit`]s are running, across all threads
+///
+/// This lets [`take_step`] skip the thread-local when no budget is being tracked.
+#[cfg(feature = "std")]
+static ACTIVE_STEP_LIMITS: core::sync::atomic::AtomicUsize =
+    core::sync::atomic::AtomicUsize::new(0);
+
+/// Sets the budget for a [`step_limit`], restoring the enclosing one on drop, even when unwinding
+#[cfg(feature = "std")]
+struct StepBudgetGuard {
+    outer: usize,
+    outer_exceeded: bool,
+    steps: usize,
+}
+
+#[cfg(feature = "std")]
+impl StepBudgetGuard {
+    fn new(max: usize) -> Self {
+        ACTIVE_STEP_LIMITS.fetch_add(1, core::sync::atomic::Ordering::SeqCst);
+        let (outer, outer_exceeded) = STEP_BUDGET.with(|budget| budget.get());
+        let steps = max.min(outer);
+        STEP_BUDGET.with(|budget| budget.set((steps, false)));
+        Self {
+            outer,
+            outer_exceeded,
+            steps,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for StepBudgetGuard {
+    fn drop(&mut self) {
+        let (remaining, exceeded) = STEP_BUDGET.with(|budget| budget.get());
+        let outer = if self.outer == usize::MAX {
+            self.outer
+        } else {
+            self.outer - (self.steps - remaining)
+        };
+        let outer_exceeded = self.outer_exceeded || (exceeded && outer == 0);
+        STEP_BUDGET.with(|budget| budget.set((outer, outer_exceeded)));
+        ACTIVE_STEP_LIMITS.fetch_sub(1, core::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+/// Take a step towards the innermost [`step_limit`], returning `false` if there are none left
+#[inline(always)]
+pub(crate) fn take_step() -> bool {
+    #[cfg(feature = "std")]
+    {
+        if ACTIVE_STEP_LIMITS.load(core::sync::atomic::Ordering::Relaxed) == 0 {
+            return true;
+        }
+        STEP_BUDGET.with(|budget| match budget.get() {
+            (usize::MAX, _) => true,
+            (0, _) => {
+                budget.set((0, true));
+                false
+            }
+            (steps, exceeded) => {
+                budget.set((steps - 1, exceeded));
+                true
+            }
+        })
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        true
+    }
+}
+
 /// A placeholder for a not-yet-implemented [`Parser`]
 ///
 /// This is analogous to the [`todo!`] macro and helps with prototyping.
 ///
+/// To report an error naming the rule rather than panicking, see [`todo_with`].
+///
 /// # Panic
 ///
 /// This will panic when parsing
@@ -504,3 +1187,331 @@ where
 {
     trace("fail", |i: &mut Input| Err(ParserError::from_input(i))).parse_next(i)
 }
+
+/// A placeholder for a grammar rule that hasn't been written yet.
+///
+/// This type checks as a parser for any output, so the rest of the grammar can be compiled and
+/// tested while rules are filled in.  When reached, it always fails with
+/// [`ErrMode::Cut`][crate::error::ErrMode::Cut], reporting a [`TodoError`] labeled with `rule`.
+///
+/// Unlike [`fail`], this is meant to be loud: it won't be quietly skipped by
+/// [`alt`][crate::combinator::alt] and it is easy to search for before release.  Unlike
+/// [`todo()`], it doesn't panic, so tests can exercise the rest of the grammar.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;
+/// use winnow::ascii::digit1;
+/// use winnow::combinator::{alt, todo_with};
+///
+/// #[derive(Debug)]
+/// enum Value {
+///     Number(u32),
+///     List(Vec<Value>),
+/// }
+///
+/// fn value(input: &mut &str) -> ModalResult<Value> {
+///     alt((
+///         digit1.parse_to().map(Value::Number),
+///         list.map(Value::List),
+///     ))
+///     .parse_next(input)
+/// }
+///
+/// fn list(input: &mut &str) -> ModalResult<Vec<Value>> {
+///     todo_with("list").parse_next(input)
+/// }
+///
+/// assert!(value.parse_peek("42").is_ok());
+///
+/// let err = value.parse("[1, 2]").unwrap_err();
+/// assert!(err.to_string().contains("invalid list"));
+/// # }
+/// ```
+#[inline]
+pub fn todo_with<Input, Output, Error>(rule: &'static str) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream,
+    Error: ParserError<Input>
+        + FromExternalError<Input, TodoError>
+        + AddContext<Input, StrContext>
+        + ModalError,
+{
+    trace(rule, move |input: &mut Input| {
+        let start = input.checkpoint();
+        Err(Error::from_external_error(input, TodoError::new(rule))
+            .add_context(input, &start, StrContext::Label(rule))
+            .cut())
+    })
+}
+
+/// Adapt a function that returns the remaining input, like [`Parser::parse_peek`], into a
+/// [`Parser`]
+///
+/// This is for parsers written in the older `Fn(I) -> IResult<I, O, E>` style, allowing them to be
+/// mixed with `&mut I` parsers while being migrated.  The output, the error type, and
+/// [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete] are all passed through unchanged.
+/// On error, the input is left at the position it was at before `f` was called.
+///
+/// To name the parser in [`trace`] output, wrap it with [`trace`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{alpha1, digit1};
+/// use winnow::combinator::{alt, from_peek_fn, trace};
+///
+/// // Not yet migrated
+/// fn number(input: &str) -> ModalResult<(&str, u32)> {
+///     let (input, digits) = digit1.parse_peek(input)?;
+///     Ok((input, digits.parse().unwrap()))
+/// }
+///
+/// fn value(input: &mut &str) -> ModalResult<u32> {
+///     alt((
+///         trace("number", from_peek_fn(number)),
+///         alpha1.value(0),
+///     ))
+///     .parse_next(input)
+/// }
+///
+/// assert_eq!(value.parse_peek("42;"), Ok((";", 42)));
+/// assert_eq!(value.parse_peek("abc;"), Ok((";", 0)));
+/// ```
+#[doc(alias = "unpeek")]
+#[inline(always)]
+pub fn from_peek_fn<Input, Output, Error, PeekFn>(
+    mut f: PeekFn,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream + Clone,
+    PeekFn: FnMut(Input) -> Result<(Input, Output), Error>,
+{
+    move |input: &mut Input| {
+        let (remaining, output) = f(input.clone())?;
+        *input = remaining;
+        Ok(output)
+    }
+}
+
+/// Defer constructing a [`Parser`] until it is first used
+///
+/// This allows recursive grammars to be built from functions returning `impl Parser`, like
+/// parsers configured by a value they capture, without manually boxing each level:
+/// constructing the recursive parser eagerly would never finish and `impl Parser` can't contain
+/// itself.
+///
+/// `thunk` is called the first time the parser is used and the result is memoized for later
+/// calls.  The parser is boxed to erase its type, so each [`Lazy`] allocates once, when built,
+/// and once, when first used, but not on later parses.  Nested `Lazy`s are only built as parsing
+/// reaches them.
+///
+/// # Example
+///
+/// A JSON value grammar, where every rule captures its configuration:
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{digit1, multispace0};
+/// use winnow::combinator::{alt, delimited, lazy, separated, separated_pair};
+/// use winnow::error::{ContextError, ErrMode};
+/// use winnow::token::take_while;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Json {
+///     Null,
+///     Number(u64),
+///     String(String),
+///     Array(Vec<Json>),
+///     Object(Vec<(String, Json)>),
+/// }
+///
+/// #[derive(Copy, Clone)]
+/// struct Config {
+///     max_items: usize,
+/// }
+///
+/// type Error = ErrMode<ContextError>;
+///
+/// fn string(input: &mut &str) -> ModalResult<String> {
+///     delimited('"', take_while(0.., |c| c != '"'), '"')
+///         .map(String::from)
+///         .parse_next(input)
+/// }
+///
+/// fn value<'i>(config: Config) -> impl Parser<&'i str, Json, Error> {
+///     let value = alt((
+///         "null".value(Json::Null),
+///         digit1.parse_to().map(Json::Number),
+///         string.map(Json::String),
+///         // `array` and `object` contain `value`, so build them when they are reached
+///         lazy(move || array(config)),
+///         lazy(move || object(config)),
+///     ));
+///     delimited(multispace0, value, multispace0)
+/// }
+///
+/// fn array<'i>(config: Config) -> impl Parser<&'i str, Json, Error> {
+///     let items = separated(0..=config.max_items, value(config), ',');
+///     delimited('[', items, ']').map(Json::Array)
+/// }
+///
+/// fn object<'i>(config: Config) -> impl Parser<&'i str, Json, Error> {
+///     let member = separated_pair(delimited(multispace0, string, multispace0), ':', value(config));
+///     let members = separated(0..=config.max_items, member, ',');
+///     delimited('{', members, '}').map(Json::Object)
+/// }
+///
+/// let config = Config { max_items: 2 };
+/// assert_eq!(
+///     value(config).parse(r#"{"a": [1, null], "b": {}}"#),
+///     Ok(Json::Object(vec![
+///         ("a".into(), Json::Array(vec![Json::Number(1), Json::Null])),
+///         ("b".into(), Json::Object(vec![])),
+///     ]))
+/// );
+/// assert!(value(config).parse("[1, 2, 3]").is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn lazy<'p, Input, Output, Error, Thunk, ParseNext>(
+    thunk: Thunk,
+) -> Lazy<'p, Input, Output, Error>
+where
+    Input: Stream,
+    Error: ParserError<Input>,
+    Thunk: Fn() -> ParseNext + 'p,
+    ParseNext: Parser<Input, Output, Error> + 'p,
+{
+    Lazy {
+        thunk: Box::new(move || Box::new(thunk()) as Box<dyn Parser<Input, Output, Error> + 'p>),
+        parser: None,
+    }
+}
+
+/// Implementation of [`lazy`]
+#[cfg(feature = "alloc")]
+pub struct Lazy<'p, I, O, E> {
+    thunk: Box<dyn Fn() -> Box<dyn Parser<I, O, E> + 'p> + 'p>,
+    parser: Option<Box<dyn Parser<I, O, E> + 'p>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I, O, E> Parser<I, O, E> for Lazy<'_, I, O, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        let thunk = &self.thunk;
+        let parser = self.parser.get_or_insert_with(|| thunk());
+        trace("lazy", |input: &mut I| parser.parse_next(input)).parse_next(input)
+    }
+}
+
+/// Produce a [`Spanned`] output, pairing the parser's output with the location of the consumed
+/// input
+///
+/// This is [`Parser::with_span`] with a named type, which is more convenient to store in an AST
+/// than a tuple.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{alpha1, digit1};
+/// use winnow::combinator::{separated_pair, spanned, Spanned};
+/// use winnow::stream::LocatingSlice;
+///
+/// fn assignment<'i>(
+///     input: &mut LocatingSlice<&'i str>,
+/// ) -> ModalResult<(Spanned<&'i str>, Spanned<&'i str>)> {
+///     separated_pair(spanned(alpha1), '=', spanned(digit1)).parse_next(input)
+/// }
+///
+/// let (name, value) = assignment.parse(LocatingSlice::new("abc=42")).unwrap();
+/// assert_eq!(name, Spanned::new("abc", 0..3));
+/// assert_eq!(value.span, 4..6);
+/// assert_eq!(value.len(), 2);
+/// assert_eq!(Spanned::map(value, |value| value.len()), Spanned::new(2, 4..6));
+/// ```
+#[inline(always)]
+pub fn spanned<Input, Output, Error, ParseNext>(
+    parser: ParseNext,
+) -> impl Parser<Input, Spanned<Output>, Error>
+where
+    Input: Stream + Location,
+    Error: ParserError<Input>,
+    ParseNext: Parser<Input, Output, Error>,
+{
+    trace(
+        "spanned",
+        parser
+            .with_span()
+            .map(|(value, span)| Spanned::new(value, span)),
+    )
+}
+
+/// A value with the location of the input it was parsed from, see [`spanned`]
+///
+/// This dereferences to the `value`, so methods on it can be called directly.  Methods of
+/// `Spanned` itself are associated functions, like [`Spanned::map`], so they don't get in the way.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Spanned<T> {
+    /// The parsed value
+    pub value: T,
+    /// The location of the input the `value` was parsed from
+    pub span: Range<usize>,
+}
+
+impl<T> Spanned<T> {
+    /// Pair a `value` with the location it was parsed from
+    #[inline]
+    pub fn new(value: T, span: Range<usize>) -> Self {
+        Self { value, span }
+    }
+
+    /// Transform the `value`, keeping the `span`
+    ///
+    /// This is an associated function, called as `Spanned::map(this, f)`, so it doesn't shadow
+    /// a method of the same name on the `value`.
+    #[inline]
+    pub fn map<U>(this: Self, f: impl FnOnce(T) -> U) -> Spanned<U> {
+        Spanned::new(f(this.value), this.span)
+    }
+
+    /// Borrow the `value`, keeping the `span`
+    ///
+    /// This is an associated function, called as `Spanned::as_ref(&this)`, see [`Spanned::map`].
+    #[inline]
+    pub fn as_ref(this: &Self) -> Spanned<&T> {
+        Spanned::new(&this.value, this.span.clone())
+    }
+
+    /// Discard the `span`
+    ///
+    /// This is an associated function, called as `Spanned::into_inner(this)`, see
+    /// [`Spanned::map`].
+    #[inline]
+    pub fn into_inner(this: Self) -> T {
+        this.value
+    }
+}
+
+impl<T> crate::lib::std::ops::Deref for Spanned<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> crate::lib::std::ops::DerefMut for Spanned<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
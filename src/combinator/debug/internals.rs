@@ -2,137 +2,37 @@
 
 use std::io::Write;
 
-use crate::error::ParserError;
-use crate::stream::Stream;
-use crate::*;
+use super::{TraceEvent, TraceStatus};
 
-pub(crate) struct Trace<P, D, I, O, E>
-where
-    P: Parser<I, O, E>,
-    I: Stream,
-    D: std::fmt::Display,
-    E: ParserError<I>,
-{
-    parser: P,
-    name: D,
-    call_count: usize,
-    i: core::marker::PhantomData<I>,
-    o: core::marker::PhantomData<O>,
-    e: core::marker::PhantomData<E>,
-}
-
-impl<P, D, I, O, E> Trace<P, D, I, O, E>
-where
-    P: Parser<I, O, E>,
-    I: Stream,
-    D: std::fmt::Display,
-    E: ParserError<I>,
-{
-    #[inline(always)]
-    pub(crate) fn new(parser: P, name: D) -> Self {
-        Self {
-            parser,
+/// Render `event` to stderr
+pub(crate) fn write(event: TraceEvent<'_>) {
+    match event {
+        TraceEvent::Enter {
+            depth,
             name,
-            call_count: 0,
-            i: Default::default(),
-            o: Default::default(),
-            e: Default::default(),
-        }
-    }
-}
-
-impl<P, D, I, O, E> Parser<I, O, E> for Trace<P, D, I, O, E>
-where
-    P: Parser<I, O, E>,
-    I: Stream,
-    D: std::fmt::Display,
-    E: ParserError<I>,
-{
-    #[inline]
-    fn parse_next(&mut self, i: &mut I) -> Result<O, E> {
-        let depth = Depth::new();
-        let original = i.checkpoint();
-        start(*depth, &self.name, self.call_count, i);
-
-        let res = self.parser.parse_next(i);
-
-        let consumed = i.offset_from(&original);
-        let severity = Severity::with_result(&res);
-        end(*depth, &self.name, self.call_count, consumed, severity);
-        self.call_count += 1;
-
-        res
-    }
-}
-
-pub(crate) struct Depth {
-    depth: usize,
-    inc: bool,
-}
-
-impl Depth {
-    pub(crate) fn new() -> Self {
-        let depth = DEPTH.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
-        let inc = true;
-        Self { depth, inc }
-    }
-
-    pub(crate) fn existing() -> Self {
-        let depth = DEPTH.load(std::sync::atomic::Ordering::SeqCst);
-        let inc = false;
-        Self { depth, inc }
-    }
-}
-
-impl Drop for Depth {
-    fn drop(&mut self) {
-        if self.inc {
-            let _ = DEPTH.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
-        }
-    }
-}
-
-impl AsRef<usize> for Depth {
-    #[inline(always)]
-    fn as_ref(&self) -> &usize {
-        &self.depth
-    }
-}
-
-impl crate::lib::std::ops::Deref for Depth {
-    type Target = usize;
-
-    #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.depth
-    }
-}
-
-static DEPTH: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
-
-pub(crate) enum Severity {
-    Success,
-    Backtrack,
-    Cut,
-    Incomplete,
-}
-
-impl Severity {
-    pub(crate) fn with_result<T, I: Stream, E: ParserError<I>>(result: &Result<T, E>) -> Self {
-        match result {
-            Ok(_) => Self::Success,
-            Err(e) if e.is_backtrack() => Self::Backtrack,
-            Err(e) if e.is_incomplete() => Self::Incomplete,
-            _ => Self::Cut,
-        }
+            call_count,
+            input,
+        } => start(depth, name, call_count, input),
+        TraceEvent::Exit {
+            depth,
+            name,
+            call_count,
+            consumed,
+            status,
+        } => end(depth, name, call_count, consumed, status),
+        TraceEvent::Result {
+            depth,
+            name,
+            status,
+        } => result(depth, name, status),
     }
 }
 
-pub(crate) fn start<I: Stream>(
+fn start(
     depth: usize,
     name: &dyn crate::lib::std::fmt::Display,
     count: usize,
-    input: &I,
+    input: &dyn crate::lib::std::fmt::Debug,
 ) {
     let gutter_style = anstyle::Style::new().bold();
     let input_style = anstyle::Style::new().underline();
@@ -149,7 +49,7 @@ pub(crate) fn start<I: Stream>(
 
     // The debug version of `slice` might be wider, either due to rendering one byte as two nibbles or
     // escaping in strings.
-    let mut debug_slice = format!("{:#?}", input.raw());
+    let mut debug_slice = format!("{input:#?}");
     let (debug_slice, eof) = if let Some(debug_offset) = debug_slice
         .char_indices()
         .enumerate()
@@ -167,10 +67,7 @@ pub(crate) fn start<I: Stream>(
         (debug_slice, eof)
     };
 
-    let writer = anstream::stderr();
-    let mut writer = writer.lock();
-    let _ = writeln!(
-        writer,
+    emit(format_args!(
         "{call_column:call_width$} {gutter_style}|{gutter_reset} {input_style}{debug_slice}{input_reset}{eof_style}{eof}{eof_reset}",
         gutter_style=gutter_style.render(),
         gutter_reset=gutter_style.render_reset(),
@@ -178,15 +75,15 @@ pub(crate) fn start<I: Stream>(
         input_reset=input_style.render_reset(),
         eof_style=eof_style.render(),
         eof_reset=eof_style.render_reset(),
-    );
+    ));
 }
 
-pub(crate) fn end(
+fn end(
     depth: usize,
     name: &dyn crate::lib::std::fmt::Display,
     count: usize,
     consumed: usize,
-    severity: Severity,
+    status: TraceStatus,
 ) {
     let gutter_style = anstyle::Style::new().bold();
 
@@ -199,74 +96,108 @@ pub(crate) fn end(
     };
     let call_column = format!("{:depth$}< {name}{count}", "");
 
-    let (status_style, status) = match severity {
-        Severity::Success => {
+    let (status_style, status) = match status {
+        TraceStatus::Success => {
             let style = anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Green.into()));
             let status = format!("+{consumed}");
             (style, status)
         }
-        Severity::Backtrack => (
+        TraceStatus::Backtrack => (
             anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Yellow.into())),
             "backtrack".to_owned(),
         ),
-        Severity::Cut => (
+        TraceStatus::Cut => (
             anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Red.into())),
             "cut".to_owned(),
         ),
-        Severity::Incomplete => (
+        TraceStatus::Incomplete => (
             anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Red.into())),
             "incomplete".to_owned(),
         ),
     };
 
-    let writer = anstream::stderr();
-    let mut writer = writer.lock();
-    let _ = writeln!(
-        writer,
+    emit(format_args!(
         "{status_style}{call_column:call_width$}{status_reset} {gutter_style}|{gutter_reset} {status_style}{status}{status_reset}",
         gutter_style=gutter_style.render(),
         gutter_reset=gutter_style.render_reset(),
         status_style=status_style.render(),
         status_reset=status_style.render_reset(),
-    );
+    ));
 }
 
-pub(crate) fn result(depth: usize, name: &dyn crate::lib::std::fmt::Display, severity: Severity) {
+fn result(depth: usize, name: &dyn crate::lib::std::fmt::Display, status: TraceStatus) {
     let gutter_style = anstyle::Style::new().bold();
 
     let (call_width, _) = column_widths();
 
     let call_column = format!("{:depth$}| {name}", "");
 
-    let (status_style, status) = match severity {
-        Severity::Success => (
+    let (status_style, status) = match status {
+        TraceStatus::Success => (
             anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Green.into())),
             "",
         ),
-        Severity::Backtrack => (
+        TraceStatus::Backtrack => (
             anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Yellow.into())),
             "backtrack",
         ),
-        Severity::Cut => (
+        TraceStatus::Cut => (
             anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Red.into())),
             "cut",
         ),
-        Severity::Incomplete => (
+        TraceStatus::Incomplete => (
             anstyle::Style::new().fg_color(Some(anstyle::AnsiColor::Red.into())),
             "incomplete",
         ),
     };
 
-    let writer = anstream::stderr();
-    let mut writer = writer.lock();
-    let _ = writeln!(
-        writer,
+    emit(format_args!(
         "{status_style}{call_column:call_width$}{status_reset} {gutter_style}|{gutter_reset} {status_style}{status}{status_reset}",
         gutter_style=gutter_style.render(),
         gutter_reset=gutter_style.render_reset(),
         status_style=status_style.render(),
         status_reset=status_style.render_reset(),
-    );
+    ));
+}
+
+fn emit(line: std::fmt::Arguments<'_>) {
+    #[cfg(test)]
+    if CAPTURE.with(|capture| {
+        let mut capture = capture.borrow_mut();
+        capture
+            .as_mut()
+            .map(|capture| writeln!(capture, "{line}"))
+            .is_some()
+    }) {
+        return;
+    }
+
+    let writer = anstream::stderr();
+    let mut writer = writer.lock();
+    let _ = writeln!(writer, "{line}");
+}
+
+#[cfg(test)]
+std::thread_local! {
+    static CAPTURE: std::cell::RefCell<Option<Vec<u8>>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Whether [`capture`] is running on the current thread
+#[cfg(test)]
+pub(crate) fn is_capturing() -> bool {
+    CAPTURE.with(|capture| capture.borrow().is_some())
+}
+
+/// Run `f`, returning the trace it writes for the current thread with styling removed
+#[cfg(test)]
+pub(crate) fn capture(f: impl FnOnce()) -> String {
+    CAPTURE.with(|capture| *capture.borrow_mut() = Some(Vec::new()));
+    f();
+    let captured = CAPTURE
+        .with(|capture| capture.borrow_mut().take())
+        .unwrap_or_default();
+    let captured = String::from_utf8(captured).unwrap();
+    anstream::adapter::strip_str(&captured).to_string()
 }
 
 fn column_widths() -> (usize, usize) {
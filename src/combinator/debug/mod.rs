@@ -1,17 +1,32 @@
-#![cfg_attr(feature = "debug", allow(clippy::std_instead_of_core))]
+#![cfg_attr(feature = "unstable-trace-sink", allow(clippy::std_instead_of_core))]
 
 #[cfg(feature = "debug")]
 mod internals;
+#[cfg(feature = "unstable-trace-sink")]
+mod sink;
+
+#[cfg(feature = "unstable-trace-sink")]
+pub use sink::{reset_trace_sink, set_trace_sink, TraceEvent, TraceStatus};
 
 use crate::error::ParserError;
 use crate::stream::Stream;
+#[cfg(feature = "std")]
+use crate::stream::{AsBStr, Location};
 use crate::Parser;
 
 /// Trace the execution of the parser
 ///
 /// Note that [`Parser::context`] also provides high level trace information.
 ///
-/// See [tutorial][crate::_tutorial::chapter_8] for more details.
+/// See also [`Parser::trace`] and the [tutorial][crate::_tutorial::chapter_8] for more details.
+///
+/// To keep the output of deeply recursive grammars manageable, set the `WINNOW_TRACE_DEPTH`
+/// environment variable to the number of nesting levels to show.
+/// It is read the first time a parser is traced.
+///
+/// Traces are written to stderr when the `debug` feature is enabled.  To collect them elsewhere,
+/// like on `no_std` targets, enable the `unstable-trace-sink` feature and see
+/// [`set_trace_sink`][crate::combinator::set_trace_sink].
 ///
 /// # Example
 ///
@@ -34,33 +49,31 @@ use crate::Parser;
 /// assert!(short_alpha.parse_peek(b"ed").is_err());
 /// assert!(short_alpha.parse_peek(b"12345").is_err());
 /// ```
-#[cfg_attr(not(feature = "debug"), allow(unused_variables))]
-#[cfg_attr(not(feature = "debug"), allow(unused_mut))]
-#[cfg_attr(not(feature = "debug"), inline(always))]
+#[cfg_attr(not(feature = "unstable-trace-sink"), allow(unused_variables))]
+#[cfg_attr(not(feature = "unstable-trace-sink"), allow(unused_mut))]
+#[cfg_attr(not(feature = "unstable-trace-sink"), inline(always))]
 pub fn trace<I: Stream, O, E: ParserError<I>>(
     name: impl crate::lib::std::fmt::Display,
     parser: impl Parser<I, O, E>,
 ) -> impl Parser<I, O, E> {
-    #[cfg(feature = "debug")]
+    #[cfg(feature = "unstable-trace-sink")]
     {
-        internals::Trace::new(parser, name)
+        sink::Trace::new(parser, name)
     }
-    #[cfg(not(feature = "debug"))]
+    #[cfg(not(feature = "unstable-trace-sink"))]
     {
         parser
     }
 }
 
-#[cfg_attr(not(feature = "debug"), allow(unused_variables))]
+#[cfg_attr(not(feature = "unstable-trace-sink"), allow(unused_variables))]
 pub(crate) fn trace_result<T, I: Stream, E: ParserError<I>>(
     name: impl crate::lib::std::fmt::Display,
     res: &Result<T, E>,
 ) {
-    #[cfg(feature = "debug")]
+    #[cfg(feature = "unstable-trace-sink")]
     {
-        let depth = internals::Depth::existing();
-        let severity = internals::Severity::with_result(res);
-        internals::result(*depth, &name, severity);
+        sink::result(&name, res);
     }
 }
 
@@ -72,6 +85,189 @@ impl<D: crate::lib::std::fmt::Debug> crate::lib::std::fmt::Display for DisplayDe
     }
 }
 
+/// Print a hex dump of the input when `parser` fails
+///
+/// On error, this writes `label`, the error, and the bytes around where the error happened to
+/// stderr, then returns the error unchanged.  Offsets are relative to where `dbg_dmp` started
+/// parsing, see [`dbg_dmp_located`] for offsets into the whole input.
+///
+/// This is for binary formats where [`trace`] doesn't show the raw bytes.  Any [`AsBStr`] stream
+/// can be dumped, like `&[u8]`, `&Bytes`, and `&str`.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::{be_u16, length_take, u8};
+/// use winnow::combinator::dbg_dmp;
+///
+/// fn record<'i>(input: &mut &'i [u8]) -> ModalResult<(u8, &'i [u8])> {
+///     dbg_dmp((u8, length_take(be_u16)), "record").parse_next(input)
+/// }
+///
+/// // Prints:
+/// // record: Backtrack(ContextError { .. }) at offset 3 (0x3)
+/// // 00000000  01 00 10 61 62 63                                |...abc          |
+/// //                    ^^
+/// assert!(record.parse_peek(&b"\x01\x00\x10abc"[..]).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn dbg_dmp<Input, Output, Error, ParseNext>(
+    mut parser: ParseNext,
+    label: impl crate::lib::std::fmt::Display,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream + AsBStr,
+    Error: ParserError<Input> + crate::lib::std::fmt::Debug,
+    ParseNext: Parser<Input, Output, Error>,
+{
+    trace("dbg_dmp", move |input: &mut Input| {
+        let start = input.checkpoint();
+        parser.parse_next(input).map_err(|err| {
+            print_dump(input, &start, 0, &label, &err);
+            err
+        })
+    })
+}
+
+/// Print a hex dump of the input when `parser` fails, with offsets into the whole input
+///
+/// See [`dbg_dmp`] for more details.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::{be_u16, length_take, u8};
+/// use winnow::combinator::dbg_dmp_located;
+/// use winnow::stream::LocatingSlice;
+///
+/// fn record<'i>(input: &mut LocatingSlice<&'i [u8]>) -> ModalResult<(u8, &'i [u8])> {
+///     dbg_dmp_located((u8, length_take(be_u16)), "record").parse_next(input)
+/// }
+///
+/// let mut input = LocatingSlice::new(&b"\x01\x00\x01a\x01\x00\x10abc"[..]);
+/// assert!(record.parse_next(&mut input).is_ok());
+/// // Prints:
+/// // record: Backtrack(ContextError { .. }) at offset 7 (0x7)
+/// // 00000000              01 00 10 61 62 63                    |    ...abc      |
+/// //                                ^^
+/// assert!(record.parse_next(&mut input).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn dbg_dmp_located<Input, Output, Error, ParseNext>(
+    mut parser: ParseNext,
+    label: impl crate::lib::std::fmt::Display,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream + Location + AsBStr,
+    Error: ParserError<Input> + crate::lib::std::fmt::Debug,
+    ParseNext: Parser<Input, Output, Error>,
+{
+    trace("dbg_dmp_located", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let base = input.current_token_start();
+        parser.parse_next(input).map_err(|err| {
+            print_dump(input, &start, base, &label, &err);
+            err
+        })
+    })
+}
+
+#[cfg(feature = "std")]
+fn print_dump<I: Stream + AsBStr>(
+    input: &mut I,
+    start: &I::Checkpoint,
+    base: usize,
+    label: &dyn crate::lib::std::fmt::Display,
+    err: &dyn crate::lib::std::fmt::Debug,
+) {
+    use std::io::Write as _;
+
+    let dump = format_dump(input, start, base, label, err);
+    let _ = std::io::stderr().write_all(dump.as_bytes());
+}
+
+#[cfg(feature = "std")]
+fn format_dump<I: Stream + AsBStr>(
+    input: &mut I,
+    start: &I::Checkpoint,
+    base: usize,
+    label: &dyn crate::lib::std::fmt::Display,
+    err: &dyn crate::lib::std::fmt::Debug,
+) -> crate::lib::std::string::String {
+    let position = input.offset_from(start);
+    let current = input.checkpoint();
+    input.reset(start);
+    let dump = HexDump {
+        data: input.as_bstr(),
+        base,
+        position,
+    };
+    let offset = base + position;
+    let dump = format!("{label}: {err:?} at offset {offset} ({offset:#x})\n{dump}");
+    input.reset(&current);
+    dump
+}
+
+/// Bytes to show before and after the error position
+#[cfg(feature = "std")]
+const DUMP_CONTEXT: usize = 32;
+#[cfg(feature = "std")]
+const DUMP_WIDTH: usize = 16;
+
+/// Hex and ASCII rows of `data`, marking `position`
+///
+/// `data` starts at `base` in the whole input.
+#[cfg(feature = "std")]
+struct HexDump<'d> {
+    data: &'d [u8],
+    base: usize,
+    position: usize,
+}
+
+#[cfg(feature = "std")]
+impl crate::lib::std::fmt::Display for HexDump<'_> {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        let position = self.position.min(self.data.len());
+        let visible = position.saturating_sub(DUMP_CONTEXT)
+            ..(position.saturating_add(DUMP_CONTEXT)).min(self.data.len());
+        let byte = |offset: usize| {
+            offset
+                .checked_sub(self.base)
+                .filter(|i| visible.contains(i))
+                .map(|i| self.data[i])
+        };
+
+        let mut row = (self.base + visible.start) / DUMP_WIDTH * DUMP_WIDTH;
+        while row < self.base + visible.end || row <= self.base + position {
+            write!(f, "{row:08x} ")?;
+            for offset in row..row + DUMP_WIDTH {
+                match byte(offset) {
+                    Some(b) => write!(f, " {b:02x}")?,
+                    None => write!(f, "   ")?,
+                }
+            }
+            write!(f, "  |")?;
+            for offset in row..row + DUMP_WIDTH {
+                let c = match byte(offset) {
+                    Some(b) if b.is_ascii_graphic() || b == b' ' => b as char,
+                    Some(_) => '.',
+                    None => ' ',
+                };
+                write!(f, "{c}")?;
+            }
+            writeln!(f, "|")?;
+            if (row..row + DUMP_WIDTH).contains(&(self.base + position)) {
+                let column = self.base + position - row;
+                writeln!(f, "{:width$}^^", "", width = 10 + 3 * column)?;
+            }
+            row += DUMP_WIDTH;
+        }
+        Ok(())
+    }
+}
+
 #[test]
 #[cfg(feature = "std")]
 #[cfg_attr(miri, ignore)]
@@ -94,3 +290,240 @@ fn example() {
     )
     .test("assets/trace.svg", [format!(r#"{cmd} '"abc"'"#).as_str()]);
 }
+
+#[test]
+#[cfg(feature = "debug")]
+fn custom_name() {
+    use crate::ascii::alpha1;
+    use crate::error::ContextError;
+    use crate::error::ErrMode;
+
+    fn tag<'i>(expected: &'static str) -> impl Parser<&'i str, &'i str, ErrMode<ContextError>> {
+        move |input: &mut &'i str| {
+            alpha1
+                .verify(move |s: &str| s == expected)
+                .trace(format_args!("tag({expected:?})"))
+                .parse_next(input)
+        }
+    }
+
+    let trace = internals::capture(|| {
+        let _ = tag("hello").parse_peek("hello");
+    });
+    let trace = trace
+        .lines()
+        .filter(|line| line.contains("tag("))
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>()
+        .join("\n");
+    snapbox::assert_data_eq!(
+        trace,
+        snapbox::str![[r#"
+> tag("hello") | "hello"∅
+< tag("hello") | +5
+"#]]
+    );
+}
+
+#[test]
+#[cfg(feature = "debug")]
+fn depth_limit() {
+    use crate::combinator::delimited;
+    use crate::error::ContextError;
+    use crate::error::ErrMode;
+
+    fn nested(input: &mut &str) -> Result<(), ErrMode<ContextError>> {
+        delimited('(', crate::combinator::opt(nested), ')')
+            .void()
+            .trace("nested")
+            .parse_next(input)
+    }
+
+    let depth = |trace: String| {
+        trace
+            .lines()
+            .filter(|line| line.contains("> nested"))
+            .count()
+    };
+
+    let trace = internals::capture(|| {
+        let _ = nested.parse_peek("((()))");
+    });
+    assert_eq!(depth(trace), 4);
+
+    // `WINNOW_TRACE_DEPTH` is read once per process, so it is overridden for this thread instead
+    let trace = sink::with_max_depth(4, || {
+        internals::capture(|| {
+            let _ = nested.parse_peek("((()))");
+        })
+    });
+    assert_eq!(depth(trace), 2);
+}
+
+#[test]
+#[cfg(feature = "debug")]
+fn complete_err_conversion() {
+    use crate::error::ContextError;
+    use crate::error::ErrMode;
+    use crate::error::StrContext;
+    use crate::stream::Partial;
+    use crate::token::take;
+
+    fn trace(
+        mut parser: impl Parser<Partial<&'static str>, &'static str, ErrMode<ContextError>>,
+    ) -> String {
+        let trace = internals::capture(|| {
+            let _ = parser.parse_peek(Partial::new("abc"));
+        });
+        trace
+            .lines()
+            .filter(|line| line.contains("->"))
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    snapbox::assert_data_eq!(
+        trace(take(5usize).complete_err()),
+        snapbox::str![[r#"
+| incomplete -> backtrack | backtrack
+"#]]
+    );
+    snapbox::assert_data_eq!(
+        trace(take(5usize).expect_complete(StrContext::Label("checksum"))),
+        snapbox::str![[r#"
+| incomplete -> backtrack | backtrack
+"#]]
+    );
+    snapbox::assert_data_eq!(
+        trace(take(2usize).complete_err()),
+        snapbox::str![[r#"
+"#]]
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable-trace-sink")]
+fn custom_sink() {
+    use crate::combinator::alt;
+    use crate::error::ContextError;
+    use crate::error::ErrMode;
+
+    std::thread_local! {
+        static EVENTS: core::cell::RefCell<Option<Vec<String>>> = const { core::cell::RefCell::new(None) };
+    }
+
+    // Only record this thread's events so parallel tests don't show up
+    fn collect(event: TraceEvent<'_>) {
+        let event = match event {
+            TraceEvent::Enter {
+                name,
+                call_count,
+                input,
+                ..
+            } => format!("> {name}:{call_count} {input:?}"),
+            TraceEvent::Exit {
+                name,
+                consumed,
+                status,
+                ..
+            } => format!("< {name} {status:?} +{consumed}"),
+            TraceEvent::Result { name, status, .. } => format!("| {name} {status:?}"),
+        };
+        EVENTS.with(|events| {
+            if let Some(events) = events.borrow_mut().as_mut() {
+                events.push(event);
+            }
+        });
+    }
+
+    fn keyword<'i>(input: &mut &'i str) -> Result<&'i str, ErrMode<ContextError>> {
+        alt(("let".trace("let"), "fn".trace("fn")))
+            .trace("keyword")
+            .parse_next(input)
+    }
+
+    EVENTS.with(|events| *events.borrow_mut() = Some(Vec::new()));
+    set_trace_sink(collect);
+    let _ = keyword.parse_peek("fn main");
+    reset_trace_sink();
+    let events = EVENTS.with(|events| events.borrow_mut().take().unwrap());
+
+    snapbox::assert_data_eq!(
+        events.join("\n"),
+        snapbox::str![[r#"
+> keyword:0 "fn main"
+> alt:0 "fn main"
+> let:0 "fn main"
+> "let":0 "fn main"
+< "let" Backtrack +0
+< let Backtrack +0
+> fn:0 "fn main"
+> "fn":0 "fn main"
+< "fn" Success +2
+< fn Success +2
+< alt Success +2
+< keyword Success +2
+"#]]
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn dump_format() {
+    use crate::binary::{be_u16, length_take, u8};
+    use crate::error::ErrMode;
+    use crate::token::literal;
+
+    fn dump(mut input: &[u8], base: usize) -> String {
+        let start = input.checkpoint();
+        let err = (u8::<_, ErrMode<()>>, length_take(be_u16), literal(b"\n"))
+            .parse_next(&mut input)
+            .unwrap_err();
+        format_dump(&mut input, &start, base, &"record", &err)
+    }
+
+    snapbox::assert_data_eq!(
+        dump(b"\x01\x00\x10abc", 0),
+        snapbox::str![[r#"
+record: Backtrack(()) at offset 3 (0x3)
+00000000  01 00 10 61 62 63                                |...abc          |
+                   ^^
+
+"#]]
+    );
+    snapbox::assert_data_eq!(
+        dump(b"\x01\x00\x03abc\r\n", 0),
+        snapbox::str![[r#"
+record: Backtrack(()) at offset 6 (0x6)
+00000000  01 00 03 61 62 63 0d 0a                          |...abc..        |
+                            ^^
+
+"#]]
+    );
+    snapbox::assert_data_eq!(
+        dump(b"\x01\x00\x03abc", 0x2e),
+        snapbox::str![[r#"
+record: Backtrack(()) at offset 52 (0x34)
+00000020                                            01 00  |              ..|
+00000030  03 61 62 63                                      |.abc            |
+                      ^^
+
+"#]]
+    );
+
+    let mut long = b"\x01\x00\x50".to_vec();
+    long.extend((0..0x50).map(|i| b'a' + i % 26));
+    long.extend(b"\r\n");
+    snapbox::assert_data_eq!(
+        dump(&long, 0),
+        snapbox::str![[r#"
+record: Backtrack(()) at offset 83 (0x53)
+00000030           77 78 79 7a 61 62 63 64 65 66 67 68 69  |   wxyzabcdefghi|
+00000040  6a 6b 6c 6d 6e 6f 70 71 72 73 74 75 76 77 78 79  |jklmnopqrstuvwxy|
+00000050  7a 61 62 0d 0a                                   |zab..           |
+                   ^^
+
+"#]]
+    );
+}
@@ -0,0 +1,362 @@
+use ::core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::error::ParserError;
+use crate::lib::std::fmt::{Debug, Display};
+use crate::stream::Stream;
+use crate::Parser;
+
+pub(crate) struct Trace<P, D, I, O, E>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    D: Display,
+    E: ParserError<I>,
+{
+    parser: P,
+    name: D,
+    call_count: usize,
+    i: ::core::marker::PhantomData<I>,
+    o: ::core::marker::PhantomData<O>,
+    e: ::core::marker::PhantomData<E>,
+}
+
+impl<P, D, I, O, E> Trace<P, D, I, O, E>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    D: Display,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    pub(crate) fn new(parser: P, name: D) -> Self {
+        Self {
+            parser,
+            name,
+            call_count: 0,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+}
+
+impl<P, D, I, O, E> Parser<I, O, E> for Trace<P, D, I, O, E>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    D: Display,
+    E: ParserError<I>,
+{
+    #[inline]
+    fn parse_next(&mut self, i: &mut I) -> Result<O, E> {
+        let depth = Depth::new();
+        let visible = is_visible(*depth);
+        let original = i.checkpoint();
+        if visible {
+            emit(TraceEvent::Enter {
+                depth: *depth,
+                name: &self.name,
+                call_count: self.call_count,
+                input: i.raw(),
+            });
+        }
+
+        let res = self.parser.parse_next(i);
+
+        if visible {
+            emit(TraceEvent::Exit {
+                depth: *depth,
+                name: &self.name,
+                call_count: self.call_count,
+                consumed: i.offset_from(&original),
+                status: TraceStatus::with_result(&res),
+            });
+        }
+        self.call_count += 1;
+
+        res
+    }
+}
+
+pub(crate) fn result<T, I: Stream, E: ParserError<I>>(name: &dyn Display, res: &Result<T, E>) {
+    let depth = Depth::existing();
+    if is_visible(*depth) {
+        emit(TraceEvent::Result {
+            depth: *depth,
+            name,
+            status: TraceStatus::with_result(res),
+        });
+    }
+}
+
+/// A step in the execution of a parser, reported to the [trace sink][set_trace_sink]
+///
+/// Events borrow from the parser and its input, so a sink must render or copy out anything it
+/// wants to keep.
+#[derive(Copy, Clone)]
+#[non_exhaustive]
+pub enum TraceEvent<'a> {
+    /// A [`trace`][super::trace]d parser is starting
+    Enter {
+        /// How many traced parsers this is nested within
+        depth: usize,
+        /// The name given to [`trace`][super::trace]
+        name: &'a dyn Display,
+        /// How many times this parser was called before
+        call_count: usize,
+        /// The remaining input, see [`Stream::raw`]
+        input: &'a dyn Debug,
+    },
+    /// A [`trace`][super::trace]d parser finished
+    Exit {
+        /// How many traced parsers this is nested within
+        depth: usize,
+        /// The name given to [`trace`][super::trace]
+        name: &'a dyn Display,
+        /// How many times this parser was called before
+        call_count: usize,
+        /// How many tokens were consumed, see [`Offset`][crate::stream::Offset]
+        consumed: usize,
+        /// How the parser finished
+        status: TraceStatus,
+    },
+    /// A step within a parser, like [`Parser::verify`] rejecting the output
+    Result {
+        /// How many traced parsers this is nested within
+        depth: usize,
+        /// A description of the step
+        name: &'a dyn Display,
+        /// How the step finished
+        status: TraceStatus,
+    },
+}
+
+/// How a traced parser finished, see [`TraceEvent`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TraceStatus {
+    /// The parser succeeded
+    Success,
+    /// See [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack]
+    Backtrack,
+    /// See [`ErrMode::Cut`][crate::error::ErrMode::Cut]
+    Cut,
+    /// See [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete]
+    Incomplete,
+}
+
+impl TraceStatus {
+    pub(crate) fn with_result<T, I: Stream, E: ParserError<I>>(result: &Result<T, E>) -> Self {
+        match result {
+            Ok(_) => Self::Success,
+            Err(e) if e.is_backtrack() => Self::Backtrack,
+            Err(e) if e.is_incomplete() => Self::Incomplete,
+            _ => Self::Cut,
+        }
+    }
+}
+
+/// Send [`trace`][super::trace] events to `sink`
+///
+/// This replaces the default sink, which writes to stderr when the `debug` feature is enabled and
+/// drops events otherwise.  This allows tracing parsers on `no_std` targets, like through `defmt`
+/// or RTT.
+///
+/// The sink is shared by all threads.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::combinator::{reset_trace_sink, set_trace_sink, trace, TraceEvent};
+///
+/// fn log(event: TraceEvent<'_>) {
+///     if let TraceEvent::Enter { depth, name, .. } = event {
+///         println!("{:depth$}{name}", "");
+///     }
+/// }
+///
+/// fn abc<'i>(input: &mut &'i str) -> ModalResult<&'i str> {
+///     trace("abc", "abc").parse_next(input)
+/// }
+///
+/// set_trace_sink(log);
+/// let _ = abc.parse_peek("abcdef");
+/// reset_trace_sink();
+/// ```
+pub fn set_trace_sink(sink: fn(TraceEvent<'_>)) {
+    SINK.store(sink as *mut (), Ordering::Release);
+}
+
+/// Restore the default trace sink, see [`set_trace_sink`]
+pub fn reset_trace_sink() {
+    SINK.store(::core::ptr::null_mut(), Ordering::Release);
+}
+
+static SINK: AtomicPtr<()> = AtomicPtr::new(::core::ptr::null_mut());
+
+fn emit(event: TraceEvent<'_>) {
+    #[cfg(all(test, feature = "debug"))]
+    if super::internals::is_capturing() {
+        super::internals::write(event);
+        return;
+    }
+
+    let sink = SINK.load(Ordering::Acquire);
+    if sink.is_null() {
+        #[cfg(feature = "debug")]
+        super::internals::write(event);
+    } else {
+        // SAFETY: `SINK` is only ever set from a `fn(TraceEvent<'_>)`
+        let sink = unsafe { crate::lib::std::mem::transmute::<*mut (), fn(TraceEvent<'_>)>(sink) };
+        sink(event);
+    }
+}
+
+pub(crate) struct Depth {
+    depth: usize,
+    inc: bool,
+}
+
+impl Depth {
+    pub(crate) fn new() -> Self {
+        let depth = depth::increment();
+        let inc = true;
+        Self { depth, inc }
+    }
+
+    pub(crate) fn existing() -> Self {
+        let depth = depth::get();
+        let inc = false;
+        Self { depth, inc }
+    }
+}
+
+impl Drop for Depth {
+    fn drop(&mut self) {
+        if self.inc {
+            depth::decrement();
+        }
+    }
+}
+
+impl AsRef<usize> for Depth {
+    #[inline(always)]
+    fn as_ref(&self) -> &usize {
+        &self.depth
+    }
+}
+
+impl crate::lib::std::ops::Deref for Depth {
+    type Target = usize;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.depth
+    }
+}
+
+#[cfg(feature = "std")]
+mod depth {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static DEPTH: AtomicUsize = AtomicUsize::new(0);
+
+    pub(super) fn increment() -> usize {
+        DEPTH.fetch_add(1, Ordering::SeqCst)
+    }
+
+    pub(super) fn decrement() {
+        let _ = DEPTH.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    pub(super) fn get() -> usize {
+        DEPTH.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod depth {
+    use ::core::sync::atomic::{AtomicUsize, Ordering};
+
+    // Plain loads and stores are used as not every target has atomic read-modify-write
+    // operations.
+    static DEPTH: AtomicUsize = AtomicUsize::new(0);
+
+    pub(super) fn increment() -> usize {
+        let depth = DEPTH.load(Ordering::Relaxed);
+        DEPTH.store(depth + 1, Ordering::Relaxed);
+        depth
+    }
+
+    pub(super) fn decrement() {
+        let depth = DEPTH.load(Ordering::Relaxed);
+        DEPTH.store(depth - 1, Ordering::Relaxed);
+    }
+
+    pub(super) fn get() -> usize {
+        DEPTH.load(Ordering::Relaxed)
+    }
+}
+
+/// Whether a trace at `depth` is within `WINNOW_TRACE_DEPTH`
+fn is_visible(depth: usize) -> bool {
+    #[cfg(feature = "std")]
+    {
+        #[cfg(test)]
+        if let Some(max) = MAX_DEPTH_OVERRIDE.with(|max| max.get()) {
+            return depth < max;
+        }
+        depth < max_depth_env()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = depth;
+        true
+    }
+}
+
+/// `WINNOW_TRACE_DEPTH`, read on first use as this is checked for every traced call
+#[cfg(feature = "std")]
+fn max_depth_env() -> usize {
+    use std::sync::atomic::AtomicUsize;
+
+    static READ: std::sync::Once = std::sync::Once::new();
+    static MAX_DEPTH: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+    READ.call_once(|| {
+        let max = parse_max_depth(std::env::var("WINNOW_TRACE_DEPTH").ok().as_deref());
+        MAX_DEPTH.store(max, Ordering::Relaxed);
+    });
+    MAX_DEPTH.load(Ordering::Relaxed)
+}
+
+#[cfg(all(test, feature = "std"))]
+std::thread_local! {
+    static MAX_DEPTH_OVERRIDE: std::cell::Cell<Option<usize>> = const { std::cell::Cell::new(None) };
+}
+
+/// Run `f` as if `WINNOW_TRACE_DEPTH` was `max` for the current thread
+#[cfg(all(test, feature = "std"))]
+pub(crate) fn with_max_depth<R>(max: usize, f: impl FnOnce() -> R) -> R {
+    MAX_DEPTH_OVERRIDE.with(|max_depth| max_depth.set(Some(max)));
+    let res = f();
+    MAX_DEPTH_OVERRIDE.with(|max_depth| max_depth.set(None));
+    res
+}
+
+/// Unset or invalid values don't limit the depth
+#[cfg(feature = "std")]
+fn parse_max_depth(value: Option<&str>) -> usize {
+    value
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(usize::MAX)
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn max_depth() {
+    assert_eq!(parse_max_depth(None), usize::MAX);
+    assert_eq!(parse_max_depth(Some("0")), 0);
+    assert_eq!(parse_max_depth(Some("1")), 1);
+    assert_eq!(parse_max_depth(Some("deep")), usize::MAX);
+}
@@ -3,6 +3,9 @@
 use crate::combinator::trace;
 use crate::combinator::trace_result;
 use crate::combinator::DisplayDebug;
+#[cfg(feature = "std")]
+use crate::error::BoxedError;
+use crate::error::ErrMode;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::error::FromRecoverableError;
@@ -32,6 +35,43 @@ where
     fn parse_next(&mut self, i: &mut I) -> Result<O, E> {
         self.p.parse_next(i)
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.p.may_match_empty()
+    }
+}
+
+/// [`Iterator`] implementation for [`Parser::parse_iter`]
+pub struct ParseIter<P, It, I, O, E> {
+    pub(crate) p: P,
+    pub(crate) inputs: It,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o: core::marker::PhantomData<O>,
+    pub(crate) e: core::marker::PhantomData<E>,
+}
+
+impl<P, It, I, O, E> Iterator for ParseIter<P, It, I, O, E>
+where
+    P: Parser<I, O, E>,
+    It: Iterator<Item = I>,
+    I: Stream,
+    I: StreamIsPartial,
+    E: ParserError<I>,
+    <E as ParserError<I>>::Inner: ParserError<I>,
+{
+    type Item = Result<O, crate::error::ParseError<I, <E as ParserError<I>>::Inner>>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = self.inputs.next()?;
+        Some(self.p.parse(input))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inputs.size_hint()
+    }
 }
 
 /// [`Parser`] implementation for [`Parser::map`]
@@ -60,6 +100,11 @@ where
             Ok(o) => Ok((self.map)(o)),
         }
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
 }
 
 /// [`Parser`] implementation for [`Parser::try_map`]
@@ -101,6 +146,90 @@ where
     }
 }
 
+/// [`Parser`] implementation for [`Parser::try_map_boxed`]
+#[cfg(feature = "std")]
+pub struct TryMapBoxed<F, G, I, O, O2, E, E2>
+where
+    F: Parser<I, O, E>,
+    G: FnMut(O) -> Result<O2, E2>,
+    E2: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    I: Stream,
+    E: FromExternalError<I, BoxedError>,
+    E: ParserError<I>,
+{
+    pub(crate) parser: F,
+    pub(crate) map: G,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o: core::marker::PhantomData<O>,
+    pub(crate) o2: core::marker::PhantomData<O2>,
+    pub(crate) e: core::marker::PhantomData<E>,
+    pub(crate) e2: core::marker::PhantomData<E2>,
+}
+
+#[cfg(feature = "std")]
+impl<F, G, I, O, O2, E, E2> Parser<I, O2, E> for TryMapBoxed<F, G, I, O, O2, E, E2>
+where
+    F: Parser<I, O, E>,
+    G: FnMut(O) -> Result<O2, E2>,
+    E2: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    I: Stream,
+    E: FromExternalError<I, BoxedError>,
+    E: ParserError<I>,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<O2, E> {
+        let start = input.checkpoint();
+        let o = self.parser.parse_next(input)?;
+        let res = (self.map)(o).map_err(|err| {
+            input.reset(&start);
+            E::from_external_error(input, BoxedError::new(err))
+        });
+        trace_result("verify", &res);
+        res
+    }
+}
+
+/// [`Parser`] implementation for [`Parser::try_map_with_offset`]
+pub struct TryMapWithOffset<F, G, I, O, O2, E, E2>
+where
+    F: Parser<I, O, E>,
+    G: FnMut(O) -> Result<O2, (usize, E2)>,
+    I: Stream,
+    E: FromExternalError<I, E2>,
+    E: ParserError<I>,
+{
+    pub(crate) parser: F,
+    pub(crate) map: G,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o: core::marker::PhantomData<O>,
+    pub(crate) o2: core::marker::PhantomData<O2>,
+    pub(crate) e: core::marker::PhantomData<E>,
+    pub(crate) e2: core::marker::PhantomData<E2>,
+}
+
+impl<F, G, I, O, O2, E, E2> Parser<I, O2, E> for TryMapWithOffset<F, G, I, O, O2, E, E2>
+where
+    F: Parser<I, O, E>,
+    G: FnMut(O) -> Result<O2, (usize, E2)>,
+    I: Stream,
+    E: FromExternalError<I, E2>,
+    E: ParserError<I>,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<O2, E> {
+        let start = input.checkpoint();
+        let o = self.parser.parse_next(input)?;
+        let res = (self.map)(o).map_err(|(offset, err)| {
+            let consumed = input.offset_from(&start);
+            input.reset(&start);
+            let _ = input.next_slice(offset.min(consumed));
+            E::from_external_error(input, err)
+        });
+        trace_result("verify", &res);
+        res
+    }
+}
+
 /// [`Parser`] implementation for [`Parser::verify_map`]
 pub struct VerifyMap<F, G, I, O, O2, E>
 where
@@ -137,6 +266,45 @@ where
     }
 }
 
+/// [`Parser`] implementation for [`Parser::verify_map_taken`]
+pub struct VerifyMapTaken<F, G, I, O, O2, E>
+where
+    F: Parser<I, O, E>,
+    G: FnMut(O, &<I as Stream>::Slice) -> Option<O2>,
+    I: Stream,
+    E: ParserError<I>,
+{
+    pub(crate) parser: F,
+    pub(crate) map: G,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o: core::marker::PhantomData<O>,
+    pub(crate) o2: core::marker::PhantomData<O2>,
+    pub(crate) e: core::marker::PhantomData<E>,
+}
+
+impl<F, G, I, O, O2, E> Parser<I, O2, E> for VerifyMapTaken<F, G, I, O, O2, E>
+where
+    F: Parser<I, O, E>,
+    G: FnMut(O, &<I as Stream>::Slice) -> Option<O2>,
+    I: Stream,
+    E: ParserError<I>,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<O2, E> {
+        let start = input.checkpoint();
+        let o = self.parser.parse_next(input)?;
+        let offset = input.offset_from(&start);
+        input.reset(&start);
+        let taken = input.next_slice(offset);
+        let res = (self.map)(o, &taken).ok_or_else(|| {
+            input.reset(&start);
+            ParserError::from_input(input)
+        });
+        trace_result("verify", &res);
+        res
+    }
+}
+
 /// [`Parser`] implementation for [`Parser::and_then`]
 pub struct AndThen<F, G, I, O, O2, E>
 where
@@ -173,6 +341,65 @@ where
     }
 }
 
+/// [`Parser`] implementation for [`Parser::and_then_str`]
+pub struct AndThenStr<F, G, I, O2, E>
+where
+    F: Parser<I, <I as Stream>::Slice, E>,
+    I: Stream,
+{
+    pub(crate) outer: F,
+    pub(crate) inner: G,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o2: core::marker::PhantomData<O2>,
+    pub(crate) e: core::marker::PhantomData<E>,
+}
+
+impl<'i, F, G, I, O2, E> Parser<I, O2, E> for AndThenStr<F, G, I, O2, E>
+where
+    F: Parser<I, &'i [u8], E>,
+    G: Parser<&'i str, O2, E>,
+    I: Stream<Slice = &'i [u8]>,
+    E: FromExternalError<I, core::str::Utf8Error>,
+{
+    #[inline]
+    fn parse_next(&mut self, i: &mut I) -> Result<O2, E> {
+        let start = i.checkpoint();
+        let bytes = self.outer.parse_next(i)?;
+        match core::str::from_utf8(bytes) {
+            Ok(s) => {
+                let mut inner = s;
+                self.inner.parse_next(&mut inner).map_err(|err| {
+                    seek_in_slice(i, &start, bytes, s.len() - inner.len());
+                    err
+                })
+            }
+            Err(err) => {
+                seek_in_slice(i, &start, bytes, err.valid_up_to());
+                Err(E::from_external_error(i, err))
+            }
+        }
+    }
+}
+
+/// Move `input` to `offset` within `slice`, a slice of `input` parsed since `start`
+fn seek_in_slice<'i, I>(input: &mut I, start: &I::Checkpoint, slice: &[u8], offset: usize)
+where
+    I: Stream<Slice = &'i [u8]>,
+{
+    let consumed = input.offset_from(start);
+    input.reset(start);
+    let rest = input.peek_slice(input.eof_offset());
+    let rest_start = rest.as_ptr() as usize;
+    let slice_start = slice.as_ptr() as usize;
+    let base = if rest_start <= slice_start && slice_start <= rest_start + rest.len() {
+        slice_start - rest_start
+    } else {
+        // Not borrowed from `input`, assume it ends where parsing stopped
+        consumed.saturating_sub(slice.len())
+    };
+    let _ = input.next_slice(base + offset);
+}
+
 /// [`Parser`] implementation for [`Parser::parse_to`]
 pub struct ParseTo<P, I, O, O2, E>
 where
@@ -256,7 +483,62 @@ where
         trace("complete_err", |input: &mut I| {
             match (self.p).parse_next(input) {
                 Err(err) => match err.needed() {
-                    Some(_) => Err(ParserError::from_input(input)),
+                    Some(_) => {
+                        let res = Err(ParserError::from_input(input));
+                        trace_result("incomplete -> backtrack", &res);
+                        res
+                    }
+                    None => Err(err),
+                },
+                rest => rest,
+            }
+        })
+        .parse_next(input)
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.p.may_match_empty()
+    }
+}
+
+/// [`Parser`] implementation for [`Parser::expect_complete`]
+pub struct ExpectComplete<P, I, O, E, C>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    E: AddContext<I, C>,
+    E: ParserError<I>,
+    C: Clone + crate::lib::std::fmt::Debug,
+{
+    pub(crate) p: P,
+    pub(crate) context: C,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o: core::marker::PhantomData<O>,
+    pub(crate) e: core::marker::PhantomData<E>,
+}
+
+impl<P, I, O, E, C> Parser<I, O, E> for ExpectComplete<P, I, O, E, C>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    E: AddContext<I, C>,
+    E: ParserError<I>,
+    C: Clone + crate::lib::std::fmt::Debug,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        trace("expect_complete", |input: &mut I| {
+            let start = input.checkpoint();
+            match (self.p).parse_next(input) {
+                Err(err) => match err.needed() {
+                    Some(_) => {
+                        let err =
+                            E::from_input(input).add_context(input, &start, self.context.clone());
+                        let res = Err(err);
+                        trace_result("incomplete -> backtrack", &res);
+                        res
+                    }
                     None => Err(err),
                 },
                 rest => rest,
@@ -328,6 +610,41 @@ where
     fn parse_next(&mut self, input: &mut I) -> Result<O2, E> {
         (self.parser).parse_next(input).map(|_| self.val.clone())
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
+}
+
+/// [`Parser`] implementation for [`Parser::value_with`]
+pub struct ValueWith<F, G, I, O, O2, E>
+where
+    F: Parser<I, O, E>,
+    G: FnMut() -> O2,
+{
+    pub(crate) parser: F,
+    pub(crate) f: G,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o: core::marker::PhantomData<O>,
+    pub(crate) o2: core::marker::PhantomData<O2>,
+    pub(crate) e: core::marker::PhantomData<E>,
+}
+
+impl<F, G, I, O, O2, E> Parser<I, O2, E> for ValueWith<F, G, I, O, O2, E>
+where
+    F: Parser<I, O, E>,
+    G: FnMut() -> O2,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<O2, E> {
+        (self.parser).parse_next(input).map(|_| (self.f)())
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
 }
 
 /// [`Parser`] implementation for [`Parser::default_value`]
@@ -352,6 +669,11 @@ where
     fn parse_next(&mut self, input: &mut I) -> Result<O2, E> {
         (self.parser).parse_next(input).map(|_| O2::default())
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
 }
 
 /// [`Parser`] implementation for [`Parser::void`]
@@ -373,6 +695,11 @@ where
     fn parse_next(&mut self, input: &mut I) -> Result<(), E> {
         (self.parser).parse_next(input).map(|_| ())
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
 }
 
 /// [`Parser`] implementation for [`Parser::take`]
@@ -405,6 +732,11 @@ where
             Err(e) => Err(e),
         }
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
 }
 
 /// [`Parser`] implementation for [`Parser::with_taken`]
@@ -437,6 +769,11 @@ where
             Err(e) => Err(e),
         }
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
 }
 
 /// [`Parser`] implementation for [`Parser::span`]
@@ -464,6 +801,11 @@ where
             start..end
         })
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
 }
 
 /// [`Parser`] implementation for [`Parser::with_span`]
@@ -491,6 +833,38 @@ where
             (output, (start..end))
         })
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
+}
+
+/// [`Parser`] implementation for [`Parser::consumed_span`]
+pub struct ConsumedSpan<F, I, O, E>
+where
+    F: Parser<I, O, E>,
+    I: Stream,
+{
+    pub(crate) parser: F,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o: core::marker::PhantomData<O>,
+    pub(crate) e: core::marker::PhantomData<E>,
+}
+
+impl<F, I, O, E> Parser<I, (O, Range<usize>), E> for ConsumedSpan<F, I, O, E>
+where
+    F: Parser<I, O, E>,
+    I: Stream,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<(O, Range<usize>), E> {
+        let start = input.checkpoint();
+        self.parser.parse_next(input).map(move |output| {
+            let end = input.offset_from(&start);
+            (output, (0..end))
+        })
+    }
 }
 
 /// [`Parser`] implementation for [`Parser::output_into`]
@@ -515,6 +889,11 @@ where
     fn parse_next(&mut self, i: &mut I) -> Result<O2, E> {
         self.parser.parse_next(i).map(|o| o.into())
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
 }
 
 /// [`Parser`] implementation for [`Parser::err_into`]
@@ -539,6 +918,44 @@ where
     fn parse_next(&mut self, i: &mut I) -> Result<O, E2> {
         self.parser.parse_next(i).map_err(|err| err.into())
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
+}
+
+/// [`Parser`] implementation for [`Parser::modal_err_into`]
+pub struct ModalErrInto<F, I, O, E, E2>
+where
+    F: Parser<I, O, E>,
+{
+    pub(crate) parser: F,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o: core::marker::PhantomData<O>,
+    pub(crate) e: core::marker::PhantomData<E>,
+    pub(crate) e2: core::marker::PhantomData<E2>,
+}
+
+impl<F, I, O, E, E2> Parser<I, O, ErrMode<E2>> for ModalErrInto<F, I, O, ErrMode<E>, E2>
+where
+    F: Parser<I, O, ErrMode<E>>,
+    I: Stream,
+    E: Into<E2>,
+    E2: ParserError<I>,
+{
+    #[inline]
+    fn parse_next(&mut self, i: &mut I) -> Result<O, ErrMode<E2>> {
+        trace("modal_err_into", |i: &mut I| {
+            self.parser.parse_next(i).map_err(|err| err.map(Into::into))
+        })
+        .parse_next(i)
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
 }
 
 /// [`Parser`] implementation for [`Parser::context`]
@@ -576,6 +993,88 @@ where
         })
         .parse_next(i)
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
+}
+
+/// [`Parser`] implementation for [`Parser::trace`]
+pub struct Trace<F, D, I, O, E>
+where
+    F: Parser<I, O, E>,
+    D: crate::lib::std::fmt::Display,
+    I: Stream,
+    E: ParserError<I>,
+{
+    pub(crate) parser: F,
+    pub(crate) name: D,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o: core::marker::PhantomData<O>,
+    pub(crate) e: core::marker::PhantomData<E>,
+}
+
+impl<F, D, I, O, E> Parser<I, O, E> for Trace<F, D, I, O, E>
+where
+    F: Parser<I, O, E>,
+    D: crate::lib::std::fmt::Display,
+    I: Stream,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, i: &mut I) -> Result<O, E> {
+        trace(&self.name, |i: &mut I| self.parser.parse_next(i)).parse_next(i)
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        self.parser.may_match_empty()
+    }
+}
+
+/// [`Parser`] implementation for built-in parsers that know whether they
+/// [may match empty input][Parser::may_match_empty]
+pub(crate) struct EmptyHint<F, I, O, E>
+where
+    F: Parser<I, O, E>,
+{
+    pub(crate) parser: F,
+    pub(crate) may_match_empty: bool,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o: core::marker::PhantomData<O>,
+    pub(crate) e: core::marker::PhantomData<E>,
+}
+
+impl<F, I, O, E> EmptyHint<F, I, O, E>
+where
+    F: Parser<I, O, E>,
+{
+    #[inline(always)]
+    pub(crate) fn new(parser: F, may_match_empty: bool) -> Self {
+        Self {
+            parser,
+            may_match_empty,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+}
+
+impl<F, I, O, E> Parser<I, O, E> for EmptyHint<F, I, O, E>
+where
+    F: Parser<I, O, E>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, i: &mut I) -> Result<O, E> {
+        self.parser.parse_next(i)
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        Some(self.may_match_empty)
+    }
 }
 
 /// [`Parser`] implementation for [`Parser::retry_after`]
@@ -12,13 +12,17 @@
 //!
 //! | combinator | usage | input | new input | output | comment |
 //! |---|---|---|---|---|---|
-//! | [`one_of`][crate::token::one_of] | `one_of(['a', 'b', 'c'])` |  `"abc"` |  `"bc"` | `Ok('a')` |Matches one of the provided [set of tokens][crate::stream::ContainsToken] (works with non ASCII characters too)|
+//! | [`one_of`][crate::token::one_of] | `one_of(['a', 'b', 'c'])` |  `"abc"` |  `"bc"` | `Ok('a')` |Matches one of the provided [set of tokens][crate::stream::ContainsToken] (works with non ASCII characters too; arrays like `['a', 'b']` can be used directly as parsers)|
 //! | [`none_of`][crate::token::none_of] | `none_of(['a', 'b', 'c'])` |  `"xyab"` |  `"yab"` | `Ok('x')` |Matches anything but one of the provided [set of tokens][crate::stream::ContainsToken]|
 //! | [`literal`][crate::token::literal] | `"hello"` |  `"hello world"` |  `" world"` | `Ok("hello")` |Recognizes a specific suite of characters or bytes (see also [`Caseless`][crate::ascii::Caseless])|
 //! | [`take`][crate::token::take] | `take(4)` |  `"hello"` |  `"o"` | `Ok("hell")` |Takes a specific number of bytes or characters|
 //! | [`take_while`][crate::token::take_while] | `take_while(0.., is_alphabetic)` |  `"abc123"` |  `"123"` | `Ok("abc")` |Returns the longest slice of bytes or characters for which the provided [set of tokens][crate::stream::ContainsToken] matches.|
+//! | [`take_bytes_while`][crate::token::take_bytes_while] | `take_bytes_while(4, is_alphabetic)` |  `"éèfg"` |  `"fg"` | `Ok("éè")` |Like `take_while` but the range is measured in bytes, never splitting a character|
 //! | [`take_till`][crate::token::take_till] | `take_till(0.., is_alphabetic)` |  `"123abc"` |  `"abc"` | `Ok("123")` |Returns a slice of bytes or characters until the provided [set of tokens][crate::stream::ContainsToken] matches. This is the reverse behaviour from `take_while`: `take_till(f)` is equivalent to `take_while(0.., \|c\| !f(c))`|
 //! | [`take_until`][crate::token::take_until] | `take_until(0.., "world")` |  `"Hello world"` |  `"world"` | `Ok("Hello ")` |Returns a slice of bytes or characters until the provided [literal][crate::token::literal] is found.|
+//! | [`take_until_unescaped`][crate::token::take_until_unescaped] | `take_until_unescaped(0.., '\\', "\"")` |  `r#"a\"b" c"#` |  `r#"" c"#` | `Ok(r#"a\"b"#)` |Like `take_until` but skips a literal that is preceded by an escape token|
+//! | [`take_until_first`][crate::token::take_until_first] | `take_until_first(("\r\n", "\n"))` |  `"Hello\nworld"` |  `"world"` | `Ok(("Hello", "\n"))` |Returns a slice of bytes or characters until the earliest of several needles is found, along with the needle|
+//! | [`prefixed_until`][crate::token::prefixed_until] | `prefixed_until("<!", '>', 80)` |  `"<!doctype html><p>"` |  `"<p>"` | `Ok("<!doctype html>")` |Returns a slice of bytes or characters from a [literal][crate::token::literal] prefix up to and including a member of a [set of tokens][crate::stream::ContainsToken], with a length cap|
 //!
 //! ## Choice combinators
 //!
@@ -26,6 +30,7 @@
 //! |---|---|---|---|---|---|
 //! | [`alt`] | `alt(("ab", "cd"))` |  `"cdef"` |  `"ef"` | `Ok("cd")` |Try a list of parsers and return the result of the first successful one|
 //! | [`dispatch`] | \- | \- | \- | \- | `match` for parsers |
+//! | [`longest`] | `longest((">", ">=", ">>="))` |  `">>=1"` |  `"1"` | `Ok(">>=")` |Try a list of parsers and return the result of the one that consumed the most input|
 //! | [`permutation`] | `permutation(("ab", "cd", "12"))` | `"cd12abc"` | `"c"` | `Ok(("ab", "cd", "12"))` |Succeeds when all its child parser have succeeded, whatever the order|
 //!
 //! ## Sequence combinators
@@ -35,8 +40,10 @@
 //! | [`(...)` (tuples)][crate::Parser] | `("ab", "XY", take(1))` | `"abXYZ!"` | `"!"` | `Ok(("ab", "XY", "Z"))` |Parse a series of values|
 //! | [`seq!`] | `seq!(_: '(', take(2), _: ')')` | `"(ab)cd"` | `"cd"` | `Ok("ab")` |Parse a series of values, discarding those you specify|
 //! | [`delimited`] | `delimited('(', take(2), ')')` | `"(ab)cd"` | `"cd"` | `Ok("ab")` |Parse three values, discarding the first and third value|
+//! | [`delimited_cut`] | `delimited_cut('(', take(2), ')')` | `"(ab)cd"` | `"cd"` | `Ok("ab")` |Like [`delimited`], committing after the first value and reporting where it was when the third value fails|
 //! | [`preceded`] | `preceded("ab", "XY")` | `"abXYZ"` | `"Z"` | `Ok("XY")` |Parse two values, discarding the first value|
 //! | [`terminated`] | `terminated("ab", "XY")` | `"abXYZ"` | `"Z"` | `Ok("ab")` |Parse two values, discarding the second value|
+//! | [`terminated_rest`] | `terminated_rest("ab")` | `"abXYZ"` | `""` | `Ok(("ab", "XYZ"))` |Parse a value, returning it along with the rest of the input|
 //! | [`separated_pair`] | `separated_pair("hello", ',', "world")` | `"hello,world!"` | `"!"` | `Ok(("hello", "world"))` | Parse three values, discarding the middle value|
 //!
 //! ## Applying a parser multiple times
@@ -45,33 +52,54 @@
 //! |---|---|---|---|---|---|
 //! | [`repeat`] | `repeat(1..=3, "ab")` | `"ababc"` | `"c"` | `Ok(vec!["ab", "ab"])` |Applies the parser between m and n times (n included) and returns the list of results in a Vec|
 //! | [`repeat_till`] | `repeat_till(0.., "ab", "ef")` | `"ababefg"` | `"g"` | `Ok((vec!["ab", "ab"], "ef"))` |Applies the first parser until the second applies. Returns a tuple containing the list of results from the first in a Vec and the result of the second|
+//! | [`repeat_till_discard`] | `repeat_till_discard(0.., "ab", "ef")` | `"ababefg"` | `"g"` | `Ok(vec!["ab", "ab"])` |Applies the first parser until the second applies. Returns the list of results from the first in a Vec|
 //! | [`separated`] | `separated(1..=3, "ab", ",")` | `"ab,ab,ab."` | `"."` | `Ok(vec!["ab", "ab", "ab"])` |Applies the parser and separator between m and n times (n included) and returns the list of results in a Vec|
+//! | [`separated_last`] | `separated_last(1.., "ab", ",", "&")` | `"ab,ab&ab."` | `"."` | `Ok(vec!["ab", "ab", "ab"])` |Like [`separated`], but the final occurrence may be preceded by a different separator, ending the list|
+//! | [`fill`] | `fill("ab", &mut buf)` | `"ababc"` | `"c"` | `Ok(())` |Applies the parser exactly `buf.len()` times, writing the results into `buf`|
+//! | [`partition`] | `partition(("ab", "cd"))` | `"abcdabe"` | `"e"` | `Ok((vec!["ab", "ab"], vec!["cd"]))` |Applies the first of the parsers that matches until none do, and returns the results of each parser in its own Vec|
+//! | [`repeat_exact`] | `repeat_exact(2, "ab")` | `"ababc"` | `"c"` | `Ok(vec!["ab", "ab"])` |Applies the parser exactly n times, with n from a variable like a length field|
 //! | [`Repeat::fold`] | <code>repeat(1..=2, `be_u8`).fold(\|\| 0, \|acc, item\| acc + item)</code> | `[1, 2, 3]` | `[3]` | `Ok(3)` |Applies the parser between m and n times (n included) and folds the list of return value|
+//! | [`Repeat::strict`] | `repeat(1..=2, "ab").strict()` | `"ababab"` | `"ab"` | `Err(Cut(...))` |Applies the parser between m and n times (n included), erroring if it would match more than n times|
+//! | [`Separated::strict`] | `separated(1..=2, "ab", ",").strict()` | `"ab,ab,ab"` | `"ab"` | `Err(Cut(...))` |Applies the parser and separator between m and n times (n included), erroring if there would be more than n occurrences|
+//! | [`Separated::collect_map_with`] | <code>separated(1.., (`alpha1`, "=", `digit1`).map(\|(k, _, v)\| (k, v)), ",").collect_map_with(DuplicateKey::FirstWins)</code> | `"a=1,a=2"` | `""` | `Ok({"a": "1"})` |Applies the parser and separator, collecting the key-value pairs into a map according to the [`DuplicateKey`] policy|
 //!
 //! ## Partial related
 //!
 //! - [`eof`]: Returns its input if it is at the end of input data
 //! - [`Parser::complete_err`]: Replaces an `Incomplete` returned by the child parser with an `Backtrack`
+//! - [`Parser::expect_complete`]: Like [`Parser::complete_err`], adding context to the error
+//! - [`with_needed_offset`]: Reports an `Incomplete` returned by the child parser as the total input length required from where it started
+//! - [`complete_scope`]: Treats the input as complete while running the child parser, restoring it afterwards
+//! - [`subparse`]: Runs the second parser on the slice from the first, requiring it to be consumed and reporting errors in the outer input's positions
 //!
 //! ## Modifiers
 //!
 //! - [`cond`]: Conditional combinator. Wraps another parser and calls it if the condition is met
+//! - [`cond_else`]: Conditional combinator. Calls the first parser if the condition is met, otherwise the second
 //! - [`Parser::flat_map`]: method to map a new parser from the output of the first parser, then apply that parser over the rest of the input
 //! - [`Parser::value`]: method to replace the result of a parser
+//! - [`Parser::value_with`]: method to replace the result of a parser with a computed value
 //! - [`Parser::default_value`]: method to replace the result of a parser
 //! - [`Parser::void`]: method to discard the result of a parser
 //! - [`Parser::map`]: method to map a function on the result of a parser
 //! - [`Parser::and_then`]: Applies a second parser over the output of the first one
+//! - [`Parser::and_then_str`]: Applies a `&str` parser over the `&[u8]` output of the first one
 //! - [`Parser::verify_map`]: Maps a function returning an `Option` on the output of a parser
+//! - [`Parser::verify_map_taken`]: Maps a function returning an `Option` on the output of a parser and the consumed input
 //! - [`Parser::try_map`]: Maps a function returning a `Result` on the output of a parser
+//! - [`Parser::try_map_boxed`]: Maps a function returning a `Result` with any error on the output of a parser
 //! - [`Parser::parse_to`]: Apply [`std::str::FromStr`] to the output of the parser
 //! - [`not`]: Returns a result only if the embedded parser returns `Backtrack` or `Incomplete`. Does not consume the input
+//! - [`not_preceded_by`]: Returns a result only if the previously consumed token is not in the set. Does not consume the input
 //! - [`opt`]: Make the underlying parser optional
+//! - [`opt_all`]: Make the underlying parser optional, even after a [`cut_err`]
 //! - [`peek`]: Returns a result without consuming the input
+//! - [`peek_with_state_rollback`]: Like [`peek`], also undoing changes to [`Stateful::state`][crate::stream::Stateful::state]
 //! - [`Parser::take`]: If the child parser was successful, return the consumed input as the produced value
 //! - [`Parser::with_taken`]: If the child parser was successful, return a tuple of the consumed input and the produced output.
 //! - [`Parser::span`]: If the child parser was successful, return the location of the consumed input as the produced value
 //! - [`Parser::with_span`]: If the child parser was successful, return a tuple of the location of the consumed input and the produced output.
+//! - [`spanned`]: If the child parser was successful, return a [`Spanned`] of the produced output and the location of the consumed input.
 //! - [`Parser::verify`]: Returns the result of the child parser if it satisfies a verification function
 //!
 //! ## Error management and debugging
@@ -80,14 +108,22 @@
 //! - [`backtrack_err`]: Attempts a parse, allowing alternative parsers to be attempted despite
 //!   use of `cut_err`
 //! - [`Parser::context`]: Add context to the error if the parser fails
+//! - [`depth_guard`]: Report an error when parsers nest too deeply
+//! - [`limited`]: Report an error when a parser consumes too much input
+//! - [`step_limit`]: Report an error when a parser backtracks too much
 //! - [`trace`]: Print the parse state with the `debug` feature flag
+//! - [`Parser::trace`]: method to print the parse state with the `debug` feature flag
+//! - [`dbg_dmp`]: Print a hex dump of the input when a parser fails
 //! - [`todo()`]: Placeholder parser
+//! - [`todo_with`]: Placeholder parser for a named rule, failing with a [`Cut`][crate::error::ErrMode::Cut] rather than panicking
 //!
 //! ## Remaining combinators
 //!
 //! - [`empty`]: Returns a value without consuming any input, always succeeds
 //! - [`fail`]: Inversion of [`empty`]. Always fails.
 //! - [`Parser::by_ref`]: Allow moving `&mut impl Parser` into other parsers
+//! - [`lazy`]: Defer constructing a parser until it is first used, for recursive grammars
+//! - [`from_peek_fn`]: Adapt a function returning the remaining input, like [`Parser::parse_peek`], into a [`Parser`]
 //!
 //! ## Text parsing
 //!
@@ -97,23 +133,36 @@
 //! - [`line_ending`][crate::ascii::line_ending]: Recognizes an end of line (both `\n` and `\r\n`)
 //! - [`newline`][crate::ascii::newline]: Matches a newline character `\n`
 //! - [`till_line_ending`][crate::ascii::till_line_ending]: Recognizes a string of any char except `\r` or `\n`
+//! - [`line`][crate::ascii::line]: Recognizes a line, consuming the line ending. See also [`line_inclusive`][crate::ascii::line_inclusive]
 //! - [`rest`][crate::token::rest]: Return the remaining input
 //!
 //! - [`alpha0`][crate::ascii::alpha0]: Recognizes zero or more lowercase and uppercase alphabetic characters: `[a-zA-Z]`. [`alpha1`][crate::ascii::alpha1] does the same but returns at least one character
 //! - [`alphanumeric0`][crate::ascii::alphanumeric0]: Recognizes zero or more numerical and alphabetic characters: `[0-9a-zA-Z]`. [`alphanumeric1`][crate::ascii::alphanumeric1] does the same but returns at least one character
 //! - [`space0`][crate::ascii::space0]: Recognizes zero or more spaces and tabs. [`space1`][crate::ascii::space1] does the same but returns at least one character
 //! - [`multispace0`][crate::ascii::multispace0]: Recognizes zero or more spaces, tabs, carriage returns and line feeds. [`multispace1`][crate::ascii::multispace1] does the same but returns at least one character
+//! - [`take_while_ascii`][crate::ascii::take_while_ascii]: Like [`take_while`][crate::token::take_while] for sets of ASCII tokens, scanning the input as bytes
+//! - [`identifier`][crate::ascii::identifier]: Recognizes an ASCII identifier: `[A-Za-z_][A-Za-z0-9_]*`. [`identifier_with`][crate::ascii::identifier_with] does the same with custom start and continue sets
+//! - [`keyword`][crate::ascii::keyword]: Recognizes a keyword that isn't the start of a longer identifier. [`keyword_with`][crate::ascii::keyword_with] does the same with a custom continue set
+//! - [`xid_identifier`][crate::ascii::unicode::xid_identifier]: Recognizes a Unicode `XID_Start XID_Continue*` identifier (requires the `unicode` feature)
 //! - [`digit0`][crate::ascii::digit0]: Recognizes zero or more numerical characters: `[0-9]`. [`digit1`][crate::ascii::digit1] does the same but returns at least one character
+//! - [`digit_fixed`][crate::ascii::digit_fixed]: Recognizes exactly `N` numerical characters: `[0-9]`
 //! - [`hex_digit0`][crate::ascii::hex_digit0]: Recognizes zero or more hexadecimal numerical characters: `[0-9A-Fa-f]`. [`hex_digit1`][crate::ascii::hex_digit1] does the same but returns at least one character
 //! - [`oct_digit0`][crate::ascii::oct_digit0]: Recognizes zero or more octal characters: `[0-7]`. [`oct_digit1`][crate::ascii::oct_digit1] does the same but returns at least one character
 //!
-//! - [`float`][crate::ascii::float]: Parse a floating point number in a byte string
+//! - [`float`][crate::ascii::float]: Parse a floating point number in a byte string. [`float_terminated`][crate::ascii::float_terminated] does the same, letting the caller end the number at the end of a partial buffer
 //! - [`dec_int`][crate::ascii::dec_int]: Decode a variable-width, decimal signed integer
 //! - [`dec_uint`][crate::ascii::dec_uint]: Decode a variable-width, decimal unsigned integer
+//! - [`dec_uint_fixed`][crate::ascii::dec_uint_fixed]: Decode a fixed-width, decimal unsigned integer
+//! - [`dec_int_with`][crate::ascii::dec_int_with], [`dec_uint_with`][crate::ascii::dec_uint_with]: Decode a variable-width, decimal integer, choosing which signs to accept
 //! - [`hex_uint`][crate::ascii::hex_uint]: Decode a variable-width, hexadecimal integer
+//! - [`hex_bytes`][crate::ascii::hex_bytes]: Decode pairs of hexadecimal digits into bytes
+//! - [`base64`][crate::ascii::base64]: Decode standard, padded base64 into bytes. [`base64_wrapped`][crate::ascii::base64_wrapped] does the same, skipping line endings
+//! - [`from_str_while`][crate::ascii::from_str_while]: Parse a `FromStr` type from the longest slice of tokens within a set
 //!
 //! - [`take_escaped`][crate::ascii::take_escaped]: Recognize the input slice with escaped characters
-//! - [`escaped_transform`][crate::ascii::escaped_transform]: Parse escaped characters, unescaping them
+//! - [`escaped`][crate::ascii::escaped]: Parse escaped characters, unescaping them
+//! - [`escaped_with_limits`][crate::ascii::escaped_with_limits]: Parse escaped characters, unescaping them, within a length range
+//! - [`escaped_with_raw`][crate::ascii::escaped_with_raw]: Parse escaped characters, returning both the raw slice and the unescaped value
 //!
 //! ### Character test functions
 //!
@@ -132,6 +181,13 @@
 //! - [`length_repeat`][crate::binary::length_repeat] Gets a number from the first parser, then applies the second parser that many times
 //! - [`length_take`][crate::binary::length_take]: Gets a number from the first parser, then takes a subslice of the input of that size, and returns that subslice
 //! - [`length_and_then`][crate::binary::length_and_then]: Gets a number from the first parser, takes a subslice of the input of that size, then applies the second parser on that subslice. If the second parser returns `Incomplete`, `length_value` will return an error
+//! - [`length_take_with_len`][crate::binary::length_take_with_len]: Like [`length_take`][crate::binary::length_take], also returning the parsed number
+//! - [`length_and_then_with_len`][crate::binary::length_and_then_with_len]: Like [`length_and_then`][crate::binary::length_and_then], also returning the parsed number
+//! - [`length_delimited`][crate::binary::length_delimited]: Gets a number from the first parser, takes a subslice of the input of that size, then applies the second parser repeatedly until the subslice is consumed
+//! - [`c_str`][crate::binary::c_str]: Takes a subslice up to a `0` terminator, consuming the terminator. See also [`c_str_bounded`][crate::binary::c_str_bounded] and [`c_str_utf8`][crate::binary::c_str_utf8]
+//! - [`flags`][crate::binary::flags]: Parses a set of bit-flags, failing if any unknown bit is set
+//! - [`take_rest_exact`][crate::binary::take_rest_exact]: Takes the remaining input as an array, failing unless exactly `N` bytes remain
+//! - [`padded`][crate::binary::padded]: Applies a parser, then skips padding to a multiple of `align` bytes from where it started. See also [`padded_zeroed`][crate::binary::padded_zeroed]
 //!
 //! ### Integers
 //!
@@ -146,6 +202,7 @@
 //! - **fixed endianness**: The functions are prefixed by `be_` for big endian numbers, and by `le_` for little endian numbers, and the suffix is the type they parse to. As an example, `be_u32` parses a big endian unsigned integer stored in 32 bits.
 //!   - [`be_f32`][crate::binary::be_f32], [`be_f64`][crate::binary::be_f64]: Big endian floating point numbers
 //!   - [`le_f32`][crate::binary::le_f32], [`le_f64`][crate::binary::le_f64]: Little endian floating point numbers
+//!   - [`be_f16`][crate::binary::be_f16], [`be_bf16`][crate::binary::be_bf16], [`le_f16`][crate::binary::le_f16], [`le_bf16`][crate::binary::le_bf16]: Half-precision floating point numbers, widened to `f32`
 //!   - [`be_i8`][crate::binary::be_i8], [`be_i16`][crate::binary::be_i16], [`be_i24`][crate::binary::be_i24], [`be_i32`][crate::binary::be_i32], [`be_i64`][crate::binary::be_i64], [`be_i128`][crate::binary::be_i128]: Big endian signed integers
 //!   - [`be_u8`][crate::binary::be_u8], [`be_u16`][crate::binary::be_u16], [`be_u24`][crate::binary::be_u24], [`be_u32`][crate::binary::be_u32], [`be_u64`][crate::binary::be_u64], [`be_u128`][crate::binary::be_u128]: Big endian unsigned integers
 //!   - [`le_i8`][crate::binary::le_i8], [`le_i16`][crate::binary::le_i16], [`le_i24`][crate::binary::le_i24], [`le_i32`][crate::binary::le_i32], [`le_i64`][crate::binary::le_i64], [`le_i128`][crate::binary::le_i128]: Little endian signed integers
@@ -156,6 +213,7 @@
 //! - [`bits`][crate::binary::bits::bits]: Transforms the current input type (byte slice `&[u8]`) to a bit stream on which bit specific parsers and more general combinators can be applied
 //! - [`bytes`][crate::binary::bits::bytes]: Transforms its bits stream input back into a byte slice for the underlying parser
 //! - [`take`][crate::binary::bits::take]: Take a set number of bits
+//! - [`take_packed`][crate::binary::bits::take_packed]: Take a set number of values, each a set number of bits
 //! - [`pattern`][crate::binary::bits::pattern]: Check if a set number of bits matches a pattern
 //! - [`bool`][crate::binary::bits::bool]: Match any one bit
 
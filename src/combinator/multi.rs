@@ -1,11 +1,19 @@
 //! Combinators applying their child parser multiple times
 
 use crate::combinator::trace;
+#[cfg(feature = "alloc")]
+use crate::error::DuplicateKeyError;
 use crate::error::FromExternalError;
+use crate::error::LengthLimitError;
+use crate::error::ModalError;
 use crate::error::ParserError;
+use crate::error::RepeatLimitError;
 use crate::stream::Accumulate;
+#[cfg(feature = "alloc")]
+use crate::stream::AccumulateMap;
 use crate::stream::Range;
 use crate::stream::Stream;
+use crate::stream::ToUsize;
 use crate::Parser;
 use crate::Result;
 
@@ -18,12 +26,33 @@ use crate::Result;
 /// (e.g. with [`.map(|()| ())`][Parser::map])
 /// and then [`Parser::take`].
 ///
+/// Accumulating into `()` avoids building a container but each output is still computed by
+/// `parser` before being dropped, the same as with [`Parser::void`].
+/// When skipping input, like whitespace and comments, prefer a `parser` that doesn't build an
+/// output you don't need (e.g. leave off an allocating [`Parser::map`]).
+///
+/// The output of `parser` can be [`Accumulate`]d into:
+/// - `()`: discard the outputs
+/// - `usize`: count the outputs
+/// - `Vec<T>`: from each `T`, including `Vec<u8>` from `u8`, or from `&[T]` slices, extending
+///   the `Vec`
+/// - `String`: from `char` or `&str`, like when decoding escaped characters without collecting
+///   into a `Vec<char>` first
+/// - `BTreeSet<T>`, `HashSet<T>`: from each `T`
+/// - `BTreeMap<K, V>`, `HashMap<K, V>`: from `(K, V)` pairs, see also [`Repeat::collect_map_with`]
+///
+/// When `occurrences` requires more than one output, its lower bound is used as the initial
+/// capacity.
+///
 /// <div class="warning">
 ///
 /// **Warning:** If the parser passed to `repeat` accepts empty inputs
 /// (like `alpha0` or `digit0`), `repeat` will return an error,
 /// to prevent going into an infinite loop.
 ///
+/// In debug builds, an unbounded `repeat` of a parser that is known to
+/// [match empty input][Parser::may_match_empty] (like `opt(...)`) panics when it is built.
+///
 /// </div>
 ///
 /// # Example
@@ -121,8 +150,13 @@ where
     ParseNext: Parser<Input, Output, Error>,
     Error: ParserError<Input>,
 {
+    let occurrences = occurrences.into();
+    debug_assert!(
+        occurrences.end_inclusive.is_some() || parser.may_match_empty() != Some(true),
+        "`repeat` parsers must always consume, but `parser` may match empty input with unbounded `occurrences`"
+    );
     Repeat {
-        occurrences: occurrences.into(),
+        occurrences,
         parser,
         i: Default::default(),
         o: Default::default(),
@@ -131,6 +165,44 @@ where
     }
 }
 
+/// [`repeat`] a parser exactly `count` times
+///
+/// This is the same as `repeat(count, parser)`, for when `count` is a number read from the input,
+/// like a length field, to make it clear it isn't a [`Range`].
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;
+/// use winnow::binary::u8;
+/// use winnow::combinator::repeat_exact;
+///
+/// fn parser<'i>(s: &mut &'i [u8]) -> ModalResult<Vec<u8>> {
+///   let count = u8.parse_next(s)?;
+///   repeat_exact(count, u8).parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&[2, 10, 20, 30][..]), Ok((&[30][..], vec![10, 20])));
+/// assert!(parser.parse_peek(&[3, 10, 20][..]).is_err());
+/// # }
+/// ```
+#[doc(alias = "count")]
+#[inline(always)]
+pub fn repeat_exact<Input, Output, Accumulator, Error, ParseNext, UsizeLike>(
+    count: UsizeLike,
+    parser: ParseNext,
+) -> Repeat<ParseNext, Input, Output, Accumulator, Error>
+where
+    Input: Stream,
+    Accumulator: Accumulate<Output>,
+    ParseNext: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+    UsizeLike: ToUsize,
+{
+    repeat(count.to_usize(), parser)
+}
+
 /// Customizable [`Parser`] implementation for [`repeat`]
 pub struct Repeat<P, I, O, C, E>
 where
@@ -432,6 +504,242 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<ParseNext, Input, Key, Value, Error> Repeat<ParseNext, Input, (Key, Value), (), Error>
+where
+    ParseNext: Parser<Input, (Key, Value), Error>,
+    Input: Stream,
+    Error: ParserError<Input>,
+{
+    /// Repeats the embedded parser, collecting the key-value pairs into a map according to a
+    /// [`DuplicateKey`] policy
+    ///
+    /// With [`DuplicateKey::Error`], the error is reported at the start of the repeated entry.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// use winnow::ascii::{alpha1, digit1};
+    /// use winnow::combinator::{repeat, terminated, DuplicateKey};
+    /// use std::collections::BTreeMap;
+    ///
+    /// fn parser<'i>(s: &mut &'i str) -> ModalResult<BTreeMap<&'i str, &'i str>> {
+    ///   repeat(0.., terminated((alpha1, digit1), ";"))
+    ///     .collect_map_with(DuplicateKey::FirstWins)
+    ///     .parse_next(s)
+    /// }
+    ///
+    /// assert_eq!(parser.parse_peek("a1;b2;a3;"), Ok(("", BTreeMap::from([("a", "1"), ("b", "2")]))));
+    /// ```
+    #[inline(always)]
+    pub fn collect_map_with<Map>(mut self, policy: DuplicateKey) -> impl Parser<Input, Map, Error>
+    where
+        Map: AccumulateMap<Key, Value>,
+        Key: crate::lib::std::fmt::Display,
+        Error: FromExternalError<Input, DuplicateKeyError> + ModalError,
+    {
+        let Range {
+            start_inclusive,
+            end_inclusive,
+        } = self.occurrences;
+        trace("repeat_collect_map_with", move |i: &mut Input| {
+            let mut map = Map::initial(None);
+            let mut entry = |i: &mut Input| accumulate_entry(policy, &mut map, &mut self.parser, i);
+            match (start_inclusive, end_inclusive) {
+                (0, None) => repeat0_::<_, _, (), _, _>(&mut entry, i),
+                (1, None) => repeat1_::<_, _, (), _, _>(&mut entry, i),
+                (start, end) if Some(start) == end => {
+                    repeat_n_::<_, _, (), _, _>(start, &mut entry, i)
+                }
+                (start, end) => {
+                    repeat_m_n_::<_, _, (), _, _>(start, end.unwrap_or(usize::MAX), &mut entry, i)
+                }
+            }?;
+            Ok(map)
+        })
+    }
+}
+
+/// How to handle a repeated key when collecting into a map
+///
+/// See [`Repeat::collect_map_with`] and [`Separated::collect_map_with`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg(feature = "alloc")]
+pub enum DuplicateKey {
+    /// Keep the value from the first occurrence of a key
+    FirstWins,
+    /// Keep the value from the last occurrence of a key, like [`Accumulate`] does for maps
+    LastWins,
+    /// Report a [`DuplicateKeyError`][crate::error::DuplicateKeyError] as an
+    /// [`ErrMode::Cut`][crate::error::ErrMode::Cut], positioned at the start of the repeated entry
+    Error,
+}
+
+#[cfg(feature = "alloc")]
+fn accumulate_entry<I, K, V, M, E, P>(
+    policy: DuplicateKey,
+    map: &mut M,
+    parser: &mut P,
+    input: &mut I,
+) -> Result<(), E>
+where
+    I: Stream,
+    M: AccumulateMap<K, V>,
+    K: crate::lib::std::fmt::Display,
+    P: Parser<I, (K, V), E>,
+    E: FromExternalError<I, DuplicateKeyError> + ModalError,
+{
+    let start = input.checkpoint();
+    let (key, value) = parser.parse_next(input)?;
+    if map.contains_key(&key) {
+        match policy {
+            DuplicateKey::FirstWins => return Ok(()),
+            DuplicateKey::LastWins => {}
+            DuplicateKey::Error => {
+                input.reset(&start);
+                let err = E::from_external_error(input, DuplicateKeyError::new(&key));
+                return Err(err.cut());
+            }
+        }
+    }
+    map.accumulate((key, value));
+    Ok(())
+}
+
+impl<P, I, O, C, E> Repeat<P, I, O, C, E>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    C: Accumulate<O>,
+    E: ParserError<I>,
+{
+    /// Repeats the embedded parser, reporting an error if it would match more than the maximum
+    /// number of times
+    ///
+    /// By default, [`repeat`] stops at the maximum, leaving any further occurrences for the next
+    /// parser.  Instead, this reports a [`RepeatLimitError`] as an
+    /// [`ErrMode::Cut`][crate::error::ErrMode::Cut], positioned at the start of the surplus
+    /// occurrence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// # use winnow::prelude::*;
+    /// use winnow::combinator::repeat;
+    ///
+    /// fn parser<'i>(s: &mut &'i str) -> ModalResult<Vec<&'i str>> {
+    ///   repeat(0..=2, "abc").strict().parse_next(s)
+    /// }
+    ///
+    /// assert_eq!(parser.parse_peek("abcabc123"), Ok(("123", vec!["abc", "abc"])));
+    /// assert!(parser.parse_peek("abcabcabc").is_err());
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn strict(mut self) -> impl Parser<I, C, E>
+    where
+        E: FromExternalError<I, RepeatLimitError> + ModalError,
+    {
+        let Range {
+            start_inclusive,
+            end_inclusive,
+        } = self.occurrences;
+        trace("repeat_strict", move |i: &mut I| {
+            let mut count = 0;
+            let mut item = |i: &mut I| -> Result<O, E> {
+                let o = self.parser.parse_next(i)?;
+                count += 1;
+                Ok(o)
+            };
+            let acc = match (start_inclusive, end_inclusive) {
+                (0, None) => repeat0_(&mut item, i),
+                (1, None) => repeat1_(&mut item, i),
+                (start, end) if Some(start) == end => repeat_n_(start, &mut item, i),
+                (start, end) => repeat_m_n_(start, end.unwrap_or(usize::MAX), &mut item, i),
+            }?;
+            if let Some(max) = end_inclusive.filter(|max| count == *max) {
+                reject_surplus(max, &mut self.parser, i)?;
+            }
+            Ok(acc)
+        })
+    }
+
+    /// Repeats the embedded parser, reporting an error if the occurrences consume more than `max`
+    /// tokens in total
+    ///
+    /// Unlike wrapping [`repeat`] in [`limited`][crate::combinator::limited], this checks after
+    /// each occurrence, so parsing stops as soon as the limit is crossed.  This reports a
+    /// [`LengthLimitError`] as an [`ErrMode::Cut`][crate::error::ErrMode::Cut], positioned at the
+    /// start of the occurrence that crossed the limit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// # use winnow::prelude::*;
+    /// use winnow::ascii::alpha1;
+    /// use winnow::combinator::{repeat, terminated};
+    ///
+    /// fn attrs<'i>(s: &mut &'i str) -> ModalResult<Vec<&'i str>> {
+    ///   repeat(0.., terminated(alpha1, ";")).max_consumed(8).parse_next(s)
+    /// }
+    ///
+    /// assert_eq!(attrs.parse_peek("abc;def;123"), Ok(("123", vec!["abc", "def"])));
+    /// assert!(attrs.parse_peek("abc;defg;").is_err());
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn max_consumed(mut self, max: usize) -> impl Parser<I, C, E>
+    where
+        E: FromExternalError<I, LengthLimitError> + ModalError,
+    {
+        let Range {
+            start_inclusive,
+            end_inclusive,
+        } = self.occurrences;
+        trace("repeat_max_consumed", move |i: &mut I| {
+            let start = i.checkpoint();
+            let mut item = |i: &mut I| -> Result<O, E> {
+                let item_start = i.checkpoint();
+                let o = self.parser.parse_next(i)?;
+                if max < i.offset_from(&start) {
+                    i.reset(&item_start);
+                    return Err(E::from_external_error(i, LengthLimitError::new(max)).cut());
+                }
+                Ok(o)
+            };
+            match (start_inclusive, end_inclusive) {
+                (0, None) => repeat0_(&mut item, i),
+                (1, None) => repeat1_(&mut item, i),
+                (start, end) if Some(start) == end => repeat_n_(start, &mut item, i),
+                (start, end) => repeat_m_n_(start, end.unwrap_or(usize::MAX), &mut item, i),
+            }
+        })
+    }
+}
+
+fn reject_surplus<I, O, E, P>(max: usize, parser: &mut P, input: &mut I) -> Result<(), E>
+where
+    I: Stream,
+    P: Parser<I, O, E>,
+    E: ParserError<I> + FromExternalError<I, RepeatLimitError> + ModalError,
+{
+    let start = input.checkpoint();
+    match parser.parse_next(input) {
+        Ok(_) => {
+            input.reset(&start);
+            Err(E::from_external_error(input, RepeatLimitError::new(max)).cut())
+        }
+        Err(e) if e.is_backtrack() => {
+            input.reset(&start);
+            Ok(())
+        }
+        Err(e) => Err(e),
+    }
+}
+
 impl<P, I, O, C, E> Parser<I, C, E> for Repeat<P, I, O, C, E>
 where
     P: Parser<I, O, E>,
@@ -455,6 +763,15 @@ where
         })
         .parse_next(i)
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        if self.occurrences.start_inclusive == 0 {
+            Some(true)
+        } else {
+            self.parser.may_match_empty()
+        }
+    }
 }
 
 fn repeat0_<I, O, C, E, F>(f: &mut F, i: &mut I) -> Result<C, E>
@@ -616,10 +933,16 @@ where
 ///
 /// `f` keeps going so long as `g` produces [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack]. To instead chain an error up, see [`cut_err`][crate::combinator::cut_err].
 ///
+/// When neither `g` nor `f` match, the error from `f` is reported as it is usually the more
+/// specific of the two, e.g. a typo in an item rather than a missing terminator.  Once `f` has
+/// matched the maximum number of times, the error from `g` is reported.
+///
 /// To take a series of tokens, [`Accumulate`] into a `()`
 /// (e.g. with [`.map(|((), _)| ())`][Parser::map])
 /// and then [`Parser::take`].
 ///
+/// To ignore the result of `g`, see [`repeat_till_discard`].
+///
 /// See also
 /// - [`take_till`][crate::token::take_till] for recognizing up-to a member of a [set of tokens][crate::stream::ContainsToken]
 /// - [`take_until`][crate::token::take_until] for recognizing up-to a [`literal`][crate::token::literal] (w/ optional simd optimizations)
@@ -674,6 +997,56 @@ where
     })
 }
 
+/// [`Accumulate`] the output of parser `f` into a container, like `Vec`, until the parser `g`
+/// produces a result, discarding the result of `g`.
+///
+/// This is [`repeat_till`] for when only the results of `f` are needed, like with an
+/// [`eof`][crate::combinator::eof] terminator.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::combinator::eof;
+/// use winnow::combinator::repeat_till_discard;
+///
+/// fn parser<'i>(s: &mut &'i str) -> ModalResult<Vec<&'i str>> {
+///   repeat_till_discard(0.., "abc", eof).parse_next(s)
+/// };
+///
+/// assert_eq!(parser.parse_peek("abcabc"), Ok(("", vec!["abc", "abc"])));
+/// assert!(parser.parse_peek("abc123").is_err());
+/// assert_eq!(parser.parse_peek(""), Ok(("", vec![])));
+/// # }
+/// ```
+pub fn repeat_till_discard<
+    Input,
+    Output,
+    Accumulator,
+    Terminator,
+    Error,
+    ParseNext,
+    TerminatorParser,
+>(
+    occurrences: impl Into<Range>,
+    parse: ParseNext,
+    terminator: TerminatorParser,
+) -> impl Parser<Input, Accumulator, Error>
+where
+    Input: Stream,
+    Accumulator: Accumulate<Output>,
+    ParseNext: Parser<Input, Output, Error>,
+    TerminatorParser: Parser<Input, Terminator, Error>,
+    Error: ParserError<Input>,
+{
+    let mut parser = repeat_till(occurrences, parse, terminator);
+    trace("repeat_till_discard", move |i: &mut Input| {
+        parser.parse_next(i).map(|(acc, _)| acc)
+    })
+}
+
 fn repeat_till0_<I, O, C, P, E, F, G>(f: &mut F, g: &mut G, i: &mut I) -> Result<(C, P), E>
 where
     I: Stream,
@@ -792,6 +1165,9 @@ where
 /// (like `alpha0` or `digit0`), `separated` will return an error,
 /// to prevent going into an infinite loop.
 ///
+/// In debug builds, an unbounded `separated` with a separator that is known to
+/// [match empty input][Parser::may_match_empty] (like `opt(...)`) panics when it is built.
+///
 /// </div>
 ///
 /// # Example
@@ -879,9 +1255,9 @@ where
 #[inline(always)]
 pub fn separated<Input, Output, Accumulator, Sep, Error, ParseNext, SepParser>(
     occurrences: impl Into<Range>,
-    mut parser: ParseNext,
-    mut separator: SepParser,
-) -> impl Parser<Input, Accumulator, Error>
+    parser: ParseNext,
+    separator: SepParser,
+) -> Separated<ParseNext, SepParser, Input, Output, Sep, Accumulator, Error>
 where
     Input: Stream,
     Accumulator: Accumulate<Output>,
@@ -889,76 +1265,279 @@ where
     SepParser: Parser<Input, Sep, Error>,
     Error: ParserError<Input>,
 {
-    let Range {
-        start_inclusive,
-        end_inclusive,
-    } = occurrences.into();
-    trace("separated", move |input: &mut Input| {
-        match (start_inclusive, end_inclusive) {
-            (0, None) => separated0_(&mut parser, &mut separator, input),
-            (1, None) => separated1_(&mut parser, &mut separator, input),
-            (start, end) if Some(start) == end => {
-                separated_n_(start, &mut parser, &mut separator, input)
-            }
-            (start, end) => separated_m_n_(
-                start,
-                end.unwrap_or(usize::MAX),
-                &mut parser,
-                &mut separator,
-                input,
-            ),
-        }
-    })
+    let occurrences = occurrences.into();
+    debug_assert!(
+        occurrences.end_inclusive.is_some() || separator.may_match_empty() != Some(true),
+        "`separated` separator parser must always consume, but `separator` may match empty input with unbounded `occurrences`"
+    );
+    Separated {
+        occurrences,
+        parser,
+        separator,
+        i: Default::default(),
+        o: Default::default(),
+        o2: Default::default(),
+        c: Default::default(),
+        e: Default::default(),
+    }
 }
 
-fn separated0_<I, O, C, O2, E, P, S>(
-    parser: &mut P,
-    separator: &mut S,
-    input: &mut I,
-) -> Result<C, E>
+/// Customizable [`Parser`] implementation for [`separated`]
+pub struct Separated<P, S, I, O, O2, C, E>
 where
-    I: Stream,
-    C: Accumulate<O>,
     P: Parser<I, O, E>,
     S: Parser<I, O2, E>,
+    I: Stream,
+    C: Accumulate<O>,
     E: ParserError<I>,
 {
-    let mut acc = C::initial(None);
-
-    let start = input.checkpoint();
-    match parser.parse_next(input) {
-        Err(e) if e.is_backtrack() => {
-            input.reset(&start);
-            return Ok(acc);
-        }
-        Err(e) => return Err(e),
-        Ok(o) => {
-            acc.accumulate(o);
-        }
-    }
-
-    loop {
-        let start = input.checkpoint();
-        let len = input.eof_offset();
-        match separator.parse_next(input) {
-            Err(e) if e.is_backtrack() => {
-                input.reset(&start);
-                return Ok(acc);
-            }
-            Err(e) => return Err(e),
-            Ok(_) => {
-                // infinite loop check
-                if input.eof_offset() == len {
-                    return Err(ParserError::assert(
-                        input,
-                        "`separated` separator parser must always consume",
-                    ));
-                }
+    occurrences: Range,
+    parser: P,
+    separator: S,
+    i: core::marker::PhantomData<I>,
+    o: core::marker::PhantomData<O>,
+    o2: core::marker::PhantomData<O2>,
+    c: core::marker::PhantomData<C>,
+    e: core::marker::PhantomData<E>,
+}
 
-                match parser.parse_next(input) {
-                    Err(e) if e.is_backtrack() => {
-                        input.reset(&start);
-                        return Ok(acc);
+#[cfg(feature = "alloc")]
+impl<ParseNext, SepParser, Input, Key, Value, Sep, Error>
+    Separated<ParseNext, SepParser, Input, (Key, Value), Sep, (), Error>
+where
+    ParseNext: Parser<Input, (Key, Value), Error>,
+    SepParser: Parser<Input, Sep, Error>,
+    Input: Stream,
+    Error: ParserError<Input>,
+{
+    /// Collect the key-value pairs into a map according to a [`DuplicateKey`] policy
+    ///
+    /// With [`DuplicateKey::Error`], the error is reported at the start of the repeated entry.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// use winnow::ascii::{alpha1, digit1};
+    /// use winnow::combinator::{separated, separated_pair, DuplicateKey};
+    /// use std::collections::BTreeMap;
+    ///
+    /// fn parser<'i>(s: &mut &'i str) -> ModalResult<BTreeMap<&'i str, &'i str>> {
+    ///   separated(1.., separated_pair(alpha1, "=", digit1), ",")
+    ///     .collect_map_with(DuplicateKey::Error)
+    ///     .parse_next(s)
+    /// }
+    ///
+    /// assert_eq!(parser.parse_peek("a=1,b=2"), Ok(("", BTreeMap::from([("a", "1"), ("b", "2")]))));
+    /// assert!(parser.parse_peek("a=1,a=2").is_err());
+    /// ```
+    #[inline(always)]
+    pub fn collect_map_with<Map>(mut self, policy: DuplicateKey) -> impl Parser<Input, Map, Error>
+    where
+        Map: AccumulateMap<Key, Value>,
+        Key: crate::lib::std::fmt::Display,
+        Error: FromExternalError<Input, DuplicateKeyError> + ModalError,
+    {
+        let Range {
+            start_inclusive,
+            end_inclusive,
+        } = self.occurrences;
+        trace("separated_collect_map_with", move |input: &mut Input| {
+            let mut map = Map::initial(None);
+            let mut entry = |i: &mut Input| accumulate_entry(policy, &mut map, &mut self.parser, i);
+            let separator = &mut self.separator;
+            match (start_inclusive, end_inclusive) {
+                (0, None) => separated0_::<_, _, (), _, _, _, _>(&mut entry, separator, input),
+                (1, None) => separated1_::<_, _, (), _, _, _, _>(&mut entry, separator, input),
+                (start, end) if Some(start) == end => {
+                    separated_n_::<_, _, (), _, _, _, _>(start, &mut entry, separator, input)
+                }
+                (start, end) => separated_m_n_::<_, _, (), _, _, _, _>(
+                    start,
+                    end.unwrap_or(usize::MAX),
+                    &mut entry,
+                    separator,
+                    input,
+                ),
+            }?;
+            Ok(map)
+        })
+    }
+}
+
+impl<P, S, I, O, O2, C, E> Separated<P, S, I, O, O2, C, E>
+where
+    P: Parser<I, O, E>,
+    S: Parser<I, O2, E>,
+    I: Stream,
+    C: Accumulate<O>,
+    E: ParserError<I>,
+{
+    /// Alternates between the parser and separator, reporting an error if there would be more
+    /// than the maximum number of occurrences
+    ///
+    /// By default, [`separated`] stops at the maximum, leaving the next separator and occurrence
+    /// for the next parser.  Instead, this reports a [`RepeatLimitError`] as an
+    /// [`ErrMode::Cut`][crate::error::ErrMode::Cut], positioned at the start of the surplus
+    /// occurrence (after its separator).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// # use winnow::prelude::*;
+    /// use winnow::combinator::separated;
+    ///
+    /// fn parser<'i>(s: &mut &'i str) -> ModalResult<Vec<&'i str>> {
+    ///   separated(0..=2, "abc", ",").strict().parse_next(s)
+    /// }
+    ///
+    /// assert_eq!(parser.parse_peek("abc,abc,123"), Ok((",123", vec!["abc", "abc"])));
+    /// assert!(parser.parse_peek("abc,abc,abc").is_err());
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn strict(mut self) -> impl Parser<I, C, E>
+    where
+        E: FromExternalError<I, RepeatLimitError> + ModalError,
+    {
+        let Range {
+            start_inclusive,
+            end_inclusive,
+        } = self.occurrences;
+        trace("separated_strict", move |input: &mut I| {
+            let mut count = 0;
+            let mut item = |i: &mut I| -> Result<O, E> {
+                let o = self.parser.parse_next(i)?;
+                count += 1;
+                Ok(o)
+            };
+            let separator = &mut self.separator;
+            let acc = match (start_inclusive, end_inclusive) {
+                (0, None) => separated0_(&mut item, separator, input),
+                (1, None) => separated1_(&mut item, separator, input),
+                (start, end) if Some(start) == end => {
+                    separated_n_(start, &mut item, separator, input)
+                }
+                (start, end) => separated_m_n_(
+                    start,
+                    end.unwrap_or(usize::MAX),
+                    &mut item,
+                    separator,
+                    input,
+                ),
+            }?;
+            if let Some(max) = end_inclusive.filter(|max| count == *max) {
+                let start = input.checkpoint();
+                if 0 < count {
+                    match self.separator.parse_next(input) {
+                        Ok(_) => {}
+                        Err(e) if e.is_backtrack() => {
+                            input.reset(&start);
+                            return Ok(acc);
+                        }
+                        Err(e) => return Err(e),
+                    }
+                }
+                reject_surplus(max, &mut self.parser, input)?;
+                input.reset(&start);
+            }
+            Ok(acc)
+        })
+    }
+}
+
+impl<P, S, I, O, O2, C, E> Parser<I, C, E> for Separated<P, S, I, O, O2, C, E>
+where
+    P: Parser<I, O, E>,
+    S: Parser<I, O2, E>,
+    I: Stream,
+    C: Accumulate<O>,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<C, E> {
+        let Range {
+            start_inclusive,
+            end_inclusive,
+        } = self.occurrences;
+        trace("separated", move |input: &mut I| {
+            match (start_inclusive, end_inclusive) {
+                (0, None) => separated0_(&mut self.parser, &mut self.separator, input),
+                (1, None) => separated1_(&mut self.parser, &mut self.separator, input),
+                (start, end) if Some(start) == end => {
+                    separated_n_(start, &mut self.parser, &mut self.separator, input)
+                }
+                (start, end) => separated_m_n_(
+                    start,
+                    end.unwrap_or(usize::MAX),
+                    &mut self.parser,
+                    &mut self.separator,
+                    input,
+                ),
+            }
+        })
+        .parse_next(input)
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        if self.occurrences.start_inclusive == 0 {
+            Some(true)
+        } else {
+            self.parser.may_match_empty()
+        }
+    }
+}
+
+fn separated0_<I, O, C, O2, E, P, S>(
+    parser: &mut P,
+    separator: &mut S,
+    input: &mut I,
+) -> Result<C, E>
+where
+    I: Stream,
+    C: Accumulate<O>,
+    P: Parser<I, O, E>,
+    S: Parser<I, O2, E>,
+    E: ParserError<I>,
+{
+    let mut acc = C::initial(None);
+
+    let start = input.checkpoint();
+    match parser.parse_next(input) {
+        Err(e) if e.is_backtrack() => {
+            input.reset(&start);
+            return Ok(acc);
+        }
+        Err(e) => return Err(e),
+        Ok(o) => {
+            acc.accumulate(o);
+        }
+    }
+
+    loop {
+        let start = input.checkpoint();
+        let len = input.eof_offset();
+        match separator.parse_next(input) {
+            Err(e) if e.is_backtrack() => {
+                input.reset(&start);
+                return Ok(acc);
+            }
+            Err(e) => return Err(e),
+            Ok(_) => {
+                // infinite loop check
+                if input.eof_offset() == len {
+                    return Err(ParserError::assert(
+                        input,
+                        "`separated` separator parser must always consume",
+                    ));
+                }
+
+                match parser.parse_next(input) {
+                    Err(e) if e.is_backtrack() => {
+                        input.reset(&start);
+                        return Ok(acc);
                     }
                     Err(e) => return Err(e),
                     Ok(o) => {
@@ -1171,11 +1750,210 @@ where
     Ok(acc)
 }
 
+/// [`Accumulate`] the output of a parser, interleaved with `separator`, except for the final
+/// occurrence which may be preceded by `last_separator` instead
+///
+/// This covers lists like `a, b and c`.  At each gap, `last_separator` is tried first and, if it
+/// and the next occurrence succeed, the list ends.  Otherwise, `separator` is tried, like with
+/// [`separated`].  `last_separator` is only tried once the occurrences would reach the minimum.
+///
+/// This stops before `n` when either parser returns [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack]. To instead chain an error up, see
+/// [`cut_err`][crate::combinator::cut_err].
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;
+/// use winnow::ascii::alpha1;
+/// use winnow::combinator::separated_last;
+///
+/// fn parser<'i>(s: &mut &'i str) -> ModalResult<Vec<&'i str>> {
+///   separated_last(1.., alpha1, ", ", " and ").parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek("a, b and c."), Ok((".", vec!["a", "b", "c"])));
+/// assert_eq!(parser.parse_peek("a and b, c"), Ok((", c", vec!["a", "b"])));
+/// assert_eq!(parser.parse_peek("a, b, c"), Ok(("", vec!["a", "b", "c"])));
+/// assert_eq!(parser.parse_peek("a"), Ok(("", vec!["a"])));
+/// assert!(parser.parse_peek("").is_err());
+/// # }
+/// ```
+#[inline(always)]
+pub fn separated_last<
+    Input,
+    Output,
+    Accumulator,
+    Sep,
+    LastSep,
+    Error,
+    ParseNext,
+    SepParser,
+    LastSepParser,
+>(
+    occurrences: impl Into<Range>,
+    mut parser: ParseNext,
+    mut separator: SepParser,
+    mut last_separator: LastSepParser,
+) -> impl Parser<Input, Accumulator, Error>
+where
+    Input: Stream,
+    Accumulator: Accumulate<Output>,
+    ParseNext: Parser<Input, Output, Error>,
+    SepParser: Parser<Input, Sep, Error>,
+    LastSepParser: Parser<Input, LastSep, Error>,
+    Error: ParserError<Input>,
+{
+    let Range {
+        start_inclusive,
+        end_inclusive,
+    } = occurrences.into();
+    trace("separated_last", move |input: &mut Input| {
+        separated_last_m_n_(
+            start_inclusive,
+            end_inclusive.unwrap_or(usize::MAX),
+            &mut parser,
+            &mut separator,
+            &mut last_separator,
+            input,
+        )
+    })
+}
+
+fn separated_last_m_n_<I, O, C, O2, O3, E, P, S, L>(
+    min: usize,
+    max: usize,
+    parser: &mut P,
+    separator: &mut S,
+    last_separator: &mut L,
+    input: &mut I,
+) -> Result<C, E>
+where
+    I: Stream,
+    C: Accumulate<O>,
+    P: Parser<I, O, E>,
+    S: Parser<I, O2, E>,
+    L: Parser<I, O3, E>,
+    E: ParserError<I>,
+{
+    if min > max {
+        return Err(ParserError::assert(
+            input,
+            "range should be ascending, rather than descending",
+        ));
+    }
+
+    let mut acc = C::initial(Some(min));
+
+    if max == 0 {
+        return Ok(acc);
+    }
+
+    let start = input.checkpoint();
+    match parser.parse_next(input) {
+        Err(e) if e.is_backtrack() => {
+            if min == 0 {
+                input.reset(&start);
+                return Ok(acc);
+            } else {
+                return Err(e.append(input, &start));
+            }
+        }
+        Err(e) => return Err(e),
+        Ok(o) => {
+            acc.accumulate(o);
+        }
+    }
+
+    for index in 1..max {
+        let start = input.checkpoint();
+        let len = input.eof_offset();
+
+        if min <= index + 1 {
+            match last_separator.parse_next(input) {
+                Err(e) if e.is_backtrack() => {
+                    input.reset(&start);
+                }
+                Err(e) => {
+                    return Err(e);
+                }
+                Ok(_) => {
+                    // infinite loop check
+                    if input.eof_offset() == len {
+                        return Err(ParserError::assert(
+                            input,
+                            "`separated_last` separator parser must always consume",
+                        ));
+                    }
+
+                    match parser.parse_next(input) {
+                        Err(e) if e.is_backtrack() => {
+                            input.reset(&start);
+                        }
+                        Err(e) => {
+                            return Err(e);
+                        }
+                        Ok(o) => {
+                            acc.accumulate(o);
+                            return Ok(acc);
+                        }
+                    }
+                }
+            }
+        }
+
+        match separator.parse_next(input) {
+            Err(e) if e.is_backtrack() => {
+                if index < min {
+                    return Err(e.append(input, &start));
+                } else {
+                    input.reset(&start);
+                    return Ok(acc);
+                }
+            }
+            Err(e) => {
+                return Err(e);
+            }
+            Ok(_) => {
+                // infinite loop check
+                if input.eof_offset() == len {
+                    return Err(ParserError::assert(
+                        input,
+                        "`separated_last` separator parser must always consume",
+                    ));
+                }
+
+                match parser.parse_next(input) {
+                    Err(e) if e.is_backtrack() => {
+                        if index < min {
+                            return Err(e.append(input, &start));
+                        } else {
+                            input.reset(&start);
+                            return Ok(acc);
+                        }
+                    }
+                    Err(e) => {
+                        return Err(e);
+                    }
+                    Ok(o) => {
+                        acc.accumulate(o);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(acc)
+}
+
 /// Alternates between two parsers, merging the results (left associative)
 ///
 /// This stops when either parser returns [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack]. To instead chain an error up, see
 /// [`cut_err`][crate::combinator::cut_err].
 ///
+/// Values are merged as they are parsed, without buffering them.
+/// For a full example, see [arithmetic][crate::_topic::arithmetic].
+///
 /// # Example
 ///
 /// ```rust
@@ -1246,6 +2024,13 @@ where
 /// This stops when either parser returns [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack]. To instead chain an error up, see
 /// [`cut_err`][crate::combinator::cut_err].
 ///
+/// <div class="warning">
+///
+/// **Note:** Unlike [`separated_foldl1`], the results can't be merged until the last value is
+/// parsed, so all separators and values are buffered in a `Vec`.
+///
+/// </div>
+///
 /// # Example
 ///
 /// ```rust
@@ -1295,7 +2080,20 @@ where
 
 /// Repeats the embedded parser, filling the given slice with results.
 ///
-/// This parser fails if the input runs out before the given slice is full.
+/// This is [`repeat`] with exactly `buf.len()` occurrences, writing into a caller-provided buffer
+/// rather than allocating.
+///
+/// This parser fails if the input runs out before the given slice is full.  Elements before the
+/// one that failed have already been overwritten.  The error is positioned at the failing element.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))`
+/// if the input runs out before the given slice is full.
+///
+/// The returned parser holds the mutable borrow of `buf` for as long as it lives, so `buf` can't
+/// be read until the parser is dropped.  When used within a larger parser, like a tuple or
+/// [`alt`][crate::combinator::alt], the buffer stays borrowed until that whole parser is dropped.
+/// Usually this means creating `fill` inside of a function, as below, and reading `buf` after the
+/// call to `parse_next`.
 ///
 /// # Example
 ///
@@ -1342,6 +2140,146 @@ where
     })
 }
 
+/// Repeatedly try a tuple of parsers, [`Accumulate`]ing each output into the collection at the
+/// matching position
+///
+/// Each iteration tries the alternatives in order, like [`alt`][crate::combinator::alt], stopping
+/// when none of them match.  This is a fused `repeat(0.., alt(...))` that sorts items into
+/// separate collections without an intermediate enum.  Within a collection, items keep the order
+/// they were parsed in.
+///
+/// To discard the output of an alternative, [`Accumulate`] it into a `()`.
+///
+/// To chain an error up, see [`cut_err`][crate::combinator::cut_err].
+///
+/// <div class="warning">
+///
+/// **Warning:** If none of the alternatives advance the stream, this will return an error rather
+/// than loop forever.
+///
+/// </div>
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{alpha1, digit1, space0};
+/// use winnow::combinator::{delimited, partition, preceded, separated_pair};
+/// use winnow::token::take_until;
+///
+/// fn attributes<'i>(s: &mut &'i str) -> ModalResult<(Vec<(&'i str, &'i str)>, Vec<&'i str>, ())> {
+///     partition((
+///         delimited(space0, separated_pair(alpha1, '=', digit1), space0),
+///         delimited(space0, preceded('+', alpha1), space0),
+///         delimited(space0, ("/*", take_until(0.., "*/"), "*/"), space0),
+///     ))
+///     .parse_next(s)
+/// }
+///
+/// assert_eq!(
+///     attributes.parse_peek("+ro a=1 /* size */ b=2 +hidden;"),
+///     Ok((";", (vec![("a", "1"), ("b", "2")], vec!["ro", "hidden"], ())))
+/// );
+/// assert_eq!(attributes.parse_peek(";"), Ok((";", (vec![], vec![], ()))));
+/// # }
+/// ```
+pub fn partition<Input, Items, Output, Error, Alternatives>(
+    mut alternatives: Alternatives,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream,
+    Error: ParserError<Input>,
+    Alternatives: Partition<Input, Items, Output, Error>,
+{
+    trace("partition", move |input: &mut Input| {
+        let mut acc = Alternatives::initial();
+        loop {
+            let start = input.checkpoint();
+            let len = input.eof_offset();
+            match alternatives.partition_next(input, &mut acc) {
+                Err(e) if e.is_backtrack() => {
+                    input.reset(&start);
+                    return Ok(acc);
+                }
+                Err(e) => return Err(e),
+                Ok(()) => {
+                    // infinite loop check: the parser must always consume
+                    if input.eof_offset() == len {
+                        return Err(ParserError::assert(
+                            input,
+                            "`partition` parsers must always consume",
+                        ));
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Helper trait for the [`partition()`] combinator
+///
+/// This trait is implemented for tuples of up to 8 elements, where `Items` is the tuple of their
+/// outputs and `Output` the tuple of the collections they [`Accumulate`] into
+pub trait Partition<I, Items, Output, E> {
+    /// Create the empty collections
+    fn initial() -> Output;
+
+    /// Parse one item with the first alternative that matches, accumulating it into its collection
+    fn partition_next(&mut self, input: &mut I, acc: &mut Output) -> Result<(), E>;
+}
+
+macro_rules! partition_trait_impl(
+  ($first:ident $first_item:ident $first_acc:ident $first_idx:tt, $($id:ident $item:ident $acc:ident $idx:tt),+) => (
+    impl<
+      I: Stream, E: ParserError<I>,
+      $first: Parser<I, $first_item, E>, $first_acc: Accumulate<$first_item>, $first_item,
+      $($id: Parser<I, $item, E>, $acc: Accumulate<$item>, $item),+
+    > Partition<I, ($first_item, $($item),+), ($first_acc, $($acc),+), E> for ($first, $($id),+) {
+      fn initial() -> ($first_acc, $($acc),+) {
+        ($first_acc::initial(None), $($acc::initial(None)),+)
+      }
+
+      fn partition_next(&mut self, input: &mut I, acc: &mut ($first_acc, $($acc),+)) -> Result<(), E> {
+        let start = input.checkpoint();
+        let mut error = match self.$first_idx.parse_next(input) {
+          Ok(o) => {
+            acc.$first_idx.accumulate(o);
+            return Ok(());
+          }
+          Err(e) if e.is_backtrack() => e,
+          Err(e) => return Err(e),
+        };
+        $(
+          input.reset(&start);
+          match self.$idx.parse_next(input) {
+            Ok(o) => {
+              acc.$idx.accumulate(o);
+              return Ok(());
+            }
+            Err(e) if e.is_backtrack() => error = error.or(e),
+            Err(e) => return Err(e),
+          }
+        )+
+        Err(error.append(input, &start))
+      }
+    }
+  );
+);
+
+partition_trait_impl!(P1 O1 C1 0, P2 O2 C2 1);
+partition_trait_impl!(P1 O1 C1 0, P2 O2 C2 1, P3 O3 C3 2);
+partition_trait_impl!(P1 O1 C1 0, P2 O2 C2 1, P3 O3 C3 2, P4 O4 C4 3);
+partition_trait_impl!(P1 O1 C1 0, P2 O2 C2 1, P3 O3 C3 2, P4 O4 C4 3, P5 O5 C5 4);
+partition_trait_impl!(P1 O1 C1 0, P2 O2 C2 1, P3 O3 C3 2, P4 O4 C4 3, P5 O5 C5 4, P6 O6 C6 5);
+partition_trait_impl!(
+    P1 O1 C1 0, P2 O2 C2 1, P3 O3 C3 2, P4 O4 C4 3, P5 O5 C5 4, P6 O6 C6 5, P7 O7 C7 6
+);
+partition_trait_impl!(
+    P1 O1 C1 0, P2 O2 C2 1, P3 O3 C3 2, P4 O4 C4 3, P5 O5 C5 4, P6 O6 C6 5, P7 O7 C7 6,
+    P8 O8 C8 7
+);
+
 fn fold_repeat0_<I, O, E, F, G, H, R>(
     f: &mut F,
     init: &mut H,
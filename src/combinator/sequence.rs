@@ -1,6 +1,6 @@
 use crate::combinator::trace;
-use crate::error::ParserError;
-use crate::stream::Stream;
+use crate::error::{AddContext, ModalError, ParserError, StrContext};
+use crate::stream::{Location, Stream};
 use crate::*;
 
 #[doc(inline)]
@@ -172,3 +172,110 @@ where
         ignored2.parse_next(input).map(|_| o2)
     })
 }
+
+/// Sequence three parsers like [`delimited`], committing once the opening delimiter is parsed
+///
+/// After `open` succeeds, errors from `parser` and `close` are converted to
+/// [`ErrMode::Cut`][crate::error::ErrMode::Cut], like with [`cut_err`][crate::combinator::cut_err].
+/// When `close` fails, [`StrContext::Unclosed`] is added with the [`Location`] of `open` so an
+/// error can point to both where parsing failed and where the delimiter was opened.
+///
+/// For [`Stream`]s without a [`Location`], see [`delimited`] with
+/// [`cut_err`][crate::combinator::cut_err].
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;
+/// # use winnow::error::ContextError;
+/// # use winnow::error::StrContext;
+/// use winnow::ascii::digit1;
+/// use winnow::combinator::{delimited_cut, separated};
+/// use winnow::stream::LocatingSlice;
+///
+/// fn list(input: &mut LocatingSlice<&str>) -> ModalResult<Vec<u32>> {
+///     delimited_cut('[', separated(0.., digit1.parse_to::<u32>(), ','), ']').parse_next(input)
+/// }
+///
+/// assert_eq!(list.parse(LocatingSlice::new("[1,2,3]")), Ok(vec![1, 2, 3]));
+///
+/// let err = list.parse(LocatingSlice::new("[1,2 3]")).unwrap_err();
+/// assert_eq!(err.offset(), 4);
+/// assert!(err.inner().context().any(|c| *c == StrContext::Unclosed(0)));
+/// # }
+/// ```
+#[doc(alias = "cut_err_at")]
+pub fn delimited_cut<
+    Input,
+    Ignored1,
+    Output,
+    Ignored2,
+    Error,
+    IgnoredParser1,
+    ParseNext,
+    IgnoredParser2,
+>(
+    mut open: IgnoredParser1,
+    mut parser: ParseNext,
+    mut close: IgnoredParser2,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream + Location,
+    Error: ParserError<Input> + ModalError + AddContext<Input, StrContext>,
+    IgnoredParser1: Parser<Input, Ignored1, Error>,
+    ParseNext: Parser<Input, Output, Error>,
+    IgnoredParser2: Parser<Input, Ignored2, Error>,
+{
+    trace("delimited_cut", move |input: &mut Input| {
+        let opened = input.current_token_start();
+        let _ = open.parse_next(input)?;
+        let o2 = parser.parse_next(input).map_err(|e| e.cut())?;
+        let start = input.checkpoint();
+        close.parse_next(input).map(|_| o2).map_err(|e| {
+            e.add_context(input, &start, StrContext::Unclosed(opened))
+                .cut()
+        })
+    })
+}
+
+/// Parse a header, returning it along with the rest of the input.
+///
+/// This is for protocols where only a header is parsed, with the body being handed off to
+/// another subsystem.
+/// As all input is consumed, this works with [`Parser::parse`] while errors in the header still
+/// report their offset.
+///
+/// With [`LocatingSlice`], the body's offset can be recovered from the span of the header, like
+/// with [`Parser::with_span`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{digit1, line_ending};
+/// use winnow::combinator::{terminated, terminated_rest};
+///
+/// fn message<'i>(input: &mut &'i str) -> ModalResult<(u32, &'i str)> {
+///     terminated_rest(terminated(digit1.parse_to(), line_ending)).parse_next(input)
+/// }
+///
+/// assert_eq!(message.parse("42\nHello\nWorld"), Ok((42, "Hello\nWorld")));
+/// assert_eq!(message.parse("42\n"), Ok((42, "")));
+///
+/// let err = message.parse("4x\nHello").unwrap_err();
+/// assert_eq!(err.offset(), 1);
+/// ```
+pub fn terminated_rest<Input, Output, Error, ParseNext>(
+    mut parser: ParseNext,
+) -> impl Parser<Input, (Output, <Input as Stream>::Slice), Error>
+where
+    Input: Stream,
+    Error: ParserError<Input>,
+    ParseNext: Parser<Input, Output, Error>,
+{
+    trace("terminated_rest", move |input: &mut Input| {
+        let o = parser.parse_next(input)?;
+        Ok((o, input.finish()))
+    })
+}
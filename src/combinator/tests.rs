@@ -12,8 +12,10 @@ use crate::error::ParserError;
 #[cfg(feature = "alloc")]
 use crate::lib::std::borrow::ToOwned;
 use crate::prelude::*;
+use crate::stream::LocatingSlice;
 use crate::stream::Stream;
 use crate::token::take;
+use crate::token::take_till;
 use crate::ModalResult;
 use crate::Partial;
 
@@ -108,6 +110,103 @@ Ok(
     );
 }
 
+#[test]
+#[cfg(feature = "std")]
+fn append_keeps_leaf_error() {
+    use crate::error::{ContextError, InputError, StrContextValue, TreeError};
+    use crate::token::one_of;
+
+    fn item<'i, E: ParserError<&'i str>>(i: &mut &'i str) -> Result<char, E> {
+        terminated(one_of('0'..='9'), ';').parse_next(i)
+    }
+
+    // `InputError` points to where the item failed, not where the repetition started
+    type Input<'i> = InputError<&'i str>;
+    let leaf = ErrMode::Backtrack(InputError::at("a;"));
+    assert_eq!(
+        repeat::<_, _, Vec<_>, _, _>(2.., item::<ErrMode<Input<'_>>>)
+            .parse_peek("1;a;")
+            .unwrap_err(),
+        leaf
+    );
+    assert_eq!(
+        repeat::<_, _, Vec<_>, _, _>(3, item::<ErrMode<Input<'_>>>)
+            .parse_peek("1;a;")
+            .unwrap_err(),
+        leaf
+    );
+    assert_eq!(
+        separated::<_, _, Vec<_>, _, _, _, _>(2.., item::<ErrMode<Input<'_>>>, ',')
+            .parse_peek("1;,a;")
+            .unwrap_err(),
+        leaf
+    );
+    assert_eq!(
+        repeat_till::<_, _, Vec<_>, _, _, _, _>(0.., item::<ErrMode<Input<'_>>>, "end")
+            .parse_peek("1;a;")
+            .unwrap_err(),
+        leaf
+    );
+    assert_eq!(
+        repeat(2.., item::<ErrMode<Input<'_>>>)
+            .fold(|| 0, |acc, _| acc + 1)
+            .parse_peek("1;a;")
+            .unwrap_err(),
+        leaf
+    );
+    assert_eq!(
+        separated_foldl1(item::<ErrMode<Input<'_>>>, ',', |l, _, _| l)
+            .parse_peek("a;")
+            .unwrap_err(),
+        leaf
+    );
+
+    // `ContextError` keeps what the item expected
+    let err = repeat::<_, _, Vec<_>, _, _>(2.., item::<ErrMode<ContextError>>)
+        .parse_peek("1;a;")
+        .unwrap_err()
+        .into_inner()
+        .unwrap();
+    assert_eq!(
+        err.expected().collect::<Vec<_>>(),
+        [&StrContextValue::CharRange('0', '9')]
+    );
+
+    // `TreeError` stacks the repetition on top of the item's error
+    let err = repeat::<_, _, Vec<_>, _, _>(2.., item::<ErrMode<TreeError<&str>>>)
+        .parse_peek("1;a;")
+        .unwrap_err()
+        .into_inner()
+        .unwrap();
+    match err {
+        TreeError::Stack { base, stack } => {
+            assert!(matches!(*base, TreeError::Base(ref base) if base.input == "a;"));
+            assert_eq!(stack.len(), 1);
+        }
+        err => panic!("expected a stack: {err:?}"),
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn eof_reports_remaining() {
+    use crate::error::{ContextError, StrContextValue};
+
+    let err = eof::<_, ContextError>
+        .parse_peek("Hello, wörld!")
+        .unwrap_err();
+    assert_eq!(
+        err.expected().collect::<Vec<_>>(),
+        [&StrContextValue::Eof(14)]
+    );
+    assert_eq!(err.to_string(), "expected end of input (14 bytes left)");
+
+    let err = eof::<_, ContextError>
+        .parse_peek(&b"\x00\xff"[..])
+        .unwrap_err();
+    assert_eq!(err.to_string(), "expected end of input (2 bytes left)");
+}
+
 use crate::lib::std::convert::From;
 impl From<u32> for CustomError {
     fn from(_: u32) -> Self {
@@ -165,6 +264,218 @@ fn test_closure_compiles_195(input: &mut &[u8]) -> ModalResult<()> {
         .parse_next(input)
 }
 
+#[test]
+#[cfg(feature = "std")]
+fn test_parser_try_map_boxed() {
+    use crate::error::ContextError;
+    use crate::lib::std::fmt;
+
+    #[derive(Debug, PartialEq)]
+    struct Odd(u32);
+
+    impl fmt::Display for Odd {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} is odd", self.0)
+        }
+    }
+
+    impl std::error::Error for Odd {}
+
+    fn even(i: &mut &str) -> ModalResult<u32> {
+        digit
+            .try_map_boxed(|s: &str| {
+                let value = s.parse::<u32>()?;
+                if value % 2 == 1 {
+                    return Err(Odd(value).into());
+                }
+                Ok::<_, Box<dyn std::error::Error + Send + Sync>>(value)
+            })
+            .parse_next(i)
+    }
+
+    assert_eq!(even.parse_peek("42;"), Ok((";", 42)));
+
+    let mut input = "99999999999;";
+    let err = even.parse_next(&mut input).unwrap_err();
+    assert_eq!(input, "99999999999;");
+    let err = err.into_inner().unwrap();
+    assert!(err
+        .cause()
+        .unwrap()
+        .downcast_ref::<std::num::ParseIntError>()
+        .is_some());
+
+    let err = even.parse("41").unwrap_err();
+    assert_eq!(err.inner().cause().unwrap().downcast_ref(), Some(&Odd(41)));
+
+    fn message<'i>(i: &mut &'i str) -> ModalResult<&'i str, ContextError> {
+        digit
+            .try_map_boxed(|_| Err::<&str, _>("digits are not allowed"))
+            .parse_next(i)
+    }
+    let err = message.parse("1").unwrap_err();
+    assert_eq!(
+        err.inner().cause().unwrap().to_string(),
+        "digits are not allowed"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_parser_try_map_cause_chain() {
+    use crate::error::{ContextError, StrContext};
+    use crate::lib::std::fmt;
+
+    #[derive(Debug)]
+    struct InvalidPort(std::num::ParseIntError);
+
+    impl fmt::Display for InvalidPort {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "invalid port")
+        }
+    }
+
+    impl std::error::Error for InvalidPort {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    fn port(i: &mut &str) -> ModalResult<u16> {
+        preceded(
+            ':',
+            cut_err(digit.try_map(|s: &str| s.parse::<u16>().map_err(InvalidPort))),
+        )
+        .context(StrContext::Label("address"))
+        .parse_next(i)
+    }
+
+    let err = port.parse(":99999").unwrap_err();
+    let chain = |err: &ContextError| err.cause_chain().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        chain(err.inner()),
+        ["invalid port", "number too large to fit in target type"]
+    );
+    assert!(err
+        .inner()
+        .cause_chain()
+        .nth(1)
+        .unwrap()
+        .downcast_ref::<std::num::ParseIntError>()
+        .is_some());
+    assert_eq!(chain(&err.inner().clone()), chain(err.inner()));
+    assert_eq!(err.inner().to_string(), "invalid address\ninvalid port");
+    assert_eq!(
+        format!("{:#}", err.inner()),
+        "invalid address\ninvalid port: caused by number too large to fit in target type"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_parser_try_map_with_offset() {
+    use crate::lib::std::fmt;
+    use crate::stream::Location as _;
+    use crate::token::take_until;
+
+    #[derive(Debug)]
+    struct InvalidEscape;
+
+    impl fmt::Display for InvalidEscape {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "invalid escape")
+        }
+    }
+
+    impl std::error::Error for InvalidEscape {}
+
+    fn validate_escapes(s: &str) -> Result<&str, (usize, InvalidEscape)> {
+        let mut chars = s.char_indices();
+        while let Some((_, c)) = chars.next() {
+            if c == '\\' {
+                match chars.next() {
+                    Some((_, 'n' | 't' | '\\')) => {}
+                    Some((offset, _)) => return Err((offset, InvalidEscape)),
+                    None => return Err((s.len(), InvalidEscape)),
+                }
+            }
+        }
+        Ok(s)
+    }
+
+    fn block<'i>(i: &mut LocatingSlice<&'i str>) -> ModalResult<&'i str> {
+        delimited("```", take_until(0.., "```"), "```")
+            .take()
+            .try_map_with_offset(validate_escapes)
+            .parse_next(i)
+    }
+
+    assert_eq!(
+        block.parse(LocatingSlice::new("```\na\\n\nb\\t\n```")),
+        Ok("```\na\\n\nb\\t\n```")
+    );
+
+    let err = block
+        .parse(LocatingSlice::new("```\nab\ncd\nx\\qy\n```"))
+        .unwrap_err();
+    assert_eq!(err.offset(), 12);
+
+    let mut input = LocatingSlice::new("  ```\né\\é\n```");
+    let _ = input.next_slice(2);
+    assert!(block.parse_next(&mut input).is_err());
+    assert_eq!(input.current_token_start(), 9);
+
+    fn clamped<'i>(i: &mut &'i str) -> ModalResult<&'i str> {
+        digit
+            .try_map_with_offset(|_| Err::<&str, _>((100, InvalidEscape)))
+            .parse_next(i)
+    }
+    let mut input = "123;";
+    assert!(clamped.parse_next(&mut input).is_err());
+    assert_eq!(input, ";");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_parser_and_then_str() {
+    use crate::ascii::{alpha1, digit1};
+    use crate::token::{take, take_until};
+
+    fn field<'i>(i: &mut &'i [u8]) -> ModalResult<(&'i str, &'i str)> {
+        take(5u8).and_then_str((alpha1, digit1)).parse_next(i)
+    }
+
+    assert_eq!(
+        field.parse_peek(&b"ab123;"[..]),
+        Ok((&b";"[..], ("ab", "123")))
+    );
+
+    // Invalid UTF-8 is reported at the first invalid byte
+    let mut input = &b"ab\xe9123;"[..];
+    assert!(field.parse_next(&mut input).is_err());
+    assert_eq!(input, &b"\xe9123;"[..]);
+    let err = field.parse(&b"abc\xc3!"[..]).unwrap_err();
+    assert_eq!(err.offset(), 3);
+
+    // Inner errors are remapped to the outer input
+    let mut input = &b"abc;1;"[..];
+    assert!(field.parse_next(&mut input).is_err());
+    assert_eq!(input, &b";1;"[..]);
+
+    // The output doesn't need to end where the outer parser stopped
+    fn quoted<'i>(i: &mut &'i [u8]) -> ModalResult<&'i str> {
+        delimited('"', take_until(0.., '"'), '"')
+            .and_then_str(alpha1)
+            .parse_next(i)
+    }
+
+    assert_eq!(quoted.parse_peek(&b"\"abc\"."[..]), Ok((&b"."[..], "abc")));
+    let err = quoted.parse(&b"\"ab\xff\""[..]).unwrap_err();
+    assert_eq!(err.offset(), 3);
+    let err = quoted.parse(&b"\"1\""[..]).unwrap_err();
+    assert_eq!(err.offset(), 1);
+}
+
 #[test]
 fn test_parser_verify_map() {
     let input: &[u8] = &[50][..];
@@ -201,6 +512,50 @@ Ok(
     );
 }
 
+#[test]
+fn test_parser_verify_map_taken() {
+    fn literal_number<'i>(
+        input: &mut &'i str,
+    ) -> ModalResult<u64, crate::error::InputError<&'i str>> {
+        (
+            'x',
+            digit
+                .parse_to::<u64>()
+                .verify_map_taken(|n, s: &&str| if s.len() <= 3 { Some(n) } else { None }),
+        )
+            .map(|(_, n)| n)
+            .parse_next(input)
+    }
+
+    assert_parse!(
+        literal_number.parse_peek("x123;"),
+        str![[r#"
+Ok(
+    (
+        ";",
+        123,
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        literal_number.parse_peek("x0012;"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "0012;",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
 #[test]
 fn test_parser_map_parser() {
     let input: &[u8] = &[100, 101, 102, 103, 104][..];
@@ -318,7 +673,9 @@ Ok(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            2,
+        ),
     ),
 )
 
@@ -328,33 +685,29 @@ Err(
 }
 
 #[test]
-fn peek_test() {
-    fn peek_literal<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
-        peek("abcd").parse_next(i)
+fn opt_cut_test() {
+    fn opt_cut<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, Option<&'i [u8]>> {
+        opt(preceded("ab", cut_err("cd"))).parse_next(i)
     }
 
     assert_parse!(
-        peek_literal.parse_peek(Partial::new(&b"abcdef"[..])),
+        opt_cut.parse_peek(Partial::new(&b"abcdef"[..])),
         str![[r#"
 Ok(
     (
         Partial {
             input: [
-                97,
-                98,
-                99,
-                100,
                 101,
                 102,
             ],
             partial: true,
         },
-        [
-            97,
-            98,
-            99,
-            100,
-        ],
+        Some(
+            [
+                99,
+                100,
+            ],
+        ),
     ),
 )
 
@@ -362,11 +715,20 @@ Ok(
         .raw()
     );
     assert_parse!(
-        peek_literal.parse_peek(Partial::new(&b"ab"[..])),
+        opt_cut.parse_peek(Partial::new(&b"efgh"[..])),
         str![[r#"
-Err(
-    Incomplete(
-        Unknown,
+Ok(
+    (
+        Partial {
+            input: [
+                101,
+                102,
+                103,
+                104,
+            ],
+            partial: true,
+        },
+        None,
     ),
 )
 
@@ -374,16 +736,15 @@ Err(
         .raw()
     );
     assert_parse!(
-        peek_literal.parse_peek(Partial::new(&b"xxx"[..])),
+        opt_cut.parse_peek(Partial::new(&b"abef"[..])),
         str![[r#"
 Err(
-    Backtrack(
+    Cut(
         InputError {
             input: Partial {
                 input: [
-                    120,
-                    120,
-                    120,
+                    101,
+                    102,
                 ],
                 partial: true,
             },
@@ -391,32 +752,51 @@ Err(
     ),
 )
 
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        opt_cut.parse_peek(Partial::new(&b"abc"[..])),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
 "#]]
         .raw()
     );
 }
 
 #[test]
-fn not_test() {
-    fn not_aaa<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, ()> {
-        not("aaa").parse_next(i)
+fn opt_all_test() {
+    fn opt_all_cut<'i>(
+        i: &mut Partial<&'i [u8]>,
+    ) -> TestResult<Partial<&'i [u8]>, Option<&'i [u8]>> {
+        opt_all(preceded("ab", cut_err("cd"))).parse_next(i)
     }
 
     assert_parse!(
-        not_aaa.parse_peek(Partial::new(&b"aaa"[..])),
+        opt_all_cut.parse_peek(Partial::new(&b"abcdef"[..])),
         str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    97,
-                    97,
-                    97,
-                ],
-                partial: true,
-            },
+Ok(
+    (
+        Partial {
+            input: [
+                101,
+                102,
+            ],
+            partial: true,
         },
+        Some(
+            [
+                99,
+                100,
+            ],
+        ),
     ),
 )
 
@@ -424,11 +804,20 @@ Err(
         .raw()
     );
     assert_parse!(
-        not_aaa.parse_peek(Partial::new(&b"aa"[..])),
+        opt_all_cut.parse_peek(Partial::new(&b"efgh"[..])),
         str![[r#"
-Err(
-    Incomplete(
-        Unknown,
+Ok(
+    (
+        Partial {
+            input: [
+                101,
+                102,
+                103,
+                104,
+            ],
+            partial: true,
+        },
+        None,
     ),
 )
 
@@ -436,7 +825,7 @@ Err(
         .raw()
     );
     assert_parse!(
-        not_aaa.parse_peek(Partial::new(&b"abcd"[..])),
+        opt_all_cut.parse_peek(Partial::new(&b"abef"[..])),
         str![[r#"
 Ok(
     (
@@ -444,36 +833,25 @@ Ok(
             input: [
                 97,
                 98,
-                99,
-                100,
+                101,
+                102,
             ],
             partial: true,
         },
-        (),
+        None,
     ),
 )
 
 "#]]
         .raw()
     );
-}
-
-#[test]
-fn test_parser_verify() {
-    use crate::token::take;
-
-    fn test<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
-        take(5u8)
-            .verify(|slice: &[u8]| slice[0] == b'a')
-            .parse_next(i)
-    }
     assert_parse!(
-        test.parse_peek(Partial::new(&b"bcd"[..])),
+        opt_all_cut.parse_peek(Partial::new(&b"abc"[..])),
         str![[r#"
 Err(
     Incomplete(
         Size(
-            2,
+            1,
         ),
     ),
 )
@@ -482,76 +860,53 @@ Err(
         .raw()
     );
     assert_parse!(
-        test.parse_peek(Partial::new(&b"bcdefg"[..])),
+        opt_all_cut.parse_peek(Partial::new(&b"a"[..])),
         str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    98,
-                    99,
-                    100,
-                    101,
-                    102,
-                    103,
-                ],
-                partial: true,
-            },
-        },
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
         .raw()
     );
+}
+
+#[test]
+fn cond_else_test() {
+    fn version_field<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], u16> {
+        let version = u8.parse_next(i)?;
+        cond_else(2 <= version, u16(Endianness::Big), u8.map(u16::from)).parse_next(i)
+    }
+
     assert_parse!(
-        test.parse_peek(Partial::new(&b"abcdefg"[..])),
+        version_field.parse_peek(&[2, 1, 0, 7][..]),
         str![[r#"
 Ok(
     (
-        Partial {
-            input: [
-                102,
-                103,
-            ],
-            partial: true,
-        },
         [
-            97,
-            98,
-            99,
-            100,
-            101,
+            7,
         ],
+        256,
     ),
 )
 
 "#]]
         .raw()
     );
-}
-
-#[test]
-#[allow(unused)]
-fn test_parser_verify_ref() {
-    use crate::token::take;
-
-    let mut parser1 = take(3u8).verify(|s: &[u8]| s == &b"abc"[..]);
-
     assert_parse!(
-        parser1.parse_peek(&b"abcd"[..]),
+        version_field.parse_peek(&[1, 1, 0, 7][..]),
         str![[r#"
 Ok(
     (
         [
-            100,
-        ],
-        [
-            97,
-            98,
-            99,
+            0,
+            7,
         ],
+        1,
     ),
 )
 
@@ -559,16 +914,13 @@ Ok(
         .raw()
     );
     assert_parse!(
-        parser1.parse_peek(&b"defg"[..]),
+        version_field.parse_peek(&[2, 1][..]),
         str![[r#"
 Err(
     Backtrack(
         InputError {
             input: [
-                100,
-                101,
-                102,
-                103,
+                1,
             ],
         },
     ),
@@ -577,35 +929,34 @@ Err(
 "#]]
         .raw()
     );
-
-    fn parser2<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], u32> {
-        crate::binary::be_u32
-            .verify(|val: &u32| *val < 3)
-            .parse_next(i)
-    }
 }
 
 #[test]
-#[cfg(feature = "alloc")]
-fn test_parser_verify_alloc() {
-    use crate::token::take;
-    let mut parser1 = take(3u8)
-        .map(|s: &[u8]| s.to_vec())
-        .verify(|s: &[u8]| s == &b"abc"[..]);
+fn with_needed_offset_test() {
+    fn record<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        (take(2usize), (take(4usize), take(8usize)))
+            .map(|(_, (_, body))| body)
+            .parse_next(i)
+    }
+    fn buffered_record<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        with_needed_offset(record).parse_next(i)
+    }
+    fn nested_record<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        preceded(take(1usize), with_needed_offset(record)).parse_next(i)
+    }
+    fn till_nul<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        with_needed_offset(preceded(take(2usize), take_till(0.., 0))).parse_next(i)
+    }
 
+    let input = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10][..];
     assert_parse!(
-        parser1.parse_peek(&b"abcd"[..]),
+        record.parse_peek(Partial::new(input)),
         str![[r#"
-Ok(
-    (
-        [
-            100,
-        ],
-        [
-            97,
-            98,
-            99,
-        ],
+Err(
+    Incomplete(
+        Size(
+            4,
+        ),
     ),
 )
 
@@ -613,39 +964,27 @@ Ok(
         .raw()
     );
     assert_parse!(
-        parser1.parse_peek(&b"defg"[..]),
+        buffered_record.parse_peek(Partial::new(input)),
         str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: [
-                100,
-                101,
-                102,
-                103,
-            ],
-        },
+    Incomplete(
+        Size(
+            14,
+        ),
     ),
 )
 
 "#]]
         .raw()
     );
-}
-
-#[test]
-fn fail_test() {
-    let a = "string";
-    let b = "another string";
-
     assert_parse!(
-        fail::<_, &str, _>.parse_peek(a),
+        nested_record.parse_peek(Partial::new(input)),
         str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: "string",
-        },
+    Incomplete(
+        Size(
+            14,
+        ),
     ),
 )
 
@@ -653,41 +992,55 @@ Err(
         .raw()
     );
     assert_parse!(
-        fail::<_, &str, _>.parse_peek(b),
+        buffered_record.parse_peek(Partial::new(&input[..3])),
         str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: "another string",
-        },
+    Incomplete(
+        Size(
+            6,
+        ),
     ),
 )
 
 "#]]
         .raw()
     );
-}
-
-#[test]
-fn complete() {
-    fn err_test<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
-        let _ = "ijkl".parse_next(i)?;
-        "mnop".parse_next(i)
-    }
-    let a = &b"ijklmn"[..];
-
-    let res_a = err_test.parse_peek(a);
     assert_parse!(
-        res_a,
+        till_nul.parse_peek(Partial::new(input)),
         str![[r#"
 Err(
-    Backtrack(
-        InputError {
+    Incomplete(
+        Size(
+            11,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        buffered_record.parse_peek(Partial::new(&[0; 16][..])),
+        str![[r#"
+Ok(
+    (
+        Partial {
             input: [
-                109,
-                110,
+                0,
+                0,
             ],
+            partial: true,
         },
+        [
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ],
     ),
 )
 
@@ -697,42 +1050,255 @@ Err(
 }
 
 #[test]
-fn separated_pair_test() {
-    #[allow(clippy::type_complexity)]
-    fn sep_pair_abc_def<'i>(
-        i: &mut Partial<&'i [u8]>,
-    ) -> TestResult<Partial<&'i [u8]>, (&'i [u8], &'i [u8])> {
-        separated_pair("abc", ",", "def").parse_next(i)
+fn complete_scope_test() {
+    use crate::error::{InputError, Needed};
+    use crate::stream::{AsChar, StreamIsPartial};
+    use crate::token::take_while;
+
+    fn trailer<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        complete_scope(take_while(0.., AsChar::is_alpha)).parse_next(i)
+    }
+    fn pair<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        complete_scope(("ab", "cd").take()).parse_next(i)
+    }
+    fn cut_pair<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        complete_scope(("ab", cut_err("cd")).take()).parse_next(i)
+    }
+
+    // Success
+    let mut input = Partial::new(&b"abc"[..]);
+    assert_eq!(trailer.parse_next(&mut input), Ok(&b"abc"[..]));
+    assert!(input.is_partial());
+    assert_eq!(
+        take_while::<_, _, ErrMode<InputError<_>>>(0.., AsChar::is_alpha).parse_next(&mut input),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+
+    // Backtrack
+    let mut input = Partial::new(&b"ab"[..]);
+    assert!(pair.parse_next(&mut input).unwrap_err().is_backtrack());
+    assert!(input.is_partial());
+
+    // Cut
+    let mut input = Partial::new(&b"abxx"[..]);
+    assert!(matches!(
+        cut_pair.parse_next(&mut input),
+        Err(ErrMode::Cut(_))
+    ));
+    assert!(input.is_partial());
+
+    // An already-complete input stays complete
+    let mut input = Partial::new(&b"abc"[..]);
+    let _ = input.complete();
+    assert_eq!(trailer.parse_next(&mut input), Ok(&b"abc"[..]));
+    assert!(!input.is_partial());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn expect_complete_test() {
+    use crate::binary::be_u16;
+    use crate::error::{ContextError, Needed, StrContext};
+    use crate::Bytes;
+
+    type Input<'i> = Partial<&'i Bytes>;
+
+    fn frame(i: &mut Input<'_>) -> ModalResult<u16> {
+        (u8, be_u16.expect_complete(StrContext::Label("checksum")))
+            .map(|(_, checksum)| checksum)
+            .parse_next(i)
+    }
+
+    assert_eq!(
+        frame.parse_peek(Partial::new(Bytes::new(b"\x01\x00\x02rest"))),
+        Ok((Partial::new(Bytes::new(b"rest")), 2))
+    );
+
+    // Only the checksum is known to be complete
+    assert_eq!(
+        frame.parse_peek(Partial::new(Bytes::new(b""))),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+
+    let err = frame
+        .parse_peek(Partial::new(Bytes::new(b"\x01\x00")))
+        .unwrap_err();
+    let err = match err {
+        ErrMode::Backtrack(err) => err,
+        err => panic!("expected backtrack, got {err:?}"),
+    };
+    assert_eq!(
+        err.context().collect::<Vec<_>>(),
+        vec![&StrContext::Label("checksum")]
+    );
+    assert_eq!(err.to_string(), "invalid checksum");
+
+    // Errors other than `Incomplete` are passed through untouched
+    let err: ErrMode<ContextError> = be_u16
+        .verify(|c| *c == 0)
+        .expect_complete(StrContext::Label("checksum"))
+        .parse_peek(Partial::new(Bytes::new(b"\x00\x01")))
+        .unwrap_err();
+    assert_eq!(err, ErrMode::Backtrack(ContextError::new()));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn parse_iter_test() {
+    use crate::error::InputError;
+
+    // The same parser, and its state, is used for every input
+    let mut calls = 0;
+    let mut parser = |i: &mut &'static str| {
+        calls += 1;
+        digit::<_, InputError<_>>.parse_next(i)
+    };
+
+    let mut records = parser.by_ref().parse_iter(vec!["1", "22", "3x", "x"]);
+    assert_eq!(records.size_hint(), (4, Some(4)));
+    assert_eq!(records.next(), Some(Ok("1")));
+    assert_eq!(records.next(), Some(Ok("22")));
+    let err = records.next().unwrap().unwrap_err();
+    assert!(err.is_trailing());
+    assert_eq!(err.offset(), 1);
+    let err = records.next().unwrap().unwrap_err();
+    assert!(!err.is_trailing());
+    assert_eq!(err.offset(), 0);
+    assert_eq!(records.next(), None);
+    assert_eq!(calls, 4);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn todo_with_test() {
+    use crate::error::{ContextError, StrContext, TodoError};
+
+    fn list<'i>(i: &mut &'i str) -> ModalResult<Vec<&'i str>> {
+        todo_with("list").parse_next(i)
+    }
+
+    fn value<'i>(i: &mut &'i str) -> ModalResult<Vec<&'i str>> {
+        alt((digit.map(|d| vec![d]), list, "[]".value(vec![]))).parse_next(i)
+    }
+
+    assert_eq!(value.parse_peek("1"), Ok(("", vec!["1"])));
+
+    // `Cut` keeps `alt` from skipping over the unimplemented rule
+    let err: ErrMode<ContextError> = value.parse_peek("[]").unwrap_err();
+    let ErrMode::Cut(err) = err else {
+        panic!("expected `Cut`, got {err:?}");
+    };
+    assert_eq!(
+        err.context().collect::<Vec<_>>(),
+        vec![&StrContext::Label("list")]
+    );
+    let cause = err.cause().unwrap().downcast_ref::<TodoError>().unwrap();
+    assert_eq!(cause.rule(), "list");
+
+    let err = value.parse("[]").unwrap_err();
+    snapbox::assert_data_eq!(
+        err.to_string(),
+        str![[r#"
+[]
+^
+invalid list
+not yet implemented: rule list
+"#]]
+    );
+}
+
+#[test]
+fn from_peek_fn_test() {
+    use crate::ascii::alpha1;
+    use crate::error::{InputError, Needed};
+
+    type PeekResult<I, O> = Result<(I, O), ErrMode<InputError<I>>>;
+
+    // Old style
+    fn number(i: Partial<&str>) -> PeekResult<Partial<&str>, &str> {
+        digit.parse_peek(i)
+    }
+    fn key(i: Partial<&str>) -> PeekResult<Partial<&str>, &str> {
+        let (i, key) = alpha1.parse_peek(i)?;
+        let (i, _) = '='.parse_peek(i)?;
+        Ok((i, key))
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Pair<'i> {
+        key: &'i str,
+        value: &'i str,
+    }
+
+    // New style
+    fn pair<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, Pair<'i>> {
+        seq!(Pair {
+            key: from_peek_fn(key),
+            value: alt((from_peek_fn(number), alpha1)),
+            _: ';',
+        })
+        .parse_next(i)
+    }
+
+    assert_eq!(
+        pair.parse_peek(Partial::new("a=1;")),
+        Ok((
+            Partial::new(""),
+            Pair {
+                key: "a",
+                value: "1"
+            }
+        ))
+    );
+    assert_eq!(
+        pair.parse_peek(Partial::new("a=b;")),
+        Ok((
+            Partial::new(""),
+            Pair {
+                key: "a",
+                value: "b"
+            }
+        ))
+    );
+    assert_eq!(
+        pair.parse_peek(Partial::new("a=12")),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+    // The error from the old style parser is kept as-is
+    assert_eq!(
+        pair.parse_peek(Partial::new("a+1;")),
+        Err(ErrMode::Backtrack(InputError::at(Partial::new("+1;"))))
+    );
+}
+
+#[test]
+fn peek_test() {
+    fn peek_literal<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        peek("abcd").parse_next(i)
     }
 
     assert_parse!(
-        sep_pair_abc_def.parse_peek(Partial::new(&b"abc,defghijkl"[..])),
+        peek_literal.parse_peek(Partial::new(&b"abcdef"[..])),
         str![[r#"
 Ok(
     (
         Partial {
             input: [
-                103,
-                104,
-                105,
-                106,
-                107,
-                108,
-            ],
-            partial: true,
-        },
-        (
-            [
                 97,
                 98,
                 99,
-            ],
-            [
                 100,
                 101,
                 102,
             ],
-        ),
+            partial: true,
+        },
+        [
+            97,
+            98,
+            99,
+            100,
+        ],
     ),
 )
 
@@ -740,23 +1306,13 @@ Ok(
         .raw()
     );
     assert_parse!(
-        sep_pair_abc_def.parse_peek(Partial::new(&b"ab"[..])),
+        peek_literal.parse_peek(Partial::new(&b"ab"[..])),
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
-    ),
-)
-
-"#]]
-        .raw()
-    );
-    assert_parse!(
-        sep_pair_abc_def.parse_peek(Partial::new(&b"abc,d"[..])),
-        str![[r#"
-Err(
-    Incomplete(
-        Unknown,
+        Size(
+            2,
+        ),
     ),
 )
 
@@ -764,7 +1320,7 @@ Err(
         .raw()
     );
     assert_parse!(
-        sep_pair_abc_def.parse_peek(Partial::new(&b"xxx"[..])),
+        peek_literal.parse_peek(Partial::new(&b"xxx"[..])),
         str![[r#"
 Err(
     Backtrack(
@@ -784,21 +1340,25 @@ Err(
 "#]]
         .raw()
     );
+}
+
+#[test]
+fn not_test() {
+    fn not_aaa<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, ()> {
+        not("aaa").parse_next(i)
+    }
+
     assert_parse!(
-        sep_pair_abc_def.parse_peek(Partial::new(&b"xxx,def"[..])),
+        not_aaa.parse_peek(Partial::new(&b"aaa"[..])),
         str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
                 input: [
-                    120,
-                    120,
-                    120,
-                    44,
-                    100,
-                    101,
-                    102,
+                    97,
+                    97,
+                    97,
                 ],
                 partial: true,
             },
@@ -810,101 +1370,59 @@ Err(
         .raw()
     );
     assert_parse!(
-        sep_pair_abc_def.parse_peek(Partial::new(&b"abc,xxx"[..])),
+        not_aaa.parse_peek(Partial::new(&b"aa"[..])),
         str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    120,
-                    120,
-                    120,
-                ],
-                partial: true,
-            },
-        },
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
         .raw()
     );
-}
-
-#[test]
-fn preceded_test() {
-    fn preceded_abcd_efgh<'i>(
-        i: &mut Partial<&'i [u8]>,
-    ) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
-        preceded("abcd", "efgh").parse_next(i)
-    }
-
     assert_parse!(
-        preceded_abcd_efgh.parse_peek(Partial::new(&b"abcdefghijkl"[..])),
+        not_aaa.parse_peek(Partial::new(&b"abcd"[..])),
         str![[r#"
 Ok(
     (
         Partial {
             input: [
-                105,
-                106,
-                107,
-                108,
+                97,
+                98,
+                99,
+                100,
             ],
             partial: true,
         },
-        [
-            101,
-            102,
-            103,
-            104,
-        ],
+        (),
     ),
 )
 
 "#]]
         .raw()
     );
-    assert_parse!(
-        preceded_abcd_efgh.parse_peek(Partial::new(&b"ab"[..])),
-        str![[r#"
-Err(
-    Incomplete(
-        Unknown,
-    ),
-)
+}
 
-"#]]
-        .raw()
-    );
-    assert_parse!(
-        preceded_abcd_efgh.parse_peek(Partial::new(&b"abcde"[..])),
-        str![[r#"
-Err(
-    Incomplete(
-        Unknown,
-    ),
-)
+#[test]
+fn test_parser_verify() {
+    use crate::token::take;
 
-"#]]
-        .raw()
-    );
+    fn test<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        take(5u8)
+            .verify(|slice: &[u8]| slice[0] == b'a')
+            .parse_next(i)
+    }
     assert_parse!(
-        preceded_abcd_efgh.parse_peek(Partial::new(&b"xxx"[..])),
+        test.parse_peek(Partial::new(&b"bcd"[..])),
         str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    120,
-                    120,
-                    120,
-                ],
-                partial: true,
-            },
-        },
+    Incomplete(
+        Size(
+            2,
+        ),
     ),
 )
 
@@ -912,20 +1430,19 @@ Err(
         .raw()
     );
     assert_parse!(
-        preceded_abcd_efgh.parse_peek(Partial::new(&b"xxxxdef"[..])),
+        test.parse_peek(Partial::new(&b"bcdefg"[..])),
         str![[r#"
 Err(
     Backtrack(
         InputError {
             input: Partial {
                 input: [
-                    120,
-                    120,
-                    120,
-                    120,
+                    98,
+                    99,
                     100,
                     101,
                     102,
+                    103,
                 ],
                 partial: true,
             },
@@ -937,20 +1454,24 @@ Err(
         .raw()
     );
     assert_parse!(
-        preceded_abcd_efgh.parse_peek(Partial::new(&b"abcdxxx"[..])),
+        test.parse_peek(Partial::new(&b"abcdefg"[..])),
         str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    120,
-                    120,
-                    120,
-                ],
-                partial: true,
-            },
+Ok(
+    (
+        Partial {
+            input: [
+                102,
+                103,
+            ],
+            partial: true,
         },
+        [
+            97,
+            98,
+            99,
+            100,
+            101,
+        ],
     ),
 )
 
@@ -960,32 +1481,24 @@ Err(
 }
 
 #[test]
-fn terminated_test() {
-    fn terminated_abcd_efgh<'i>(
-        i: &mut Partial<&'i [u8]>,
-    ) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
-        terminated("abcd", "efgh").parse_next(i)
-    }
+#[allow(unused)]
+fn test_parser_verify_ref() {
+    use crate::token::take;
+
+    let mut parser1 = take(3u8).verify(|s: &[u8]| s == &b"abc"[..]);
 
     assert_parse!(
-        terminated_abcd_efgh.parse_peek(Partial::new(&b"abcdefghijkl"[..])),
+        parser1.parse_peek(&b"abcd"[..]),
         str![[r#"
 Ok(
     (
-        Partial {
-            input: [
-                105,
-                106,
-                107,
-                108,
-            ],
-            partial: true,
-        },
+        [
+            100,
+        ],
         [
             97,
             98,
             99,
-            100,
         ],
     ),
 )
@@ -994,23 +1507,53 @@ Ok(
         .raw()
     );
     assert_parse!(
-        terminated_abcd_efgh.parse_peek(Partial::new(&b"ab"[..])),
+        parser1.parse_peek(&b"defg"[..]),
         str![[r#"
 Err(
-    Incomplete(
-        Unknown,
+    Backtrack(
+        InputError {
+            input: [
+                100,
+                101,
+                102,
+                103,
+            ],
+        },
     ),
 )
 
 "#]]
         .raw()
     );
+
+    fn parser2<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], u32> {
+        crate::binary::be_u32
+            .verify(|val: &u32| *val < 3)
+            .parse_next(i)
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_parser_verify_alloc() {
+    use crate::token::take;
+    let mut parser1 = take(3u8)
+        .map(|s: &[u8]| s.to_vec())
+        .verify(|s: &[u8]| s == &b"abc"[..]);
+
     assert_parse!(
-        terminated_abcd_efgh.parse_peek(Partial::new(&b"abcde"[..])),
+        parser1.parse_peek(&b"abcd"[..]),
         str![[r#"
-Err(
-    Incomplete(
-        Unknown,
+Ok(
+    (
+        [
+            100,
+        ],
+        [
+            97,
+            98,
+            99,
+        ],
     ),
 )
 
@@ -1018,19 +1561,17 @@ Err(
         .raw()
     );
     assert_parse!(
-        terminated_abcd_efgh.parse_peek(Partial::new(&b"xxx"[..])),
+        parser1.parse_peek(&b"defg"[..]),
         str![[r#"
 Err(
     Backtrack(
         InputError {
-            input: Partial {
-                input: [
-                    120,
-                    120,
-                    120,
-                ],
-                partial: true,
-            },
+            input: [
+                100,
+                101,
+                102,
+                103,
+            ],
         },
     ),
 )
@@ -1038,18 +1579,175 @@ Err(
 "#]]
         .raw()
     );
+}
+
+#[test]
+fn fail_test() {
+    let a = "string";
+    let b = "another string";
+
     assert_parse!(
-        terminated_abcd_efgh.parse_peek(Partial::new(&b"xxxxdef"[..])),
+        fail::<_, &str, _>.parse_peek(a),
         str![[r#"
 Err(
     Backtrack(
         InputError {
-            input: Partial {
-                input: [
+            input: "string",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        fail::<_, &str, _>.parse_peek(b),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "another string",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn complete() {
+    fn err_test<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+        let _ = "ijkl".parse_next(i)?;
+        "mnop".parse_next(i)
+    }
+    let a = &b"ijklmn"[..];
+
+    let res_a = err_test.parse_peek(a);
+    assert_parse!(
+        res_a,
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                109,
+                110,
+            ],
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn separated_pair_test() {
+    #[allow(clippy::type_complexity)]
+    fn sep_pair_abc_def<'i>(
+        i: &mut Partial<&'i [u8]>,
+    ) -> TestResult<Partial<&'i [u8]>, (&'i [u8], &'i [u8])> {
+        separated_pair("abc", ",", "def").parse_next(i)
+    }
+
+    assert_parse!(
+        sep_pair_abc_def.parse_peek(Partial::new(&b"abc,defghijkl"[..])),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                103,
+                104,
+                105,
+                106,
+                107,
+                108,
+            ],
+            partial: true,
+        },
+        (
+            [
+                97,
+                98,
+                99,
+            ],
+            [
+                100,
+                101,
+                102,
+            ],
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        sep_pair_abc_def.parse_peek(Partial::new(&b"ab"[..])),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        sep_pair_abc_def.parse_peek(Partial::new(&b"abc,d"[..])),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            2,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        sep_pair_abc_def.parse_peek(Partial::new(&b"xxx"[..])),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    120,
+                    120,
                     120,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        sep_pair_abc_def.parse_peek(Partial::new(&b"xxx,def"[..])),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
                     120,
                     120,
                     120,
+                    44,
                     100,
                     101,
                     102,
@@ -1064,7 +1762,7 @@ Err(
         .raw()
     );
     assert_parse!(
-        terminated_abcd_efgh.parse_peek(Partial::new(&b"abcdxxxx"[..])),
+        sep_pair_abc_def.parse_peek(Partial::new(&b"abc,xxx"[..])),
         str![[r#"
 Err(
     Backtrack(
@@ -1074,7 +1772,6 @@ Err(
                     120,
                     120,
                     120,
-                    120,
                 ],
                 partial: true,
             },
@@ -1088,20 +1785,21 @@ Err(
 }
 
 #[test]
-fn delimited_test() {
-    fn delimited_abc_def_ghi<'i>(
+fn preceded_test() {
+    fn preceded_abcd_efgh<'i>(
         i: &mut Partial<&'i [u8]>,
     ) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
-        delimited("abc", "def", "ghi").parse_next(i)
+        preceded("abcd", "efgh").parse_next(i)
     }
 
     assert_parse!(
-        delimited_abc_def_ghi.parse_peek(Partial::new(&b"abcdefghijkl"[..])),
+        preceded_abcd_efgh.parse_peek(Partial::new(&b"abcdefghijkl"[..])),
         str![[r#"
 Ok(
     (
         Partial {
             input: [
+                105,
                 106,
                 107,
                 108,
@@ -1109,9 +1807,10 @@ Ok(
             partial: true,
         },
         [
-            100,
             101,
             102,
+            103,
+            104,
         ],
     ),
 )
@@ -1120,23 +1819,13 @@ Ok(
         .raw()
     );
     assert_parse!(
-        delimited_abc_def_ghi.parse_peek(Partial::new(&b"ab"[..])),
-        str![[r#"
-Err(
-    Incomplete(
-        Unknown,
-    ),
-)
-
-"#]]
-        .raw()
-    );
-    assert_parse!(
-        delimited_abc_def_ghi.parse_peek(Partial::new(&b"abcde"[..])),
+        preceded_abcd_efgh.parse_peek(Partial::new(&b"ab"[..])),
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            2,
+        ),
     ),
 )
 
@@ -1144,11 +1833,13 @@ Err(
         .raw()
     );
     assert_parse!(
-        delimited_abc_def_ghi.parse_peek(Partial::new(&b"abcdefgh"[..])),
+        preceded_abcd_efgh.parse_peek(Partial::new(&b"abcde"[..])),
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            3,
+        ),
     ),
 )
 
@@ -1156,7 +1847,7 @@ Err(
         .raw()
     );
     assert_parse!(
-        delimited_abc_def_ghi.parse_peek(Partial::new(&b"xxx"[..])),
+        preceded_abcd_efgh.parse_peek(Partial::new(&b"xxx"[..])),
         str![[r#"
 Err(
     Backtrack(
@@ -1177,7 +1868,7 @@ Err(
         .raw()
     );
     assert_parse!(
-        delimited_abc_def_ghi.parse_peek(Partial::new(&b"xxxdefghi"[..])),
+        preceded_abcd_efgh.parse_peek(Partial::new(&b"xxxxdef"[..])),
         str![[r#"
 Err(
     Backtrack(
@@ -1187,12 +1878,10 @@ Err(
                     120,
                     120,
                     120,
+                    120,
                     100,
                     101,
                     102,
-                    103,
-                    104,
-                    105,
                 ],
                 partial: true,
             },
@@ -1204,7 +1893,7 @@ Err(
         .raw()
     );
     assert_parse!(
-        delimited_abc_def_ghi.parse_peek(Partial::new(&b"abcxxxghi"[..])),
+        preceded_abcd_efgh.parse_peek(Partial::new(&b"abcdxxx"[..])),
         str![[r#"
 Err(
     Backtrack(
@@ -1214,9 +1903,6 @@ Err(
                     120,
                     120,
                     120,
-                    103,
-                    104,
-                    105,
                 ],
                 partial: true,
             },
@@ -1227,125 +1913,1285 @@ Err(
 "#]]
         .raw()
     );
+}
+
+#[test]
+fn terminated_test() {
+    fn terminated_abcd_efgh<'i>(
+        i: &mut Partial<&'i [u8]>,
+    ) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        terminated("abcd", "efgh").parse_next(i)
+    }
+
     assert_parse!(
-        delimited_abc_def_ghi.parse_peek(Partial::new(&b"abcdefxxx"[..])),
+        terminated_abcd_efgh.parse_peek(Partial::new(&b"abcdefghijkl"[..])),
         str![[r#"
-Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    120,
-                    120,
-                    120,
-                ],
-                partial: true,
-            },
+Ok(
+    (
+        Partial {
+            input: [
+                105,
+                106,
+                107,
+                108,
+            ],
+            partial: true,
         },
+        [
+            97,
+            98,
+            99,
+            100,
+        ],
     ),
 )
 
 "#]]
         .raw()
     );
-}
+    assert_parse!(
+        terminated_abcd_efgh.parse_peek(Partial::new(&b"ab"[..])),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            2,
+        ),
+    ),
+)
 
-#[cfg(feature = "alloc")]
-#[test]
-fn alt_test() {
-    #[cfg(feature = "alloc")]
-    use crate::{
-        error::ParserError,
-        lib::std::{fmt::Debug, string::String},
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        terminated_abcd_efgh.parse_peek(Partial::new(&b"abcde"[..])),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            3,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        terminated_abcd_efgh.parse_peek(Partial::new(&b"xxx"[..])),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    120,
+                    120,
+                    120,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        terminated_abcd_efgh.parse_peek(Partial::new(&b"xxxxdef"[..])),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    120,
+                    120,
+                    120,
+                    120,
+                    100,
+                    101,
+                    102,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        terminated_abcd_efgh.parse_peek(Partial::new(&b"abcdxxxx"[..])),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    120,
+                    120,
+                    120,
+                    120,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn delimited_test() {
+    fn delimited_abc_def_ghi<'i>(
+        i: &mut Partial<&'i [u8]>,
+    ) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        delimited("abc", "def", "ghi").parse_next(i)
+    }
+
+    assert_parse!(
+        delimited_abc_def_ghi.parse_peek(Partial::new(&b"abcdefghijkl"[..])),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                106,
+                107,
+                108,
+            ],
+            partial: true,
+        },
+        [
+            100,
+            101,
+            102,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        delimited_abc_def_ghi.parse_peek(Partial::new(&b"ab"[..])),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        delimited_abc_def_ghi.parse_peek(Partial::new(&b"abcde"[..])),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        delimited_abc_def_ghi.parse_peek(Partial::new(&b"abcdefgh"[..])),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        delimited_abc_def_ghi.parse_peek(Partial::new(&b"xxx"[..])),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    120,
+                    120,
+                    120,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        delimited_abc_def_ghi.parse_peek(Partial::new(&b"xxxdefghi"[..])),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    120,
+                    120,
+                    120,
+                    100,
+                    101,
+                    102,
+                    103,
+                    104,
+                    105,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        delimited_abc_def_ghi.parse_peek(Partial::new(&b"abcxxxghi"[..])),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    120,
+                    120,
+                    120,
+                    103,
+                    104,
+                    105,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        delimited_abc_def_ghi.parse_peek(Partial::new(&b"abcdefxxx"[..])),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    120,
+                    120,
+                    120,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn subparse_test() {
+    use crate::ascii::{alpha1, digit1};
+    use crate::binary::length_take;
+    use crate::stream::Location as _;
+
+    fn record<'i>(i: &mut LocatingSlice<&'i str>) -> ModalResult<(&'i str, &'i str)> {
+        subparse(
+            length_take(digit1.parse_to::<usize>()),
+            separated_pair(alpha1, ';', alpha1),
+        )
+        .parse_next(i)
+    }
+
+    assert_eq!(
+        record
+            .parse_peek(LocatingSlice::new("5ab;cd!"))
+            .map(|(i, o)| (*i, o)),
+        Ok(("!", ("ab", "cd")))
+    );
+
+    // Start of the slice
+    let err = record.parse(LocatingSlice::new("5;b;cd")).unwrap_err();
+    assert_eq!(err.offset(), 1);
+    // Middle of the slice
+    let err = record.parse(LocatingSlice::new("5ab:cd")).unwrap_err();
+    assert_eq!(err.offset(), 3);
+    // End of the slice, even though more input follows
+    let err = record.parse(LocatingSlice::new("3ab;cd")).unwrap_err();
+    assert_eq!(err.offset(), 4);
+    // Not all of the slice was consumed
+    let err = record.parse(LocatingSlice::new("6ab;cd!")).unwrap_err();
+    assert_eq!(err.offset(), 6);
+    // The slice itself failed
+    let err = record.parse(LocatingSlice::new("9ab;cd")).unwrap_err();
+    assert_eq!(err.offset(), 1);
+
+    // Locations within the slice are in the outer stream's coordinates
+    let mut input = LocatingSlice::new("5ab;cd");
+    let span: ModalResult<_> =
+        subparse(length_take(digit1.parse_to::<usize>()), alpha1.span()).parse_next(&mut input);
+    assert!(span.is_err());
+    assert_eq!(input.current_token_start(), 3);
+    let mut input = LocatingSlice::new("2ab");
+    let span: ModalResult<_> =
+        subparse(length_take(digit1.parse_to::<usize>()), alpha1.span()).parse_next(&mut input);
+    assert_eq!(span, Ok(1..3));
+
+    // The slice is complete
+    fn partial<'i>(i: &mut Partial<&'i str>) -> ModalResult<&'i str> {
+        subparse(length_take(digit1.parse_to::<usize>()), alpha1).parse_next(i)
+    }
+    assert_eq!(
+        partial.parse_peek(Partial::new("2ab;")),
+        Ok((Partial::new(";"), "ab"))
+    );
+}
+
+#[test]
+fn terminated_rest_test() {
+    use crate::ascii::{alpha1, line_ending};
+    use crate::lib::std::ops::Range;
+
+    fn message<'i>(i: &mut LocatingSlice<&'i str>) -> ModalResult<(&'i str, &'i str)> {
+        terminated_rest(terminated(alpha1, line_ending)).parse_next(i)
+    }
+
+    assert_eq!(
+        message.parse(LocatingSlice::new("GET\r\nbody\n")),
+        Ok(("GET", "body\n"))
+    );
+    assert_eq!(message.parse(LocatingSlice::new("GET\n")), Ok(("GET", "")));
+
+    let err = message.parse(LocatingSlice::new("GET body")).unwrap_err();
+    assert_eq!(err.offset(), 3);
+    let err = message.parse(LocatingSlice::new("")).unwrap_err();
+    assert_eq!(err.offset(), 0);
+
+    // The body starts where the header's span ends
+    fn spanned<'i>(
+        i: &mut LocatingSlice<&'i str>,
+    ) -> ModalResult<((&'i str, Range<usize>), &'i str)> {
+        terminated_rest(terminated(alpha1, line_ending).with_span()).parse_next(i)
+    }
+
+    let mut input = LocatingSlice::new("  PUT\nbody");
+    let _ = input.next_slice(2);
+    let ((header, header_span), body) = spanned.parse_next(&mut input).unwrap();
+    assert_eq!(header, "PUT");
+    assert_eq!(header_span, 2..6);
+    assert_eq!(body, "body");
+    assert_eq!(input.eof_offset(), 0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn delimited_cut_test() {
+    use crate::ascii::{alpha1, multispace0};
+    use crate::error::{ContextError, StrContext};
+
+    fn block<'i>(i: &mut LocatingSlice<&'i str>) -> ModalResult<Vec<&'i str>> {
+        delimited_cut(
+            ('{', multispace0),
+            repeat(0.., terminated(alpha1, (';', multispace0))),
+            '}',
+        )
+        .parse_next(i)
+    }
+
+    assert_eq!(
+        block.parse(LocatingSlice::new("{ a; b;\n}")),
+        Ok(vec!["a", "b"])
+    );
+
+    // Not committed until the opening delimiter
+    let err: ErrMode<ContextError> = block.parse_peek(LocatingSlice::new("a;}")).unwrap_err();
+    assert!(matches!(err, ErrMode::Backtrack(_)));
+
+    let err = preceded("fn() ", block)
+        .parse(LocatingSlice::new("fn() {\n  a;\n  b\n"))
+        .unwrap_err();
+    assert_eq!(err.offset(), 14);
+    assert_eq!(
+        err.inner().context().collect::<Vec<_>>(),
+        vec![&StrContext::Unclosed(5)]
+    );
+    // Line and column are only rendered with `std`
+    #[cfg(feature = "std")]
+    snapbox::assert_data_eq!(
+        err.to_string(),
+        str![[r#"
+parse error at line 3, column 3
+  |
+3 |   b
+  |   ^
+expected `}`
+unclosed delimiter at offset 5
+"#]]
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn alt_test() {
+    #[cfg(feature = "alloc")]
+    use crate::{
+        error::ParserError,
+        lib::std::{fmt::Debug, string::String},
     };
 
-    #[cfg(feature = "alloc")]
-    #[derive(Debug, Clone, Eq, PartialEq)]
-    struct ErrorStr(String);
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    struct ErrorStr(String);
+
+    #[cfg(feature = "alloc")]
+    impl From<u32> for ErrorStr {
+        fn from(i: u32) -> Self {
+            ErrorStr(format!("custom error code: {i}"))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<'a> From<&'a str> for ErrorStr {
+        fn from(i: &'a str) -> Self {
+            ErrorStr(format!("custom error message: {i}"))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<I: Stream + Debug> ParserError<I> for ErrorStr {
+        type Inner = Self;
+
+        fn from_input(input: &I) -> Self {
+            ErrorStr(format!("custom error message: ({input:?})"))
+        }
+
+        fn append(self, input: &I, _: &<I as Stream>::Checkpoint) -> Self {
+            ErrorStr(format!("custom error message: ({input:?}) - {self:?}"))
+        }
+
+        fn into_inner(self) -> Result<Self::Inner, Self> {
+            Ok(self)
+        }
+    }
+
+    fn work<'i>(input: &mut &'i [u8]) -> ModalResult<&'i [u8], ErrorStr> {
+        Ok(input.finish())
+    }
+
+    #[allow(unused_variables)]
+    fn dont_work<'i>(input: &mut &'i [u8]) -> ModalResult<&'i [u8], ErrorStr> {
+        Err(ErrMode::Backtrack(ErrorStr("abcd".to_owned())))
+    }
+
+    fn work2<'i>(_input: &mut &'i [u8]) -> ModalResult<&'i [u8], ErrorStr> {
+        Ok(&b""[..])
+    }
+
+    fn alt1<'i>(i: &mut &'i [u8]) -> ModalResult<&'i [u8], ErrorStr> {
+        alt((dont_work, dont_work)).parse_next(i)
+    }
+    fn alt2<'i>(i: &mut &'i [u8]) -> ModalResult<&'i [u8], ErrorStr> {
+        alt((dont_work, work)).parse_next(i)
+    }
+    fn alt3<'i>(i: &mut &'i [u8]) -> ModalResult<&'i [u8], ErrorStr> {
+        alt((dont_work, dont_work, work2, dont_work)).parse_next(i)
+    }
+    //named!(alt1, alt!(dont_work | dont_work));
+    //named!(alt2, alt!(dont_work | work));
+    //named!(alt3, alt!(dont_work | dont_work | work2 | dont_work));
+
+    let a = &b"abcd"[..];
+    assert_eq!(
+        alt1.parse_peek(a),
+        Err(ErrMode::Backtrack(ErrorStr(
+            "custom error message: ([97, 98, 99, 100]) - ErrorStr(\"abcd\")".to_owned()
+        )))
+    );
+    assert_eq!(alt2.parse_peek(a), Ok((&b""[..], a)));
+    assert_eq!(alt3.parse_peek(a), Ok((a, &b""[..])));
+
+    fn alt4<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+        alt(("abcd", "efgh")).parse_next(i)
+    }
+    let b = &b"efgh"[..];
+    assert_parse!(
+        alt4.parse_peek(a),
+        str![[r#"
+Ok(
+    (
+        [],
+        [
+            97,
+            98,
+            99,
+            100,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        alt4.parse_peek(b),
+        str![[r#"
+Ok(
+    (
+        [],
+        [
+            101,
+            102,
+            103,
+            104,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn alt_incomplete() {
+    fn alt1<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        alt(("a", "bc", "def")).parse_next(i)
+    }
+
+    let a = &b""[..];
+    assert_parse!(
+        alt1.parse_peek(Partial::new(a)),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    let a = &b"b"[..];
+    assert_parse!(
+        alt1.parse_peek(Partial::new(a)),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    let a = &b"bcd"[..];
+    assert_parse!(
+        alt1.parse_peek(Partial::new(a)),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                100,
+            ],
+            partial: true,
+        },
+        [
+            98,
+            99,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    let a = &b"cde"[..];
+    assert_parse!(
+        alt1.parse_peek(Partial::new(a)),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    99,
+                    100,
+                    101,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    let a = &b"de"[..];
+    assert_parse!(
+        alt1.parse_peek(Partial::new(a)),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    let a = &b"defg"[..];
+    assert_parse!(
+        alt1.parse_peek(Partial::new(a)),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                103,
+            ],
+            partial: true,
+        },
+        [
+            100,
+            101,
+            102,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn alt_furthest_error() {
+    use crate::error::{ContextError, InputError, StrContextValue};
+
+    fn input_error<'i>(i: &mut &'i str) -> ModalResult<&'i str, InputError<&'i str>> {
+        alt((("a", "b").take(), ("a", "c", "d").take(), ("e", "f").take())).parse_next(i)
+    }
+    // The second case got the furthest, even though the third case was tried last
+    let mut input = "acx";
+    let err = input_error.parse_next(&mut input).unwrap_err();
+    assert_eq!(err, ErrMode::Backtrack(InputError::at("x")));
+    assert_eq!(input, "x");
+
+    fn context_error(i: &mut &str) -> ModalResult<(char, char), ContextError> {
+        alt((('a', 'b'), ('a', 'c'), ('d', 'e'))).parse_next(i)
+    }
+    // Cases that fail at the same position are combined
+    let mut input = "ax";
+    let err = context_error
+        .parse_next(&mut input)
+        .unwrap_err()
+        .into_inner()
+        .unwrap();
+    assert_eq!(
+        err.expected().collect::<Vec<_>>(),
+        [
+            &StrContextValue::CharLiteral('b'),
+            &StrContextValue::CharLiteral('c')
+        ]
+    );
+    assert_eq!(input, "x");
+
+    fn slice<'i>(i: &mut &'i str) -> ModalResult<&'i str, InputError<&'i str>> {
+        alt(&mut [("a", "bd").take(), ("ab", "c").take()][..]).parse_next(i)
+    }
+    let mut input = "abx";
+    let err = slice.parse_next(&mut input).unwrap_err();
+    assert_eq!(err, ErrMode::Backtrack(InputError::at("x")));
+    assert_eq!(input, "x");
+}
+
+#[test]
+fn alt_cut() {
+    use crate::error::InputError;
+    use ::core::cell::Cell;
+
+    let tried = Cell::new(false);
+    let mut parser = alt::<_, _, ErrMode<InputError<&str>>, _>((
+        ('a', cut_err('b')).void(),
+        ('a', 'c').void().map(|()| tried.set(true)),
+    ));
+    let mut input = "ac";
+    let err = parser.parse_next(&mut input).unwrap_err();
+    assert!(matches!(err, ErrMode::Cut(_)));
+    assert_eq!(input, "c");
+    assert!(!tried.get());
+}
+
+#[test]
+fn alt_array() {
+    fn alt1<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+        alt(["a", "bc", "def"]).parse_next(i)
+    }
+
+    let i = &b"a"[..];
+    assert_parse!(
+        alt1.parse_peek(i),
+        str![[r#"
+Ok(
+    (
+        [],
+        [
+            97,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+
+    let i = &b"bc"[..];
+    assert_parse!(
+        alt1.parse_peek(i),
+        str![[r#"
+Ok(
+    (
+        [],
+        [
+            98,
+            99,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+
+    let i = &b"defg"[..];
+    assert_parse!(
+        alt1.parse_peek(i),
+        str![[r#"
+Ok(
+    (
+        [
+            103,
+        ],
+        [
+            100,
+            101,
+            102,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+
+    let i = &b"z"[..];
+    assert_parse!(
+        alt1.parse_peek(i),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                122,
+            ],
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn alt_dynamic_array() {
+    fn alt1<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+        alt(&mut ["a", "bc", "def"][..]).parse_next(i)
+    }
+
+    let a = &b"a"[..];
+    assert_parse!(
+        alt1.parse_peek(a),
+        str![[r#"
+Ok(
+    (
+        [],
+        [
+            97,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+
+    let bc = &b"bc"[..];
+    assert_parse!(
+        alt1.parse_peek(bc),
+        str![[r#"
+Ok(
+    (
+        [],
+        [
+            98,
+            99,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
 
-    #[cfg(feature = "alloc")]
-    impl From<u32> for ErrorStr {
-        fn from(i: u32) -> Self {
-            ErrorStr(format!("custom error code: {i}"))
-        }
+    let defg = &b"defg"[..];
+    assert_parse!(
+        alt1.parse_peek(defg),
+        str![[r#"
+Ok(
+    (
+        [
+            103,
+        ],
+        [
+            100,
+            101,
+            102,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn alt_boxed_schema() {
+    use crate::ascii::{alpha1, dec_uint};
+    use crate::error::ContextError;
+    use crate::lib::std::boxed::Box;
+
+    #[derive(Debug, PartialEq)]
+    enum Value<'i> {
+        Int(u32),
+        Bool(bool),
+        Word(&'i str),
     }
 
-    #[cfg(feature = "alloc")]
-    impl<'a> From<&'a str> for ErrorStr {
-        fn from(i: &'a str) -> Self {
-            ErrorStr(format!("custom error message: {i}"))
+    type BoxedParser<'i> = Box<dyn Parser<&'i str, Value<'i>, ErrMode<ContextError>> + 'i>;
+
+    fn field<'i>(kind: &str) -> BoxedParser<'i> {
+        match kind {
+            "int" => Box::new(dec_uint.map(Value::Int)),
+            // Consumes "tr" before failing on "tree", requiring a backtrack
+            "bool" => {
+                Box::new(alt((("tr", "ue").value(true), "false".value(false))).map(Value::Bool))
+            }
+            "word" => Box::new(alpha1.map(Value::Word)),
+            _ => unreachable!(),
         }
     }
 
-    #[cfg(feature = "alloc")]
-    impl<I: Stream + Debug> ParserError<I> for ErrorStr {
-        type Inner = Self;
+    let schema = ["int", "bool", "word"];
+    let mut value = alt(schema.iter().map(|kind| field(kind)).collect::<Vec<_>>());
+    assert_eq!(value.parse_peek("42,"), Ok((",", Value::Int(42))));
+    assert_eq!(value.parse_peek("true,"), Ok((",", Value::Bool(true))));
+    assert_eq!(value.parse_peek("tree,"), Ok((",", Value::Word("tree"))));
+    assert!(value.parse_peek(",").is_err());
+
+    // Trait objects can be nested in combinators without giving up ownership
+    let mut boxed: BoxedParser<'_> = Box::new(value);
+    let values: Vec<Value<'_>> = separated(1.., &mut *boxed, ',')
+        .parse("1,false,tree")
+        .unwrap();
+    assert_eq!(
+        values,
+        vec![Value::Int(1), Value::Bool(false), Value::Word("tree")]
+    );
+    assert_eq!(boxed.parse_peek("7"), Ok(("", Value::Int(7))));
+}
 
-        fn from_input(input: &I) -> Self {
-            ErrorStr(format!("custom error message: ({input:?})"))
-        }
+#[test]
+fn longest_operators() {
+    fn operator<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+        longest((">", ">=", ">>", ">>=", "=", "==", "=>")).parse_next(i)
+    }
 
-        fn append(self, input: &I, _: &<I as Stream>::Checkpoint) -> Self {
-            ErrorStr(format!("custom error message: ({input:?}) - {self:?}"))
-        }
+    assert_eq!(operator.parse_peek(">"), Ok(("", ">")));
+    assert_eq!(operator.parse_peek(">= 1"), Ok((" 1", ">=")));
+    assert_eq!(operator.parse_peek(">> 1"), Ok((" 1", ">>")));
+    assert_eq!(operator.parse_peek(">>=1"), Ok(("1", ">>=")));
+    assert_eq!(operator.parse_peek(">>>"), Ok((">", ">>")));
+    assert_eq!(operator.parse_peek("=>="), Ok(("=", "=>")));
+    assert_eq!(operator.parse_peek("==="), Ok(("=", "==")));
+    assert_parse!(
+        operator.parse_peek("<"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "<",
+        },
+    ),
+)
 
-        fn into_inner(self) -> Result<Self::Inner, Self> {
-            Ok(self)
-        }
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn longest_tie_goes_to_first() {
+    use crate::ascii::alpha1;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Token<'i> {
+        Keyword(&'i str),
+        Ident(&'i str),
+    }
+
+    fn token<'i>(i: &mut &'i str) -> TestResult<&'i str, Token<'i>> {
+        longest((
+            alt(("if", "in")).map(Token::Keyword),
+            alpha1.map(Token::Ident),
+        ))
+        .parse_next(i)
+    }
+
+    assert_eq!(token.parse_peek("if x"), Ok((" x", Token::Keyword("if"))));
+    assert_eq!(token.parse_peek("in"), Ok(("", Token::Keyword("in"))));
+    assert_eq!(token.parse_peek("iffy x"), Ok((" x", Token::Ident("iffy"))));
+    assert_eq!(token.parse_peek("x"), Ok(("", Token::Ident("x"))));
+
+    fn reversed<'i>(i: &mut &'i str) -> TestResult<&'i str, Token<'i>> {
+        longest((
+            alpha1.map(Token::Ident),
+            alt(("if", "in")).map(Token::Keyword),
+        ))
+        .parse_next(i)
+    }
+
+    assert_eq!(reversed.parse_peek("if x"), Ok((" x", Token::Ident("if"))));
+}
+
+#[test]
+fn longest_furthest_error() {
+    fn parser<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+        longest(("ab", ("a", "bc", "d").take(), "x")).parse_next(i)
+    }
+
+    assert_eq!(parser.parse_peek("abcd"), Ok(("", "abcd")));
+    assert_eq!(parser.parse_peek("abce"), Ok(("ce", "ab")));
+    assert_parse!(
+        parser.parse_peek("ac"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "c",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn longest_cut() {
+    use crate::error::InputError;
+    use ::core::cell::Cell;
+
+    let tried = Cell::new(false);
+    let mut parser = longest::<_, _, ErrMode<InputError<&str>>, _>((
+        "a".void(),
+        ('a', cut_err('b')).void(),
+        ('a', 'c').void().map(|()| tried.set(true)),
+    ));
+    let mut input = "ac";
+    let err = parser.parse_next(&mut input).unwrap_err();
+    assert!(matches!(err, ErrMode::Cut(_)));
+    assert_eq!(input, "c");
+    assert!(!tried.get());
+}
+
+#[test]
+fn longest_incomplete() {
+    fn operator<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, &'i str> {
+        longest((">", ">=", ">>")).parse_next(i)
+    }
+
+    assert_parse!(
+        operator.parse_peek(Partial::new(">")),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        operator.parse_peek(Partial::new(">= 1")),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: " 1",
+            partial: true,
+        },
+        ">=",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn longest_array() {
+    fn operator<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+        longest(["<", "<=", "<<", "<<="]).parse_next(i)
+    }
+
+    assert_eq!(operator.parse_peek("<<= 1"), Ok((" 1", "<<=")));
+    assert_eq!(operator.parse_peek("<= 1"), Ok((" 1", "<=")));
+    assert_eq!(operator.parse_peek("< 1"), Ok((" 1", "<")));
+    assert!(operator.parse_peek("> 1").is_err());
+}
+
+#[test]
+fn permutation_test() {
+    #[allow(clippy::type_complexity)]
+    fn perm<'i>(
+        i: &mut Partial<&'i [u8]>,
+    ) -> TestResult<Partial<&'i [u8]>, (&'i [u8], &'i [u8], &'i [u8])> {
+        permutation(("abcd", "efg", "hi")).parse_next(i)
     }
 
-    fn work<'i>(input: &mut &'i [u8]) -> ModalResult<&'i [u8], ErrorStr> {
-        Ok(input.finish())
-    }
+    let a = &b"abcdefghijk"[..];
+    assert_parse!(
+        perm.parse_peek(Partial::new(a)),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                106,
+                107,
+            ],
+            partial: true,
+        },
+        (
+            [
+                97,
+                98,
+                99,
+                100,
+            ],
+            [
+                101,
+                102,
+                103,
+            ],
+            [
+                104,
+                105,
+            ],
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    let b = &b"efgabcdhijk"[..];
+    assert_parse!(
+        perm.parse_peek(Partial::new(b)),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                106,
+                107,
+            ],
+            partial: true,
+        },
+        (
+            [
+                97,
+                98,
+                99,
+                100,
+            ],
+            [
+                101,
+                102,
+                103,
+            ],
+            [
+                104,
+                105,
+            ],
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    let c = &b"hiefgabcdjk"[..];
+    assert_parse!(
+        perm.parse_peek(Partial::new(c)),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                106,
+                107,
+            ],
+            partial: true,
+        },
+        (
+            [
+                97,
+                98,
+                99,
+                100,
+            ],
+            [
+                101,
+                102,
+                103,
+            ],
+            [
+                104,
+                105,
+            ],
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
 
-    #[allow(unused_variables)]
-    fn dont_work<'i>(input: &mut &'i [u8]) -> ModalResult<&'i [u8], ErrorStr> {
-        Err(ErrMode::Backtrack(ErrorStr("abcd".to_owned())))
-    }
+    let d = &b"efgxyzabcdefghi"[..];
+    assert_parse!(
+        perm.parse_peek(Partial::new(d)),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    120,
+                    121,
+                    122,
+                    97,
+                    98,
+                    99,
+                    100,
+                    101,
+                    102,
+                    103,
+                    104,
+                    105,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
 
-    fn work2<'i>(_input: &mut &'i [u8]) -> ModalResult<&'i [u8], ErrorStr> {
-        Ok(&b""[..])
-    }
+"#]]
+        .raw()
+    );
 
-    fn alt1<'i>(i: &mut &'i [u8]) -> ModalResult<&'i [u8], ErrorStr> {
-        alt((dont_work, dont_work)).parse_next(i)
-    }
-    fn alt2<'i>(i: &mut &'i [u8]) -> ModalResult<&'i [u8], ErrorStr> {
-        alt((dont_work, work)).parse_next(i)
-    }
-    fn alt3<'i>(i: &mut &'i [u8]) -> ModalResult<&'i [u8], ErrorStr> {
-        alt((dont_work, dont_work, work2, dont_work)).parse_next(i)
-    }
-    //named!(alt1, alt!(dont_work | dont_work));
-    //named!(alt2, alt!(dont_work | work));
-    //named!(alt3, alt!(dont_work | dont_work | work2 | dont_work));
+    let e = &b"efgabc"[..];
+    assert_parse!(
+        perm.parse_peek(Partial::new(e)),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
 
-    let a = &b"abcd"[..];
-    assert_eq!(
-        alt1.parse_peek(a),
-        Err(ErrMode::Backtrack(ErrorStr(
-            "custom error message: ([97, 98, 99, 100]) - ErrorStr(\"abcd\")".to_owned()
-        )))
+"#]]
+        .raw()
     );
-    assert_eq!(alt2.parse_peek(a), Ok((&b""[..], a)));
-    assert_eq!(alt3.parse_peek(a), Ok((a, &b""[..])));
+}
 
-    fn alt4<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
-        alt(("abcd", "efgh")).parse_next(i)
+#[test]
+fn permutation_optional_all() {
+    #[allow(clippy::type_complexity)]
+    fn perm<'i>(i: &mut &'i str) -> TestResult<&'i str, (Option<&'i str>, Option<&'i str>)> {
+        permutation((opt("ab"), opt("cd"))).parse_next(i)
     }
-    let b = &b"efgh"[..];
+
     assert_parse!(
-        alt4.parse_peek(a),
+        perm.parse_peek("cdab;"),
         str![[r#"
 Ok(
     (
-        [],
-        [
-            97,
-            98,
-            99,
-            100,
-        ],
+        ";",
+        (
+            Some(
+                "ab",
+            ),
+            Some(
+                "cd",
+            ),
+        ),
     ),
 )
 
@@ -1353,17 +3199,33 @@ Ok(
         .raw()
     );
     assert_parse!(
-        alt4.parse_peek(b),
+        perm.parse_peek("cd;"),
         str![[r#"
 Ok(
     (
-        [],
-        [
-            101,
-            102,
-            103,
-            104,
-        ],
+        ";",
+        (
+            None,
+            Some(
+                "cd",
+            ),
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        perm.parse_peek(";"),
+        str![[r#"
+Ok(
+    (
+        ";",
+        (
+            None,
+            None,
+        ),
     ),
 )
 
@@ -1373,74 +3235,106 @@ Ok(
 }
 
 #[test]
-fn alt_incomplete() {
-    fn alt1<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
-        alt(("a", "bc", "def")).parse_next(i)
+fn permutation_optional_mixed() {
+    #[allow(clippy::type_complexity)]
+    fn perm<'i>(i: &mut &'i str) -> TestResult<&'i str, (&'i str, Option<&'i str>, &'i str)> {
+        permutation(("ab", opt("cd"), "ef")).parse_next(i)
     }
 
-    let a = &b""[..];
     assert_parse!(
-        alt1.parse_peek(Partial::new(a)),
+        perm.parse_peek("efab;"),
         str![[r#"
-Err(
-    Incomplete(
-        Unknown,
+Ok(
+    (
+        ";",
+        (
+            "ab",
+            None,
+            "ef",
+        ),
     ),
 )
 
 "#]]
         .raw()
     );
-    let a = &b"b"[..];
     assert_parse!(
-        alt1.parse_peek(Partial::new(a)),
+        perm.parse_peek("cdefab;"),
         str![[r#"
-Err(
-    Incomplete(
-        Unknown,
+Ok(
+    (
+        ";",
+        (
+            "ab",
+            Some(
+                "cd",
+            ),
+            "ef",
+        ),
     ),
 )
 
 "#]]
         .raw()
     );
-    let a = &b"bcd"[..];
     assert_parse!(
-        alt1.parse_peek(Partial::new(a)),
+        perm.parse_peek("cdab;"),
         str![[r#"
-Ok(
-    (
-        Partial {
-            input: [
-                100,
-            ],
-            partial: true,
+Err(
+    Backtrack(
+        InputError {
+            input: ";",
         },
-        [
-            98,
-            99,
-        ],
     ),
 )
 
 "#]]
         .raw()
     );
-    let a = &b"cde"[..];
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn permutation_optional_missing() {
+    use crate::error::{ContextError, StrContext};
+
+    #[allow(clippy::type_complexity)]
+    fn perm<'i>(i: &mut &'i str) -> ModalResult<(&'i str, Option<&'i str>, &'i str), ContextError> {
+        permutation((
+            "ab".context(StrContext::Label("ab")),
+            opt("cd"),
+            "ef".context(StrContext::Label("ef")),
+        ))
+        .parse_next(i)
+    }
+
+    // The first missing required member is reported
+    let err = perm.parse_peek("cd;").unwrap_err().into_inner().unwrap();
+    assert_eq!(
+        err.context().collect::<Vec<_>>(),
+        [&StrContext::Label("ab")]
+    );
+    let err = perm.parse_peek("abcd;").unwrap_err().into_inner().unwrap();
+    assert_eq!(
+        err.context().collect::<Vec<_>>(),
+        [&StrContext::Label("ef")]
+    );
+}
+
+#[test]
+fn permutation_optional_duplicate() {
+    #[allow(clippy::type_complexity)]
+    fn perm<'i>(i: &mut &'i str) -> TestResult<&'i str, (&'i str, Option<&'i str>)> {
+        permutation(("ab", opt("cd"))).parse_next(i)
+    }
+
     assert_parse!(
-        alt1.parse_peek(Partial::new(a)),
+        perm.parse_peek("cdcdab"),
         str![[r#"
 Err(
     Backtrack(
         InputError {
-            input: Partial {
-                input: [
-                    99,
-                    100,
-                    101,
-                ],
-                partial: true,
-            },
+            input: "cdab",
         },
     ),
 )
@@ -1448,35 +3342,69 @@ Err(
 "#]]
         .raw()
     );
-    let a = &b"de"[..];
     assert_parse!(
-        alt1.parse_peek(Partial::new(a)),
+        perm.parse_peek("cdabcd"),
         str![[r#"
-Err(
-    Incomplete(
-        Unknown,
+Ok(
+    (
+        "cd",
+        (
+            "ab",
+            Some(
+                "cd",
+            ),
+        ),
     ),
 )
 
 "#]]
         .raw()
     );
-    let a = &b"defg"[..];
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn separated0_test() {
+    fn multi<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
+        separated(0.., "abcd", ",").parse_next(i)
+    }
+    fn multi_empty<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
+        separated(0.., "", ",").parse_next(i)
+    }
+    fn multi_longsep<'i>(
+        i: &mut Partial<&'i [u8]>,
+    ) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
+        separated(0.., "abcd", "..").parse_next(i)
+    }
+
+    let a = &b"abcdef"[..];
+    let b = &b"abcd,abcdef"[..];
+    let c = &b"azerty"[..];
+    let d = &b",,abc"[..];
+    let e = &b"abcd,abcd,ef"[..];
+    let f = &b"abc"[..];
+    let g = &b"abcd."[..];
+    let h = &b"abcd,abc"[..];
+
     assert_parse!(
-        alt1.parse_peek(Partial::new(a)),
+        multi.parse_peek(Partial::new(a)),
         str![[r#"
 Ok(
     (
         Partial {
             input: [
-                103,
+                101,
+                102,
             ],
             partial: true,
         },
         [
-            100,
-            101,
-            102,
+            [
+                97,
+                98,
+                99,
+                100,
+            ],
         ],
     ),
 )
@@ -1484,23 +3412,31 @@ Ok(
 "#]]
         .raw()
     );
-}
-
-#[test]
-fn alt_array() {
-    fn alt1<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
-        alt(["a", "bc", "def"]).parse_next(i)
-    }
-
-    let i = &b"a"[..];
     assert_parse!(
-        alt1.parse_peek(i),
+        multi.parse_peek(Partial::new(b)),
         str![[r#"
 Ok(
     (
-        [],
+        Partial {
+            input: [
+                101,
+                102,
+            ],
+            partial: true,
+        },
         [
-            97,
+            [
+                97,
+                98,
+                99,
+                100,
+            ],
+            [
+                97,
+                98,
+                99,
+                100,
+            ],
         ],
     ),
 )
@@ -1508,38 +3444,46 @@ Ok(
 "#]]
         .raw()
     );
-
-    let i = &b"bc"[..];
     assert_parse!(
-        alt1.parse_peek(i),
+        multi.parse_peek(Partial::new(c)),
         str![[r#"
 Ok(
     (
+        Partial {
+            input: [
+                97,
+                122,
+                101,
+                114,
+                116,
+                121,
+            ],
+            partial: true,
+        },
         [],
-        [
-            98,
-            99,
-        ],
     ),
 )
 
 "#]]
         .raw()
     );
-
-    let i = &b"defg"[..];
     assert_parse!(
-        alt1.parse_peek(i),
+        multi_empty.parse_peek(Partial::new(d)),
         str![[r#"
 Ok(
     (
+        Partial {
+            input: [
+                97,
+                98,
+                99,
+            ],
+            partial: true,
+        },
         [
-            103,
-        ],
-        [
-            100,
-            101,
-            102,
+            [],
+            [],
+            [],
         ],
     ),
 )
@@ -1547,81 +3491,76 @@ Ok(
 "#]]
         .raw()
     );
-
-    let i = &b"z"[..];
     assert_parse!(
-        alt1.parse_peek(i),
+        multi.parse_peek(Partial::new(e)),
         str![[r#"
-Err(
-    Backtrack(
-        InputError {
+Ok(
+    (
+        Partial {
             input: [
-                122,
+                44,
+                101,
+                102,
             ],
+            partial: true,
         },
+        [
+            [
+                97,
+                98,
+                99,
+                100,
+            ],
+            [
+                97,
+                98,
+                99,
+                100,
+            ],
+        ],
     ),
 )
 
 "#]]
         .raw()
     );
-}
-
-#[test]
-fn alt_dynamic_array() {
-    fn alt1<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
-        alt(&mut ["a", "bc", "def"][..]).parse_next(i)
-    }
 
-    let a = &b"a"[..];
     assert_parse!(
-        alt1.parse_peek(a),
+        multi.parse_peek(Partial::new(f)),
         str![[r#"
-Ok(
-    (
-        [],
-        [
-            97,
-        ],
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
         .raw()
     );
-
-    let bc = &b"bc"[..];
     assert_parse!(
-        alt1.parse_peek(bc),
+        multi_longsep.parse_peek(Partial::new(g)),
         str![[r#"
-Ok(
-    (
-        [],
-        [
-            98,
-            99,
-        ],
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
         .raw()
     );
-
-    let defg = &b"defg"[..];
     assert_parse!(
-        alt1.parse_peek(defg),
+        multi.parse_peek(Partial::new(h)),
         str![[r#"
-Ok(
-    (
-        [
-            103,
-        ],
-        [
-            100,
-            101,
-            102,
-        ],
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -1631,64 +3570,78 @@ Ok(
 }
 
 #[test]
-fn permutation_test() {
-    #[allow(clippy::type_complexity)]
-    fn perm<'i>(
+#[cfg(feature = "alloc")]
+#[cfg_attr(debug_assertions, should_panic)]
+fn separated0_empty_sep_test() {
+    fn empty_sep<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
+        separated(0.., "abc", "").parse_next(i)
+    }
+
+    let i = &b"abcabc"[..];
+
+    assert_parse!(empty_sep.parse_peek(Partial::new(i)), str![]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn separated1_test() {
+    fn multi<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
+        separated(1.., "abcd", ",").parse_next(i)
+    }
+    fn multi_longsep<'i>(
         i: &mut Partial<&'i [u8]>,
-    ) -> TestResult<Partial<&'i [u8]>, (&'i [u8], &'i [u8], &'i [u8])> {
-        permutation(("abcd", "efg", "hi")).parse_next(i)
+    ) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
+        separated(1.., "abcd", "..").parse_next(i)
     }
 
-    let a = &b"abcdefghijk"[..];
+    let a = &b"abcdef"[..];
+    let b = &b"abcd,abcdef"[..];
+    let c = &b"azerty"[..];
+    let d = &b"abcd,abcd,ef"[..];
+
+    let f = &b"abc"[..];
+    let g = &b"abcd."[..];
+    let h = &b"abcd,abc"[..];
+
     assert_parse!(
-        perm.parse_peek(Partial::new(a)),
+        multi.parse_peek(Partial::new(a)),
         str![[r#"
 Ok(
     (
         Partial {
             input: [
-                106,
-                107,
+                101,
+                102,
             ],
             partial: true,
         },
-        (
+        [
             [
                 97,
                 98,
                 99,
                 100,
             ],
-            [
-                101,
-                102,
-                103,
-            ],
-            [
-                104,
-                105,
-            ],
-        ),
+        ],
     ),
 )
 
 "#]]
         .raw()
     );
-    let b = &b"efgabcdhijk"[..];
     assert_parse!(
-        perm.parse_peek(Partial::new(b)),
+        multi.parse_peek(Partial::new(b)),
         str![[r#"
 Ok(
     (
         Partial {
             input: [
-                106,
-                107,
+                101,
+                102,
             ],
             partial: true,
         },
-        (
+        [
             [
                 97,
                 98,
@@ -1696,35 +3649,56 @@ Ok(
                 100,
             ],
             [
-                101,
-                102,
-                103,
-            ],
-            [
-                104,
-                105,
+                97,
+                98,
+                99,
+                100,
             ],
-        ),
+        ],
     ),
 )
 
 "#]]
         .raw()
     );
-    let c = &b"hiefgabcdjk"[..];
     assert_parse!(
-        perm.parse_peek(Partial::new(c)),
+        multi.parse_peek(Partial::new(c)),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    97,
+                    122,
+                    101,
+                    114,
+                    116,
+                    121,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        multi.parse_peek(Partial::new(d)),
         str![[r#"
 Ok(
     (
         Partial {
             input: [
-                106,
-                107,
+                44,
+                101,
+                102,
             ],
             partial: true,
         },
-        (
+        [
             [
                 97,
                 98,
@@ -1732,14 +3706,26 @@ Ok(
                 100,
             ],
             [
-                101,
-                102,
-                103,
-            ],
-            [
-                104,
-                105,
+                97,
+                98,
+                99,
+                100,
             ],
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+
+    assert_parse!(
+        multi.parse_peek(Partial::new(f)),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
         ),
     ),
 )
@@ -1747,46 +3733,28 @@ Ok(
 "#]]
         .raw()
     );
-
-    let d = &b"efgxyzabcdefghi"[..];
     assert_parse!(
-        perm.parse_peek(Partial::new(d)),
+        multi_longsep.parse_peek(Partial::new(g)),
         str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    120,
-                    121,
-                    122,
-                    97,
-                    98,
-                    99,
-                    100,
-                    101,
-                    102,
-                    103,
-                    104,
-                    105,
-                ],
-                partial: true,
-            },
-        },
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
 "#]]
         .raw()
     );
-
-    let e = &b"efgabc"[..];
     assert_parse!(
-        perm.parse_peek(Partial::new(e)),
+        multi.parse_peek(Partial::new(h)),
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -1797,48 +3765,31 @@ Err(
 
 #[test]
 #[cfg(feature = "alloc")]
-fn separated0_test() {
+fn separated_test() {
     fn multi<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
-        separated(0.., "abcd", ",").parse_next(i)
-    }
-    fn multi_empty<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
-        separated(0.., "", ",").parse_next(i)
-    }
-    fn multi_longsep<'i>(
-        i: &mut Partial<&'i [u8]>,
-    ) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
-        separated(0.., "abcd", "..").parse_next(i)
+        separated(2..=4, "abcd", ",").parse_next(i)
     }
 
-    let a = &b"abcdef"[..];
-    let b = &b"abcd,abcdef"[..];
-    let c = &b"azerty"[..];
-    let d = &b",,abc"[..];
-    let e = &b"abcd,abcd,ef"[..];
-    let f = &b"abc"[..];
-    let g = &b"abcd."[..];
-    let h = &b"abcd,abc"[..];
+    let a = &b"abcd,ef"[..];
+    let b = &b"abcd,abcd,efgh"[..];
+    let c = &b"abcd,abcd,abcd,abcd,efgh"[..];
+    let d = &b"abcd,abcd,abcd,abcd,abcd,efgh"[..];
+    let e = &b"abcd,ab"[..];
 
     assert_parse!(
         multi.parse_peek(Partial::new(a)),
         str![[r#"
-Ok(
-    (
-        Partial {
-            input: [
-                101,
-                102,
-            ],
-            partial: true,
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    101,
+                    102,
+                ],
+                partial: true,
+            },
         },
-        [
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
-        ],
     ),
 )
 
@@ -1852,8 +3803,11 @@ Ok(
     (
         Partial {
             input: [
+                44,
                 101,
                 102,
+                103,
+                104,
             ],
             partial: true,
         },
@@ -1884,39 +3838,39 @@ Ok(
     (
         Partial {
             input: [
-                97,
-                122,
+                44,
                 101,
-                114,
-                116,
-                121,
+                102,
+                103,
+                104,
             ],
             partial: true,
         },
-        [],
-    ),
-)
-
-"#]]
-        .raw()
-    );
-    assert_parse!(
-        multi_empty.parse_peek(Partial::new(d)),
-        str![[r#"
-Ok(
-    (
-        Partial {
-            input: [
+        [
+            [
                 97,
                 98,
                 99,
+                100,
+            ],
+            [
+                97,
+                98,
+                99,
+                100,
+            ],
+            [
+                97,
+                98,
+                99,
+                100,
+            ],
+            [
+                97,
+                98,
+                99,
+                100,
             ],
-            partial: true,
-        },
-        [
-            [],
-            [],
-            [],
         ],
     ),
 )
@@ -1925,15 +3879,22 @@ Ok(
         .raw()
     );
     assert_parse!(
-        multi.parse_peek(Partial::new(e)),
+        multi.parse_peek(Partial::new(d)),
         str![[r#"
 Ok(
     (
         Partial {
             input: [
+                44,
+                97,
+                98,
+                99,
+                100,
                 44,
                 101,
                 102,
+                103,
+                104,
             ],
             partial: true,
         },
@@ -1950,6 +3911,18 @@ Ok(
                 99,
                 100,
             ],
+            [
+                97,
+                98,
+                99,
+                100,
+            ],
+            [
+                97,
+                98,
+                99,
+                100,
+            ],
         ],
     ),
 )
@@ -1957,25 +3930,38 @@ Ok(
 "#]]
         .raw()
     );
-
     assert_parse!(
-        multi.parse_peek(Partial::new(f)),
+        multi.parse_peek(Partial::new(e)),
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            2,
+        ),
     ),
 )
 
 "#]]
         .raw()
     );
+}
+
+#[test]
+fn separated_foldl1_test() {
+    fn sub<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, u32> {
+        separated_foldl1(digit.parse_to::<u32>(), "-", |l, _, r| l - r).parse_next(i)
+    }
+
     assert_parse!(
-        multi_longsep.parse_peek(Partial::new(g)),
+        sub.parse_peek(Partial::new("9;")),
         str![[r#"
-Err(
-    Incomplete(
-        Unknown,
+Ok(
+    (
+        Partial {
+            input: ";",
+            partial: true,
+        },
+        9,
     ),
 )
 
@@ -1983,11 +3969,45 @@ Err(
         .raw()
     );
     assert_parse!(
-        multi.parse_peek(Partial::new(h)),
+        sub.parse_peek(Partial::new("9-3;")),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: ";",
+            partial: true,
+        },
+        6,
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        sub.parse_peek(Partial::new("9-3-;")),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: "-;",
+            partial: true,
+        },
+        6,
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        sub.parse_peek(Partial::new("9-3")),
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -1997,58 +4017,65 @@ Err(
 }
 
 #[test]
-#[cfg(feature = "alloc")]
-#[cfg_attr(debug_assertions, should_panic)]
-fn separated0_empty_sep_test() {
-    fn empty_sep<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
-        separated(0.., "abc", "").parse_next(i)
+fn separated_foldl1_cut_test() {
+    fn sub<'i>(i: &mut &'i str) -> TestResult<&'i str, u32> {
+        separated_foldl1(digit.parse_to::<u32>(), "-", |l, _, r| l - r).parse_next(i)
+    }
+    fn sub_cut<'i>(i: &mut &'i str) -> TestResult<&'i str, u32> {
+        separated_foldl1(cut_err(digit.parse_to::<u32>()), "-", |l, _, r| l - r).parse_next(i)
     }
 
-    let i = &b"abcabc"[..];
-
-    assert_parse!(empty_sep.parse_peek(Partial::new(i)), str![]);
-}
+    assert_parse!(
+        sub.parse_peek("9-a"),
+        str![[r#"
+Ok(
+    (
+        "-a",
+        9,
+    ),
+)
 
-#[test]
-#[cfg(feature = "alloc")]
-fn separated1_test() {
-    fn multi<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
-        separated(1.., "abcd", ",").parse_next(i)
-    }
-    fn multi_longsep<'i>(
-        i: &mut Partial<&'i [u8]>,
-    ) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
-        separated(1.., "abcd", "..").parse_next(i)
-    }
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        sub_cut.parse_peek("9-a"),
+        str![[r#"
+Err(
+    Cut(
+        InputError {
+            input: "a",
+        },
+    ),
+)
 
-    let a = &b"abcdef"[..];
-    let b = &b"abcd,abcdef"[..];
-    let c = &b"azerty"[..];
-    let d = &b"abcd,abcd,ef"[..];
+"#]]
+        .raw()
+    );
+}
 
-    let f = &b"abc"[..];
-    let g = &b"abcd."[..];
-    let h = &b"abcd,abc"[..];
+#[test]
+#[cfg(feature = "alloc")]
+fn repeat_strict_test() {
+    fn lenient<'i>(i: &mut &'i str) -> TestResult<&'i str, Vec<&'i str>> {
+        repeat(0..=2, "ab").parse_next(i)
+    }
+    fn strict<'i>(i: &mut &'i str) -> TestResult<&'i str, Vec<&'i str>> {
+        repeat(0..=2, "ab").strict().parse_next(i)
+    }
+    fn strict_partial<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, Vec<&'i str>> {
+        repeat(0..=2, "ab").strict().parse_next(i)
+    }
 
     assert_parse!(
-        multi.parse_peek(Partial::new(a)),
+        lenient.parse_peek("ababab."),
         str![[r#"
 Ok(
     (
-        Partial {
-            input: [
-                101,
-                102,
-            ],
-            partial: true,
-        },
+        "ab.",
         [
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
+            "ab",
+            "ab",
         ],
     ),
 )
@@ -2057,30 +4084,28 @@ Ok(
         .raw()
     );
     assert_parse!(
-        multi.parse_peek(Partial::new(b)),
+        strict.parse_peek("ababab."),
+        str![[r#"
+Err(
+    Cut(
+        InputError {
+            input: "ab.",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        strict.parse_peek("abab."),
         str![[r#"
 Ok(
     (
-        Partial {
-            input: [
-                101,
-                102,
-            ],
-            partial: true,
-        },
+        ".",
         [
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
+            "ab",
+            "ab",
         ],
     ),
 )
@@ -2089,55 +4114,46 @@ Ok(
         .raw()
     );
     assert_parse!(
-        multi.parse_peek(Partial::new(c)),
+        strict_partial.parse_peek(Partial::new("abab")),
         str![[r#"
 Err(
-    Backtrack(
-        InputError {
-            input: Partial {
-                input: [
-                    97,
-                    122,
-                    101,
-                    114,
-                    116,
-                    121,
-                ],
-                partial: true,
-            },
-        },
+    Incomplete(
+        Size(
+            2,
+        ),
     ),
 )
 
 "#]]
         .raw()
     );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn repeat_max_consumed_test() {
+    use crate::ascii::alpha1;
+
+    fn boundary<'i>(i: &mut &'i str) -> TestResult<&'i str, Vec<&'i str>> {
+        repeat(0.., terminated(alpha1, ";"))
+            .max_consumed(6)
+            .parse_next(i)
+    }
+    fn mid_element<'i>(i: &mut &'i str) -> TestResult<&'i str, Vec<&'i str>> {
+        repeat(0.., terminated(alpha1, ";"))
+            .max_consumed(5)
+            .parse_next(i)
+    }
+
     assert_parse!(
-        multi.parse_peek(Partial::new(d)),
+        boundary.parse_peek("ab;cd;."),
         str![[r#"
 Ok(
     (
-        Partial {
-            input: [
-                44,
-                101,
-                102,
-            ],
-            partial: true,
-        },
+        ".",
         [
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
+            "ab",
+            "cd",
         ],
     ),
 )
@@ -2145,13 +4161,14 @@ Ok(
 "#]]
         .raw()
     );
-
     assert_parse!(
-        multi.parse_peek(Partial::new(f)),
+        boundary.parse_peek("ab;cd;ef;."),
         str![[r#"
 Err(
-    Incomplete(
-        Unknown,
+    Cut(
+        InputError {
+            input: "ef;.",
+        },
     ),
 )
 
@@ -2159,11 +4176,13 @@ Err(
         .raw()
     );
     assert_parse!(
-        multi_longsep.parse_peek(Partial::new(g)),
+        mid_element.parse_peek("ab;cd;."),
         str![[r#"
 Err(
-    Incomplete(
-        Unknown,
+    Cut(
+        InputError {
+            input: "cd;.",
+        },
     ),
 )
 
@@ -2171,11 +4190,14 @@ Err(
         .raw()
     );
     assert_parse!(
-        multi.parse_peek(Partial::new(h)),
+        mid_element.parse_peek("ab;."),
         str![[r#"
-Err(
-    Incomplete(
-        Unknown,
+Ok(
+    (
+        ".",
+        [
+            "ab",
+        ],
     ),
 )
 
@@ -2186,30 +4208,40 @@ Err(
 
 #[test]
 #[cfg(feature = "alloc")]
-fn separated_test() {
-    fn multi<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
-        separated(2..=4, "abcd", ",").parse_next(i)
+fn separated_strict_test() {
+    fn lenient<'i>(i: &mut &'i str) -> TestResult<&'i str, Vec<&'i str>> {
+        separated(0..=2, "ab", ",").parse_next(i)
+    }
+    fn strict<'i>(i: &mut &'i str) -> TestResult<&'i str, Vec<&'i str>> {
+        separated(0..=2, "ab", ",").strict().parse_next(i)
+    }
+    fn strict_none<'i>(i: &mut &'i str) -> TestResult<&'i str, Vec<&'i str>> {
+        separated(0..=0, "ab", ",").strict().parse_next(i)
     }
 
-    let a = &b"abcd,ef"[..];
-    let b = &b"abcd,abcd,efgh"[..];
-    let c = &b"abcd,abcd,abcd,abcd,efgh"[..];
-    let d = &b"abcd,abcd,abcd,abcd,abcd,efgh"[..];
-    let e = &b"abcd,ab"[..];
+    assert_parse!(
+        lenient.parse_peek("ab,ab,ab."),
+        str![[r#"
+Ok(
+    (
+        ",ab.",
+        [
+            "ab",
+            "ab",
+        ],
+    ),
+)
 
+"#]]
+        .raw()
+    );
     assert_parse!(
-        multi.parse_peek(Partial::new(a)),
+        strict.parse_peek("ab,ab,ab."),
         str![[r#"
 Err(
-    Backtrack(
+    Cut(
         InputError {
-            input: Partial {
-                input: [
-                    101,
-                    102,
-                ],
-                partial: true,
-            },
+            input: "ab.",
         },
     ),
 )
@@ -2218,33 +4250,14 @@ Err(
         .raw()
     );
     assert_parse!(
-        multi.parse_peek(Partial::new(b)),
+        strict.parse_peek("ab,ab,."),
         str![[r#"
 Ok(
     (
-        Partial {
-            input: [
-                44,
-                101,
-                102,
-                103,
-                104,
-            ],
-            partial: true,
-        },
+        ",.",
         [
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
+            "ab",
+            "ab",
         ],
     ),
 )
@@ -2253,46 +4266,93 @@ Ok(
         .raw()
     );
     assert_parse!(
-        multi.parse_peek(Partial::new(c)),
+        strict_none.parse_peek("ab"),
+        str![[r#"
+Err(
+    Cut(
+        InputError {
+            input: "ab",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn separated_last_test() {
+    use crate::ascii::alpha1;
+
+    fn list<'i>(i: &mut &'i str) -> TestResult<&'i str, Vec<&'i str>> {
+        separated_last(1.., alpha1, ", ", " and ").parse_next(i)
+    }
+    fn oxford<'i>(i: &mut &'i str) -> TestResult<&'i str, Vec<&'i str>> {
+        separated_last(2.., alpha1, ", ", alt((", and ", " and "))).parse_next(i)
+    }
+
+    assert_eq!(
+        list.parse_peek("a, b and c."),
+        Ok((".", vec!["a", "b", "c"]))
+    );
+    // Only the final separator
+    assert_eq!(list.parse_peek("a and b."), Ok((".", vec!["a", "b"])));
+    assert_eq!(oxford.parse_peek("a and b."), Ok((".", vec!["a", "b"])));
+    // The list ends after the final separator
+    assert_eq!(
+        list.parse_peek("a and b and c"),
+        Ok((" and c", vec!["a", "b"]))
+    );
+    // `and` is also an item
+    assert_eq!(
+        list.parse_peek("a, and and b"),
+        Ok(("", vec!["a", "and", "b"]))
+    );
+    assert_eq!(
+        list.parse_peek("a, andy and b"),
+        Ok(("", vec!["a", "andy", "b"]))
+    );
+    assert_eq!(list.parse_peek("a and, b"), Ok((" and, b", vec!["a"])));
+    assert_eq!(
+        oxford.parse_peek("a, b, and c"),
+        Ok(("", vec!["a", "b", "c"]))
+    );
+    assert_eq!(
+        oxford.parse_peek("a, and, and b"),
+        Ok(("", vec!["a", "and", "b"]))
+    );
+    // Too few occurrences
+    assert!(oxford.parse_peek("a.").is_err());
+    assert!(oxford.parse_peek("a and .").is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[cfg_attr(debug_assertions, should_panic)]
+fn separated_last_empty_sep_test() {
+    fn empty_sep<'i>(i: &mut &'i str) -> TestResult<&'i str, Vec<&'i str>> {
+        separated_last(0.., "abc", ",", "").parse_next(i)
+    }
+
+    assert_parse!(empty_sep.parse_peek("abcabc"), str![]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn separated_foldr1_test() {
+    fn pow<'i>(i: &mut &'i str) -> TestResult<&'i str, u32> {
+        separated_foldr1(digit.parse_to::<u32>(), "^", |l: u32, _, r: u32| l.pow(r)).parse_next(i)
+    }
+
+    assert_parse!(
+        pow.parse_peek("2;"),
         str![[r#"
 Ok(
     (
-        Partial {
-            input: [
-                44,
-                101,
-                102,
-                103,
-                104,
-            ],
-            partial: true,
-        },
-        [
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
-        ],
+        ";",
+        2,
     ),
 )
 
@@ -2300,51 +4360,12 @@ Ok(
         .raw()
     );
     assert_parse!(
-        multi.parse_peek(Partial::new(d)),
+        pow.parse_peek("2^3;"),
         str![[r#"
 Ok(
     (
-        Partial {
-            input: [
-                44,
-                97,
-                98,
-                99,
-                100,
-                44,
-                101,
-                102,
-                103,
-                104,
-            ],
-            partial: true,
-        },
-        [
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
-            [
-                97,
-                98,
-                99,
-                100,
-            ],
-        ],
+        ";",
+        8,
     ),
 )
 
@@ -2352,11 +4373,12 @@ Ok(
         .raw()
     );
     assert_parse!(
-        multi.parse_peek(Partial::new(e)),
+        pow.parse_peek("2^3^2;"),
         str![[r#"
-Err(
-    Incomplete(
-        Unknown,
+Ok(
+    (
+        ";",
+        512,
     ),
 )
 
@@ -2460,7 +4482,9 @@ Ok(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            2,
+        ),
     ),
 )
 
@@ -2472,7 +4496,9 @@ Err(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            4,
+        ),
     ),
 )
 
@@ -2484,7 +4510,9 @@ Err(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            4,
+        ),
     ),
 )
 
@@ -2504,6 +4532,204 @@ fn repeat0_empty_test() {
     assert_parse!(multi_empty.parse_peek(Partial::new(&b"abcdef"[..])), str![]);
 }
 
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic = "`repeat` parsers must always consume, but `parser` may match empty input"
+)]
+fn repeat_may_match_empty_opt() {
+    use crate::error::InputError;
+
+    let mut parser = repeat::<_, _, (), InputError<_>, _>(0.., opt('a'));
+    // Without `debug_assertions`, building is unaffected and the runtime check catches it
+    assert!(parser.parse_peek("aab").is_err());
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic = "`separated` separator parser must always consume, but `separator` may match empty input"
+)]
+fn separated_may_match_empty_separator() {
+    use crate::error::InputError;
+
+    let mut parser = separated::<_, _, (), _, InputError<_>, _, _>(1.., 'a', opt(','));
+    assert!(parser.parse_peek("a,ab").is_err());
+}
+
+#[test]
+fn may_match_empty_hints() {
+    use crate::ascii::alpha0;
+    use crate::error::InputError;
+    use crate::token::take_while;
+
+    fn may_match_empty<O>(
+        parser: impl Parser<&'static str, O, InputError<&'static str>>,
+    ) -> Option<bool> {
+        parser.may_match_empty()
+    }
+
+    assert_eq!(may_match_empty(opt('a')), Some(true));
+    assert_eq!(
+        may_match_empty(opt('a').map(|_| ()).context("a")),
+        Some(true)
+    );
+    assert_eq!(may_match_empty(""), Some(true));
+    assert_eq!(may_match_empty(take_while(0.., 'a').take()), Some(true));
+    assert_eq!(may_match_empty((opt('a'), opt('b'))), Some(true));
+    assert_eq!(
+        may_match_empty(repeat::<_, _, (), _, _>(0.., 'a')),
+        Some(true)
+    );
+    assert_eq!(may_match_empty('a'), Some(false));
+    assert_eq!(may_match_empty("abc".value(1)), Some(false));
+    assert_eq!(may_match_empty(take_while(1.., 'a')), Some(false));
+    assert_eq!(may_match_empty((opt('a'), 'b')), Some(false));
+    assert_eq!(
+        may_match_empty(repeat::<_, _, (), _, _>(1.., 'a')),
+        Some(false)
+    );
+    assert_eq!(may_match_empty(alpha0), None);
+    assert_eq!(may_match_empty((opt('a'), alpha0)), None);
+    assert_eq!(may_match_empty(opt('a').verify(|a| a.is_some())), None);
+}
+
+#[test]
+fn may_match_empty_legitimate_grammars() {
+    use crate::error::InputError;
+
+    // None of these panic when built
+    let mut list = separated::<_, _, (), _, InputError<_>, _, _>(0.., opt('a'), ',');
+    assert_eq!(list.parse_peek(",a,"), Ok(("", ())));
+    let mut items = repeat::<_, _, (), InputError<_>, _>(0.., (opt('a'), 'b'));
+    assert_eq!(items.parse_peek("abbc"), Ok(("c", ())));
+    let mut bounded = repeat::<_, _, (), InputError<_>, _>(0..=2, opt('a'));
+    assert_eq!(bounded.parse_peek("aab"), Ok(("b", ())));
+    let mut nested = repeat::<_, _, (), InputError<_>, _>(0.., repeat::<_, _, (), _, _>(1.., 'a'));
+    assert_eq!(nested.parse_peek("aab"), Ok(("b", ())));
+}
+
+#[test]
+fn repeat_unit_accumulator_test() {
+    // Accumulating into `()` drops outputs but doesn't skip computing them
+    let mut built = 0;
+    let mut input = "ababc";
+    let res: TestResult<&str, ()> = repeat(
+        0..,
+        "ab".map(|s: &str| {
+            built += 1;
+            s
+        }),
+    )
+    .parse_next(&mut input);
+    assert_eq!(res, Ok(()));
+    assert_eq!(input, "c");
+    assert_eq!(built, 2);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn repeat_char_accumulator_test() {
+    use crate::lib::std::string::String;
+    use crate::token::{any, none_of};
+
+    // Decode escapes a `char` at a time, without collecting into a `Vec<char>` first
+    fn unescape<'i>(i: &mut &'i str) -> TestResult<&'i str, String> {
+        repeat(
+            3..,
+            alt((
+                none_of(['"', '\\']),
+                preceded(
+                    '\\',
+                    alt((
+                        'n'.value('\n'),
+                        't'.value('\t'),
+                        '"'.value('"'),
+                        '\\'.value('\\'),
+                    )),
+                ),
+            )),
+        )
+        .parse_next(i)
+    }
+
+    let mut input = r#"a\"b\\c\nd""#;
+    let output = unescape.parse_next(&mut input).unwrap();
+    assert_eq!(output, "a\"b\\c\nd");
+    assert_eq!(input, "\"");
+    // Reserved the lower bound up front
+    assert!(3 <= output.capacity());
+    assert!(output.capacity() < 64);
+
+    fn bytes<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], Vec<u8>> {
+        repeat(4, any).parse_next(i)
+    }
+
+    let mut input = &b"\x01\x02\x03\x04\x05"[..];
+    let output = bytes.parse_next(&mut input).unwrap();
+    assert_eq!(output, vec![1, 2, 3, 4]);
+    assert_eq!(input, &b"\x05"[..]);
+    assert_eq!(output.capacity(), 4);
+}
+
+#[test]
+fn fill_test() {
+    use crate::error::InputError;
+
+    fn fill_n<'i, const N: usize>(i: &mut &'i str) -> TestResult<&'i str, [&'i str; N]> {
+        let mut buf = [""; N];
+        fill("ab", &mut buf).parse_next(i)?;
+        Ok(buf)
+    }
+
+    assert_eq!(fill_n::<0>.parse_peek("abc"), Ok(("abc", [])));
+    assert_eq!(fill_n::<0>.parse_peek(""), Ok(("", [])));
+    assert_eq!(
+        fill_n::<3>.parse_peek("abababc"),
+        Ok(("c", ["ab", "ab", "ab"]))
+    );
+    assert_eq!(
+        fill_n::<3>.parse_peek("abab"),
+        Err(ErrMode::Backtrack(InputError::at("")))
+    );
+}
+
+#[test]
+fn fill_middle_failure_test() {
+    use crate::error::InputError;
+
+    let mut buf = ["", "", ""];
+    let mut input = "ab12ab";
+    let res: TestResult<&str, ()> = fill("ab", &mut buf).parse_next(&mut input);
+    assert_eq!(res, Err(ErrMode::Backtrack(InputError::at("12ab"))));
+    // Elements before the failure were written, the rest are untouched
+    assert_eq!(buf, ["ab", "", ""]);
+}
+
+#[test]
+fn fill_partial_test() {
+    use crate::error::Needed;
+
+    fn fill_2<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, [&'i str; 2]> {
+        let mut buf = ["", ""];
+        fill("ab", &mut buf).parse_next(i)?;
+        Ok(buf)
+    }
+
+    assert_eq!(
+        fill_2.parse_peek(Partial::new("abab")),
+        Ok((Partial::new(""), ["ab", "ab"]))
+    );
+    assert_eq!(
+        fill_2.parse_peek(Partial::new("aba")),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+    assert_eq!(
+        fill_2.parse_peek(Partial::new("ab")),
+        Err(ErrMode::Incomplete(Needed::new(2)))
+    );
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn repeat1_test() {
@@ -2605,7 +4831,136 @@ Err(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            2,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn partition_test() {
+    use crate::lib::std::ops::Range;
+
+    type Attributes = (Vec<Range<usize>>, Vec<Range<usize>>, ());
+
+    fn attributes<'i>(
+        i: &mut LocatingSlice<&'i str>,
+    ) -> TestResult<LocatingSlice<&'i str>, Attributes> {
+        partition((
+            (crate::ascii::alpha1, '=', digit).span(),
+            ('+', crate::ascii::alpha1).span(),
+            ('#', take_till(0.., ';'), ';').void(),
+        ))
+        .parse_next(i)
+    }
+
+    // spans show the order is kept within each collection, and interleaved across them
+    assert_parse!(
+        attributes.parse_peek(LocatingSlice::new("+ro#c;a=1b=2+hide.")),
+        str![[r#"
+Ok(
+    (
+        LocatingSlice {
+            initial: "+ro#c;a=1b=2+hide.",
+            input: ".",
+        },
+        (
+            [
+                6..9,
+                9..12,
+            ],
+            [
+                0..3,
+                12..17,
+            ],
+            (),
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        attributes.parse_peek(LocatingSlice::new(".")),
+        str![[r#"
+Ok(
+    (
+        LocatingSlice {
+            initial: ".",
+            input: ".",
+        },
+        (
+            [],
+            [],
+            (),
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn partition_partial_test() {
+    type Items<'i> = (Vec<&'i [u8]>, Vec<&'i [u8]>);
+
+    fn parser<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, Items<'i>> {
+        partition(("ab", "cd")).parse_next(i)
+    }
+
+    assert_parse!(
+        parser.parse_peek(Partial::new(&b"abcdabe"[..])),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                101,
+            ],
+            partial: true,
+        },
+        (
+            [
+                [
+                    97,
+                    98,
+                ],
+                [
+                    97,
+                    98,
+                ],
+            ],
+            [
+                [
+                    99,
+                    100,
+                ],
+            ],
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        parser.parse_peek(Partial::new(&b"abcda"[..])),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -2614,6 +4969,17 @@ Err(
     );
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+#[cfg_attr(debug_assertions, should_panic)]
+fn partition_empty_test() {
+    fn parser<'i>(i: &mut &'i str) -> TestResult<&'i str, (Vec<&'i str>, Vec<&'i str>)> {
+        partition(("a", "")).parse_next(i)
+    }
+
+    assert_parse!(parser.parse_peek("bc"), str![]);
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn repeat_till_test() {
@@ -2797,13 +5163,62 @@ Ok(
         "",
         (
             [
-                "ab",
-                "ab",
-                "ab",
-                "ab",
+                "ab",
+                "ab",
+                "ab",
+                "ab",
+            ],
+            "cd",
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        multi.parse_peek("abababababcd"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "abcd",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn repeat_till_discard_test() {
+    fn multi<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], Vec<&'i [u8]>> {
+        repeat_till_discard(0.., "abcd", eof).parse_next(i)
+    }
+
+    assert_parse!(
+        multi.parse_peek(&b"abcdabcd"[..]),
+        str![[r#"
+Ok(
+    (
+        [],
+        [
+            [
+                97,
+                98,
+                99,
+                100,
+            ],
+            [
+                97,
+                98,
+                99,
+                100,
             ],
-            "cd",
-        ),
+        ],
     ),
 )
 
@@ -2811,12 +5226,15 @@ Ok(
         .raw()
     );
     assert_parse!(
-        multi.parse_peek("abababababcd"),
+        multi.parse_peek(&b"abcdab"[..]),
         str![[r#"
 Err(
     Backtrack(
         InputError {
-            input: "abcd",
+            input: [
+                97,
+                98,
+            ],
         },
     ),
 )
@@ -2826,6 +5244,42 @@ Err(
     );
 }
 
+#[test]
+#[cfg(feature = "std")]
+fn repeat_till_item_error_test() {
+    use crate::error::{StrContext, StrContextValue};
+
+    fn value<'i>(i: &mut &'i str) -> ModalResult<&'i str> {
+        alt((digit, "true", "false"))
+            .context(StrContext::Label("value"))
+            .parse_next(i)
+    }
+
+    fn array<'i>(i: &mut &'i str) -> ModalResult<Vec<&'i str>> {
+        preceded(
+            '[',
+            repeat_till_discard(
+                0..,
+                terminated(value, opt(',')),
+                ']'.context(StrContext::Expected(StrContextValue::CharLiteral(']'))),
+            ),
+        )
+        .parse_next(i)
+    }
+
+    assert_eq!(array.parse("[1,true,2]"), Ok(vec!["1", "true", "2"]));
+    let err = array.parse("[1,tru,2]").unwrap_err();
+    assert_eq!(err.offset(), 3);
+    snapbox::assert_data_eq!(
+        err.to_string(),
+        str![[r#"
+[1,tru,2]
+   ^
+invalid value
+"#]]
+    );
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn infinite_many() {
@@ -3055,7 +5509,9 @@ Ok(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            2,
+        ),
     ),
 )
 
@@ -3064,6 +5520,27 @@ Err(
     );
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn repeat_exact_test() {
+    use crate::binary::be_u16;
+
+    fn length_prefixed<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], Vec<&'i [u8]>> {
+        let count = be_u16.parse_next(i)?;
+        repeat_exact(count, "ab").parse_next(i)
+    }
+
+    assert_eq!(
+        length_prefixed.parse_peek(&b"\x00\x02ababab"[..]),
+        Ok((&b"ab"[..], vec![&b"ab"[..], &b"ab"[..]]))
+    );
+    assert_eq!(
+        length_prefixed.parse_peek(&b"\x00\x00ab"[..]),
+        Ok((&b"ab"[..], vec![]))
+    );
+    assert!(length_prefixed.parse_peek(&b"\x00\x03abab"[..]).is_err());
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn count_test() {
@@ -3111,7 +5588,9 @@ Ok(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -3123,7 +5602,9 @@ Err(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -3473,7 +5954,9 @@ Ok(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            2,
+        ),
     ),
 )
 
@@ -3485,7 +5968,9 @@ Err(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            4,
+        ),
     ),
 )
 
@@ -3497,7 +5982,9 @@ Err(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            4,
+        ),
     ),
 )
 
@@ -3628,7 +6115,9 @@ Err(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            2,
+        ),
     ),
 )
 
@@ -3811,7 +6300,9 @@ Ok(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            2,
+        ),
     ),
 )
 
@@ -3969,3 +6460,461 @@ Err(
         .raw()
     );
 }
+
+#[test]
+fn not_preceded_by_test() {
+    fn number<'i>(
+        i: &mut LocatingSlice<&'i [u8]>,
+    ) -> TestResult<LocatingSlice<&'i [u8]>, &'i [u8]> {
+        preceded(
+            take_till(0.., b'0'..=b'9'),
+            preceded(not_preceded_by((b'a'..=b'z', b'_')), digit),
+        )
+        .parse_next(i)
+    }
+
+    assert_parse!(
+        number.parse_peek(LocatingSlice::new(&b"10"[..])),
+        str![[r#"
+Ok(
+    (
+        LocatingSlice {
+            initial: [
+                49,
+                48,
+            ],
+            input: [],
+        },
+        [
+            49,
+            48,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        number.parse_peek(LocatingSlice::new(&b"-10"[..])),
+        str![[r#"
+Ok(
+    (
+        LocatingSlice {
+            initial: [
+                45,
+                49,
+                48,
+            ],
+            input: [],
+        },
+        [
+            49,
+            48,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        number.parse_peek(LocatingSlice::new(&b"x10"[..])),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: LocatingSlice {
+                initial: [
+                    120,
+                    49,
+                    48,
+                ],
+                input: [
+                    49,
+                    48,
+                ],
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn separated_collect_map_with_test() {
+    use crate::ascii::alpha1;
+    use crate::error::ContextError;
+    use crate::lib::std::collections::BTreeMap;
+
+    fn key_value<'i>(i: &mut &'i str) -> ModalResult<(&'i str, &'i str)> {
+        separated_pair(alpha1, '=', digit).parse_next(i)
+    }
+    fn map<'i>(
+        policy: DuplicateKey,
+    ) -> impl Parser<&'i str, BTreeMap<&'i str, &'i str>, ErrMode<ContextError>> {
+        separated(1.., key_value, ',').collect_map_with(policy)
+    }
+
+    snapbox::assert_data_eq!(
+        map(DuplicateKey::FirstWins).parse("a=1,b=2,a=3").to_debug(),
+        str![[r#"
+Ok(
+    {
+        "a": "1",
+        "b": "2",
+    },
+)
+
+"#]]
+    );
+    snapbox::assert_data_eq!(
+        map(DuplicateKey::LastWins).parse("a=1,b=2,a=3").to_debug(),
+        str![[r#"
+Ok(
+    {
+        "a": "3",
+        "b": "2",
+    },
+)
+
+"#]]
+    );
+
+    let err = map(DuplicateKey::Error).parse("a=1,b=2,a=3").unwrap_err();
+    assert_eq!(err.offset(), 8);
+    snapbox::assert_data_eq!(
+        err.to_string(),
+        str![[r#"
+a=1,b=2,a=3
+        ^
+duplicate key `a`
+"#]]
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn repeat_collect_map_with_test() {
+    use crate::lib::std::collections::HashMap;
+
+    fn map<'i>(i: &mut &'i str) -> TestResult<&'i str, HashMap<&'i str, &'i str>> {
+        repeat(0.., terminated(separated_pair("a", '=', digit), ';'))
+            .collect_map_with(DuplicateKey::Error)
+            .parse_next(i)
+    }
+
+    assert_parse!(
+        map.parse_peek("a=1;a=2;"),
+        str![[r#"
+Err(
+    Cut(
+        InputError {
+            input: "a=2;",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn peek_with_state_rollback_test() {
+    use crate::stream::Stateful;
+
+    type Input<'i> = Stateful<&'i str, usize>;
+
+    fn counted<'i>(i: &mut Input<'i>) -> ModalResult<&'i str> {
+        i.state += 1;
+        "abc".parse_next(i)
+    }
+
+    // `peek` leaves state changes behind
+    let mut input = Input {
+        input: "abc;",
+        state: 0,
+    };
+    assert_eq!(peek(counted).parse_next(&mut input), Ok("abc"));
+    assert_eq!(input.input, "abc;");
+    assert_eq!(input.state, 1);
+
+    let mut input = Input {
+        input: "abc;",
+        state: 0,
+    };
+    assert_eq!(
+        peek_with_state_rollback(counted).parse_next(&mut input),
+        Ok("abc")
+    );
+    assert_eq!(input.input, "abc;");
+    assert_eq!(input.state, 0);
+
+    let mut input = Input {
+        input: "xyz;",
+        state: 0,
+    };
+    assert!(peek_with_state_rollback(counted)
+        .parse_next(&mut input)
+        .is_err());
+    assert_eq!(input.input, "xyz;");
+    assert_eq!(input.state, 0);
+
+    // Later changes are kept
+    let mut input = Input {
+        input: "abc;",
+        state: 0,
+    };
+    assert_eq!(
+        (peek_with_state_rollback(counted), counted).parse_next(&mut input),
+        Ok(("abc", "abc"))
+    );
+    assert_eq!(input.input, ";");
+    assert_eq!(input.state, 1);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn depth_guard_test() {
+    use crate::stream::Stateful;
+
+    type Input<'i> = Stateful<&'i str, usize>;
+
+    fn nested(i: &mut Input<'_>) -> ModalResult<usize> {
+        alt((
+            delimited('[', depth_guard(100, nested), ']').map(|inner| inner + 1),
+            empty.value(0),
+        ))
+        .parse_next(i)
+    }
+
+    let mut input = Input {
+        input: "[[[]]]",
+        state: 0,
+    };
+    assert_eq!(nested.parse_next(&mut input), Ok(3));
+    assert_eq!(input.input, "");
+    assert_eq!(input.state, 0);
+
+    // Backtracking out of guarded parsers restores the depth
+    let mut input = Input {
+        input: "[[[x",
+        state: 0,
+    };
+    assert_eq!(nested.parse_next(&mut input), Ok(0));
+    assert_eq!(input.input, "[[[x");
+    assert_eq!(input.state, 0);
+
+    let deep = format!("{}{}", "[".repeat(10_000), "]".repeat(10_000));
+    let mut input = Input {
+        input: &deep,
+        state: 0,
+    };
+    let err = nested.parse_next(&mut input).unwrap_err();
+    assert!(matches!(err, ErrMode::Cut(_)));
+    assert_eq!(input.input.len(), deep.len() - 101);
+    assert_eq!(input.state, 0);
+    #[cfg(feature = "std")]
+    assert_eq!(
+        err.into_inner().unwrap().to_string(),
+        "recursion limit exceeded (max depth 100)"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn step_limit_test() {
+    // Each nested `(` is parsed twice, taking exponential time
+    fn expr(i: &mut &str) -> ModalResult<()> {
+        alt(((term, '+', expr).void(), term)).parse_next(i)
+    }
+    fn term(i: &mut &str) -> ModalResult<()> {
+        alt((delimited('(', opt(expr), ')').void(), 'x'.void())).parse_next(i)
+    }
+    fn limited_expr(i: &mut &str) -> ModalResult<()> {
+        step_limit(10_000, expr).parse_next(i)
+    }
+
+    assert_eq!(limited_expr.parse_peek("(x+(x))+x"), Ok(("", ())));
+
+    let deep = "(".repeat(64);
+    let mut input = deep.as_str();
+    let err = limited_expr.parse_next(&mut input).unwrap_err();
+    assert!(matches!(err, ErrMode::Cut(_)));
+    assert_eq!(input, deep);
+    assert_eq!(
+        err.into_inner().unwrap().to_string(),
+        "step budget exceeded (max steps 10000)"
+    );
+
+    // Steps are not counted outside of `step_limit`
+    assert_eq!(expr.parse_peek("(x+(x))+x"), Ok(("", ())));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn step_limit_nested_test() {
+    fn items<'i>(i: &mut &'i str) -> ModalResult<Vec<char>> {
+        repeat(0.., alt(('a', 'b'))).parse_next(i)
+    }
+
+    // The inner limit is capped by what is left of the outer one
+    let mut parser = step_limit(5, (step_limit(100, items), items));
+    let err = parser.parse_peek("aaaaaa").unwrap_err();
+    assert_eq!(
+        err.into_inner().unwrap().to_string(),
+        "step budget exceeded (max steps 5)"
+    );
+
+    // Steps taken by the inner limit count towards the outer one
+    let mut parser = step_limit(5, (step_limit(100, items), ',', items));
+    assert_eq!(
+        parser.parse_peek("aa,b"),
+        Ok(("", (vec!['a', 'a'], ',', vec!['b'])))
+    );
+    assert!(parser.parse_peek("aa,bb").is_err());
+
+    // The inner limit reports its own budget
+    let mut parser = step_limit(100, (step_limit(2, items), items));
+    let err = parser.parse_peek("aaa").unwrap_err();
+    assert_eq!(
+        err.into_inner().unwrap().to_string(),
+        "step budget exceeded (max steps 2)"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn step_limit_unwind_test() {
+    fn items<'i>(i: &mut &'i str) -> ModalResult<Vec<char>> {
+        repeat(0.., alt(('a', 'b'))).parse_next(i)
+    }
+    fn panics(i: &mut &str) -> ModalResult<()> {
+        let _ = items.parse_next(i);
+        panic!("parser panicked");
+    }
+
+    let res = std::panic::catch_unwind(|| step_limit(2, panics).parse_peek("a"));
+    assert!(res.is_err());
+
+    // The budget is restored when unwinding, so steps are no longer counted
+    assert_eq!(items.parse_peek("aaaa"), Ok(("", vec!['a', 'a', 'a', 'a'])));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn lazy_test() {
+    use crate::error::ContextError;
+    use ::core::cell::Cell;
+
+    fn nested<'c>(
+        built: &'c Cell<usize>,
+    ) -> impl Parser<&'c str, usize, ErrMode<ContextError>> + 'c {
+        alt((
+            delimited(
+                '[',
+                lazy(move || {
+                    built.set(built.get() + 1);
+                    nested(built)
+                }),
+                ']',
+            )
+            .map(|depth| depth + 1),
+            empty.value(0),
+        ))
+    }
+
+    let depth = 100;
+    let input = format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+
+    let built = Cell::new(0);
+    let mut parser = nested(&built);
+    assert_eq!(parser.parse_peek("x"), Ok(("x", 0)));
+    // Not built until reached
+    assert_eq!(built.get(), 0);
+
+    assert_eq!(parser.parse_peek(input.as_str()), Ok(("", depth)));
+    assert_eq!(built.get(), depth);
+
+    // Memoized, so parsing again doesn't rebuild any level
+    assert_eq!(parser.parse_peek(input.as_str()), Ok(("", depth)));
+    assert_eq!(parser.parse_peek("[[]]"), Ok(("", 2)));
+    assert_eq!(built.get(), depth);
+
+    // Only the levels reached are built
+    let mut parser = nested(&built);
+    assert_eq!(parser.parse_peek("[[]]"), Ok(("", 2)));
+    assert_eq!(built.get(), depth + 2);
+}
+
+#[test]
+fn limited_test() {
+    use crate::error::ContextError;
+
+    fn header<'i>(i: &mut &'i [u8]) -> ModalResult<&'i [u8]> {
+        limited(4, take_till(0.., b';')).parse_next(i)
+    }
+
+    // Exactly at the limit
+    assert_eq!(
+        header.parse_peek(&b"abcd;"[..]),
+        Ok((&b";"[..], &b"abcd"[..]))
+    );
+
+    // One over the limit
+    let err = header.parse_peek(&b"abcde;"[..]).unwrap_err();
+    assert!(matches!(err, ErrMode::Cut(_)));
+    #[cfg(feature = "std")]
+    assert_eq!(
+        err.into_inner().unwrap().to_string(),
+        "length limit exceeded (max length 4)"
+    );
+
+    let mut input = &b"abcde;"[..];
+    assert!(header.parse_next(&mut input).is_err());
+    assert_eq!(input, &b"abcde;"[..]);
+
+    // Other errors are passed through
+    let err: ErrMode<ContextError> = limited(4, u8.verify(|b| *b == b'a'))
+        .parse_peek(&b"b"[..])
+        .unwrap_err();
+    assert_eq!(err, ErrMode::Backtrack(ContextError::new()));
+}
+
+#[test]
+fn limited_partial_test() {
+    use crate::error::Needed;
+
+    fn header<'i>(i: &mut Partial<&'i [u8]>) -> ModalResult<&'i [u8]> {
+        limited(4, take_till(0.., b';')).parse_next(i)
+    }
+
+    assert_eq!(
+        header.parse_peek(Partial::new(&b"abcd;"[..])),
+        Ok((Partial::new(&b";"[..]), &b"abcd"[..]))
+    );
+
+    // More input may still fit within the limit
+    assert_eq!(
+        header.parse_peek(Partial::new(&b"abc"[..])),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+
+    // Waiting for more input can't help
+    assert!(matches!(
+        header.parse_peek(Partial::new(&b"abcd"[..])),
+        Err(ErrMode::Cut(_))
+    ));
+    let err = header.parse_peek(Partial::new(&b"abcde"[..])).unwrap_err();
+    assert!(matches!(err, ErrMode::Cut(_)));
+    #[cfg(feature = "std")]
+    assert_eq!(
+        err.into_inner().unwrap().to_string(),
+        "length limit exceeded (max length 4)"
+    );
+}
@@ -78,11 +78,57 @@ impl Needed {
         }
     }
 
+    /// Creates `Needed::Size`, returns `None` if the argument is zero
+    ///
+    /// Unlike [`Needed::new`], this doesn't silently turn a miscalculated size into
+    /// [`Needed::Unknown`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::error::Needed;
+    /// assert_eq!(Needed::checked_new(2), Some(Needed::new(2)));
+    /// assert_eq!(Needed::checked_new(0), None);
+    /// ```
+    pub fn checked_new(s: usize) -> Option<Self> {
+        NonZeroUsize::new(s).map(Needed::Size)
+    }
+
     /// Indicates if we know how many bytes we need
     pub fn is_known(&self) -> bool {
         *self != Needed::Unknown
     }
 
+    /// Returns the contained [`Needed::Size`] value
+    ///
+    /// This is mostly useful for tests of [partial][crate::_topic::partial] parsers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is [`Needed::Unknown`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// # use winnow::error::{ContextError, ErrMode, Needed};
+    /// # use winnow::Partial;
+    /// use winnow::token::take;
+    ///
+    /// let err = take::<_, _, ErrMode<ContextError>>(4usize)
+    ///     .parse_peek(Partial::new(&b"ab"[..]))
+    ///     .unwrap_err();
+    /// assert_eq!(err.needed().map(Needed::unwrap_size), Some(2));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_size(self) -> usize {
+        match self {
+            Needed::Size(n) => n.get(),
+            Needed::Unknown => panic!("called `Needed::unwrap_size()` on `Needed::Unknown`"),
+        }
+    }
+
     /// Maps a `Needed` to `Needed` by applying a function to a contained `Size` value.
     #[inline]
     pub fn map<F: Fn(NonZeroUsize) -> usize>(self, f: F) -> Needed {
@@ -135,6 +181,15 @@ impl<E> ErrMode<E> {
         matches!(self, ErrMode::Incomplete(_))
     }
 
+    /// Returns the [`Needed`] of an [`ErrMode::Incomplete`]
+    #[inline]
+    pub fn needed(&self) -> Option<Needed> {
+        match self {
+            ErrMode::Incomplete(needed) => Some(*needed),
+            _ => None,
+        }
+    }
+
     /// Prevent backtracking, bubbling the error up to the top
     pub fn cut(self) -> Self {
         match self {
@@ -163,6 +218,19 @@ impl<E> ErrMode<E> {
         }
     }
 
+    /// Applies the given function to the [`Needed`] of an [`ErrMode::Incomplete`]
+    ///
+    /// See also [`with_needed_offset`][crate::combinator::with_needed_offset]
+    pub fn map_incomplete<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Needed) -> Needed,
+    {
+        match self {
+            ErrMode::Incomplete(n) => ErrMode::Incomplete(f(n)),
+            rest => rest,
+        }
+    }
+
     /// Automatically converts between errors if the underlying type supports it
     pub fn convert<F>(self) -> ErrMode<F>
     where
@@ -212,6 +280,11 @@ impl<I: Stream, E: ParserError<I>> ParserError<I> for ErrMode<E> {
         }
     }
 
+    #[inline(always)]
+    fn add_expected(self, input: &I, expected: StrContextValue) -> Self {
+        self.map(|e| e.add_expected(input, expected))
+    }
+
     fn or(self, other: Self) -> Self {
         match (self, other) {
             (ErrMode::Backtrack(e), ErrMode::Backtrack(o)) => ErrMode::Backtrack(e.or(o)),
@@ -332,6 +405,9 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+impl<E> std::error::Error for ErrMode<E> where E: fmt::Debug {}
+
 /// The basic [`Parser`] trait for errors
 ///
 /// It provides methods to create an error from some combinators,
@@ -374,6 +450,12 @@ pub trait ParserError<I: Stream>: Sized {
     ///
     /// This is useful when backtracking through a parse tree, accumulating error context on the
     /// way.
+    ///
+    /// Combinators, like [`repeat`][crate::combinator::repeat] and
+    /// [`separated`][crate::combinator::separated], call this with their own starting position when
+    /// an item fails.  The original error is from the parser that failed, which is usually the most
+    /// useful to report, so by default, and for [`InputError`] and [`ContextError`], this keeps
+    /// the original error as-is.  [`TreeError`] records each call as a frame on top of it.
     #[inline]
     fn append(self, _input: &I, _token_start: &<I as Stream>::Checkpoint) -> Self {
         self
@@ -388,6 +470,18 @@ pub trait ParserError<I: Stream>: Sized {
         other
     }
 
+    /// Record a literal token that would have allowed parsing to continue
+    ///
+    /// This is called by parsers for single tokens, like `'a'` or
+    /// [`one_of`][crate::token::one_of], so errors can describe what was expected without each
+    /// use being annotated with [`StrContext::Expected`].
+    ///
+    /// By default, this does nothing, so error types that don't report expectations pay nothing.
+    #[inline(always)]
+    fn add_expected(self, _input: &I, _expected: StrContextValue) -> Self {
+        self
+    }
+
     /// Is backtracking and trying new parse branches allowed?
     #[inline(always)]
     fn is_backtrack(&self) -> bool {
@@ -423,6 +517,43 @@ pub trait ModalError {
     fn backtrack(self) -> Self;
 }
 
+/// Inspect a [`ModalResult`] without matching on [`ErrMode`]
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::{ContextError, ErrMode, ModalResultExt as _, Needed};
+/// # use winnow::Partial;
+/// use winnow::token::take;
+///
+/// let result = take::<_, _, ErrMode<ContextError>>(4usize)
+///     .parse_peek(Partial::new(&b"ab"[..]));
+/// assert!(result.is_incomplete());
+/// assert_eq!(result.needed(), Some(Needed::new(2)));
+/// ```
+pub trait ModalResultExt {
+    /// Tests if the result is [`ErrMode::Incomplete`]
+    fn is_incomplete(&self) -> bool;
+    /// Returns the [`Needed`] if the result is [`ErrMode::Incomplete`]
+    fn needed(&self) -> Option<Needed>;
+}
+
+impl<O, E> ModalResultExt for ModalResult<O, E> {
+    #[inline]
+    fn is_incomplete(&self) -> bool {
+        matches!(self, Err(ErrMode::Incomplete(_)))
+    }
+
+    #[inline]
+    fn needed(&self) -> Option<Needed> {
+        match self {
+            Err(ErrMode::Incomplete(needed)) => Some(*needed),
+            _ => None,
+        }
+    }
+}
+
 /// Used by [`Parser::context`] to add custom data to error while backtracking
 ///
 /// May be implemented multiple times for different kinds of context.
@@ -464,6 +595,65 @@ pub trait FromExternalError<I, E> {
 }
 
 /// Equivalent of `From` implementation to avoid orphan rules in bits parsers
+///
+/// [`bits`][crate::binary::bits::bits] runs its parser on a `(I, usize)` stream, the byte input
+/// paired with the bit offset into its first byte.  Errors are converted back to the byte-level
+/// error when leaving `bits` and, the other way, when leaving
+/// [`bytes`][crate::binary::bits::bytes].
+///
+/// Error types that don't hold on to the input, like [`ContextError`] and [`EmptyError`], convert
+/// to themselves, so the same error type can be used on both sides.  Error types that hold on to
+/// the input need to translate it, usually by dropping the bit offset.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::bits::{bits, take};
+/// use winnow::error::{ErrMode, ErrorConvert, ParserError};
+/// use winnow::stream::Stream;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct MyError<I> {
+///     input: I,
+/// }
+///
+/// impl<I: Stream + Clone> ParserError<I> for MyError<I> {
+///     type Inner = Self;
+///
+///     fn from_input(input: &I) -> Self {
+///         MyError { input: input.clone() }
+///     }
+///
+///     fn into_inner(self) -> Result<Self::Inner, Self> {
+///         Ok(self)
+///     }
+/// }
+///
+/// // Leaving `bits`
+/// impl<I> ErrorConvert<MyError<I>> for MyError<(I, usize)> {
+///     fn convert(self) -> MyError<I> {
+///         MyError { input: self.input.0 }
+///     }
+/// }
+///
+/// // Leaving `bytes`
+/// impl<I> ErrorConvert<MyError<(I, usize)>> for MyError<I> {
+///     fn convert(self) -> MyError<(I, usize)> {
+///         MyError { input: (self.input, 0) }
+///     }
+/// }
+///
+/// fn nibble<'i>(input: &mut &'i [u8]) -> ModalResult<u8, MyError<&'i [u8]>> {
+///     bits::<_, _, ErrMode<MyError<(&[u8], usize)>>, _, _>(take(4usize)).parse_next(input)
+/// }
+///
+/// assert_eq!(nibble.parse_peek(&[0x12][..]), Ok((&[][..], 0x01)));
+/// assert_eq!(
+///     nibble.parse_peek(&[][..]),
+///     Err(ErrMode::Backtrack(MyError { input: &[][..] }))
+/// );
+/// ```
 pub trait ErrorConvert<E> {
     /// Transform to another error type
     fn convert(self) -> E;
@@ -500,6 +690,33 @@ impl<I: Clone> InputError<I> {
             input: op(self.input),
         }
     }
+
+    /// Bounded, human-oriented rendering of where the error occurred, for logging errors from
+    /// large buffers
+    ///
+    /// See [`Preview`][crate::stream::Preview] for the format.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// use winnow::error::InputError;
+    /// use winnow::stream::{LocatingSlice, Partial};
+    ///
+    /// let input = Partial::new(LocatingSlice::new(&[0xff; 1_000_000][..]));
+    /// let err = InputError::at(input);
+    /// assert_eq!(
+    ///     err.preview().max_len(4).to_string(),
+    ///     r#"b"\xff\xff\xff\xff"… (1000000 bytes) at offset 0 (partial)"#
+    /// );
+    /// ```
+    #[inline]
+    pub fn preview(&self) -> crate::stream::Preview<'_, I>
+    where
+        I: crate::stream::StreamPreview,
+    {
+        crate::stream::Preview::new(&self.input)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -680,6 +897,9 @@ pub struct ContextError<C = StrContext> {
     context: crate::lib::std::vec::Vec<C>,
     #[cfg(not(feature = "alloc"))]
     context: core::marker::PhantomData<C>,
+    /// Boxed so errors that never record tokens stay small
+    #[cfg(feature = "alloc")]
+    expected: Option<crate::lib::std::boxed::Box<ExpectedTokens>>,
     #[cfg(feature = "std")]
     cause: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 }
@@ -690,6 +910,8 @@ impl<C> ContextError<C> {
     pub fn new() -> Self {
         Self {
             context: Default::default(),
+            #[cfg(feature = "alloc")]
+            expected: None,
             #[cfg(feature = "std")]
             cause: None,
         }
@@ -702,24 +924,124 @@ impl<C> ContextError<C> {
         self.context.iter()
     }
 
+    /// Access tokens recorded by [`ParserError::add_expected`]
+    ///
+    /// When [`alt`][crate::combinator::alt] branches fail at the same position, their expected
+    /// tokens are combined.
+    /// Only a handful of tokens are tracked; when there are more, or when a branch doesn't describe
+    /// what it expected, none are reported.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub fn expected(&self) -> impl Iterator<Item = &StrContextValue> {
+        self.expected
+            .as_deref()
+            .map(ExpectedTokens::tokens)
+            .unwrap_or_default()
+            .iter()
+    }
+
     /// Originating [`std::error::Error`]
     #[inline]
     #[cfg(feature = "std")]
     pub fn cause(&self) -> Option<&(dyn std::error::Error + Send + Sync + 'static)> {
         self.cause.as_deref()
     }
+
+    /// Walk the [`cause`][Self::cause] and its [`source`][std::error::Error::source]s
+    ///
+    /// The alternate [`Display`][std::fmt::Display] (`{:#}`) includes these after the cause.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// # use winnow::error::ContextError;
+    /// use winnow::ascii::digit1;
+    ///
+    /// #[derive(Debug)]
+    /// struct InvalidPort(std::num::ParseIntError);
+    ///
+    /// impl std::fmt::Display for InvalidPort {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         "invalid port".fmt(f)
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for InvalidPort {
+    ///     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// fn port(input: &mut &str) -> ModalResult<u16> {
+    ///     digit1.try_map(|s: &str| s.parse().map_err(InvalidPort)).parse_next(input)
+    /// }
+    ///
+    /// let err = port.parse("99999").unwrap_err();
+    /// let chain = err.inner().cause_chain().map(|e| e.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(chain, ["invalid port", "number too large to fit in target type"]);
+    /// assert_eq!(
+    ///     format!("{:#}", err.inner()),
+    ///     "invalid port: caused by number too large to fit in target type"
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn cause_chain(&self) -> impl Iterator<Item = &(dyn std::error::Error + 'static)> {
+        let cause = self
+            .cause
+            .as_deref()
+            .map(|e| e as &(dyn std::error::Error + 'static));
+        crate::lib::std::iter::successors(cause, |e| e.source())
+    }
 }
 
 impl<C: Clone> Clone for ContextError<C> {
     fn clone(&self) -> Self {
         Self {
             context: self.context.clone(),
+            #[cfg(feature = "alloc")]
+            expected: self.expected.clone(),
             #[cfg(feature = "std")]
-            cause: self.cause.as_ref().map(|e| e.to_string().into()),
+            cause: self
+                .cause
+                .as_deref()
+                .map(|e| Box::new(ClonedCause::new(e)) as Box<_>),
         }
     }
 }
 
+/// Snapshot of a [`ContextError::cause`] chain, as the original can't be cloned
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct ClonedCause {
+    message: String,
+    source: Option<Box<ClonedCause>>,
+}
+
+#[cfg(feature = "std")]
+impl ClonedCause {
+    fn new(error: &(dyn std::error::Error + 'static)) -> Self {
+        Self {
+            message: error.to_string(),
+            source: error.source().map(|source| Box::new(Self::new(source))),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl crate::lib::std::fmt::Display for ClonedCause {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        self.message.fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ClonedCause {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.as_deref().map(|e| e as _)
+    }
+}
+
 impl<C> Default for ContextError<C> {
     #[inline]
     fn default() -> Self {
@@ -735,6 +1057,29 @@ impl<I: Stream, C> ParserError<I> for ContextError<C> {
         Self::new()
     }
 
+    #[inline(always)]
+    #[cfg(feature = "alloc")]
+    fn add_expected(mut self, input: &I, expected: StrContextValue) -> Self {
+        self.expected
+            .get_or_insert_with(|| crate::lib::std::boxed::Box::new(ExpectedTokens::new()))
+            .push(input.eof_offset(), expected);
+        self
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn or(self, mut other: Self) -> Self {
+        other.expected = match (self.expected, other.expected) {
+            (Some(earlier), Some(mut expected)) => {
+                expected.merge_earlier(&earlier);
+                Some(expected)
+            }
+            // Only a complete list of alternatives is useful
+            _ => None,
+        };
+        other
+    }
+
     #[inline(always)]
     fn into_inner(self) -> Result<Self::Inner, Self> {
         Ok(self)
@@ -777,7 +1122,7 @@ impl<C, I, E: std::error::Error + Send + Sync + 'static> FromExternalError<I, E>
     fn from_external_error(_input: &I, e: E) -> Self {
         let mut err = Self::new();
         {
-            err.cause = Some(Box::new(e));
+            err.cause = Some(BoxedError::unwrap_cause(Box::new(e)));
         }
         err
     }
@@ -810,6 +1155,12 @@ impl<C: core::cmp::PartialEq> core::cmp::PartialEq for ContextError<C> {
                 return false;
             }
         }
+        #[cfg(feature = "alloc")]
+        {
+            if !self.expected().eq(other.expected()) {
+                return false;
+            }
+        }
 
         true
     }
@@ -823,13 +1174,22 @@ impl crate::lib::std::fmt::Display for ContextError<StrContext> {
                 StrContext::Label(c) => Some(c),
                 _ => None,
             });
-            let expected = self
+            let mut expected = self
                 .context()
                 .filter_map(|c| match c {
                     StrContext::Expected(c) => Some(c),
                     _ => None,
                 })
                 .collect::<crate::lib::std::vec::Vec<_>>();
+            // Recorded tokens are alternatives, rather than a list from the user
+            let alternatives = expected.is_empty();
+            if alternatives {
+                expected.extend(self.expected());
+            }
+            let unclosed = self.context().find_map(|c| match c {
+                StrContext::Unclosed(offset) => Some(offset),
+                _ => None,
+            });
 
             let mut newline = false;
 
@@ -846,20 +1206,45 @@ impl crate::lib::std::fmt::Display for ContextError<StrContext> {
                 newline = true;
 
                 write!(f, "expected ")?;
-                for (i, expected) in expected.iter().enumerate() {
+                for (i, value) in expected.iter().enumerate() {
                     if i != 0 {
-                        write!(f, ", ")?;
+                        let separator = if !alternatives || i + 1 < expected.len() {
+                            ", "
+                        } else if expected.len() == 2 {
+                            " or "
+                        } else {
+                            ", or "
+                        };
+                        write!(f, "{separator}")?;
                     }
-                    write!(f, "{expected}")?;
+                    write!(f, "{value}")?;
+                }
+            }
+
+            if let Some(offset) = unclosed {
+                if newline {
+                    writeln!(f)?;
                 }
+                #[cfg(feature = "std")]
+                {
+                    newline = true;
+                }
+
+                write!(f, "unclosed delimiter at offset {offset}")?;
             }
             #[cfg(feature = "std")]
             {
-                if let Some(cause) = self.cause() {
+                let mut causes = self.cause_chain();
+                if let Some(cause) = causes.next() {
                     if newline {
                         writeln!(f)?;
                     }
                     write!(f, "{cause}")?;
+                    if f.alternate() {
+                        for source in causes {
+                            write!(f, ": caused by {source}")?;
+                        }
+                    }
                 }
             }
         }
@@ -868,6 +1253,86 @@ impl crate::lib::std::fmt::Display for ContextError<StrContext> {
     }
 }
 
+#[cfg(feature = "alloc")]
+const EXPECTED_CAPACITY: usize = 4;
+
+/// Tokens recorded by [`ParserError::add_expected`], stored inline
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+struct ExpectedTokens {
+    tokens: [StrContextValue; EXPECTED_CAPACITY],
+    len: usize,
+    /// [`Stream::eof_offset`] that `tokens` apply to
+    at: usize,
+    /// The list is known to be incomplete for `at`
+    poisoned: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl ExpectedTokens {
+    #[inline]
+    fn new() -> Self {
+        Self {
+            tokens: [
+                StrContextValue::CharLiteral('\0'),
+                StrContextValue::CharLiteral('\0'),
+                StrContextValue::CharLiteral('\0'),
+                StrContextValue::CharLiteral('\0'),
+            ],
+            len: 0,
+            at: 0,
+            poisoned: false,
+        }
+    }
+
+    #[inline]
+    fn tokens(&self) -> &[StrContextValue] {
+        if self.poisoned {
+            &[]
+        } else {
+            &self.tokens[..self.len]
+        }
+    }
+
+    #[inline(always)]
+    fn push(&mut self, at: usize, token: StrContextValue) {
+        if self.at != at {
+            *self = Self::new();
+            self.at = at;
+        }
+        if self.poisoned || self.tokens().contains(&token) {
+            return;
+        }
+        if self.len == EXPECTED_CAPACITY {
+            self.poisoned = true;
+            return;
+        }
+        self.tokens[self.len] = token;
+        self.len += 1;
+    }
+
+    /// Combine the tokens from an earlier failed alternative into this one
+    ///
+    /// Only a complete list of alternatives is useful, so if either failed somewhere else or
+    /// didn't describe what it expected, nothing is reported.
+    ///
+    /// This works in-place as [`alt`][crate::combinator::alt] calls it for every failed branch and
+    /// most branches record nothing.
+    #[inline]
+    fn merge_earlier(&mut self, earlier: &Self) {
+        if earlier.tokens().is_empty() || self.tokens().is_empty() || earlier.at != self.at {
+            self.at = earlier.at;
+            self.poisoned = true;
+            return;
+        }
+        let mut merged = earlier.clone();
+        for token in self.tokens() {
+            merged.push(self.at, token.clone());
+        }
+        *self = merged;
+    }
+}
+
 impl<C> ErrorConvert<ContextError<C>> for ContextError<C> {
     #[inline]
     fn convert(self) -> ContextError<C> {
@@ -875,72 +1340,545 @@ impl<C> ErrorConvert<ContextError<C>> for ContextError<C> {
     }
 }
 
-/// Additional parse context for [`ContextError`] added via [`Parser::context`]
+/// Accumulate context while backtracking errors, without allocating
+///
+/// This is a drop-in for [`ContextError`] when `alloc` is unavailable, storing up to `N` contexts
+/// inline.
+/// Contexts are added from the innermost parser outwards, so once `N` contexts are stored, any
+/// further contexts are dropped, keeping the most specific ones.
+/// See [`StackContextError::dropped`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::error::StackContextError;
+/// use winnow::error::StrContext;
+/// use winnow::ascii::digit1;
+///
+/// fn parser<'i>(input: &mut &'i str) -> Result<&'i str, StackContextError<StrContext, 2>> {
+///     digit1.context(StrContext::Label("number")).parse_next(input)
+/// }
+///
+/// let err = parser.parse_peek("abc").unwrap_err();
+/// assert_eq!(err.to_string(), "invalid number");
+/// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
-#[non_exhaustive]
-pub enum StrContext {
-    /// Description of what is currently being parsed
-    Label(&'static str),
-    /// Grammar item that was expected
-    Expected(StrContextValue),
+pub struct StackContextError<C = StrContext, const N: usize = 4> {
+    context: [Option<C>; N],
+    len: usize,
+    dropped: usize,
 }
 
-impl crate::lib::std::fmt::Display for StrContext {
-    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
-        match self {
-            Self::Label(name) => write!(f, "invalid {name}"),
-            Self::Expected(value) => write!(f, "expected {value}"),
+impl<C, const N: usize> StackContextError<C, N> {
+    /// Create an empty error
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            context: core::array::from_fn(|_| None),
+            len: 0,
+            dropped: 0,
         }
     }
-}
 
-/// See [`StrContext`]
-#[derive(Clone, Debug, PartialEq, Eq)]
-#[non_exhaustive]
-pub enum StrContextValue {
-    /// A [`char`] token
-    CharLiteral(char),
-    /// A [`&str`] token
-    StringLiteral(&'static str),
-    /// A description of what was being parsed
-    Description(&'static str),
+    /// Access context from [`Parser::context`]
+    #[inline]
+    pub fn context(&self) -> impl Iterator<Item = &C> {
+        self.context[..self.len].iter().filter_map(Option::as_ref)
+    }
+
+    /// The number of contexts that did not fit
+    #[inline]
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
 }
 
-impl From<char> for StrContextValue {
+impl<C, const N: usize> Default for StackContextError<C, N> {
     #[inline]
-    fn from(inner: char) -> Self {
-        Self::CharLiteral(inner)
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl From<&'static str> for StrContextValue {
+impl<I: Stream, C, const N: usize> ParserError<I> for StackContextError<C, N> {
+    type Inner = Self;
+
     #[inline]
-    fn from(inner: &'static str) -> Self {
-        Self::StringLiteral(inner)
+    fn from_input(_input: &I) -> Self {
+        Self::new()
+    }
+
+    #[inline(always)]
+    fn into_inner(self) -> Result<Self::Inner, Self> {
+        Ok(self)
     }
 }
 
-impl crate::lib::std::fmt::Display for StrContextValue {
-    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
-        match self {
-            Self::CharLiteral('\n') => "newline".fmt(f),
-            Self::CharLiteral('`') => "'`'".fmt(f),
-            Self::CharLiteral(c) if c.is_ascii_control() => {
-                write!(f, "`{}`", c.escape_debug())
-            }
-            Self::CharLiteral(c) => write!(f, "`{c}`"),
-            Self::StringLiteral(c) => write!(f, "`{c}`"),
-            Self::Description(c) => write!(f, "{c}"),
+impl<C, I: Stream, const N: usize> AddContext<I, C> for StackContextError<C, N> {
+    #[inline]
+    fn add_context(
+        mut self,
+        _input: &I,
+        _token_start: &<I as Stream>::Checkpoint,
+        context: C,
+    ) -> Self {
+        if self.len < N {
+            self.context[self.len] = Some(context);
+            self.len += 1;
+        } else {
+            self.dropped += 1;
         }
+        self
     }
 }
 
-/// Trace all error paths, particularly for tests
-#[derive(Debug)]
+#[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
-pub enum TreeError<I, C = StrContext> {
-    /// Initial error that kicked things off
-    Base(TreeErrorBase<I>),
+impl<I: Stream, C, const N: usize> FromRecoverableError<I, Self> for StackContextError<C, N> {
+    #[inline]
+    fn from_recoverable_error(
+        _token_start: &<I as Stream>::Checkpoint,
+        _err_start: &<I as Stream>::Checkpoint,
+        _input: &I,
+        e: Self,
+    ) -> Self {
+        e
+    }
+}
+
+impl<C, I, E, const N: usize> FromExternalError<I, E> for StackContextError<C, N> {
+    #[inline]
+    fn from_external_error(_input: &I, _e: E) -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> crate::lib::std::fmt::Display for StackContextError<StrContext, N> {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        let expression = self.context().find_map(|c| match c {
+            StrContext::Label(c) => Some(c),
+            _ => None,
+        });
+        let expected = self.context().filter_map(|c| match c {
+            StrContext::Expected(c) => Some(c),
+            _ => None,
+        });
+
+        let mut newline = false;
+
+        if let Some(expression) = expression {
+            newline = true;
+
+            write!(f, "invalid {expression}")?;
+        }
+
+        for (i, expected) in expected.enumerate() {
+            if i == 0 {
+                if newline {
+                    writeln!(f)?;
+                }
+                write!(f, "expected ")?;
+            } else {
+                write!(f, ", ")?;
+            }
+            write!(f, "{expected}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C, const N: usize> ErrorConvert<StackContextError<C, N>> for StackContextError<C, N> {
+    #[inline]
+    fn convert(self) -> StackContextError<C, N> {
+        self
+    }
+}
+
+/// Additional parse context for [`ContextError`] added via [`Parser::context`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StrContext {
+    /// Description of what is currently being parsed
+    Label(&'static str),
+    /// Grammar item that was expected
+    Expected(StrContextValue),
+    /// Offset of a delimiter that was not closed, see
+    /// [`delimited_cut`][crate::combinator::delimited_cut]
+    Unclosed(usize),
+}
+
+impl crate::lib::std::fmt::Display for StrContext {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        match self {
+            Self::Label(name) => write!(f, "invalid {name}"),
+            Self::Expected(value) => write!(f, "expected {value}"),
+            Self::Unclosed(offset) => write!(f, "unclosed delimiter at offset {offset}"),
+        }
+    }
+}
+
+/// See [`StrContext`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StrContextValue {
+    /// A [`char`] token
+    CharLiteral(char),
+    /// A [`&str`] token
+    StringLiteral(&'static str),
+    /// A description of what was being parsed
+    Description(&'static str),
+    /// Any [`char`] token in the inclusive range, like from [`one_of('0'..='9')`][crate::token::one_of]
+    CharRange(char, char),
+    /// A [`u8`] token
+    ByteLiteral(u8),
+    /// The end of input, like from [`eof`][crate::combinator::eof], with how much input was left
+    ///
+    /// This is the [`Stream::eof_offset`], so bytes for `&str` and `&[u8]`.
+    Eof(usize),
+}
+
+impl From<char> for StrContextValue {
+    #[inline]
+    fn from(inner: char) -> Self {
+        Self::CharLiteral(inner)
+    }
+}
+
+impl From<&'static str> for StrContextValue {
+    #[inline]
+    fn from(inner: &'static str) -> Self {
+        Self::StringLiteral(inner)
+    }
+}
+
+impl From<crate::lib::std::ops::RangeInclusive<char>> for StrContextValue {
+    #[inline]
+    fn from(inner: crate::lib::std::ops::RangeInclusive<char>) -> Self {
+        Self::CharRange(*inner.start(), *inner.end())
+    }
+}
+
+impl From<u8> for StrContextValue {
+    #[inline]
+    fn from(inner: u8) -> Self {
+        Self::ByteLiteral(inner)
+    }
+}
+
+impl crate::lib::std::fmt::Display for StrContextValue {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        match self {
+            Self::CharLiteral('\n') => "newline".fmt(f),
+            Self::CharLiteral('`') => "'`'".fmt(f),
+            Self::CharLiteral(c) if c.is_ascii_control() => {
+                write!(f, "`{}`", c.escape_debug())
+            }
+            Self::CharLiteral(c) => write!(f, "`{c}`"),
+            Self::StringLiteral(c) => write!(f, "`{c}`"),
+            Self::Description(c) => write!(f, "{c}"),
+            Self::CharRange(start, end) => write!(
+                f,
+                "{}-{}",
+                Self::CharLiteral(*start),
+                Self::CharLiteral(*end)
+            ),
+            Self::ByteLiteral(b) if b.is_ascii() => Self::CharLiteral(*b as char).fmt(f),
+            Self::ByteLiteral(b) => write!(f, "`{}`", b.escape_ascii()),
+            Self::Eof(remaining) => write!(f, "end of input ({remaining} bytes left)"),
+        }
+    }
+}
+
+/// A key was repeated, see [`DuplicateKey::Error`][crate::combinator::DuplicateKey::Error]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg(feature = "alloc")]
+pub struct DuplicateKeyError {
+    key: crate::lib::std::string::String,
+}
+
+#[cfg(feature = "alloc")]
+impl DuplicateKeyError {
+    /// Report `key` as being repeated
+    pub fn new(key: &impl crate::lib::std::fmt::Display) -> Self {
+        use crate::lib::std::string::ToString as _;
+        Self {
+            key: key.to_string(),
+        }
+    }
+
+    /// The repeated key, rendered with [`Display`][crate::lib::std::fmt::Display]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl crate::lib::std::fmt::Display for DuplicateKeyError {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        write!(f, "duplicate key `{}`", self.key)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DuplicateKeyError {}
+
+/// Type-erased external error, see [`Parser::try_map_boxed`]
+///
+/// [`ContextError`] and [`TreeError`] store the inner error directly as their cause, so it can
+/// still be downcast to its original type.
+#[derive(Debug)]
+#[cfg(feature = "std")]
+pub struct BoxedError(Box<dyn std::error::Error + Send + Sync + 'static>);
+
+#[cfg(feature = "std")]
+impl BoxedError {
+    /// Type-erase `error`
+    pub fn new(error: impl Into<Box<dyn std::error::Error + Send + Sync + 'static>>) -> Self {
+        Self(error.into())
+    }
+
+    /// The type-erased error
+    pub fn into_inner(self) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+        self.0
+    }
+
+    /// Avoid double-boxing an already type-erased cause
+    fn unwrap_cause(
+        cause: Box<dyn std::error::Error + Send + Sync + 'static>,
+    ) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+        match cause.downcast::<Self>() {
+            Ok(boxed) => boxed.into_inner(),
+            Err(cause) => cause,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl crate::lib::std::fmt::Display for BoxedError {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BoxedError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.0.source()
+    }
+}
+
+/// Parsers were nested too deeply, see [`depth_guard`][crate::combinator::depth_guard]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RecursionLimitError {
+    max: usize,
+}
+
+impl RecursionLimitError {
+    /// Report that nesting went past `max`
+    pub fn new(max: usize) -> Self {
+        Self { max }
+    }
+
+    /// The maximum nesting depth that was exceeded
+    pub fn max(&self) -> usize {
+        self.max
+    }
+}
+
+impl crate::lib::std::fmt::Display for RecursionLimitError {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        write!(f, "recursion limit exceeded (max depth {})", self.max)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RecursionLimitError {}
+
+/// Parsing took too many steps, see [`step_limit`][crate::combinator::step_limit]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct StepLimitError {
+    max: usize,
+}
+
+impl StepLimitError {
+    /// Report that parsing took more than `max` steps
+    pub fn new(max: usize) -> Self {
+        Self { max }
+    }
+
+    /// The maximum number of steps that was exceeded
+    pub fn max(&self) -> usize {
+        self.max
+    }
+}
+
+impl crate::lib::std::fmt::Display for StepLimitError {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        write!(f, "step budget exceeded (max steps {})", self.max)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StepLimitError {}
+
+/// A parser consumed too much input, see [`limited`][crate::combinator::limited] and
+/// [`Repeat::max_consumed`][crate::combinator::Repeat::max_consumed]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LengthLimitError {
+    max: usize,
+}
+
+impl LengthLimitError {
+    /// Report that parsing went past `max`
+    pub fn new(max: usize) -> Self {
+        Self { max }
+    }
+
+    /// The maximum length that was exceeded
+    pub fn max(&self) -> usize {
+        self.max
+    }
+}
+
+impl crate::lib::std::fmt::Display for LengthLimitError {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        write!(f, "length limit exceeded (max length {})", self.max)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthLimitError {}
+
+/// There were more occurrences than allowed, see [`Repeat::strict`][crate::combinator::Repeat::strict]
+/// and [`Separated::strict`][crate::combinator::Separated::strict]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RepeatLimitError {
+    max: usize,
+}
+
+impl RepeatLimitError {
+    /// Report that there were more than `max` occurrences
+    pub fn new(max: usize) -> Self {
+        Self { max }
+    }
+
+    /// The maximum number of occurrences that was exceeded
+    pub fn max(&self) -> usize {
+        self.max
+    }
+}
+
+impl crate::lib::std::fmt::Display for RepeatLimitError {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        write!(f, "too many elements, expected at most {}", self.max)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RepeatLimitError {}
+
+/// A grammar rule hasn't been implemented yet, see [`todo_with`][crate::combinator::todo_with]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TodoError {
+    rule: &'static str,
+}
+
+impl TodoError {
+    /// Report that `rule` hasn't been implemented yet
+    pub fn new(rule: &'static str) -> Self {
+        Self { rule }
+    }
+
+    /// The name of the rule that hasn't been implemented
+    pub fn rule(&self) -> &'static str {
+        self.rule
+    }
+}
+
+impl crate::lib::std::fmt::Display for TodoError {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        write!(f, "not yet implemented: rule {}", self.rule)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TodoError {}
+
+/// The wrong number of bytes remained, see [`take_rest_exact`][crate::binary::take_rest_exact]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RestLengthError {
+    expected: usize,
+    actual: usize,
+}
+
+impl RestLengthError {
+    /// Report that `actual` bytes remained when `expected` were required
+    pub fn new(expected: usize, actual: usize) -> Self {
+        Self { expected, actual }
+    }
+
+    /// The number of bytes that were required to remain
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// The number of bytes that actually remained
+    pub fn actual(&self) -> usize {
+        self.actual
+    }
+}
+
+impl crate::lib::std::fmt::Display for RestLengthError {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        write!(
+            f,
+            "expected {} bytes remaining, found {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RestLengthError {}
+
+/// Bits outside of the known mask were set, see [`flags`][crate::binary::flags]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnknownFlagsError<T> {
+    bits: T,
+}
+
+impl<T> UnknownFlagsError<T> {
+    /// Report that `bits` are not known flags
+    pub fn new(bits: T) -> Self {
+        Self { bits }
+    }
+
+    /// The bits that were set but are not known flags
+    pub fn bits(&self) -> &T {
+        &self.bits
+    }
+}
+
+impl<T: crate::lib::std::fmt::LowerHex> crate::lib::std::fmt::Display for UnknownFlagsError<T> {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        write!(f, "unknown flags set ({:#x})", self.bits)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: crate::lib::std::fmt::Debug + crate::lib::std::fmt::LowerHex> std::error::Error
+    for UnknownFlagsError<T>
+{
+}
+
+/// Trace all error paths, particularly for tests
+#[derive(Debug)]
+#[cfg(feature = "std")]
+pub enum TreeError<I, C = StrContext> {
+    /// Initial error that kicked things off
+    Base(TreeErrorBase<I>),
     /// Traces added to the error while walking back up the stack
     Stack {
         /// Initial error that kicked things off
@@ -1118,7 +2056,7 @@ where
     fn from_external_error(input: &I, e: E) -> Self {
         TreeError::Base(TreeErrorBase {
             input: input.clone(),
-            cause: Some(Box::new(e)),
+            cause: Some(BoxedError::unwrap_cause(Box::new(e))),
         })
     }
 }
@@ -1240,6 +2178,7 @@ pub struct ParseError<I, E> {
     input: I,
     offset: usize,
     inner: E,
+    trailing: bool,
 }
 
 impl<I: Stream, E: ParserError<I>> ParseError<I, E> {
@@ -1250,8 +2189,15 @@ impl<I: Stream, E: ParserError<I>> ParseError<I, E> {
             input,
             offset,
             inner,
+            trailing: false,
         }
     }
+
+    pub(crate) fn trailing(input: I, start: I::Checkpoint, inner: E) -> Self {
+        let mut err = Self::new(input, start, inner);
+        err.trailing = true;
+        err
+    }
 }
 
 impl<I, E> ParseError<I, E> {
@@ -1261,6 +2207,14 @@ impl<I, E> ParseError<I, E> {
         &self.input
     }
 
+    /// Whether the parser succeeded but did not consume all of [`ParseError::input`]
+    ///
+    /// See also [`ParseError::trailing_len`]
+    #[inline]
+    pub fn is_trailing(&self) -> bool {
+        self.trailing
+    }
+
     /// The location in [`ParseError::input`] where parsing failed
     ///
     /// <div class="warning">
@@ -1285,6 +2239,66 @@ impl<I, E> ParseError<I, E> {
     pub fn into_inner(self) -> E {
         self.inner
     }
+
+    /// The original [`ParserError`] with the location in [`ParseError::input`] where parsing
+    /// failed
+    #[inline]
+    pub fn into_inner_with_offset(self) -> (E, usize) {
+        (self.inner, self.offset)
+    }
+}
+
+impl<I: Stream, E> ParseError<I, E> {
+    /// The length of [`ParseError::input`] that was left unparsed, starting from
+    /// [`ParseError::offset`]
+    #[inline]
+    pub fn remaining_len(&self) -> usize {
+        self.input.eof_offset().saturating_sub(self.offset)
+    }
+
+    /// The length of input left over after the parser succeeded
+    ///
+    /// This is `None` when the parser itself failed.
+    #[inline]
+    pub fn trailing_len(&self) -> Option<usize> {
+        self.trailing.then(|| self.remaining_len())
+    }
+}
+
+impl<I: AsBStr, E> ParseError<I, E> {
+    /// The byte at [`ParseError::offset`], as a range into [`ParseError::input`]
+    ///
+    /// This may end in the middle of a UTF-8 character, so slicing a `&str` input with it can
+    /// panic.  See [`ParseError::char_span`] for that.
+    /// This is empty at eof.
+    pub fn byte_span(&self) -> crate::lib::std::ops::Range<usize> {
+        let input = self.input.as_bstr();
+        let start = self.offset.min(input.len());
+        let end = (start + 1).min(input.len());
+        start..end
+    }
+
+    /// The UTF-8 character containing [`ParseError::offset`], as a byte range
+    ///
+    /// The range is clamped to character boundaries, so slicing a `&str` input with it will not
+    /// panic.
+    /// This is empty at eof.
+    pub fn char_span(&self) -> crate::lib::std::ops::Range<usize> {
+        let input = self.input.as_bstr();
+        let mut start = self.offset.min(input.len());
+        while 0 < start && start < input.len() && is_utf8_continuation(input[start]) {
+            start -= 1;
+        }
+        let mut end = (start + 1).min(input.len());
+        while end < input.len() && is_utf8_continuation(input[end]) {
+            end += 1;
+        }
+        start..end
+    }
+}
+
+fn is_utf8_continuation(b: u8) -> bool {
+    b & 0b1100_0000 == 0b1000_0000
 }
 
 impl<I, E> core::fmt::Display for ParseError<I, E>
@@ -1295,7 +2309,6 @@ where
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let input = self.input.as_bstr();
         let span_start = self.offset;
-        let span_end = span_start;
         #[cfg(feature = "std")]
         if input.contains(&b'\n') {
             let (line_idx, col_idx) = translate_position(input, span_start);
@@ -1326,33 +2339,46 @@ where
             for _ in 0..col_idx {
                 write!(f, " ")?;
             }
-            // The span will be empty at eof, so we need to make sure we always print at least
-            // one `^`
-            write!(f, "^")?;
-            for _ in (span_start + 1)..(span_end.min(span_start + content.len())) {
-                write!(f, "^")?;
-            }
-            writeln!(f)?;
+            writeln!(f, "^")?;
         } else {
             let content = input;
             writeln!(f, "{}", String::from_utf8_lossy(content))?;
             for _ in 0..span_start {
                 write!(f, " ")?;
             }
-            // The span will be empty at eof, so we need to make sure we always print at least
-            // one `^`
-            write!(f, "^")?;
-            for _ in (span_start + 1)..(span_end.min(span_start + content.len())) {
-                write!(f, "^")?;
+            writeln!(f, "^")?;
+        }
+        if self.trailing {
+            let trailing = &input[span_start.min(input.len())..];
+            let mut preview_end = trailing.len().min(TRAILING_PREVIEW_LEN);
+            while 0 < preview_end
+                && preview_end < trailing.len()
+                && is_utf8_continuation(trailing[preview_end])
+            {
+                preview_end -= 1;
+            }
+            let ellipsis = if preview_end < trailing.len() {
+                "..."
+            } else {
+                ""
+            };
+            let preview = &trailing[..preview_end];
+            write!(f, "unexpected trailing input ({} bytes): `", trailing.len())?;
+            match crate::lib::std::str::from_utf8(preview) {
+                Ok(preview) => write!(f, "{preview}")?,
+                Err(_) => write!(f, "{}", preview.escape_ascii())?,
             }
-            writeln!(f)?;
+            write!(f, "{ellipsis}`")?;
+        } else {
+            write!(f, "{}", self.inner)?;
         }
-        write!(f, "{}", self.inner)?;
 
         Ok(())
     }
 }
 
+const TRAILING_PREVIEW_LEN: usize = 16;
+
 #[cfg(feature = "std")]
 fn translate_position(input: &[u8], index: usize) -> (usize, usize) {
     if input.is_empty() {
@@ -1384,6 +2410,316 @@ fn translate_position(input: &[u8], index: usize) -> (usize, usize) {
     (line, column)
 }
 
+#[cfg(test)]
+mod test_stack_context_error {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let err = StackContextError::<StrContext, 2>::new()
+            .add_context(
+                &"",
+                &"".checkpoint(),
+                StrContext::Expected(StrContextValue::CharLiteral('a')),
+            )
+            .add_context(
+                &"",
+                &"".checkpoint(),
+                StrContext::Expected(StrContextValue::CharLiteral('b')),
+            );
+        assert_eq!(err.dropped(), 0);
+        let mut buffer = [0u8; 32];
+        let written = {
+            use core::fmt::Write as _;
+            let mut writer = BufWriter(&mut buffer[..], 0);
+            write!(writer, "{err}").unwrap();
+            writer.1
+        };
+        assert_eq!(&buffer[..written], b"expected `a`, `b`");
+    }
+
+    #[test]
+    fn overflow() {
+        fn parser<'i>(input: &mut &'i str) -> Result<&'i str, StackContextError<StrContext, 2>> {
+            crate::ascii::digit1
+                .context(StrContext::Label("digits"))
+                .context(StrContext::Label("number"))
+                .context(StrContext::Label("value"))
+                .parse_next(input)
+        }
+
+        let err = parser.parse_peek("abc").unwrap_err();
+        let mut context = err.context();
+        assert_eq!(context.next(), Some(&StrContext::Label("digits")));
+        assert_eq!(context.next(), Some(&StrContext::Label("number")));
+        assert_eq!(context.next(), None);
+        assert_eq!(err.dropped(), 1);
+    }
+
+    struct BufWriter<'b>(&'b mut [u8], usize);
+
+    impl core::fmt::Write for BufWriter<'_> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.1 + s.len();
+            if self.0.len() < end {
+                return Err(core::fmt::Error);
+            }
+            self.0[self.1..end].copy_from_slice(s.as_bytes());
+            self.1 = end;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod test_incomplete {
+    use super::*;
+
+    use crate::token::take;
+    use crate::Partial;
+
+    #[test]
+    fn needed() {
+        let result =
+            take::<_, _, ErrMode<ContextError>>(4usize).parse_peek(Partial::new(&b"ab"[..]));
+        assert!(result.is_incomplete());
+        assert_eq!(result.needed(), Some(Needed::new(2)));
+        assert_eq!(
+            result.unwrap_err().needed().map(Needed::unwrap_size),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn complete() {
+        let result = take::<_, _, ErrMode<ContextError>>(4usize).parse_peek("ab");
+        assert!(!result.is_incomplete());
+        assert_eq!(result.needed(), None);
+        assert_eq!(result.unwrap_err().needed(), None);
+
+        let result =
+            take::<_, _, ErrMode<ContextError>>(2usize).parse_peek(Partial::new(&b"ab"[..]));
+        assert!(!result.is_incomplete());
+        assert_eq!(result.needed(), None);
+    }
+
+    #[test]
+    #[should_panic = "called `Needed::unwrap_size()` on `Needed::Unknown`"]
+    fn unwrap_unknown_size() {
+        Needed::Unknown.unwrap_size();
+    }
+
+    #[test]
+    fn std_error() {
+        fn app(input: &[u8]) -> core::result::Result<usize, Box<dyn std::error::Error>> {
+            let mut input = Partial::new(input);
+            let value = take::<_, _, ErrMode<ContextError>>(4usize).parse_next(&mut input)?;
+            Ok(value.len())
+        }
+
+        assert_eq!(app(b"abcd").unwrap(), 4);
+        assert_eq!(
+            app(b"ab").unwrap_err().to_string(),
+            "Parsing requires 2 more data"
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod test_str_context_value {
+    use super::*;
+    use crate::ascii::digit1;
+    use crate::lib::std::string::ToString;
+    use crate::token::{one_of, take_while};
+
+    #[test]
+    fn display() {
+        assert_eq!(StrContextValue::from('0'..='9').to_string(), "`0`-`9`");
+        assert_eq!(
+            StrContextValue::from('\0'..='\n').to_string(),
+            "`\\0`-newline"
+        );
+        assert_eq!(StrContextValue::from(b'a').to_string(), "`a`");
+        assert_eq!(StrContextValue::from(b'\n').to_string(), "newline");
+        assert_eq!(StrContextValue::from(0xff_u8).to_string(), "`\\xff`");
+    }
+
+    #[test]
+    fn rendered() {
+        fn digit(input: &mut &str) -> ModalResult<char> {
+            one_of('0'..='9')
+                .context(StrContext::Expected(('0'..='9').into()))
+                .parse_next(input)
+        }
+        assert_eq!(
+            digit.parse("x").unwrap_err().to_string(),
+            "\
+x
+^
+expected `0`-`9`"
+        );
+
+        fn number<'i>(input: &mut &'i str) -> ModalResult<&'i str> {
+            digit1
+                .context(StrContext::Label("number"))
+                .context(StrContext::Expected(('0'..='9').into()))
+                .parse_next(input)
+        }
+        assert_eq!(
+            number.parse("x").unwrap_err().to_string(),
+            "\
+x
+^
+invalid number
+expected `0`-`9`"
+        );
+
+        fn terminator<'i>(input: &mut &'i [u8]) -> ModalResult<&'i [u8]> {
+            take_while(1.., 0x00)
+                .context(StrContext::Expected(0x00.into()))
+                .context(StrContext::Expected(0xff.into()))
+                .parse_next(input)
+        }
+        assert_eq!(
+            terminator.parse(b"x").unwrap_err().into_inner().to_string(),
+            "expected `\\0`, `\\xff`"
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod test_expected {
+    use super::*;
+    use crate::combinator::alt;
+    use crate::lib::std::string::ToString;
+    use crate::token::{any, one_of};
+
+    fn render<O>(
+        mut parser: impl Parser<&'static str, O, ErrMode<ContextError>>,
+        input: &'static str,
+    ) -> String {
+        parser.parse(input).map(|_| ()).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn literals() {
+        assert_eq!(
+            render(alt(('a', 'b')), "c"),
+            "\
+c
+^
+expected `a` or `b`"
+        );
+        assert_eq!(
+            render(alt(('a', 'b', 'c')), "d"),
+            "\
+d
+^
+expected `a`, `b`, or `c`"
+        );
+        assert_eq!(
+            render(one_of(['+', '-']), "c"),
+            "\
+c
+^
+expected `+` or `-`"
+        );
+        assert_eq!(
+            render(one_of(('0'..='9', '_')), "c"),
+            "\
+c
+^
+expected `0`-`9` or `_`"
+        );
+        assert_eq!(
+            render(alt(('a', 'a')), "c"),
+            "\
+c
+^
+expected `a`"
+        );
+        assert_eq!(
+            render(('a', 'b'), "ac"),
+            "\
+ac
+ ^
+expected `b`"
+        );
+    }
+
+    #[test]
+    fn bytes() {
+        fn sign<'i>(input: &mut &'i [u8]) -> ModalResult<u8> {
+            one_of([b'+', b'-']).parse_next(input)
+        }
+        assert_eq!(
+            sign.parse(b"c").unwrap_err().into_inner().to_string(),
+            "expected `+` or `-`"
+        );
+    }
+
+    #[test]
+    fn label() {
+        assert_eq!(
+            render('a'.label("letter"), "c"),
+            "\
+c
+^
+invalid letter
+expected `a`"
+        );
+    }
+
+    #[test]
+    fn context_takes_precedence() {
+        assert_eq!(
+            render(
+                one_of('0'..='9')
+                    .context(StrContext::Expected(StrContextValue::Description("digit"))),
+                "c"
+            ),
+            "\
+c
+^
+expected digit"
+        );
+    }
+
+    #[test]
+    fn furthest() {
+        // Only the alternative that got the furthest is reported
+        assert_eq!(
+            render(alt((('a', 'b'), ('c', 'd'))), "ac"),
+            "\
+ac
+ ^
+expected `b`"
+        );
+    }
+
+    #[test]
+    fn incomplete() {
+        // Only a complete list of alternatives is reported
+        assert_eq!(
+            render(alt(('a', any.verify(|c: &char| *c == 'b'))), "c"),
+            "\
+c
+^
+"
+        );
+        assert_eq!(
+            render(one_of(['0', '1', '2', '3', '4', '5']), "c"),
+            "\
+c
+^
+"
+        );
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "std")]
 mod test_parse_error {
@@ -1403,6 +2739,134 @@ mod test_parse_error {
 failed to parse starting at: Z123";
         assert_eq!(error.to_string(), expected);
     }
+
+    #[test]
+    fn trailing_str() {
+        let err = crate::ascii::digit1::<_, ContextError>
+            .parse("123abc")
+            .unwrap_err();
+        assert!(err.is_trailing());
+        assert_eq!(err.trailing_len(), Some(3));
+        assert_eq!(err.offset(), 3);
+        let expected = "\
+123abc
+   ^
+unexpected trailing input (3 bytes): `abc`";
+        assert_eq!(err.to_string(), expected);
+
+        let err = crate::ascii::digit1::<_, ContextError>
+            .parse("1abcdefghijklmnopqrstuvwxyz")
+            .unwrap_err();
+        assert_eq!(err.trailing_len(), Some(26));
+        let expected = "\
+1abcdefghijklmnopqrstuvwxyz
+ ^
+unexpected trailing input (26 bytes): `abcdefghijklmnop...`";
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn trailing_bytes() {
+        let err = crate::ascii::digit1::<_, ContextError>
+            .parse(&b"123\xe2\x82\xac"[..])
+            .unwrap_err();
+        assert!(err.is_trailing());
+        assert_eq!(err.trailing_len(), Some(3));
+        let expected = "\
+123€
+   ^
+unexpected trailing input (3 bytes): `€`";
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn trailing_preview_char_boundary() {
+        // `é` straddles the preview length, so it is left out rather than split
+        let err = crate::ascii::digit1::<_, ContextError>
+            .parse("1abcdefghijklmnoé and more")
+            .unwrap_err();
+        assert_eq!(err.trailing_len(), Some(26));
+        let expected = "\
+1abcdefghijklmnoé and more
+ ^
+unexpected trailing input (26 bytes): `abcdefghijklmno...`";
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn trailing_bytes_hex() {
+        let err = crate::ascii::digit1::<_, ContextError>
+            .parse(&b"123\x00\xffabc"[..])
+            .unwrap_err();
+        assert_eq!(err.trailing_len(), Some(5));
+        let rendered = err.to_string();
+        assert!(rendered.ends_with("unexpected trailing input (5 bytes): `\\x00\\xffabc`"));
+        assert_eq!(
+            err.inner().expected().collect::<Vec<_>>(),
+            [&StrContextValue::Eof(5)]
+        );
+    }
+
+    #[test]
+    fn inner_failure() {
+        let err = crate::ascii::digit1::<_, InputError<_>>
+            .parse("abc")
+            .unwrap_err();
+        assert!(!err.is_trailing());
+        assert_eq!(err.trailing_len(), None);
+        let expected = "\
+abc
+^
+failed to parse starting at: abc";
+        assert_eq!(err.to_string(), expected);
+
+        let err = crate::ascii::digit1::<_, InputError<_>>
+            .parse(&b"abc"[..])
+            .unwrap_err();
+        assert!(!err.is_trailing());
+        assert_eq!(err.trailing_len(), None);
+    }
+
+    #[test]
+    fn offset_start() {
+        let input = "é1";
+        let start = input.checkpoint();
+        let error = ParseError::new(input, start, InputError::at(input));
+        assert_eq!(error.offset(), 0);
+        assert_eq!(error.remaining_len(), 3);
+        assert_eq!(error.byte_span(), 0..1);
+        assert!(!input.is_char_boundary(error.byte_span().end));
+        assert_eq!(error.char_span(), 0..2);
+        assert_eq!(&input[error.char_span()], "é");
+        assert_eq!(error.into_inner_with_offset(), (InputError::at(input), 0));
+    }
+
+    #[test]
+    fn offset_mid_char() {
+        let mut input = "aé1".as_bytes();
+        let start = input.checkpoint();
+        let _ = input.next_slice(2);
+        let error = ParseError::new(input, start, InputError::at(input));
+        assert_eq!(error.offset(), 2);
+        assert_eq!(error.remaining_len(), 2);
+        assert_eq!(error.byte_span(), 2..3);
+        assert_eq!(error.char_span(), 1..3);
+        let (_, offset) = error.into_inner_with_offset();
+        assert_eq!(offset, 2);
+    }
+
+    #[test]
+    fn offset_eof() {
+        let mut input = "aé";
+        let start = input.checkpoint();
+        let _ = input.finish();
+        let error = ParseError::new(input, start, InputError::at(input));
+        assert_eq!(error.offset(), 3);
+        assert_eq!(error.remaining_len(), 0);
+        assert_eq!(error.byte_span(), 3..3);
+        assert_eq!(error.char_span(), 3..3);
+        assert_eq!(&"aé"[error.char_span()], "");
+    }
 }
 
 #[cfg(test)]
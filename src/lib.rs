@@ -85,7 +85,7 @@ pub(crate) mod lib {
 
         #[doc(hidden)]
         pub(crate) use core::{
-            cmp, convert, fmt, hash, iter, mem, ops, option, result, slice, str,
+            any, cmp, convert, fmt, hash, iter, mem, ops, option, result, slice, str,
         };
     }
 
@@ -95,8 +95,8 @@ pub(crate) mod lib {
         #![allow(clippy::std_instead_of_core)]
         #[doc(hidden)]
         pub(crate) use std::{
-            borrow, boxed, cmp, collections, convert, fmt, hash, iter, mem, ops, result, slice,
-            str, string, vec,
+            any, borrow, boxed, cmp, collections, convert, fmt, hash, iter, mem, ops, result,
+            slice, str, string, vec,
         };
     }
 }
@@ -116,6 +116,9 @@ pub mod binary;
 pub mod combinator;
 pub mod token;
 
+#[cfg(feature = "std")]
+pub mod testing;
+
 #[cfg(feature = "unstable-doc")]
 pub mod _topic;
 #[cfg(feature = "unstable-doc")]
@@ -3,6 +3,12 @@
 /// Unlike normal struct initialization syntax:
 /// - `_` fields can exist to run a parser but ignore the result
 /// - Parse results for a field can later be referenced using the field name
+/// - Errors from a named field are labeled with the field name as a
+///   [`StrContext::Label`][crate::error::StrContext::Label], requiring the error to implement
+///   [`AddContext<I, StrContext>`][crate::error::AddContext]
+///   - Prefix a field with `#[no_label]` to skip this, like for a hot path
+///   - Prefix the struct name with `#[no_label]` to skip this for all fields, like for error types
+///     without [`StrContext`][crate::error::StrContext] support, and `#[label]` to opt a field back in
 ///
 /// Unlike normal tuple initialization syntax:
 /// - Struct-style initialization (`{ 0: _, 1: _}`) is not supported
@@ -37,6 +43,8 @@
 ///         _: (space0, b':', space0),
 ///         value: alphanumeric1.map(|s: &[u8]| s.to_owned()),
 ///         _: (space0, b':', space0),
+///         // Errors are labeled with the field name, unless opted out
+///         #[no_label]
 ///         point: point,
 ///         // default initialization also works
 ///         ..Default::default()
@@ -73,9 +81,24 @@
 #[doc(alias = "struct_parser")]
 #[doc(hidden)] // forced to be visible in intended location
 macro_rules! seq {
+    (#[no_label] $($name: ident)::* { $($fields: tt)* }) => {
+        $crate::combinator::trace(stringify!($($name)::*), move |input: &mut _| {
+            $crate::seq_parse_struct_fields!(
+                no_label;
+                ( $($fields)* );
+                ( _0, _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20 );
+                input ;
+            );
+            Ok($crate::seq_init_struct_fields!(
+                ( $($fields)* );
+                $($name)::* ;
+            ))
+        })
+    };
     ($($name: ident)::* { $($fields: tt)* }) => {
         $crate::combinator::trace(stringify!($($name)::*), move |input: &mut _| {
             $crate::seq_parse_struct_fields!(
+                label;
                 ( $($fields)* );
                 ( _0, _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20 );
                 input ;
@@ -125,18 +148,21 @@ macro_rules! seq {
 #[doc(hidden)]
 macro_rules! seq_parse_struct_fields {
     (
+        $label: ident;
         ( _ : $head_parser: expr, $($fields: tt)* );
         ( $unnamed1: ident, $($unnamed: ident),* );
         $input: ident ;
     ) => {
         let $unnamed1 = $crate::Parser::parse_next(&mut $head_parser, $input)?;
         $crate::seq_parse_struct_fields!(
+            $label;
             ( $($fields)* );
             ( $($unnamed),* );
             $input ;
         )
     };
     (
+        $label: ident;
         ( _ : $head_parser: expr );
         ( $unnamed1: ident, $($unnamed: ident),* );
         $input: ident ;
@@ -144,36 +170,110 @@ macro_rules! seq_parse_struct_fields {
         let $unnamed1 = $crate::Parser::parse_next(&mut $head_parser, $input)?;
     };
     (
-        ( $head_field: ident : $head_parser: expr, $($fields: tt)* );
+        $label: ident;
+        ( #[label] $head_field: ident : $head_parser: expr, $($fields: tt)* );
+        ( $unnamed1: ident, $($unnamed: ident),* );
+        $input: ident ;
+    ) => {
+        let $head_field = $crate::seq_parse_struct_field!(label; $head_field: $head_parser; $input);
+        $crate::seq_parse_struct_fields!(
+            $label;
+            ( $($fields)* );
+            ( $($unnamed),* );
+            $input ;
+        )
+    };
+    (
+        $label: ident;
+        ( #[label] $head_field: ident : $head_parser: expr );
+        ( $unnamed1: ident, $($unnamed: ident),* );
+        $input: ident ;
+    ) => {
+        let $head_field = $crate::seq_parse_struct_field!(label; $head_field: $head_parser; $input);
+    };
+    (
+        $label: ident;
+        ( #[no_label] $head_field: ident : $head_parser: expr, $($fields: tt)* );
         ( $unnamed1: ident, $($unnamed: ident),* );
         $input: ident ;
     ) => {
         let $head_field = $crate::Parser::parse_next(&mut $head_parser, $input)?;
         $crate::seq_parse_struct_fields!(
+            $label;
             ( $($fields)* );
             ( $($unnamed),* );
             $input ;
         )
     };
     (
-        ( $head_field: ident : $head_parser: expr );
+        $label: ident;
+        ( #[no_label] $head_field: ident : $head_parser: expr );
         ( $unnamed1: ident, $($unnamed: ident),* );
         $input: ident ;
     ) => {
         let $head_field = $crate::Parser::parse_next(&mut $head_parser, $input)?;
     };
     (
+        $label: ident;
+        ( $head_field: ident : $head_parser: expr, $($fields: tt)* );
+        ( $unnamed1: ident, $($unnamed: ident),* );
+        $input: ident ;
+    ) => {
+        let $head_field = $crate::seq_parse_struct_field!($label; $head_field: $head_parser; $input);
+        $crate::seq_parse_struct_fields!(
+            $label;
+            ( $($fields)* );
+            ( $($unnamed),* );
+            $input ;
+        )
+    };
+    (
+        $label: ident;
+        ( $head_field: ident : $head_parser: expr );
+        ( $unnamed1: ident, $($unnamed: ident),* );
+        $input: ident ;
+    ) => {
+        let $head_field = $crate::seq_parse_struct_field!($label; $head_field: $head_parser; $input);
+    };
+    (
+        $label: ident;
         ( .. $update: expr );
         ( $($unnamed: ident),* );
         $input: expr ;
     ) => {};
     (
+        $label: ident;
         ( $(,)? );
         ( $($unnamed: ident),* );
         $input: expr ;
     ) => {};
 }
 
+#[macro_export]
+#[doc(hidden)]
+macro_rules! seq_parse_struct_field {
+    (
+        label;
+        $field: ident : $parser: expr;
+        $input: ident
+    ) => {
+        $crate::Parser::parse_next(
+            &mut $crate::Parser::context(
+                $crate::Parser::by_ref(&mut $parser),
+                $crate::error::StrContext::Label(stringify!($field)),
+            ),
+            $input,
+        )?
+    };
+    (
+        no_label;
+        $field: ident : $parser: expr;
+        $input: ident
+    ) => {
+        $crate::Parser::parse_next(&mut $parser, $input)?
+    };
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! seq_parse_tuple_fields {
@@ -206,6 +306,29 @@ macro_rules! seq_parse_tuple_fields {
 #[macro_export]
 #[doc(hidden)]
 macro_rules! seq_init_struct_fields {
+    (
+        ( #[$attr: ident] $head_field: ident : $head_parser: expr, $($fields: tt)* );
+        $($name: ident)::* ;
+        $($inits: tt)*
+    ) =>
+    {
+        $crate::seq_init_struct_fields!(
+            ( $($fields)* );
+            $($name)::* ;
+            $($inits)* $head_field,
+        )
+    };
+    (
+        ( #[$attr: ident] $head_field: ident : $head_parser: expr );
+        $($name: ident)::* ;
+        $($inits: tt)*
+    ) => {
+        $crate::seq_init_struct_fields!(
+            ();
+            $($name)::* ;
+            $($inits)* $head_field,
+        )
+    };
     (
         ( _ : $head_parser: expr, $($fields: tt)* );
         $($name: ident)::* ;
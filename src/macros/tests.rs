@@ -3,6 +3,7 @@ use snapbox::str;
 
 use crate::ascii::dec_uint;
 use crate::ascii::digit0;
+use crate::combinator::cond_else;
 use crate::combinator::dispatch;
 use crate::combinator::empty;
 use crate::combinator::fail;
@@ -132,6 +133,72 @@ Err(
     );
 }
 
+#[test]
+fn seq_struct_cond_else() {
+    #[derive(Debug, PartialEq)]
+    struct Header {
+        version: u32,
+        size: u32,
+    }
+
+    fn parser<'i>(input: &mut &'i str) -> TestResult<&'i str, Header> {
+        seq! {
+            Header {
+                version: dec_uint,
+                _: ':',
+                size: cond_else(2 <= version, dec_uint, empty.value(0)),
+            }
+        }
+        .parse_next(input)
+    }
+    assert_parse!(
+        parser.parse_peek("2:10 remaining"),
+        str![[r#"
+Ok(
+    (
+        " remaining",
+        Header {
+            version: 2,
+            size: 10,
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        parser.parse_peek("1:10 remaining"),
+        str![[r#"
+Ok(
+    (
+        "10 remaining",
+        Header {
+            version: 1,
+            size: 0,
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        parser.parse_peek("2: remaining"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: " remaining",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
 #[test]
 fn seq_struct_default_init() {
     #[derive(Debug, PartialEq, Default)]
@@ -352,6 +419,126 @@ fn seq_struct_borrow() {
     }
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn seq_struct_field_label() {
+    use crate::combinator::{cut_err, delimited, separated};
+    use crate::error::{StrContext, StrContextValue};
+    use crate::lib::std::string::ToString;
+    use crate::lib::std::vec::Vec;
+
+    #[derive(Debug, PartialEq)]
+    struct List {
+        items: Vec<u32>,
+    }
+
+    fn item(input: &mut &str) -> ModalResult<u32> {
+        dec_uint
+            .context(StrContext::Expected(StrContextValue::Description(
+                "integer",
+            )))
+            .parse_next(input)
+    }
+
+    fn labeled(input: &mut &str) -> ModalResult<List> {
+        seq! {
+            List {
+                _: '[',
+                items: cut_err(separated(1.., item, ',')),
+                _: ']',
+            }
+        }
+        .parse_next(input)
+    }
+
+    fn opted_out(input: &mut &str) -> ModalResult<List> {
+        seq! {
+            List {
+                _: '[',
+                #[no_label]
+                items: cut_err(separated(1.., item, ',')),
+                _: ']',
+            }
+        }
+        .parse_next(input)
+    }
+
+    fn opted_in(input: &mut &str) -> ModalResult<List> {
+        seq! {
+            #[no_label]
+            List {
+                _: '[',
+                #[label]
+                items: cut_err(separated(1.., item, ',')),
+                _: ']',
+            }
+        }
+        .parse_next(input)
+    }
+
+    fn unlabeled_struct(input: &mut &str) -> ModalResult<List> {
+        seq! {
+            #[no_label]
+            List {
+                _: '[',
+                items: cut_err(separated(1.., item, ',')),
+                _: ']',
+            }
+        }
+        .parse_next(input)
+    }
+
+    assert_eq!(labeled.parse("[1,2]"), Ok(List { items: vec![1, 2] }));
+    assert_eq!(
+        labeled.parse("[x]").unwrap_err().inner().to_string(),
+        "invalid items\nexpected integer"
+    );
+    assert_eq!(opted_out.parse("[1,2]"), Ok(List { items: vec![1, 2] }));
+    assert_eq!(
+        opted_out.parse("[x]").unwrap_err().inner().to_string(),
+        "expected integer"
+    );
+    assert_eq!(
+        opted_in.parse("[x]").unwrap_err().inner().to_string(),
+        "invalid items\nexpected integer"
+    );
+    assert_eq!(
+        unlabeled_struct
+            .parse("[x]")
+            .unwrap_err()
+            .inner()
+            .to_string(),
+        "expected integer"
+    );
+
+    // Labels can be disabled for errors that don't support `StrContext`
+    fn custom_context(
+        input: &mut &str,
+    ) -> ModalResult<List, crate::error::ContextError<&'static str>> {
+        seq! {
+            #[no_label]
+            List {
+                items: delimited('[', separated(1.., dec_uint::<_, u32, _>.context("integer"), ','), ']'),
+            }
+        }
+        .parse_next(input)
+    }
+
+    assert_eq!(
+        custom_context.parse("[1,2]"),
+        Ok(List { items: vec![1, 2] })
+    );
+    assert_eq!(
+        custom_context
+            .parse("[x]")
+            .unwrap_err()
+            .inner()
+            .context()
+            .collect::<Vec<_>>(),
+        vec![&"integer"]
+    );
+}
+
 #[test]
 fn seq_tuple_struct_basics() {
     #[derive(Debug, PartialEq)]
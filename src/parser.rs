@@ -5,8 +5,10 @@ use crate::combinator::impls;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::error::FromRecoverableError;
-use crate::error::{AddContext, FromExternalError, ParseError, ParserError, Result};
-use crate::stream::{Compare, Location, ParseSlice, Stream, StreamIsPartial};
+use crate::error::{
+    AddContext, FromExternalError, ParseError, ParserError, Result, StrContext, StrContextValue,
+};
+use crate::stream::{Compare, ContainsToken, Location, ParseSlice, Stream, StreamIsPartial};
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::stream::{Recover, Recoverable};
@@ -43,34 +45,64 @@ use crate::stream::{Recover, Recoverable};
 /// ```
 ///
 /// Additionally, some basic types implement `Parser` as well, including
-/// - `u8` and `char`, see [`winnow::token::one_of`][crate::token::one_of]
+/// - `u8` and `char`, and arrays of them, see [`winnow::token::one_of`][crate::token::one_of]
 /// - `&[u8]` and `&str`, see [`winnow::token::literal`][crate::token::literal]
 pub trait Parser<I, O, E> {
     /// Parse all of `input`, generating `O` from it
+    ///
+    /// When the parser succeeds without consuming all of `input`, the error will report
+    /// [`ParseError::is_trailing`].
+    ///
+    /// As `input` is all there is, a [`Partial`][crate::Partial] stream is
+    /// [marked complete][StreamIsPartial::complete] for the duration of the parse.  This lets the
+    /// same parser serve both streaming callers, through [`Parser::parse_next`], and callers with
+    /// all of the data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// # use winnow::error::{ErrMode, Needed};
+    /// use winnow::ascii::digit1;
+    /// use winnow::Partial;
+    ///
+    /// fn number<'i>(input: &mut Partial<&'i str>) -> ModalResult<&'i str> {
+    ///     digit1.parse_next(input)
+    /// }
+    ///
+    /// // More digits may be coming
+    /// assert_eq!(number.parse_peek(Partial::new("123")), Err(ErrMode::Incomplete(Needed::new(1))));
+    /// // There is nothing more to come
+    /// assert_eq!(number.parse(Partial::new("123")), Ok("123"));
+    /// ```
     #[inline]
     fn parse(&mut self, mut input: I) -> Result<O, ParseError<I, <E as ParserError<I>>::Inner>>
     where
         Self: core::marker::Sized,
         I: Stream,
-        // Force users to deal with `Incomplete` when `StreamIsPartial<true>`
         I: StreamIsPartial,
         E: ParserError<I>,
         <E as ParserError<I>>::Inner: ParserError<I>,
     {
-        debug_assert!(
-            !I::is_partial_supported(),
-            "partial streams need to handle `ErrMode::Incomplete`"
-        );
-
+        let state = input.complete();
         let start = input.checkpoint();
-        let (o, _) = (self.by_ref(), crate::combinator::eof)
-            .parse_next(&mut input)
-            .map_err(|e| {
+        let o = match self.parse_next(&mut input) {
+            Ok(o) => o,
+            Err(e) => {
                 let e = e.into_inner().unwrap_or_else(|_err| {
                     panic!("complete parsers should not report `ErrMode::Incomplete(_)`")
                 });
-                ParseError::new(input, start, e)
-            })?;
+                input.restore_partial(state);
+                return Err(ParseError::new(input, start, e));
+            }
+        };
+        if let Err(e) = crate::combinator::eof::<_, E>.parse_next(&mut input) {
+            let e = e.into_inner().unwrap_or_else(|_err| {
+                panic!("complete parsers should not report `ErrMode::Incomplete(_)`")
+            });
+            input.restore_partial(state);
+            return Err(ParseError::trailing(input, start, e));
+        }
         Ok(o)
     }
 
@@ -103,10 +135,120 @@ pub trait Parser<I, O, E> {
         }
     }
 
+    /// Whether this parser may succeed without consuming input, when known ahead of time
+    ///
+    /// - `Some(true)`: it may, like [`opt`][crate::combinator::opt] or
+    ///   [`take_while(0.., ...)`][crate::token::take_while]
+    /// - `Some(false)`: it never does, like a non-empty [`literal`][crate::token::literal]
+    /// - `None`: unknown, the default, like for `fn` parsers
+    ///
+    /// This lets grammar bugs, like an unbounded [`repeat`][crate::combinator::repeat] of a parser
+    /// that will eventually stop consuming, be caught when the parser is built rather than when
+    /// the problematic input is parsed.  Combinators that don't change what gets consumed, like
+    /// [`Parser::map`], report their inner parser's hint; ones that may reject a match, like
+    /// [`Parser::verify`], report `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// # use winnow::error::ContextError;
+    /// use winnow::ascii::alpha0;
+    /// use winnow::combinator::opt;
+    /// use winnow::token::take_while;
+    ///
+    /// fn may_match_empty<O>(parser: impl Parser<&'static str, O, ContextError>) -> Option<bool> {
+    ///     parser.may_match_empty()
+    /// }
+    ///
+    /// assert_eq!(may_match_empty(opt('a')), Some(true));
+    /// assert_eq!(may_match_empty(take_while(0.., 'a')), Some(true));
+    /// assert_eq!(may_match_empty(take_while(1.., 'a')), Some(false));
+    /// assert_eq!(may_match_empty((opt('a'), "b").map(|(a, _)| a)), Some(false));
+    /// assert_eq!(may_match_empty(alpha0), None);
+    /// ```
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        None
+    }
+
+    /// Parse each of `inputs` in full, like [`Parser::parse`]
+    ///
+    /// This reuses `self` for every input, so anything done when building the parser (like
+    /// allocating the state captured by a closure) happens once for the batch.  To keep using the
+    /// parser afterwards, see [`Parser::by_ref`].
+    ///
+    /// <div class="warning">
+    ///
+    /// **Note:** Parsers take `&mut self` and may carry state between calls, so one parser can't
+    /// be shared between threads.  For parallel parsing, give each thread its own parser.
+    /// Parsers built from `fn`s and combinators are cheap to construct and are usually [`Clone`]
+    /// (`fn` items are also [`Copy`], [`Send`], and [`Sync`]).
+    ///
+    /// </div>
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// use winnow::ascii::{alpha1, dec_uint};
+    ///
+    /// fn record<'i>(i: &mut &'i str) -> ModalResult<(&'i str, u32)> {
+    ///     (alpha1, '=', dec_uint).map(|(k, _, v)| (k, v)).parse_next(i)
+    /// }
+    ///
+    /// let lines = ["a=1", "b=2", "c"];
+    /// let mut records = record.parse_iter(lines);
+    /// assert_eq!(records.next(), Some(Ok(("a", 1))));
+    /// assert_eq!(records.next(), Some(Ok(("b", 2))));
+    /// assert!(records.next().unwrap().is_err());
+    /// assert_eq!(records.next(), None);
+    ///
+    /// // One parser per thread
+    /// let lines = ["a=1", "b=2", "c=3", "d=4"];
+    /// let total = std::thread::scope(|s| {
+    ///     let workers = lines
+    ///         .chunks(2)
+    ///         .map(|chunk| {
+    ///             s.spawn(move || {
+    ///                 record
+    ///                     .parse_iter(chunk.iter().copied())
+    ///                     .map(|r| r.unwrap().1)
+    ///                     .sum::<u32>()
+    ///             })
+    ///         })
+    ///         .collect::<Vec<_>>();
+    ///     workers.into_iter().map(|w| w.join().unwrap()).sum::<u32>()
+    /// });
+    /// assert_eq!(total, 10);
+    /// ```
+    #[inline(always)]
+    fn parse_iter<It>(self, inputs: It) -> impls::ParseIter<Self, It::IntoIter, I, O, E>
+    where
+        Self: core::marker::Sized,
+        It: IntoIterator<Item = I>,
+        I: Stream,
+        // Force users to deal with `Incomplete` when `StreamIsPartial<true>`
+        I: StreamIsPartial,
+        E: ParserError<I>,
+        <E as ParserError<I>>::Inner: ParserError<I>,
+    {
+        impls::ParseIter {
+            p: self,
+            inputs: inputs.into_iter(),
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+
     /// Treat `&mut Self` as a parser
     ///
     /// This helps when needing to move a `Parser` when all you have is a `&mut Parser`.
     ///
+    /// For trait objects, like a `Box<dyn Parser<I, O, E>>`, `&mut dyn Parser<I, O, E>` is
+    /// itself a `Parser`.
+    ///
     /// # Example
     ///
     /// Because parsers are `FnMut`, they can be called multiple times. This prevents moving `f`
@@ -193,6 +335,43 @@ pub trait Parser<I, O, E> {
         }
     }
 
+    /// Produce a value computed by `f` on each success
+    ///
+    /// Unlike [`Parser::value`], this doesn't require [`Clone`], making it a better fit for
+    /// values that are expensive to clone, like inside of [`repeat`][crate::combinator::repeat].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::{error::ErrMode, Parser};
+    /// # use winnow::prelude::*;
+    /// use winnow::ascii::alpha1;
+    /// # fn main() {
+    ///
+    /// fn parser<'i>(input: &mut &'i str) -> ModalResult<String> {
+    ///     alpha1.value_with(|| String::from("word")).parse_next(input)
+    /// }
+    ///
+    /// assert_eq!(parser.parse_peek("abcd"), Ok(("", String::from("word"))));
+    /// assert!(parser.parse_peek("123abcd;").is_err());
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn value_with<G, O2>(self, f: G) -> impls::ValueWith<Self, G, I, O, O2, E>
+    where
+        Self: core::marker::Sized,
+        G: FnMut() -> O2,
+    {
+        impls::ValueWith {
+            parser: self,
+            f,
+            i: Default::default(),
+            o: Default::default(),
+            o2: Default::default(),
+            e: Default::default(),
+        }
+    }
+
     /// Produce a type's default value
     ///
     /// # Example
@@ -228,6 +407,9 @@ pub trait Parser<I, O, E> {
 
     /// Discards the output of the `Parser`
     ///
+    /// The output is still computed; to avoid work, like allocations, drop it from the parser
+    /// rather than discarding the result.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -445,6 +627,46 @@ pub trait Parser<I, O, E> {
         }
     }
 
+    /// Produce the span of consumed input, relative to where this parser started, with the output
+    ///
+    /// Unlike [`Parser::with_span`], this works with any [`Stream`] and doesn't require
+    /// [`Location`].  The trade off is that the span is *relative*: it always starts at `0`
+    /// (the position of `input` when this parser was called) and ends at the number of
+    /// [offsets][crate::stream::Offset::offset_from] consumed.
+    ///
+    /// For absolute spans, wrap your input in [`LocatingSlice`][crate::stream::LocatingSlice] and use
+    /// [`Parser::with_span`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// # use std::ops::Range;
+    /// use winnow::ascii::alpha1;
+    /// use winnow::combinator::separated_pair;
+    ///
+    /// fn parser<'i>(input: &mut &'i str) -> ModalResult<((&'i str, Range<usize>), (&'i str, Range<usize>))> {
+    ///     separated_pair(alpha1.consumed_span(), ',', alpha1.consumed_span()).parse_next(input)
+    /// }
+    ///
+    /// // Both spans are relative to the start of their own `alpha1`
+    /// assert_eq!(parser.parse("abcd,ef"), Ok((("abcd", 0..4), ("ef", 0..2))));
+    /// assert!(parser.parse_peek("abcd;").is_err());
+    /// ```
+    #[inline(always)]
+    fn consumed_span(self) -> impls::ConsumedSpan<Self, I, O, E>
+    where
+        Self: core::marker::Sized,
+        I: Stream,
+    {
+        impls::ConsumedSpan {
+            parser: self,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+
     /// Maps a function over the output of a parser
     ///
     /// # Example
@@ -526,6 +748,125 @@ pub trait Parser<I, O, E> {
         }
     }
 
+    /// Applies a function returning a `Result` with any error over the output of a parser.
+    ///
+    /// Unlike [`Parser::try_map`], the error only needs to convert into a
+    /// `Box<dyn std::error::Error + Send + Sync>`, like any [`std::error::Error`], a [`String`],
+    /// or a `&str`, making `?` usable with mixed error types.  The error is reported as a
+    /// [`BoxedError`][crate::error::BoxedError], e.g. as the [`ContextError::cause`][crate::error::ContextError::cause].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// # use winnow::error::ContextError;
+    /// use winnow::ascii::digit1;
+    ///
+    /// fn percent<'i>(input: &mut &'i str) -> ModalResult<u8> {
+    ///     digit1
+    ///         .try_map_boxed(|s: &str| -> Result<u8, Box<dyn std::error::Error + Send + Sync>> {
+    ///             let value = s.parse::<u8>()?;
+    ///             if 100 < value {
+    ///                 return Err(format!("{value} is over 100%").into());
+    ///             }
+    ///             Ok(value)
+    ///         })
+    ///         .parse_next(input)
+    /// }
+    ///
+    /// assert_eq!(percent.parse_peek("42"), Ok(("", 42)));
+    ///
+    /// let err = percent.parse("256").unwrap_err();
+    /// let cause = err.inner().cause().unwrap();
+    /// assert!(cause.downcast_ref::<std::num::ParseIntError>().is_some());
+    ///
+    /// let err = percent.parse("200").unwrap_err();
+    /// assert_eq!(err.inner().cause().unwrap().to_string(), "200 is over 100%");
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "std")]
+    fn try_map_boxed<G, O2, E2>(self, map: G) -> impls::TryMapBoxed<Self, G, I, O, O2, E, E2>
+    where
+        Self: core::marker::Sized,
+        G: FnMut(O) -> Result<O2, E2>,
+        E2: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+        I: Stream,
+        E: FromExternalError<I, crate::error::BoxedError>,
+        E: ParserError<I>,
+    {
+        impls::TryMapBoxed {
+            parser: self,
+            map,
+            i: Default::default(),
+            o: Default::default(),
+            o2: Default::default(),
+            e: Default::default(),
+            e2: Default::default(),
+        }
+    }
+
+    /// Applies a function returning a `Result` over the output of a parser, reporting the error
+    /// at an offset within what was parsed.
+    ///
+    /// Like [`Parser::try_map`] but the error also carries a byte offset, relative to where the
+    /// parser started, so errors inside of multi-line tokens like block strings can point at the
+    /// offending character rather than the start of the token.
+    /// The offset is clamped to the length of what was parsed.
+    ///
+    /// # Panics
+    ///
+    /// If the offset does not lie on a token boundary, like a UTF-8 sequence boundary for `&str`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// use winnow::token::take_till;
+    ///
+    /// fn lines<'i>(input: &mut &'i str) -> ModalResult<Vec<u8>> {
+    ///     take_till(0.., ';')
+    ///         .try_map_with_offset(|s: &str| {
+    ///             let mut offset = 0;
+    ///             s.split('\n')
+    ///                 .map(|line| {
+    ///                     let value = line.parse::<u8>().map_err(|e| (offset, e));
+    ///                     offset += line.len() + 1;
+    ///                     value
+    ///                 })
+    ///                 .collect()
+    ///         })
+    ///         .parse_next(input)
+    /// }
+    ///
+    /// assert_eq!(lines.parse_peek("1\n2\n3;"), Ok((";", vec![1, 2, 3])));
+    ///
+    /// // the error points at the line that failed, rather than the start of the block
+    /// let err = lines.parse("1\n2\n300").unwrap_err();
+    /// assert_eq!(err.offset(), 4);
+    /// ```
+    #[inline(always)]
+    fn try_map_with_offset<G, O2, E2>(
+        self,
+        map: G,
+    ) -> impls::TryMapWithOffset<Self, G, I, O, O2, E, E2>
+    where
+        Self: core::marker::Sized,
+        G: FnMut(O) -> Result<O2, (usize, E2)>,
+        I: Stream,
+        E: FromExternalError<I, E2>,
+        E: ParserError<I>,
+    {
+        impls::TryMapWithOffset {
+            parser: self,
+            map,
+            i: Default::default(),
+            o: Default::default(),
+            o2: Default::default(),
+            e: Default::default(),
+            e2: Default::default(),
+        }
+    }
+
     /// Apply both [`Parser::verify`] and [`Parser::map`].
     ///
     /// # Example
@@ -571,6 +912,55 @@ pub trait Parser<I, O, E> {
         }
     }
 
+    /// Apply both [`Parser::verify`] and [`Parser::map`], with access to the consumed input
+    ///
+    /// This is like [`Parser::with_taken`] followed by [`Parser::verify_map`], without the
+    /// intermediate tuple.
+    ///
+    /// On failure, the input is reset to where this parser started.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::{error::ErrMode, Parser};
+    /// # use winnow::prelude::*;
+    /// use winnow::ascii::digit1;
+    /// # fn main() {
+    ///
+    /// fn parser<'i>(input: &mut &'i str) -> ModalResult<u64> {
+    ///     digit1
+    ///         .parse_to::<u64>()
+    ///         // leading zeros would be lost when normalizing
+    ///         .verify_map_taken(|n, s: &&str| (!s.starts_with('0') || *s == "0").then_some(n))
+    ///         .parse_next(input)
+    /// }
+    ///
+    /// assert_eq!(parser.parse_peek("123"), Ok(("", 123)));
+    /// assert_eq!(parser.parse_peek("0"), Ok(("", 0)));
+    ///
+    /// // this will fail if the mapped function fails
+    /// assert!(parser.parse_peek("0123").is_err());
+    /// # }
+    /// ```
+    #[doc(alias = "verify_map_recognized")]
+    #[inline(always)]
+    fn verify_map_taken<G, O2>(self, map: G) -> impls::VerifyMapTaken<Self, G, I, O, O2, E>
+    where
+        Self: core::marker::Sized,
+        G: FnMut(O, &<I as Stream>::Slice) -> Option<O2>,
+        I: Stream,
+        E: ParserError<I>,
+    {
+        impls::VerifyMapTaken {
+            parser: self,
+            map,
+            i: Default::default(),
+            o: Default::default(),
+            o2: Default::default(),
+            e: Default::default(),
+        }
+    }
+
     /// Creates a parser from the output of this one
     ///
     /// # Example
@@ -659,6 +1049,48 @@ pub trait Parser<I, O, E> {
         }
     }
 
+    /// Applies a `&str` parser over the `&[u8]` output of the first one
+    ///
+    /// The output is validated as UTF-8, reporting a [`Utf8Error`][core::str::Utf8Error] at the
+    /// first invalid byte.  Errors from `inner` are positioned in the outer input, at the same
+    /// place as in the output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// use winnow::ascii::{alpha1, digit1};
+    /// use winnow::token::take;
+    ///
+    /// fn name<'i>(input: &mut &'i [u8]) -> ModalResult<(&'i str, &'i str)> {
+    ///     take(5u8).and_then_str((alpha1, digit1)).parse_next(input)
+    /// }
+    ///
+    /// assert_eq!(name.parse_peek(&b"abc12;"[..]), Ok((&b";"[..], ("abc", "12"))));
+    ///
+    /// let err = name.parse(&b"ab\xff12"[..]).unwrap_err();
+    /// assert_eq!(err.offset(), 2);
+    /// let err = name.parse(&b"abc;1"[..]).unwrap_err();
+    /// assert_eq!(err.offset(), 3);
+    /// ```
+    #[inline(always)]
+    fn and_then_str<'i, G, O2>(self, inner: G) -> impls::AndThenStr<Self, G, I, O2, E>
+    where
+        Self: core::marker::Sized,
+        Self: Parser<I, &'i [u8], E>,
+        G: Parser<&'i str, O2, E>,
+        I: Stream<Slice = &'i [u8]>,
+        E: FromExternalError<I, core::str::Utf8Error>,
+    {
+        impls::AndThenStr {
+            outer: self,
+            inner,
+            i: Default::default(),
+            o2: Default::default(),
+            e: Default::default(),
+        }
+    }
+
     /// Apply [`std::str::FromStr`] to the output of the parser
     ///
     /// # Example
@@ -763,38 +1195,160 @@ pub trait Parser<I, O, E> {
         }
     }
 
-    /// Transforms [`Incomplete`][crate::error::ErrMode::Incomplete] into [`Backtrack`][crate::error::ErrMode::Backtrack]
+    /// If parsing fails, name what was being parsed
+    ///
+    /// This is shorthand for [`.context(StrContext::Label(label))`][Parser::context].
     ///
     /// # Example
     ///
     /// ```rust
-    /// # use winnow::{error::ErrMode, error::InputError, stream::Partial, Parser};
-    /// # use winnow::token::take;
+    /// # #[cfg(feature = "std")] {
     /// # use winnow::prelude::*;
-    /// # fn main() {
+    /// use winnow::combinator::alt;
     ///
-    /// fn parser<'i>(input: &mut Partial<&'i str>) -> ModalResult<&'i str, InputError<Partial<&'i str>>> {
-    ///     take(5u8).complete_err().parse_next(input)
+    /// fn boolean(input: &mut &str) -> ModalResult<bool> {
+    ///     alt(("true".value(true), "false".value(false)))
+    ///         .label("boolean")
+    ///         .parse_next(input)
     /// }
     ///
-    /// assert_eq!(parser.parse_peek(Partial::new("abcdefg")), Ok((Partial::new("fg"), "abcde")));
-    /// assert_eq!(parser.parse_peek(Partial::new("abcd")), Err(ErrMode::Backtrack(InputError::at(Partial::new("abcd")))));
+    /// let err = boolean.parse("maybe").unwrap_err();
+    /// assert_eq!(err.inner().to_string(), "invalid boolean");
     /// # }
     /// ```
     #[inline(always)]
-    fn complete_err(self) -> impls::CompleteErr<Self, I, O, E>
+    fn label(self, label: &'static str) -> impls::Context<Self, I, O, E, StrContext>
     where
         Self: core::marker::Sized,
+        I: Stream,
+        E: AddContext<I, StrContext>,
+        E: ParserError<I>,
     {
-        impls::CompleteErr {
-            p: self,
-            i: Default::default(),
-            o: Default::default(),
+        self.context(StrContext::Label(label))
+    }
+
+    /// Trace the execution of the parser under `name`
+    ///
+    /// This is the method form of [`trace`][crate::combinator::trace], see it for more details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// # use winnow::error::ContextError;
+    /// use winnow::ascii::alpha1;
+    ///
+    /// fn tag<'i>(expected: &'static str) -> impl ModalParser<&'i str, &'i str, ContextError> {
+    ///     move |input: &mut &'i str| {
+    ///         alpha1
+    ///             .verify(move |s: &str| s == expected)
+    ///             .trace(format_args!("tag({expected:?})"))
+    ///             .parse_next(input)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(tag("hello").parse_peek("hello world"), Ok((" world", "hello")));
+    /// assert!(tag("hello").parse_peek("world").is_err());
+    /// ```
+    #[inline(always)]
+    fn trace<D>(self, name: D) -> impls::Trace<Self, D, I, O, E>
+    where
+        Self: core::marker::Sized,
+        D: crate::lib::std::fmt::Display,
+        I: Stream,
+        E: ParserError<I>,
+    {
+        impls::Trace {
+            parser: self,
+            name,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+
+    /// Transforms [`Incomplete`][crate::error::ErrMode::Incomplete] into [`Backtrack`][crate::error::ErrMode::Backtrack]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::{error::ErrMode, error::InputError, stream::Partial, Parser};
+    /// # use winnow::token::take;
+    /// # use winnow::prelude::*;
+    /// # fn main() {
+    ///
+    /// fn parser<'i>(input: &mut Partial<&'i str>) -> ModalResult<&'i str, InputError<Partial<&'i str>>> {
+    ///     take(5u8).complete_err().parse_next(input)
+    /// }
+    ///
+    /// assert_eq!(parser.parse_peek(Partial::new("abcdefg")), Ok((Partial::new("fg"), "abcde")));
+    /// assert_eq!(parser.parse_peek(Partial::new("abcd")), Err(ErrMode::Backtrack(InputError::at(Partial::new("abcd")))));
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn complete_err(self) -> impls::CompleteErr<Self, I, O, E>
+    where
+        Self: core::marker::Sized,
+    {
+        impls::CompleteErr {
+            p: self,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+
+    /// Transforms [`Incomplete`][crate::error::ErrMode::Incomplete] into [`Backtrack`][crate::error::ErrMode::Backtrack],
+    /// adding `context` to the error
+    ///
+    /// Like [`Parser::complete_err`], this is for when the input is known to be complete, like
+    /// within a frame whose length was already read, but the error explains that waiting for more
+    /// data won't help.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// # use winnow::{error::ErrMode, error::StrContext, stream::Partial, Parser};
+    /// # use winnow::token::take;
+    /// # use winnow::prelude::*;
+    /// # fn main() {
+    ///
+    /// fn parser<'i>(input: &mut Partial<&'i str>) -> ModalResult<&'i str> {
+    ///     take(5u8)
+    ///         .expect_complete(StrContext::Label("checksum"))
+    ///         .parse_next(input)
+    /// }
+    ///
+    /// assert_eq!(parser.parse_peek(Partial::new("abcdefg")), Ok((Partial::new("fg"), "abcde")));
+    ///
+    /// let err = parser.parse_peek(Partial::new("abcd")).unwrap_err();
+    /// assert!(matches!(err, ErrMode::Backtrack(_)));
+    /// assert_eq!(err.into_inner().unwrap().to_string(), "invalid checksum");
+    /// # }
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn expect_complete<C>(self, context: C) -> impls::ExpectComplete<Self, I, O, E, C>
+    where
+        Self: core::marker::Sized,
+        I: Stream,
+        E: AddContext<I, C>,
+        E: ParserError<I>,
+        C: Clone + crate::lib::std::fmt::Debug,
+    {
+        impls::ExpectComplete {
+            p: self,
+            context,
+            i: Default::default(),
+            o: Default::default(),
             e: Default::default(),
         }
     }
 
     /// Convert the parser's error to another type using [`std::convert::From`]
+    ///
+    /// For [`ModalResult`][crate::ModalResult] parsers, see [`Parser::modal_err_into`].
     #[inline(always)]
     fn err_into<E2>(self) -> impls::ErrInto<Self, I, O, E, E2>
     where
@@ -810,6 +1364,80 @@ pub trait Parser<I, O, E> {
         }
     }
 
+    /// Convert the inner error of an [`ErrMode`][crate::error::ErrMode] to another type using
+    /// [`std::convert::From`]
+    ///
+    /// [`Parser::err_into`] converts the error as a whole, which doesn't work for
+    /// [`ModalResult`][crate::ModalResult] parsers as [`ErrMode`][crate::error::ErrMode] can't
+    /// implement `From` for other `ErrMode`s.  This instead converts the error inside of the
+    /// `ErrMode`, preserving whether it is
+    /// - [`Backtrack`][crate::error::ErrMode::Backtrack], so alternatives are still tried
+    /// - [`Cut`][crate::error::ErrMode::Cut], so parsing still stops
+    /// - [`Incomplete`][crate::error::ErrMode::Incomplete], with its [`Needed`][crate::error::Needed] left as-is
+    ///
+    /// This is intended for gluing together crates with different error types, like a shared
+    /// low-level crate reporting [`ContextError`][crate::error::ContextError] being called from an
+    /// application crate with its own error type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// use winnow::ascii::digit1;
+    /// use winnow::combinator::alt;
+    /// use winnow::error::{ContextError, ParserError};
+    ///
+    /// // Shared crate
+    /// fn number<'i>(i: &mut &'i str) -> ModalResult<&'i str, ContextError> {
+    ///     digit1.parse_next(i)
+    /// }
+    ///
+    /// // Application crate
+    /// #[derive(Debug, PartialEq)]
+    /// enum AppError {
+    ///     Lexer(ContextError),
+    ///     Parser,
+    /// }
+    ///
+    /// impl From<ContextError> for AppError {
+    ///     fn from(err: ContextError) -> Self {
+    ///         AppError::Lexer(err)
+    ///     }
+    /// }
+    ///
+    /// impl<'i> ParserError<&'i str> for AppError {
+    ///     type Inner = Self;
+    ///
+    ///     fn from_input(_input: &&'i str) -> Self {
+    ///         AppError::Parser
+    ///     }
+    ///
+    ///     fn into_inner(self) -> Result<Self::Inner, Self> {
+    ///         Ok(self)
+    ///     }
+    /// }
+    ///
+    /// fn value<'i>(i: &mut &'i str) -> ModalResult<&'i str, AppError> {
+    ///     alt((number.modal_err_into(), "none")).parse_next(i)
+    /// }
+    ///
+    /// assert_eq!(value.parse_peek("42"), Ok(("", "42")));
+    /// assert_eq!(value.parse_peek("none"), Ok(("", "none")));
+    /// ```
+    #[inline(always)]
+    fn modal_err_into<E2>(self) -> impls::ModalErrInto<Self, I, O, E, E2>
+    where
+        Self: core::marker::Sized,
+    {
+        impls::ModalErrInto {
+            parser: self,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+            e2: Default::default(),
+        }
+    }
+
     /// Recover from an error by skipping everything `recover` consumes and trying again
     ///
     /// If `recover` consumes nothing, the error is returned, allowing an alternative recovery
@@ -897,7 +1525,15 @@ where
 {
     #[inline(always)]
     fn parse_next(&mut self, i: &mut I) -> Result<u8, E> {
-        crate::token::literal(*self).value(*self).parse_next(i)
+        crate::token::sized_literal(*self)
+            .value(*self)
+            .parse_next(i)
+            .map_err(|e: E| e.add_expected(i, StrContextValue::ByteLiteral(*self)))
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        Some(false)
     }
 }
 
@@ -925,7 +1561,15 @@ where
 {
     #[inline(always)]
     fn parse_next(&mut self, i: &mut I) -> Result<char, E> {
-        crate::token::literal(*self).value(*self).parse_next(i)
+        crate::token::sized_literal(*self)
+            .value(*self)
+            .parse_next(i)
+            .map_err(|e: E| e.add_expected(i, StrContextValue::CharLiteral(*self)))
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        Some(false)
     }
 }
 
@@ -954,7 +1598,12 @@ where
 {
     #[inline(always)]
     fn parse_next(&mut self, i: &mut I) -> Result<<I as Stream>::Slice, E> {
-        crate::token::literal(*self).parse_next(i)
+        crate::token::sized_literal(*self).parse_next(i)
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        Some(self.is_empty())
     }
 }
 
@@ -986,7 +1635,12 @@ where
 {
     #[inline(always)]
     fn parse_next(&mut self, i: &mut I) -> Result<<I as Stream>::Slice, E> {
-        crate::token::literal(*self).parse_next(i)
+        crate::token::sized_literal(*self).parse_next(i)
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        Some(self.0.is_empty())
     }
 }
 
@@ -1015,7 +1669,12 @@ where
 {
     #[inline(always)]
     fn parse_next(&mut self, i: &mut I) -> Result<<I as Stream>::Slice, E> {
-        crate::token::literal(*self).parse_next(i)
+        crate::token::sized_literal(*self).parse_next(i)
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        Some(N == 0)
     }
 }
 
@@ -1048,7 +1707,12 @@ where
 {
     #[inline(always)]
     fn parse_next(&mut self, i: &mut I) -> Result<<I as Stream>::Slice, E> {
-        crate::token::literal(*self).parse_next(i)
+        crate::token::sized_literal(*self).parse_next(i)
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        Some(N == 0)
     }
 }
 
@@ -1077,7 +1741,12 @@ where
 {
     #[inline(always)]
     fn parse_next(&mut self, i: &mut I) -> Result<<I as Stream>::Slice, E> {
-        crate::token::literal(*self).parse_next(i)
+        crate::token::sized_literal(*self).parse_next(i)
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        Some(self.is_empty())
     }
 }
 
@@ -1109,7 +1778,57 @@ where
 {
     #[inline(always)]
     fn parse_next(&mut self, i: &mut I) -> Result<<I as Stream>::Slice, E> {
-        crate::token::literal(*self).parse_next(i)
+        crate::token::sized_literal(*self).parse_next(i)
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        Some(self.0.is_empty())
+    }
+}
+
+/// This is a shortcut for [`one_of`][crate::token::one_of].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError};
+/// fn parser<'s>(i: &mut &'s str) -> ModalResult<char> {
+///     ['t', 'f'].parse_next(i)
+/// }
+/// assert_eq!(parser.parse_peek("true"), Ok(("rue", 't')));
+/// assert_eq!(parser.parse_peek("false"), Ok(("alse", 'f')));
+/// assert!(parser.parse_peek("none").is_err());
+/// assert!(parser.parse_peek("").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError};
+/// fn parser<'s>(i: &mut &'s [u8]) -> ModalResult<u8> {
+///     [b'+', b'-'].parse_next(i)
+/// }
+/// assert_eq!(parser.parse_peek(&b"-1"[..]), Ok((&b"1"[..], b'-')));
+/// assert!(parser.parse_peek(&b"1"[..]).is_err());
+/// assert!(parser.parse_peek(&b""[..]).is_err());
+/// ```
+impl<I, E, C, const N: usize> Parser<I, <I as Stream>::Token, E> for [C; N]
+where
+    I: StreamIsPartial,
+    I: Stream,
+    <I as Stream>::Token: Clone,
+    [C; N]: ContainsToken<<I as Stream>::Token> + Clone,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, i: &mut I) -> Result<<I as Stream>::Token, E> {
+        crate::token::one_of(self.clone()).parse_next(i)
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        Some(false)
     }
 }
 
@@ -1118,6 +1837,11 @@ impl<I: Stream, E: ParserError<I>> Parser<I, (), E> for () {
     fn parse_next(&mut self, _i: &mut I) -> Result<(), E> {
         Ok(())
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        Some(true)
+    }
 }
 
 macro_rules! impl_parser_for_tuple {
@@ -1133,6 +1857,17 @@ macro_rules! impl_parser_for_tuple {
 
         Ok(($($output),+,))
       }
+
+      #[inline(always)]
+      fn may_match_empty(&self) -> Option<bool> {
+        let mut may_match_empty = Some(true);
+        $(match self.$index.may_match_empty() {
+          Some(false) => return Some(false),
+          Some(true) => {}
+          None => may_match_empty = None,
+        })+
+        may_match_empty
+      }
     }
   )
 }
@@ -1184,6 +1919,23 @@ impl<I, O, E> Parser<I, O, E> for Box<dyn Parser<I, O, E> + '_> {
     fn parse_next(&mut self, i: &mut I) -> Result<O, E> {
         (**self).parse_next(i)
     }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        (**self).may_match_empty()
+    }
+}
+
+impl<I, O, E> Parser<I, O, E> for &mut (dyn Parser<I, O, E> + '_) {
+    #[inline(always)]
+    fn parse_next(&mut self, i: &mut I) -> Result<O, E> {
+        (**self).parse_next(i)
+    }
+
+    #[inline(always)]
+    fn may_match_empty(&self) -> Option<bool> {
+        (**self).may_match_empty()
+    }
 }
 
 /// Trait alias for [`Parser`] to be used with [`ModalResult`][crate::error::ModalResult]
@@ -1265,6 +2017,7 @@ mod tests {
     use crate::error::ErrMode;
     use crate::error::Needed;
     use crate::error::TestResult;
+    use crate::lib::std::ops::Range;
     use crate::token::take;
     use crate::Partial;
 
@@ -1285,6 +2038,135 @@ mod tests {
         assert_size!(ErrMode<u32>, 16);
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn value_with_repeat() {
+        use crate::lib::std::string::String;
+        use crate::lib::std::vec::Vec;
+
+        let mut calls = 0;
+        let res = crate::combinator::repeat::<_, _, Vec<_>, _, _>(
+            0..,
+            "ab".value_with(|| {
+                calls += 1;
+                String::from("x")
+            }),
+        )
+        .parse_peek("ababc");
+        assert_parse!(
+            res,
+            str![[r#"
+Ok(
+    (
+        "c",
+        [
+            "x",
+            "x",
+        ],
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn default_value_repeat() {
+        fn parser<'i>(i: &mut &'i str) -> TestResult<&'i str, ()> {
+            crate::combinator::repeat(0.., "ab".default_value::<u32>())
+                .fold(|| (), |(), n| assert_eq!(n, 0))
+                .parse_next(i)
+        }
+
+        assert_parse!(
+            parser.parse_peek("ababc"),
+            str![[r#"
+Ok(
+    (
+        "c",
+        (),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn consumed_span_nested() {
+        fn inner<'i>(i: &mut &'i str) -> TestResult<&'i str, (&'i str, Range<usize>)> {
+            take(2usize).consumed_span().parse_next(i)
+        }
+        fn outer<'i>(i: &mut &'i str) -> TestResult<&'i str, (Range<usize>, Range<usize>)> {
+            (take(1usize), inner)
+                .map(|(_, (_, inner))| inner)
+                .consumed_span()
+                .parse_next(i)
+        }
+
+        assert_parse!(
+            outer.parse_peek("abcd"),
+            str![[r#"
+Ok(
+    (
+        "d",
+        (
+            0..2,
+            0..3,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn consumed_span_alt_backtrack() {
+        fn parser<'i>(i: &mut &'i str) -> TestResult<&'i str, (&'i str, Range<usize>)> {
+            crate::combinator::alt((("ab", "x").take(), "a"))
+                .consumed_span()
+                .parse_next(i)
+        }
+
+        assert_parse!(
+            parser.parse_peek("aby"),
+            str![[r#"
+Ok(
+    (
+        "by",
+        (
+            "a",
+            0..1,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        assert_parse!(
+            parser.parse_peek("abx"),
+            str![[r#"
+Ok(
+    (
+        "",
+        (
+            "abx",
+            0..3,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+    }
+
     #[test]
     fn err_map_test() {
         let e = ErrMode::Backtrack(1);
@@ -1384,7 +2266,9 @@ Err(
             str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            2,
+        ),
     ),
 )
 
@@ -1454,6 +2338,178 @@ Ok(
     ),
 )
 
+"#]]
+            .raw()
+        );
+    }
+
+    #[test]
+    fn modal_err_into_test() {
+        use crate::combinator::{alt, cut_err};
+        use crate::error::InputError;
+        use crate::ModalResult;
+
+        #[derive(Debug, PartialEq)]
+        enum AppError<'i> {
+            Lexer(InputError<&'i str>),
+            Parser(&'i str),
+        }
+
+        impl<'i> From<InputError<&'i str>> for AppError<'i> {
+            fn from(err: InputError<&'i str>) -> Self {
+                AppError::Lexer(err)
+            }
+        }
+
+        impl<'i> ParserError<&'i str> for AppError<'i> {
+            type Inner = Self;
+
+            fn from_input(input: &&'i str) -> Self {
+                AppError::Parser(input)
+            }
+
+            fn into_inner(self) -> Result<Self::Inner, Self> {
+                Ok(self)
+            }
+        }
+
+        fn lexer<'i>(i: &mut &'i str) -> ModalResult<&'i str, InputError<&'i str>> {
+            alt(("a", ("!", cut_err("b")).take())).parse_next(i)
+        }
+        fn lexer_partial<'i>(
+            i: &mut Partial<&'i str>,
+        ) -> ModalResult<&'i str, InputError<Partial<&'i str>>> {
+            "abc".parse_next(i)
+        }
+        fn parser<'i>(i: &mut &'i str) -> ModalResult<&'i str, AppError<'i>> {
+            alt((lexer.modal_err_into(), "c")).parse_next(i)
+        }
+
+        assert_eq!(parser.parse_peek("a"), Ok(("", "a")));
+        assert_eq!(parser.parse_peek("c"), Ok(("", "c")));
+        assert_eq!(
+            parser.parse_peek("d"),
+            Err(ErrMode::Backtrack(AppError::Parser("d")))
+        );
+        assert_eq!(
+            parser.parse_peek("!d"),
+            Err(ErrMode::Cut(AppError::Lexer(InputError::at("d"))))
+        );
+        assert_eq!(
+            lexer_partial
+                .modal_err_into::<InputError<Partial<&str>>>()
+                .parse_peek(Partial::new("ab")),
+            Err(ErrMode::Incomplete(Needed::new(1)))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn parse_partial_as_complete() {
+        use crate::binary::length_take;
+        use crate::combinator::repeat;
+        use crate::lib::std::vec::Vec;
+
+        fn records<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, Vec<&'i [u8]>> {
+            repeat(0.., length_take(be_u16)).parse_next(i)
+        }
+
+        let data = &b"\x00\x02ab\x00\x01c"[..];
+        // Streaming: more records may follow
+        assert_parse!(
+            records.parse_peek(Partial::new(data)),
+            str![[r#"
+Err(
+    Incomplete(
+        Size(
+            2,
+        ),
+    ),
+)
+
+"#]]
+            .raw()
+        );
+        // Batch: this is all of the data
+        snapbox::assert_data_eq!(
+            records.parse(Partial::new(data)).to_debug(),
+            str![[r#"
+Ok(
+    [
+        [
+            97,
+            98,
+        ],
+        [
+            99,
+        ],
+    ],
+)
+
+"#]]
+            .raw()
+        );
+        // The reported input is left partial, like it was given
+        snapbox::assert_data_eq!(
+            records
+                .parse(Partial::new(&b"\x00\x02ab\x00\x03c"[..]))
+                .to_debug(),
+            str![[r#"
+Err(
+    ParseError {
+        input: Partial {
+            input: [
+                0,
+                2,
+                97,
+                98,
+                0,
+                3,
+                99,
+            ],
+            partial: true,
+        },
+        offset: 4,
+        inner: InputError {
+            input: Partial {
+                input: [
+                    0,
+                    3,
+                    99,
+                ],
+                partial: false,
+            },
+        },
+        trailing: true,
+    },
+)
+
+"#]]
+            .raw()
+        );
+        let results = records
+            .parse_iter([Partial::new(data), Partial::new(&b""[..])])
+            .collect::<Vec<_>>();
+        snapbox::assert_data_eq!(
+            results.to_debug(),
+            str![[r#"
+[
+    Ok(
+        [
+            [
+                97,
+                98,
+            ],
+            [
+                99,
+            ],
+        ],
+    ),
+    Ok(
+        [],
+    ),
+]
+
 "#]]
             .raw()
         );
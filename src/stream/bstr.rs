@@ -4,6 +4,7 @@ use crate::error::Needed;
 use crate::lib::std::iter::{Cloned, Enumerate};
 use crate::lib::std::slice::Iter;
 use crate::lib::std::{cmp::Ordering, fmt, ops};
+use crate::stream::fmt_preview_ascii;
 use crate::stream::AsBStr;
 use crate::stream::Checkpoint;
 use crate::stream::Compare;
@@ -16,6 +17,7 @@ use crate::stream::Recover;
 use crate::stream::SliceLen;
 use crate::stream::Stream;
 use crate::stream::StreamIsPartial;
+use crate::stream::StreamPreview;
 use crate::stream::UpdateSlice;
 
 /// Improved `Debug` experience for `&[u8]` UTF-8-ish streams
@@ -241,6 +243,12 @@ impl fmt::Debug for BStr {
     }
 }
 
+impl StreamPreview for &BStr {
+    fn fmt_preview(&self, max_len: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_preview_ascii(self.as_bytes(), max_len, f)
+    }
+}
+
 impl ops::Deref for BStr {
     type Target = [u8];
 
@@ -0,0 +1,175 @@
+use crate::lib::std::ops::RangeInclusive;
+
+use crate::stream::ContainsToken;
+
+/// A [set of bytes][ContainsToken] backed by a 256-entry lookup table
+///
+/// Tables are built in `const` contexts, so classifying a token is a single lookup rather than
+/// a chain of comparisons.  With `char` tokens, like for `&str`, only ASCII characters can match.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::stream::ByteTable;
+/// use winnow::token::take_while;
+///
+/// const IDENT_START: ByteTable = ByteTable::new(b"_")
+///     .with_range(b'a'..=b'z')
+///     .with_range(b'A'..=b'Z');
+/// const IDENT_CONTINUE: ByteTable = IDENT_START.union(ByteTable::from_range(b'0'..=b'9'));
+///
+/// fn ident<'i>(input: &mut &'i str) -> ModalResult<&'i str> {
+///     (take_while(1, IDENT_START), take_while(0.., IDENT_CONTINUE))
+///         .take()
+///         .parse_next(input)
+/// }
+///
+/// assert_eq!(ident.parse_peek("_foo1 = 2"), Ok((" = 2", "_foo1")));
+/// assert!(ident.parse_peek("1foo").is_err());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ByteTable {
+    table: [bool; 256],
+}
+
+impl ByteTable {
+    /// The table without any bytes
+    pub const EMPTY: Self = Self {
+        table: [false; 256],
+    };
+
+    /// The table of each of `bytes`
+    #[inline]
+    pub const fn new(bytes: &[u8]) -> Self {
+        Self::EMPTY.with_bytes(bytes)
+    }
+
+    /// The table of each byte in `range`
+    #[inline]
+    pub const fn from_range(range: RangeInclusive<u8>) -> Self {
+        Self::EMPTY.with_range(range)
+    }
+
+    /// Add each of `bytes` to the table
+    #[inline]
+    pub const fn with_bytes(mut self, bytes: &[u8]) -> Self {
+        let mut i = 0;
+        while i < bytes.len() {
+            self.table[bytes[i] as usize] = true;
+            i += 1;
+        }
+        self
+    }
+
+    /// Add each byte in `range` to the table
+    #[inline]
+    pub const fn with_range(mut self, range: RangeInclusive<u8>) -> Self {
+        let mut byte = *range.start() as usize;
+        let end = *range.end() as usize;
+        while byte <= end {
+            self.table[byte] = true;
+            byte += 1;
+        }
+        self
+    }
+
+    /// The bytes in either table
+    #[inline]
+    pub const fn union(mut self, other: Self) -> Self {
+        let mut i = 0;
+        while i < self.table.len() {
+            self.table[i] |= other.table[i];
+            i += 1;
+        }
+        self
+    }
+
+    /// The bytes in both tables
+    #[inline]
+    pub const fn intersection(mut self, other: Self) -> Self {
+        let mut i = 0;
+        while i < self.table.len() {
+            self.table[i] &= other.table[i];
+            i += 1;
+        }
+        self
+    }
+
+    /// The bytes not in this table
+    #[inline]
+    pub const fn complement(mut self) -> Self {
+        let mut i = 0;
+        while i < self.table.len() {
+            self.table[i] = !self.table[i];
+            i += 1;
+        }
+        self
+    }
+
+    /// Whether `byte` is in the table
+    #[inline(always)]
+    pub const fn contains(&self, byte: u8) -> bool {
+        self.table[byte as usize]
+    }
+}
+
+impl Default for ByteTable {
+    #[inline]
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl crate::lib::std::fmt::Debug for ByteTable {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        // Show runs of bytes as ranges to keep this readable
+        let mut set = f.debug_set();
+        let mut byte = 0;
+        while byte < self.table.len() {
+            if !self.table[byte] {
+                byte += 1;
+                continue;
+            }
+            let start = byte;
+            while byte + 1 < self.table.len() && self.table[byte + 1] {
+                byte += 1;
+            }
+            if start == byte {
+                set.entry(&(start as u8));
+            } else {
+                set.entry(&(start as u8..=byte as u8));
+            }
+            byte += 1;
+        }
+        set.finish()
+    }
+}
+
+impl ContainsToken<u8> for ByteTable {
+    #[inline(always)]
+    fn contains_token(&self, token: u8) -> bool {
+        self.contains(token)
+    }
+}
+
+impl ContainsToken<&u8> for ByteTable {
+    #[inline(always)]
+    fn contains_token(&self, token: &u8) -> bool {
+        self.contains(*token)
+    }
+}
+
+impl ContainsToken<char> for ByteTable {
+    #[inline(always)]
+    fn contains_token(&self, token: char) -> bool {
+        token.is_ascii() && self.contains(token as u8)
+    }
+}
+
+impl ContainsToken<&char> for ByteTable {
+    #[inline(always)]
+    fn contains_token(&self, token: &char) -> bool {
+        self.contains_token(*token)
+    }
+}
@@ -4,6 +4,8 @@ use crate::error::Needed;
 use crate::lib::std::iter::{Cloned, Enumerate};
 use crate::lib::std::slice::Iter;
 use crate::lib::std::{cmp::Ordering, fmt, ops};
+use crate::stream::fmt_preview_rest;
+use crate::stream::AsBStr;
 use crate::stream::AsBytes;
 use crate::stream::Checkpoint;
 use crate::stream::Compare;
@@ -16,6 +18,7 @@ use crate::stream::Recover;
 use crate::stream::SliceLen;
 use crate::stream::Stream;
 use crate::stream::StreamIsPartial;
+use crate::stream::StreamPreview;
 use crate::stream::UpdateSlice;
 
 /// Improved `Debug` experience for `&[u8]` byte streams
@@ -186,6 +189,13 @@ impl AsBytes for &Bytes {
     }
 }
 
+impl AsBStr for &Bytes {
+    #[inline(always)]
+    fn as_bstr(&self) -> &[u8] {
+        (*self).as_bytes()
+    }
+}
+
 impl<'a, T> Compare<T> for &'a Bytes
 where
     &'a [u8]: Compare<T>,
@@ -230,6 +240,17 @@ impl fmt::Debug for Bytes {
     }
 }
 
+impl StreamPreview for &Bytes {
+    fn fmt_preview(&self, max_len: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.as_bytes();
+        let (shown, rest) = bytes.split_at(max_len.min(bytes.len()));
+        for byte in shown {
+            write!(f, "{byte:0>2X}")?;
+        }
+        fmt_preview_rest(rest.len(), bytes.len(), f)
+    }
+}
+
 impl fmt::LowerHex for Bytes {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -0,0 +1,319 @@
+use crate::ascii::Caseless as AsciiCaseless;
+use crate::error::Needed;
+use crate::stream::AsBStr;
+use crate::stream::AsBytes;
+use crate::stream::Checkpoint;
+use crate::stream::Compare;
+use crate::stream::CompareResult;
+use crate::stream::FindSlice;
+use crate::stream::Location;
+use crate::stream::LookBehind;
+use crate::stream::Offset;
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+use crate::stream::Recover;
+use crate::stream::RecursionDepth;
+use crate::stream::SliceLen;
+use crate::stream::Stream;
+use crate::stream::StreamIsPartial;
+use crate::stream::UpdateSlice;
+
+/// Match literals while ignoring ASCII case, for case-insensitive grammars
+///
+/// Every [`Compare`] against the wrapped [`Stream`] is done as if the literal was wrapped in
+/// [`ascii::Caseless`][crate::ascii::Caseless], so `"select"` matches `SELECT` and `Select`
+/// without annotating each literal.
+///
+/// Only literal comparisons are affected:
+/// - Slices returned from parsers borrow the original, unmodified input
+/// - Token sets, like in [`one_of`][crate::token::one_of] or
+///   [`take_while`][crate::token::take_while], see tokens as-is
+/// - [`FindSlice`], like in [`take_until`][crate::token::take_until], is case-sensitive
+///
+/// Only ASCII letters are folded; other characters must match exactly.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::{alpha1, space1};
+/// # use winnow::combinator::preceded;
+/// use winnow::stream::CaseInsensitive;
+///
+/// fn select<'i>(i: &mut CaseInsensitive<&'i str>) -> ModalResult<&'i str> {
+///     preceded(("select", space1), alpha1).parse_next(i)
+/// }
+///
+/// assert_eq!(select.parse(CaseInsensitive::new("SELECT Name")), Ok("Name"));
+/// assert_eq!(select.parse(CaseInsensitive::new("Select name")), Ok("name"));
+/// ```
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[doc(alias = "Caseless")]
+#[doc(alias = "NoCase")]
+pub struct CaseInsensitive<I> {
+    input: I,
+}
+
+impl<I> CaseInsensitive<I> {
+    /// Compare literals against `input` without regard to ASCII case
+    #[inline(always)]
+    pub fn new(input: I) -> Self {
+        Self { input }
+    }
+
+    /// Extract the original [`Stream`]
+    #[inline(always)]
+    pub fn into_inner(self) -> I {
+        self.input
+    }
+}
+
+impl<I> AsRef<I> for CaseInsensitive<I> {
+    #[inline(always)]
+    fn as_ref(&self) -> &I {
+        &self.input
+    }
+}
+
+impl<I> crate::lib::std::ops::Deref for CaseInsensitive<I> {
+    type Target = I;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.input
+    }
+}
+
+impl<I: crate::lib::std::fmt::Display> crate::lib::std::fmt::Display for CaseInsensitive<I> {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        self.input.fmt(f)
+    }
+}
+
+impl<I> SliceLen for CaseInsensitive<I>
+where
+    I: SliceLen,
+{
+    #[inline(always)]
+    fn slice_len(&self) -> usize {
+        self.input.slice_len()
+    }
+}
+
+impl<I: Stream> Stream for CaseInsensitive<I> {
+    type Token = <I as Stream>::Token;
+    type Slice = <I as Stream>::Slice;
+
+    type IterOffsets = <I as Stream>::IterOffsets;
+
+    type Checkpoint = Checkpoint<I::Checkpoint, Self>;
+
+    #[inline(always)]
+    fn iter_offsets(&self) -> Self::IterOffsets {
+        self.input.iter_offsets()
+    }
+    #[inline(always)]
+    fn eof_offset(&self) -> usize {
+        self.input.eof_offset()
+    }
+
+    #[inline(always)]
+    fn next_token(&mut self) -> Option<Self::Token> {
+        self.input.next_token()
+    }
+
+    #[inline(always)]
+    fn peek_token(&self) -> Option<Self::Token> {
+        self.input.peek_token()
+    }
+
+    #[inline(always)]
+    fn offset_for<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(Self::Token) -> bool,
+    {
+        self.input.offset_for(predicate)
+    }
+    #[inline(always)]
+    fn offset_at(&self, tokens: usize) -> Result<usize, Needed> {
+        self.input.offset_at(tokens)
+    }
+    #[inline(always)]
+    fn next_slice(&mut self, offset: usize) -> Self::Slice {
+        self.input.next_slice(offset)
+    }
+    #[inline(always)]
+    fn peek_slice(&self, offset: usize) -> Self::Slice {
+        self.input.peek_slice(offset)
+    }
+
+    #[inline(always)]
+    fn checkpoint(&self) -> Self::Checkpoint {
+        Checkpoint::<_, Self>::new(self.input.checkpoint())
+    }
+    #[inline(always)]
+    fn reset(&mut self, checkpoint: &Self::Checkpoint) {
+        self.input.reset(&checkpoint.inner);
+    }
+
+    #[inline(always)]
+    fn raw(&self) -> &dyn crate::lib::std::fmt::Debug {
+        &self.input
+    }
+}
+
+impl<I> Location for CaseInsensitive<I>
+where
+    I: Location,
+{
+    #[inline(always)]
+    fn previous_token_end(&self) -> usize {
+        self.input.previous_token_end()
+    }
+    #[inline(always)]
+    fn current_token_start(&self) -> usize {
+        self.input.current_token_start()
+    }
+}
+
+impl<I> LookBehind for CaseInsensitive<I>
+where
+    I: LookBehind,
+{
+    #[inline(always)]
+    fn peek_previous_token(&self) -> Option<Self::Token> {
+        self.input.peek_previous_token()
+    }
+}
+
+impl<I> RecursionDepth for CaseInsensitive<I>
+where
+    I: RecursionDepth,
+{
+    #[inline(always)]
+    fn recursion_depth(&mut self) -> &mut usize {
+        self.input.recursion_depth()
+    }
+}
+
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+impl<I, E> Recover<E> for CaseInsensitive<I>
+where
+    I: Recover<E>,
+    I: Stream,
+{
+    #[inline(always)]
+    fn record_err(
+        &mut self,
+        _token_start: &Self::Checkpoint,
+        _err_start: &Self::Checkpoint,
+        err: E,
+    ) -> Result<(), E> {
+        Err(err)
+    }
+
+    /// Report whether the [`Stream`] can save off errors for recovery
+    #[inline(always)]
+    fn is_recovery_supported() -> bool {
+        false
+    }
+}
+
+impl<I> StreamIsPartial for CaseInsensitive<I>
+where
+    I: StreamIsPartial,
+{
+    type PartialState = I::PartialState;
+
+    #[inline]
+    fn complete(&mut self) -> Self::PartialState {
+        self.input.complete()
+    }
+
+    #[inline]
+    fn restore_partial(&mut self, state: Self::PartialState) {
+        self.input.restore_partial(state);
+    }
+
+    #[inline(always)]
+    fn is_partial_supported() -> bool {
+        I::is_partial_supported()
+    }
+
+    #[inline(always)]
+    fn is_partial(&self) -> bool {
+        self.input.is_partial()
+    }
+}
+
+impl<I> Offset for CaseInsensitive<I>
+where
+    I: Stream,
+{
+    #[inline(always)]
+    fn offset_from(&self, other: &Self) -> usize {
+        self.offset_from(&other.checkpoint())
+    }
+}
+
+impl<I> Offset<<CaseInsensitive<I> as Stream>::Checkpoint> for CaseInsensitive<I>
+where
+    I: Stream,
+{
+    #[inline(always)]
+    fn offset_from(&self, other: &<CaseInsensitive<I> as Stream>::Checkpoint) -> usize {
+        self.checkpoint().offset_from(other)
+    }
+}
+
+impl<I> AsBytes for CaseInsensitive<I>
+where
+    I: AsBytes,
+{
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self.input.as_bytes()
+    }
+}
+
+impl<I> AsBStr for CaseInsensitive<I>
+where
+    I: AsBStr,
+{
+    #[inline(always)]
+    fn as_bstr(&self) -> &[u8] {
+        self.input.as_bstr()
+    }
+}
+
+impl<I, U> Compare<U> for CaseInsensitive<I>
+where
+    I: Compare<AsciiCaseless<U>>,
+{
+    #[inline(always)]
+    fn compare(&self, other: U) -> CompareResult {
+        self.input.compare(AsciiCaseless(other))
+    }
+}
+
+impl<I, T> FindSlice<T> for CaseInsensitive<I>
+where
+    I: FindSlice<T>,
+{
+    #[inline(always)]
+    fn find_slice(&self, substr: T) -> Option<crate::lib::std::ops::Range<usize>> {
+        self.input.find_slice(substr)
+    }
+}
+
+impl<I> UpdateSlice for CaseInsensitive<I>
+where
+    I: UpdateSlice,
+{
+    #[inline(always)]
+    fn update_slice(mut self, inner: Self::Slice) -> Self {
+        self.input = I::update_slice(self.input, inner);
+        self
+    }
+}
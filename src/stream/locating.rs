@@ -1,18 +1,24 @@
 use crate::error::Needed;
 use crate::stream::AsBStr;
 use crate::stream::AsBytes;
+use crate::stream::BStr;
+use crate::stream::Bytes;
 use crate::stream::Checkpoint;
 use crate::stream::Compare;
 use crate::stream::CompareResult;
 use crate::stream::FindSlice;
 use crate::stream::Location;
+use crate::stream::LookBehind;
 use crate::stream::Offset;
+use crate::stream::Partial;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::stream::Recover;
+use crate::stream::RecursionDepth;
 use crate::stream::SliceLen;
 use crate::stream::Stream;
 use crate::stream::StreamIsPartial;
+use crate::stream::StreamPreview;
 use crate::stream::UpdateSlice;
 
 /// Allow collecting the span of a parsed token within a slice
@@ -43,6 +49,48 @@ where
         Self { initial, input }
     }
 
+    /// Convert the wrapped [`Stream`], preserving locations
+    ///
+    /// `f` is applied to both the start of the input and the current input, and must preserve the
+    /// offset between them, like when converting between `&[u8]`, [`&Bytes`][crate::stream::Bytes]
+    /// and [`&BStr`][crate::stream::BStr] views of the same memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// # use winnow::error::ContextError;
+    /// # use winnow::stream::Location;
+    /// # use winnow::token::literal;
+    /// use winnow::stream::Bytes;
+    /// use winnow::stream::LocatingSlice;
+    ///
+    /// let mut input = LocatingSlice::new(&b"abcdef"[..]);
+    /// let _ = literal::<_, _, ContextError>("abc").parse_next(&mut input).unwrap();
+    /// let input = input.map_input(Bytes::new);
+    /// assert_eq!(input.current_token_start(), 3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// With `debug_assertions` enabled, this panics if the offset into the input changes.
+    #[inline]
+    pub fn map_input<J, F>(self, mut f: F) -> LocatingSlice<J>
+    where
+        J: Clone + Offset,
+        F: FnMut(I) -> J,
+    {
+        let offset = self.input.offset_from(&self.initial);
+        let initial = f(self.initial);
+        let input = f(self.input);
+        debug_assert_eq!(
+            input.offset_from(&initial),
+            offset,
+            "`LocatingSlice::map_input` must preserve offsets"
+        );
+        LocatingSlice { initial, input }
+    }
+
     #[inline]
     fn previous_token_end(&self) -> usize {
         // Assumptions:
@@ -74,6 +122,34 @@ where
     }
 }
 
+macro_rules! impl_from_locating {
+    ($($from:ty => $to:ty),* $(,)?) => {
+        $(
+            impl<'a> From<LocatingSlice<$from>> for LocatingSlice<$to> {
+                #[inline]
+                fn from(input: LocatingSlice<$from>) -> Self {
+                    input.map_input(Into::into)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_locating!(
+    &'a [u8] => &'a Bytes,
+    &'a Bytes => &'a [u8],
+    &'a [u8] => &'a BStr,
+    &'a BStr => &'a [u8],
+    &'a str => &'a Bytes,
+    &'a str => &'a BStr,
+    Partial<&'a [u8]> => Partial<&'a Bytes>,
+    Partial<&'a Bytes> => Partial<&'a [u8]>,
+    Partial<&'a [u8]> => Partial<&'a BStr>,
+    Partial<&'a BStr> => Partial<&'a [u8]>,
+    Partial<&'a str> => Partial<&'a Bytes>,
+    Partial<&'a str> => Partial<&'a BStr>,
+);
+
 impl<I> AsRef<I> for LocatingSlice<I> {
     #[inline(always)]
     fn as_ref(&self) -> &I {
@@ -96,6 +172,20 @@ impl<I: crate::lib::std::fmt::Display> crate::lib::std::fmt::Display for Locatin
     }
 }
 
+impl<I> StreamPreview for LocatingSlice<I>
+where
+    I: StreamPreview + Clone + Offset,
+{
+    fn fmt_preview(
+        &self,
+        max_len: usize,
+        f: &mut crate::lib::std::fmt::Formatter<'_>,
+    ) -> crate::lib::std::fmt::Result {
+        self.input.fmt_preview(max_len, f)?;
+        write!(f, " at offset {}", self.current_token_start())
+    }
+}
+
 impl<I> SliceLen for LocatingSlice<I>
 where
     I: SliceLen,
@@ -182,6 +272,86 @@ where
     }
 }
 
+impl<I> RecursionDepth for LocatingSlice<I>
+where
+    I: RecursionDepth,
+{
+    #[inline(always)]
+    fn recursion_depth(&mut self) -> &mut usize {
+        self.input.recursion_depth()
+    }
+}
+
+impl LookBehind for LocatingSlice<&str> {
+    #[inline]
+    fn peek_previous_token(&self) -> Option<Self::Token> {
+        let consumed = self.input.offset_from(&self.initial);
+        self.initial[..consumed].chars().next_back()
+    }
+}
+
+impl<T> LookBehind for LocatingSlice<&[T]>
+where
+    T: Clone + crate::lib::std::fmt::Debug,
+{
+    #[inline]
+    fn peek_previous_token(&self) -> Option<Self::Token> {
+        let consumed = self.input.offset_from(&self.initial);
+        self.initial[..consumed].last().cloned()
+    }
+}
+
+impl LookBehind for LocatingSlice<&crate::stream::BStr> {
+    #[inline]
+    fn peek_previous_token(&self) -> Option<Self::Token> {
+        let consumed = self.input.offset_from(&self.initial);
+        self.initial[..consumed].last().copied()
+    }
+}
+
+impl LookBehind for LocatingSlice<&crate::stream::Bytes> {
+    #[inline]
+    fn peek_previous_token(&self) -> Option<Self::Token> {
+        let consumed = self.input.offset_from(&self.initial);
+        self.initial[..consumed].last().copied()
+    }
+}
+
+impl LookBehind for LocatingSlice<Partial<&str>> {
+    #[inline]
+    fn peek_previous_token(&self) -> Option<Self::Token> {
+        let consumed = self.input.offset_from(&self.initial);
+        self.initial[..consumed].chars().next_back()
+    }
+}
+
+impl<T> LookBehind for LocatingSlice<Partial<&[T]>>
+where
+    T: Clone + crate::lib::std::fmt::Debug,
+{
+    #[inline]
+    fn peek_previous_token(&self) -> Option<Self::Token> {
+        let consumed = self.input.offset_from(&self.initial);
+        self.initial[..consumed].last().cloned()
+    }
+}
+
+impl LookBehind for LocatingSlice<Partial<&crate::stream::BStr>> {
+    #[inline]
+    fn peek_previous_token(&self) -> Option<Self::Token> {
+        let consumed = self.input.offset_from(&self.initial);
+        self.initial[..consumed].last().copied()
+    }
+}
+
+impl LookBehind for LocatingSlice<Partial<&crate::stream::Bytes>> {
+    #[inline]
+    fn peek_previous_token(&self) -> Option<Self::Token> {
+        let consumed = self.input.offset_from(&self.initial);
+        self.initial[..consumed].last().copied()
+    }
+}
+
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 impl<I, E> Recover<E> for LocatingSlice<I>
@@ -6,6 +6,7 @@
 //! - [`LocatingSlice`] can track the location within the original buffer to report
 //!   [spans][crate::Parser::with_span]
 //! - [`Stateful`] to thread global state through your parsers
+//! - [`Shared`] to parse a shared, owned buffer like `Arc<str>` or `bytes::Bytes`
 //! - [`Partial`] can mark an input as partial buffer that is being streamed into
 //! - [Custom stream types][crate::_topic::stream]
 
@@ -14,6 +15,8 @@ use core::num::NonZeroUsize;
 
 use crate::ascii::Caseless as AsciiCaseless;
 use crate::error::Needed;
+use crate::error::StrContextValue;
+use crate::lib::std::fmt;
 use crate::lib::std::iter::{Cloned, Enumerate};
 use crate::lib::std::slice::Iter;
 use crate::lib::std::str::from_utf8;
@@ -38,32 +41,108 @@ use crate::lib::std::string::String;
 use crate::lib::std::vec::Vec;
 
 mod bstr;
+mod byte_table;
 mod bytes;
+mod case_insensitive;
 mod locating;
 mod partial;
 mod range;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 mod recoverable;
+mod shared;
 mod stateful;
 #[cfg(test)]
 mod tests;
 mod token;
 
 pub use bstr::BStr;
+pub use byte_table::ByteTable;
 pub use bytes::Bytes;
+pub use case_insensitive::CaseInsensitive;
 pub use locating::LocatingSlice;
 pub use partial::Partial;
 pub use range::Range;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 pub use recoverable::Recoverable;
+pub use shared::{Shared, SharedSlice};
 pub use stateful::Stateful;
 pub use token::TokenSlice;
 
 /// UTF-8 Stream
 pub type Str<'i> = &'i str;
 
+/// [`LocatingSlice`] over a [`Partial`] buffer
+///
+/// See [nesting wrappers][crate::_topic::stream#nesting-wrappers] for how wrappers compose.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::{ErrMode, Needed};
+/// use winnow::ascii::alpha1;
+/// use winnow::stream::{LocatingPartial, Partial, LocatingSlice};
+///
+/// fn word<'i>(i: &mut LocatingPartial<&'i str>) -> ModalResult<(&'i str, std::ops::Range<usize>)> {
+///     (alpha1, ' ').take().with_span().parse_next(i)
+/// }
+///
+/// let input = LocatingSlice::new(Partial::new("hello world"));
+/// assert_eq!(word.parse_peek(input).unwrap().1, ("hello ", 0..6));
+/// assert_eq!(word.parse_peek(LocatingSlice::new(Partial::new("hello"))), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+pub type LocatingPartial<I> = LocatingSlice<Partial<I>>;
+
+/// [`Stateful`] over a [`LocatingSlice`]
+///
+/// See [nesting wrappers][crate::_topic::stream#nesting-wrappers] for how wrappers compose.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::alpha1;
+/// use winnow::stream::{LocatingSlice, Stateful, StatefulLocating};
+///
+/// fn word<'i>(i: &mut StatefulLocating<&'i str, usize>) -> ModalResult<std::ops::Range<usize>> {
+///     i.state += 1;
+///     alpha1.span().parse_next(i)
+/// }
+///
+/// let mut input = Stateful { input: LocatingSlice::new("hello world"), state: 0 };
+/// assert_eq!(word.parse_next(&mut input), Ok(0..5));
+/// assert_eq!(input.state, 1);
+/// ```
+pub type StatefulLocating<I, S> = Stateful<LocatingSlice<I>, S>;
+
+/// [`Stateful`] over a [`LocatingSlice`] of a [`Partial`] buffer
+///
+/// See [nesting wrappers][crate::_topic::stream#nesting-wrappers] for how wrappers compose.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::{ErrMode, Needed};
+/// use winnow::ascii::alpha1;
+/// use winnow::stream::{LocatingSlice, Partial, Stateful, StatefulLocatingPartial};
+///
+/// fn word<'i>(i: &mut StatefulLocatingPartial<&'i str, usize>) -> ModalResult<std::ops::Range<usize>> {
+///     i.state += 1;
+///     (alpha1, ' ').span().parse_next(i)
+/// }
+///
+/// let mut input = Stateful { input: LocatingSlice::new(Partial::new("hello world")), state: 0 };
+/// assert_eq!(word.parse_next(&mut input), Ok(0..6));
+/// assert_eq!(input.state, 1);
+///
+/// let mut input = Stateful { input: LocatingSlice::new(Partial::new("hello")), state: 0 };
+/// assert_eq!(word.parse_next(&mut input), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+pub type StatefulLocatingPartial<I, S> = Stateful<LocatingSlice<Partial<I>>, S>;
+
 /// Abstract method to calculate the input length
 pub trait SliceLen {
     /// Calculates the input length, as indicated by its name,
@@ -351,7 +430,8 @@ impl<'i> Stream for &'i str {
         if cnt == tokens {
             Ok(self.eof_offset())
         } else {
-            Err(Needed::Unknown)
+            // Each remaining `char` is at least one byte
+            Err(Needed::new(tokens - cnt))
         }
     }
     #[inline(always)]
@@ -548,6 +628,148 @@ pub trait Location {
     fn current_token_start(&self) -> usize;
 }
 
+/// Render a bounded, human-oriented view of a stream, see [`Preview`]
+pub trait StreamPreview {
+    /// Write at most `max_len` tokens of the stream, followed by annotations for its state
+    fn fmt_preview(&self, max_len: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Bounded, human-oriented rendering of a [`Stream`] for error messages and logs
+///
+/// At most [`Preview::DEFAULT_MAX_LEN`] tokens are shown (see [`Preview::max_len`]), followed by
+/// an ellipsis and the total length in bytes when there is more.  Bytes are shown as ASCII, with
+/// other bytes hex-escaped, and [`Bytes`] as hex.  Wrappers annotate the stream compactly, like
+/// with the [`LocatingSlice`] offset or whether a [`Partial`] is incomplete.
+///
+/// See also [`InputError::preview`][crate::error::InputError::preview].
+///
+/// # Example
+///
+/// ```rust
+/// use winnow::stream::{LocatingSlice, Partial, Preview, Stream};
+///
+/// let mut input = Partial::new(LocatingSlice::new(&b"GET /index.html HTTP/1.1\r\n"[..]));
+/// input.next_slice(4);
+/// assert_eq!(
+///     Preview::new(&input).max_len(11).to_string(),
+///     r#"b"/index.html"… (22 bytes) at offset 4 (partial)"#
+/// );
+/// ```
+#[derive(Copy, Clone)]
+pub struct Preview<'i, I> {
+    input: &'i I,
+    max_len: usize,
+}
+
+impl<'i, I: StreamPreview> Preview<'i, I> {
+    /// The number of tokens shown, unless overridden with [`Preview::max_len`]
+    pub const DEFAULT_MAX_LEN: usize = 32;
+
+    /// Preview `input`, showing at most [`Preview::DEFAULT_MAX_LEN`] tokens
+    #[inline]
+    pub fn new(input: &'i I) -> Self {
+        Self {
+            input,
+            max_len: Self::DEFAULT_MAX_LEN,
+        }
+    }
+
+    /// Show at most `max_len` tokens
+    #[inline]
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+}
+
+impl<I: StreamPreview> fmt::Display for Preview<'_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.input.fmt_preview(self.max_len, f)
+    }
+}
+
+impl<I: StreamPreview> fmt::Debug for Preview<'_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.input.fmt_preview(self.max_len, f)
+    }
+}
+
+impl StreamPreview for &str {
+    fn fmt_preview(&self, max_len: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (shown, rest) = match self.char_indices().nth(max_len) {
+            Some((offset, _)) => self.split_at(offset),
+            None => (*self, ""),
+        };
+        write!(f, "\"{}\"", shown.escape_debug())?;
+        fmt_preview_rest(rest.len(), self.len(), f)
+    }
+}
+
+impl StreamPreview for &[u8] {
+    fn fmt_preview(&self, max_len: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "b")?;
+        fmt_preview_ascii(self, max_len, f)
+    }
+}
+
+/// Write `bytes` as ASCII, hex-escaping the rest
+fn fmt_preview_ascii(bytes: &[u8], max_len: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let (shown, rest) = bytes.split_at(max_len.min(bytes.len()));
+    write!(f, "\"")?;
+    for byte in shown {
+        write!(f, "{}", core::ascii::escape_default(*byte))?;
+    }
+    write!(f, "\"")?;
+    fmt_preview_rest(rest.len(), bytes.len(), f)
+}
+
+/// Note the length of the stream when `rest` of it wasn't shown
+fn fmt_preview_rest(rest: usize, len: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if rest == 0 {
+        Ok(())
+    } else {
+        write!(f, "… ({len} bytes)")
+    }
+}
+
+/// Peek at input that was already consumed
+///
+/// See [`LocatingSlice`] for retaining consumed input in your [`Stream`].
+///
+/// Only input that is still referenced by the [`Stream`] can be looked at.  For
+/// [`Partial`] streams, this means the previous token is forgotten when the buffer is refilled
+/// and a new stream is created from it.
+pub trait LookBehind: Stream {
+    /// The token immediately before the current position, if any
+    fn peek_previous_token(&self) -> Option<Self::Token>;
+}
+
+/// Track how deeply [`depth_guard`][crate::combinator::depth_guard]s are nested
+///
+/// This is implemented for [`Stateful`] by delegating to its `state`, so the state carries the
+/// depth while the [`Stream`] is being parsed.
+pub trait RecursionDepth {
+    /// The number of [`depth_guard`][crate::combinator::depth_guard]s currently entered
+    fn recursion_depth(&mut self) -> &mut usize;
+}
+
+impl RecursionDepth for usize {
+    #[inline(always)]
+    fn recursion_depth(&mut self) -> &mut usize {
+        self
+    }
+}
+
+impl<D> RecursionDepth for &mut D
+where
+    D: RecursionDepth + ?Sized,
+{
+    #[inline(always)]
+    fn recursion_depth(&mut self) -> &mut usize {
+        (**self).recursion_depth()
+    }
+}
+
 /// Capture top-level errors in the middle of parsing so parsing can resume
 ///
 /// See [`Recoverable`] for adding error recovery tracking to your [`Stream`]
@@ -854,9 +1076,20 @@ pub trait Compare<T> {
     fn compare(&self, t: T) -> CompareResult;
 }
 
-impl<'b> Compare<&'b [u8]> for &[u8] {
+impl<'b, T> Compare<&'b [T]> for &[T]
+where
+    T: PartialEq,
+{
     #[inline]
-    fn compare(&self, t: &'b [u8]) -> CompareResult {
+    fn compare(&self, t: &'b [T]) -> CompareResult {
+        if let [t] = t {
+            // Single-token literals are common enough (delimiters, operators) to skip the zip
+            return match self.first() {
+                Some(c) if t == c => CompareResult::Ok(1),
+                Some(_) => CompareResult::Error,
+                None => CompareResult::Incomplete,
+            };
+        }
         if t.iter().zip(*self).any(|(a, b)| a != b) {
             CompareResult::Error
         } else if self.len() < t.slice_len() {
@@ -884,9 +1117,12 @@ impl<'b> Compare<AsciiCaseless<&'b [u8]>> for &[u8] {
     }
 }
 
-impl<const LEN: usize> Compare<[u8; LEN]> for &[u8] {
+impl<T, const LEN: usize> Compare<[T; LEN]> for &[T]
+where
+    T: PartialEq,
+{
     #[inline(always)]
-    fn compare(&self, t: [u8; LEN]) -> CompareResult {
+    fn compare(&self, t: [T; LEN]) -> CompareResult {
         self.compare(&t[..])
     }
 }
@@ -898,9 +1134,12 @@ impl<const LEN: usize> Compare<AsciiCaseless<[u8; LEN]>> for &[u8] {
     }
 }
 
-impl<'b, const LEN: usize> Compare<&'b [u8; LEN]> for &[u8] {
+impl<'b, T, const LEN: usize> Compare<&'b [T; LEN]> for &[T]
+where
+    T: PartialEq,
+{
     #[inline(always)]
-    fn compare(&self, t: &'b [u8; LEN]) -> CompareResult {
+    fn compare(&self, t: &'b [T; LEN]) -> CompareResult {
         self.compare(&t[..])
     }
 }
@@ -926,11 +1165,14 @@ impl<'b> Compare<AsciiCaseless<&'b str>> for &[u8] {
     }
 }
 
-impl Compare<u8> for &[u8] {
+impl<T> Compare<T> for &[T]
+where
+    T: PartialEq,
+{
     #[inline]
-    fn compare(&self, t: u8) -> CompareResult {
-        match self.first().copied() {
-            Some(c) if t == c => CompareResult::Ok(t.slice_len()),
+    fn compare(&self, t: T) -> CompareResult {
+        match self.first() {
+            Some(c) if t == *c => CompareResult::Ok(1),
             Some(_) => CompareResult::Error,
             None => CompareResult::Incomplete,
         }
@@ -951,6 +1193,9 @@ impl Compare<AsciiCaseless<u8>> for &[u8] {
 impl Compare<char> for &[u8] {
     #[inline(always)]
     fn compare(&self, t: char) -> CompareResult {
+        if t.is_ascii() {
+            return self.compare(t as u8);
+        }
         self.compare(t.encode_utf8(&mut [0; 4]).as_bytes())
     }
 }
@@ -958,6 +1203,9 @@ impl Compare<char> for &[u8] {
 impl Compare<AsciiCaseless<char>> for &[u8] {
     #[inline(always)]
     fn compare(&self, t: AsciiCaseless<char>) -> CompareResult {
+        if t.0.is_ascii() {
+            return self.compare(AsciiCaseless(t.0 as u8));
+        }
         self.compare(AsciiCaseless(t.0.encode_utf8(&mut [0; 4]).as_bytes()))
     }
 }
@@ -991,8 +1239,56 @@ impl Compare<AsciiCaseless<char>> for &str {
 }
 
 /// Look for a slice in self
+///
+/// This powers [`take_until`][crate::token::take_until] and
+/// [`take_until_first`][crate::token::take_until_first].  When `simd` is enabled, the built-in
+/// implementations use [`memchr`](https://docs.rs/memchr/latest/memchr/).
+///
+/// Tuples of needles look for the earliest occurrence of any of them.  When several needles match
+/// at the same offset, the first one listed wins.
+///
+/// # Example
+///
+/// Implementing `FindSlice` for a custom needle, here looking for a token kind in a token stream:
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::stream::FindSlice;
+/// use winnow::token::take_until;
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// enum Kind {
+///     Ident,
+///     Semicolon,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// struct Token {
+///     kind: Kind,
+/// }
+///
+/// impl FindSlice<Kind> for &[Token] {
+///     fn find_slice(&self, kind: Kind) -> Option<std::ops::Range<usize>> {
+///         let start = self.iter().position(|t| t.kind == kind)?;
+///         Some(start..start + 1)
+///     }
+/// }
+///
+/// fn statement<'i>(i: &mut &'i [Token]) -> ModalResult<&'i [Token]> {
+///     take_until(0.., Kind::Semicolon).parse_next(i)
+/// }
+///
+/// let ident = Token { kind: Kind::Ident };
+/// let semicolon = Token { kind: Kind::Semicolon };
+/// let tokens = [ident, ident, semicolon, ident];
+/// assert_eq!(
+///     statement.parse_peek(&tokens[..]),
+///     Ok((&[semicolon, ident][..], &[ident, ident][..]))
+/// );
+/// ```
 pub trait FindSlice<T> {
-    /// Returns the offset of the slice if it is found
+    /// Returns the range of the slice if it is found
+    ///
+    /// The range's `start` is where the match begins and its `end` is just past the match.
     fn find_slice(&self, substr: T) -> Option<crate::lib::std::ops::Range<usize>>;
 }
 
@@ -1423,6 +1719,37 @@ where
     }
 }
 
+/// Maps that can be [accumulated][Accumulate] into while checking for duplicate keys
+///
+/// See [`DuplicateKey`][crate::combinator::DuplicateKey]
+pub trait AccumulateMap<K, V>: Accumulate<(K, V)> {
+    /// Returns true if `key` was already accumulated
+    fn contains_key(&self, key: &K) -> bool;
+}
+
+#[cfg(feature = "alloc")]
+impl<K, V> AccumulateMap<K, V> for BTreeMap<K, V>
+where
+    K: crate::lib::std::cmp::Ord,
+{
+    #[inline(always)]
+    fn contains_key(&self, key: &K) -> bool {
+        BTreeMap::contains_key(self, key)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> AccumulateMap<K, V> for HashMap<K, V, S>
+where
+    K: crate::lib::std::cmp::Eq + crate::lib::std::hash::Hash,
+    S: BuildHasher + Default,
+{
+    #[inline(always)]
+    fn contains_key(&self, key: &K) -> bool {
+        HashMap::contains_key(self, key)
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<K> Accumulate<K> for BTreeSet<K>
 where
@@ -1732,6 +2059,7 @@ impl AsChar for &char {
 /// - `|c| true`
 /// - `b'a'..=b'z'`, `'a'..='z'` (etc for each [range type][std::ops])
 /// - `(set1, set2, ...)`
+/// - [`ByteTable`] for a `const` lookup table
 ///
 /// # Example
 ///
@@ -1751,6 +2079,20 @@ impl AsChar for &char {
 pub trait ContainsToken<T> {
     /// Returns true if self contains the token
     fn contains_token(&self, token: T) -> bool;
+
+    /// Describe each token in the set, for error reporting
+    ///
+    /// This is used by [`one_of`][crate::token::one_of] to report what it expected through
+    /// [`ParserError::add_expected`][crate::error::ParserError::add_expected].
+    /// Sets that are not a list of literal tokens, like ranges and predicates, describe nothing.
+    #[inline(always)]
+    fn fold_expected<A, F>(&self, init: A, _f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+        Self: Sized,
+    {
+        init
+    }
 }
 
 impl ContainsToken<u8> for u8 {
@@ -1758,6 +2100,14 @@ impl ContainsToken<u8> for u8 {
     fn contains_token(&self, token: u8) -> bool {
         *self == token
     }
+
+    #[inline(always)]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        f(init, StrContextValue::ByteLiteral(*self))
+    }
 }
 
 impl ContainsToken<&u8> for u8 {
@@ -1765,6 +2115,14 @@ impl ContainsToken<&u8> for u8 {
     fn contains_token(&self, token: &u8) -> bool {
         self.contains_token(*token)
     }
+
+    #[inline(always)]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        f(init, StrContextValue::ByteLiteral(*self))
+    }
 }
 
 impl ContainsToken<char> for u8 {
@@ -1772,6 +2130,14 @@ impl ContainsToken<char> for u8 {
     fn contains_token(&self, token: char) -> bool {
         self.as_char() == token
     }
+
+    #[inline(always)]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        f(init, StrContextValue::ByteLiteral(*self))
+    }
 }
 
 impl ContainsToken<&char> for u8 {
@@ -1779,6 +2145,14 @@ impl ContainsToken<&char> for u8 {
     fn contains_token(&self, token: &char) -> bool {
         self.contains_token(*token)
     }
+
+    #[inline(always)]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        f(init, StrContextValue::ByteLiteral(*self))
+    }
 }
 
 impl<C: AsChar> ContainsToken<C> for char {
@@ -1786,6 +2160,14 @@ impl<C: AsChar> ContainsToken<C> for char {
     fn contains_token(&self, token: C) -> bool {
         *self == token.as_char()
     }
+
+    #[inline(always)]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        f(init, StrContextValue::CharLiteral(self.as_char()))
+    }
 }
 
 impl<C, F: Fn(C) -> bool> ContainsToken<C> for F {
@@ -1813,6 +2195,16 @@ impl<C1: AsChar, C2: AsChar + Clone> ContainsToken<C1>
         let end = self.end().clone().as_char();
         (start..=end).contains(&token.as_char())
     }
+
+    #[inline(always)]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        let start = self.start().clone().as_char();
+        let end = self.end().clone().as_char();
+        f(init, StrContextValue::CharRange(start, end))
+    }
 }
 
 impl<C1: AsChar, C2: AsChar + Clone> ContainsToken<C1> for crate::lib::std::ops::RangeFrom<C2> {
@@ -1854,6 +2246,15 @@ impl<C: AsChar> ContainsToken<C> for &'_ [u8] {
         let token = token.as_char();
         self.iter().any(|t| t.as_char() == token)
     }
+
+    #[inline]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        self.iter()
+            .fold(init, |acc, t| f(acc, StrContextValue::ByteLiteral(*t)))
+    }
 }
 
 impl<C: AsChar> ContainsToken<C> for &'_ [char] {
@@ -1862,6 +2263,16 @@ impl<C: AsChar> ContainsToken<C> for &'_ [char] {
         let token = token.as_char();
         self.iter().any(|t| *t == token)
     }
+
+    #[inline]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        self.iter().fold(init, |acc, t| {
+            f(acc, StrContextValue::CharLiteral(t.as_char()))
+        })
+    }
 }
 
 impl<const LEN: usize, C: AsChar> ContainsToken<C> for &'_ [u8; LEN] {
@@ -1870,6 +2281,15 @@ impl<const LEN: usize, C: AsChar> ContainsToken<C> for &'_ [u8; LEN] {
         let token = token.as_char();
         self.iter().any(|t| t.as_char() == token)
     }
+
+    #[inline]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        self.iter()
+            .fold(init, |acc, t| f(acc, StrContextValue::ByteLiteral(*t)))
+    }
 }
 
 impl<const LEN: usize, C: AsChar> ContainsToken<C> for &'_ [char; LEN] {
@@ -1878,6 +2298,16 @@ impl<const LEN: usize, C: AsChar> ContainsToken<C> for &'_ [char; LEN] {
         let token = token.as_char();
         self.iter().any(|t| *t == token)
     }
+
+    #[inline]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        self.iter().fold(init, |acc, t| {
+            f(acc, StrContextValue::CharLiteral(t.as_char()))
+        })
+    }
 }
 
 impl<const LEN: usize, C: AsChar> ContainsToken<C> for [u8; LEN] {
@@ -1886,6 +2316,15 @@ impl<const LEN: usize, C: AsChar> ContainsToken<C> for [u8; LEN] {
         let token = token.as_char();
         self.iter().any(|t| t.as_char() == token)
     }
+
+    #[inline]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        self.iter()
+            .fold(init, |acc, t| f(acc, StrContextValue::ByteLiteral(*t)))
+    }
 }
 
 impl<const LEN: usize, C: AsChar> ContainsToken<C> for [char; LEN] {
@@ -1894,6 +2333,16 @@ impl<const LEN: usize, C: AsChar> ContainsToken<C> for [char; LEN] {
         let token = token.as_char();
         self.iter().any(|t| *t == token)
     }
+
+    #[inline]
+    fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+    where
+        F: FnMut(A, StrContextValue) -> A,
+    {
+        self.iter().fold(init, |acc, t| {
+            f(acc, StrContextValue::CharLiteral(t.as_char()))
+        })
+    }
 }
 
 impl<T> ContainsToken<T> for () {
@@ -1903,6 +2352,19 @@ impl<T> ContainsToken<T> for () {
     }
 }
 
+impl<C: AsChar, S: ContainsToken<char>> ContainsToken<C> for AsciiCaseless<S> {
+    #[inline]
+    fn contains_token(&self, token: C) -> bool {
+        let token = token.as_char();
+        if token.is_ascii_alphabetic() {
+            self.0.contains_token(token.to_ascii_lowercase())
+                || self.0.contains_token(token.to_ascii_uppercase())
+        } else {
+            self.0.contains_token(token)
+        }
+    }
+}
+
 macro_rules! impl_contains_token_for_tuple {
   ($($haystack:ident),+) => (
     #[allow(non_snake_case)]
@@ -1916,6 +2378,17 @@ macro_rules! impl_contains_token_for_tuple {
         let ($(ref $haystack),+,) = *self;
         $($haystack.contains_token(token.clone()) || )+ false
       }
+
+    #[inline]
+      fn fold_expected<A, F>(&self, init: A, mut f: F) -> A
+      where
+        F: FnMut(A, StrContextValue) -> A,
+      {
+        let ($(ref $haystack),+,) = *self;
+        let acc = init;
+        $(let acc = $haystack.fold_expected(acc, &mut f);)+
+        acc
+      }
     }
   )
 }
@@ -1937,6 +2410,42 @@ impl_contains_token_for_tuples!(
     F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12, F13, F14, F15, F16, F17, F18, F19, F20, F21
 );
 
+/// Invert a [`ContainsToken`] set, matching every token *not* in `set`
+///
+/// This keeps the specialized [`ContainsToken`] implementations of `set` (ranges, arrays, tuples,
+/// etc) rather than falling back to a closure.
+///
+/// # Example
+///
+/// ```
+/// # use winnow::prelude::*;
+/// # use winnow::stream::not;
+/// # use winnow::token::take_while;
+/// fn not_alphanumeric<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+///     take_while(1.., not(('a'..='z', '0'..='9'))).parse_next(input)
+/// }
+///
+/// assert_eq!(not_alphanumeric.parse_peek("-+ab"), Ok(("ab", "-+")));
+/// assert!(not_alphanumeric.parse_peek("a").is_err());
+/// ```
+#[inline(always)]
+pub fn not<S>(set: S) -> Not<S> {
+    Not { set }
+}
+
+/// Inverted [`ContainsToken`] set, see [`not`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Not<S> {
+    set: S,
+}
+
+impl<T, S: ContainsToken<T>> ContainsToken<T> for Not<S> {
+    #[inline(always)]
+    fn contains_token(&self, token: T) -> bool {
+        !self.set.contains_token(token)
+    }
+}
+
 #[cfg(feature = "simd")]
 #[inline(always)]
 fn memchr(token: u8, slice: &[u8]) -> Option<usize> {
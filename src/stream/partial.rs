@@ -1,18 +1,23 @@
 use crate::error::Needed;
 use crate::stream::AsBStr;
 use crate::stream::AsBytes;
+use crate::stream::BStr;
+use crate::stream::Bytes;
 use crate::stream::Checkpoint;
 use crate::stream::Compare;
 use crate::stream::CompareResult;
 use crate::stream::FindSlice;
 use crate::stream::Location;
+use crate::stream::LookBehind;
 use crate::stream::Offset;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::stream::Recover;
+use crate::stream::RecursionDepth;
 use crate::stream::SliceLen;
 use crate::stream::Stream;
 use crate::stream::StreamIsPartial;
+use crate::stream::StreamPreview;
 use crate::stream::UpdateSlice;
 
 /// Mark the input as a partial buffer for streaming input.
@@ -106,8 +111,60 @@ where
     pub fn into_inner(self) -> I {
         self.input
     }
+
+    /// Convert the wrapped [`Stream`], preserving whether the input is partial
+    ///
+    /// This is useful for switching between views of the same data mid-parse, like `&[u8]` and
+    /// [`&Bytes`][crate::stream::Bytes].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::stream::Partial;
+    /// # use winnow::stream::StreamIsPartial;
+    /// use winnow::stream::Bytes;
+    ///
+    /// let mut input = Partial::new(&b"abc"[..]);
+    /// let _ = input.complete();
+    /// let input = input.map_input(Bytes::new);
+    /// assert_eq!(*input, Bytes::new(b"abc"));
+    /// assert!(!input.is_partial());
+    /// ```
+    #[inline]
+    pub fn map_input<J, F>(self, f: F) -> Partial<J>
+    where
+        J: StreamIsPartial,
+        F: FnOnce(I) -> J,
+    {
+        Partial {
+            input: f(self.input),
+            partial: self.partial,
+        }
+    }
+}
+
+macro_rules! impl_from_partial {
+    ($($from:ty => $to:ty),* $(,)?) => {
+        $(
+            impl<'a> From<Partial<$from>> for Partial<$to> {
+                #[inline]
+                fn from(input: Partial<$from>) -> Self {
+                    input.map_input(Into::into)
+                }
+            }
+        )*
+    };
 }
 
+impl_from_partial!(
+    &'a [u8] => &'a Bytes,
+    &'a Bytes => &'a [u8],
+    &'a [u8] => &'a BStr,
+    &'a BStr => &'a [u8],
+    &'a str => &'a Bytes,
+    &'a str => &'a BStr,
+);
+
 impl<I> Default for Partial<I>
 where
     I: Default + StreamIsPartial,
@@ -133,6 +190,20 @@ impl<I: crate::lib::std::fmt::Display> crate::lib::std::fmt::Display for Partial
     }
 }
 
+impl<I: StreamPreview> StreamPreview for Partial<I> {
+    fn fmt_preview(
+        &self,
+        max_len: usize,
+        f: &mut crate::lib::std::fmt::Formatter<'_>,
+    ) -> crate::lib::std::fmt::Result {
+        self.input.fmt_preview(max_len, f)?;
+        if self.partial {
+            write!(f, " (partial)")?;
+        }
+        Ok(())
+    }
+}
+
 impl<I> SliceLen for Partial<I>
 where
     I: SliceLen,
@@ -205,6 +276,26 @@ impl<I: Stream> Stream for Partial<I> {
     }
 }
 
+impl<I> LookBehind for Partial<I>
+where
+    I: LookBehind,
+{
+    #[inline(always)]
+    fn peek_previous_token(&self) -> Option<Self::Token> {
+        self.input.peek_previous_token()
+    }
+}
+
+impl<I> RecursionDepth for Partial<I>
+where
+    I: RecursionDepth,
+{
+    #[inline(always)]
+    fn recursion_depth(&mut self) -> &mut usize {
+        self.input.recursion_depth()
+    }
+}
+
 impl<I> Location for Partial<I>
 where
     I: Location,
@@ -26,6 +26,30 @@
 /// let parser: Vec<_> = repeat(5..=8, inner).parse_next(input).unwrap();
 /// # }
 /// ```
+///
+/// Any integer type can be used for the bounds, like a `u64` read from a length field:
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// # use winnow::prelude::*;
+/// # use winnow::token::any;
+/// # use winnow::combinator::repeat;
+/// # fn inner(input: &mut &str) -> ModalResult<char> {
+/// #     any.parse_next(input)
+/// # }
+/// # let mut input = "0123456789012345678901234567890123456789";
+/// # let input = &mut input;
+/// let len: u64 = 5;
+/// let parser: Vec<_> = repeat(len, inner).parse_next(input).unwrap();
+/// # let mut input = "0123456789012345678901234567890123456789";
+/// # let input = &mut input;
+/// let parser: Vec<_> = repeat(1..len, inner).parse_next(input).unwrap();
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Converting panics if a bound is negative or doesn't fit in a `usize`, like a `u64` above
+/// `u32::MAX` on 32-bit platforms.  Validate untrusted counts before parsing with them.
 #[derive(PartialEq, Eq)]
 pub struct Range {
     pub(crate) start_inclusive: usize,
@@ -119,6 +143,75 @@ impl From<crate::lib::std::ops::RangeToInclusive<usize>> for Range {
     }
 }
 
+/// Convert a bound of another integer type, panicking if it doesn't fit
+#[inline(always)]
+#[track_caller]
+fn to_usize<T>(bound: T) -> usize
+where
+    T: TryInto<usize> + Copy + crate::lib::std::fmt::Display,
+{
+    match bound.try_into() {
+        Ok(bound) => bound,
+        Err(_) => panic!("range bound `{bound}` is not a valid `usize`"),
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Range {
+                #[inline(always)]
+                #[track_caller]
+                fn from(fixed: $int) -> Self {
+                    to_usize(fixed).into()
+                }
+            }
+
+            impl From<crate::lib::std::ops::Range<$int>> for Range {
+                #[inline(always)]
+                #[track_caller]
+                fn from(range: crate::lib::std::ops::Range<$int>) -> Self {
+                    (to_usize(range.start)..to_usize(range.end)).into()
+                }
+            }
+
+            impl From<crate::lib::std::ops::RangeFrom<$int>> for Range {
+                #[inline(always)]
+                #[track_caller]
+                fn from(range: crate::lib::std::ops::RangeFrom<$int>) -> Self {
+                    (to_usize(range.start)..).into()
+                }
+            }
+
+            impl From<crate::lib::std::ops::RangeTo<$int>> for Range {
+                #[inline(always)]
+                #[track_caller]
+                fn from(range: crate::lib::std::ops::RangeTo<$int>) -> Self {
+                    (..to_usize(range.end)).into()
+                }
+            }
+
+            impl From<crate::lib::std::ops::RangeInclusive<$int>> for Range {
+                #[inline(always)]
+                #[track_caller]
+                fn from(range: crate::lib::std::ops::RangeInclusive<$int>) -> Self {
+                    (to_usize(*range.start())..=to_usize(*range.end())).into()
+                }
+            }
+
+            impl From<crate::lib::std::ops::RangeToInclusive<$int>> for Range {
+                #[inline(always)]
+                #[track_caller]
+                fn from(range: crate::lib::std::ops::RangeToInclusive<$int>) -> Self {
+                    (..=to_usize(range.end)).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize);
+
 impl crate::lib::std::fmt::Display for Range {
     fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
         self.start_inclusive.fmt(f)?;
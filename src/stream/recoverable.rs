@@ -7,10 +7,12 @@ use crate::stream::Compare;
 use crate::stream::CompareResult;
 use crate::stream::FindSlice;
 use crate::stream::Location;
+use crate::stream::LookBehind;
 use crate::stream::Offset;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::stream::Recover;
+use crate::stream::RecursionDepth;
 use crate::stream::SliceLen;
 use crate::stream::Stream;
 use crate::stream::StreamIsPartial;
@@ -178,6 +180,27 @@ where
     }
 }
 
+impl<I, E> LookBehind for Recoverable<I, E>
+where
+    I: LookBehind,
+    E: crate::lib::std::fmt::Debug,
+{
+    #[inline(always)]
+    fn peek_previous_token(&self) -> Option<Self::Token> {
+        self.input.peek_previous_token()
+    }
+}
+
+impl<I, E> RecursionDepth for Recoverable<I, E>
+where
+    I: Stream + RecursionDepth,
+{
+    #[inline(always)]
+    fn recursion_depth(&mut self) -> &mut usize {
+        self.input.recursion_depth()
+    }
+}
+
 impl<I, E> Location for Recoverable<I, E>
 where
     I: Location,
@@ -0,0 +1,495 @@
+use crate::error::Needed;
+use crate::lib::std::ops::Deref;
+use crate::stream::AsBStr;
+use crate::stream::Checkpoint;
+use crate::stream::Compare;
+use crate::stream::CompareResult;
+use crate::stream::FindSlice;
+use crate::stream::Offset;
+use crate::stream::ParseSlice;
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+use crate::stream::Recover;
+use crate::stream::SliceLen;
+use crate::stream::Stream;
+use crate::stream::StreamIsPartial;
+use crate::stream::UpdateSlice;
+
+/// Parse a shared, owned buffer, like `Arc<str>` or `Rc<[u8]>`
+///
+/// Slices and checkpoints are views into the same buffer, cloning the pointer rather than the
+/// data.  This lets parse results and errors own their input, outliving the function doing the
+/// parsing, without copying the text.
+///
+/// With the `bytes` feature, [`bytes::Bytes`](https://docs.rs/bytes) buffers can be parsed too.
+/// Like any `Shared` input, the [`Stream::Slice`] is `Shared<Bytes>`, not `Bytes`, so parsers
+/// returning `Bytes` convert the views back with `.map(Shared::into_bytes)`.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use std::sync::Arc;
+/// use winnow::ascii::{alpha1, dec_uint};
+/// use winnow::error::{ContextError, ParseError};
+/// use winnow::stream::Shared;
+///
+/// type Input = Shared<Arc<str>>;
+///
+/// fn key_value(input: &mut Input) -> ModalResult<(Input, u32)> {
+///     (alpha1, '=', dec_uint).map(|(k, _, v)| (k, v)).parse_next(input)
+/// }
+///
+/// fn parse(text: Arc<str>) -> Result<(Input, u32), ParseError<Input, ContextError>> {
+///     key_value.parse(Shared::new(text))
+/// }
+///
+/// let (key, value) = parse(Arc::from("answer=42")).unwrap();
+/// assert_eq!(&*key, "answer");
+/// assert_eq!(key.span(), 0..6);
+/// assert_eq!(value, 42);
+///
+/// // The error owns a handle to the input, rather than borrowing it
+/// let err = parse(Arc::from("answer=")).unwrap_err();
+/// assert_eq!(err.offset(), 7);
+/// assert_eq!(&**err.input(), "answer=");
+/// ```
+#[derive(Clone)]
+pub struct Shared<P> {
+    source: P,
+    start: usize,
+    end: usize,
+}
+
+impl<P> Shared<P>
+where
+    P: Deref,
+    P::Target: SharedSlice,
+{
+    /// Make a stream to parse all of `source`
+    #[inline]
+    pub fn new(source: P) -> Self {
+        let end = source.shared_len();
+        Self {
+            source,
+            start: 0,
+            end,
+        }
+    }
+
+    /// The buffer this is a view into
+    #[inline(always)]
+    pub fn source(&self) -> &P {
+        &self.source
+    }
+
+    /// The location of this view within [`Shared::source`]
+    #[inline(always)]
+    pub fn span(&self) -> crate::lib::std::ops::Range<usize> {
+        self.start..self.end
+    }
+
+    #[inline(always)]
+    fn as_slice(&self) -> &P::Target {
+        &self.source[self.start..self.end]
+    }
+
+    #[inline(always)]
+    fn view(&self, start: usize, end: usize) -> Self
+    where
+        P: Clone,
+    {
+        Self {
+            source: self.source.clone(),
+            start,
+            end,
+        }
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Shared<bytes::Bytes> {
+    /// The view as a [`bytes::Bytes`], sharing the buffer rather than copying it
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// use winnow::binary::{be_u16, length_take};
+    /// use winnow::combinator::repeat;
+    /// use winnow::stream::Shared;
+    ///
+    /// type Input = Shared<bytes::Bytes>;
+    ///
+    /// fn frame(input: &mut Input) -> ModalResult<bytes::Bytes> {
+    ///     length_take(be_u16).map(Shared::into_bytes).parse_next(input)
+    /// }
+    ///
+    /// let buffer = bytes::Bytes::from_static(b"\x00\x05hello\x00\x05world");
+    /// let frames: Vec<bytes::Bytes> = repeat(0.., frame)
+    ///     .parse(Shared::new(buffer.clone()))
+    ///     .unwrap();
+    /// assert_eq!(frames, [&b"hello"[..], &b"world"[..]]);
+    /// // Payloads point into the original buffer
+    /// assert_eq!(frames[0].as_ptr(), buffer[2..].as_ptr());
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> bytes::Bytes {
+        self.source.slice(self.start..self.end)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl From<Shared<bytes::Bytes>> for bytes::Bytes {
+    #[inline]
+    fn from(shared: Shared<bytes::Bytes>) -> Self {
+        shared.into_bytes()
+    }
+}
+
+/// Slice types that can be parsed with [`Shared`]
+pub trait SharedSlice:
+    crate::lib::std::ops::Index<crate::lib::std::ops::Range<usize>, Output = Self>
+{
+    /// Type for a single element of the slice
+    type Token: crate::lib::std::fmt::Debug + Clone;
+
+    #[doc(hidden)]
+    fn shared_len(&self) -> usize;
+    #[doc(hidden)]
+    fn token_at(&self, offset: usize) -> Option<(Self::Token, usize)>;
+    #[doc(hidden)]
+    fn shared_bytes(&self) -> &[u8];
+}
+
+impl SharedSlice for str {
+    type Token = char;
+
+    #[inline(always)]
+    fn shared_len(&self) -> usize {
+        self.len()
+    }
+    #[inline(always)]
+    fn token_at(&self, offset: usize) -> Option<(Self::Token, usize)> {
+        let c = self[offset..].chars().next()?;
+        Some((c, c.len_utf8()))
+    }
+    #[inline(always)]
+    fn shared_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl SharedSlice for [u8] {
+    type Token = u8;
+
+    #[inline(always)]
+    fn shared_len(&self) -> usize {
+        self.len()
+    }
+    #[inline(always)]
+    fn token_at(&self, offset: usize) -> Option<(Self::Token, usize)> {
+        self.get(offset).map(|b| (*b, 1))
+    }
+    #[inline(always)]
+    fn shared_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+/// Iterator for [`Shared`]'s [`Stream::iter_offsets`]
+#[derive(Clone)]
+pub struct SharedIterOffsets<P> {
+    input: Shared<P>,
+    offset: usize,
+}
+
+impl<P> Iterator for SharedIterOffsets<P>
+where
+    P: Deref,
+    P::Target: SharedSlice,
+{
+    type Item = (usize, <P::Target as SharedSlice>::Token);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset;
+        let (token, len) = self.input.as_slice().token_at(offset)?;
+        self.offset += len;
+        Some((offset, token))
+    }
+}
+
+impl<P> Deref for Shared<P>
+where
+    P: Deref,
+    P::Target: SharedSlice,
+{
+    type Target = P::Target;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<P> PartialEq for Shared<P>
+where
+    P: Deref,
+    P::Target: SharedSlice + PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<P> Eq for Shared<P>
+where
+    P: Deref,
+    P::Target: SharedSlice + Eq,
+{
+}
+
+impl<P> crate::lib::std::fmt::Debug for Shared<P>
+where
+    P: Deref,
+    P::Target: SharedSlice + crate::lib::std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<P> crate::lib::std::fmt::Display for Shared<P>
+where
+    P: Deref,
+    P::Target: SharedSlice + crate::lib::std::fmt::Display,
+{
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<P> SliceLen for Shared<P>
+where
+    P: Deref,
+    P::Target: SharedSlice,
+{
+    #[inline(always)]
+    fn slice_len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+impl<P> Stream for Shared<P>
+where
+    P: Deref + Clone,
+    P::Target: SharedSlice + crate::lib::std::fmt::Debug,
+{
+    type Token = <P::Target as SharedSlice>::Token;
+    type Slice = Self;
+
+    type IterOffsets = SharedIterOffsets<P>;
+
+    type Checkpoint = Checkpoint<Self, Self>;
+
+    #[inline(always)]
+    fn iter_offsets(&self) -> Self::IterOffsets {
+        SharedIterOffsets {
+            input: self.clone(),
+            offset: 0,
+        }
+    }
+    #[inline(always)]
+    fn eof_offset(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[inline(always)]
+    fn next_token(&mut self) -> Option<Self::Token> {
+        let (token, len) = self.as_slice().token_at(0)?;
+        self.start += len;
+        Some(token)
+    }
+
+    #[inline(always)]
+    fn peek_token(&self) -> Option<Self::Token> {
+        self.as_slice().token_at(0).map(|(token, _)| token)
+    }
+
+    #[inline(always)]
+    fn offset_for<P2>(&self, predicate: P2) -> Option<usize>
+    where
+        P2: Fn(Self::Token) -> bool,
+    {
+        let slice = self.as_slice();
+        let mut offset = 0;
+        while let Some((token, len)) = slice.token_at(offset) {
+            if predicate(token) {
+                return Some(offset);
+            }
+            offset += len;
+        }
+        None
+    }
+    #[inline]
+    fn offset_at(&self, tokens: usize) -> Result<usize, Needed> {
+        let slice = self.as_slice();
+        let mut offset = 0;
+        for cnt in 0..tokens {
+            match slice.token_at(offset) {
+                Some((_, len)) => offset += len,
+                None => return Err(Needed::new(tokens - cnt)),
+            }
+        }
+        Ok(offset)
+    }
+    #[inline(always)]
+    fn next_slice(&mut self, offset: usize) -> Self::Slice {
+        let slice = self.peek_slice(offset);
+        self.start = slice.end;
+        slice
+    }
+    #[inline(always)]
+    fn peek_slice(&self, offset: usize) -> Self::Slice {
+        // Ensure `offset` is in bounds and, for `str`, on a `char` boundary
+        let _ = &self.as_slice()[0..offset];
+        self.view(self.start, self.start + offset)
+    }
+
+    #[inline(always)]
+    fn checkpoint(&self) -> Self::Checkpoint {
+        Checkpoint::<_, Self>::new(self.clone())
+    }
+    #[inline(always)]
+    fn reset(&mut self, checkpoint: &Self::Checkpoint) {
+        self.start = checkpoint.inner.start;
+    }
+
+    #[inline(always)]
+    fn raw(&self) -> &dyn crate::lib::std::fmt::Debug {
+        self
+    }
+}
+
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+impl<P, E> Recover<E> for Shared<P>
+where
+    P: Deref + Clone,
+    P::Target: SharedSlice + crate::lib::std::fmt::Debug,
+{
+    #[inline(always)]
+    fn record_err(
+        &mut self,
+        _token_start: &Self::Checkpoint,
+        _err_start: &Self::Checkpoint,
+        err: E,
+    ) -> Result<(), E> {
+        Err(err)
+    }
+
+    /// Report whether the [`Stream`] can save off errors for recovery
+    #[inline(always)]
+    fn is_recovery_supported() -> bool {
+        false
+    }
+}
+
+impl<P> StreamIsPartial for Shared<P> {
+    type PartialState = ();
+
+    #[inline]
+    fn complete(&mut self) -> Self::PartialState {
+        // Already complete
+    }
+
+    #[inline]
+    fn restore_partial(&mut self, _state: Self::PartialState) {}
+
+    #[inline(always)]
+    fn is_partial_supported() -> bool {
+        false
+    }
+}
+
+impl<P> Offset for Shared<P> {
+    #[inline(always)]
+    fn offset_from(&self, start: &Self) -> usize {
+        debug_assert!(
+            start.start <= self.start,
+            "`Offset::offset_from` only accepts views that come before `self`"
+        );
+        self.start - start.start
+    }
+}
+
+impl<P> Offset<<Shared<P> as Stream>::Checkpoint> for Shared<P>
+where
+    P: Deref + Clone,
+    P::Target: SharedSlice + crate::lib::std::fmt::Debug,
+{
+    #[inline(always)]
+    fn offset_from(&self, other: &<Shared<P> as Stream>::Checkpoint) -> usize {
+        self.checkpoint().offset_from(other)
+    }
+}
+
+impl<P> AsBStr for Shared<P>
+where
+    P: Deref,
+    P::Target: SharedSlice,
+{
+    #[inline(always)]
+    fn as_bstr(&self) -> &[u8] {
+        self.as_slice().shared_bytes()
+    }
+}
+
+impl<P, T> Compare<T> for Shared<P>
+where
+    P: Deref,
+    P::Target: SharedSlice,
+    for<'s> &'s P::Target: Compare<T>,
+{
+    #[inline(always)]
+    fn compare(&self, t: T) -> CompareResult {
+        self.as_slice().compare(t)
+    }
+}
+
+impl<P, T> FindSlice<T> for Shared<P>
+where
+    P: Deref,
+    P::Target: SharedSlice,
+    for<'s> &'s P::Target: FindSlice<T>,
+{
+    #[inline(always)]
+    fn find_slice(&self, substr: T) -> Option<crate::lib::std::ops::Range<usize>> {
+        self.as_slice().find_slice(substr)
+    }
+}
+
+impl<P, R> ParseSlice<R> for Shared<P>
+where
+    P: Deref,
+    P::Target: SharedSlice,
+    for<'s> &'s P::Target: ParseSlice<R>,
+{
+    #[inline(always)]
+    fn parse_slice(&self) -> Option<R> {
+        self.as_slice().parse_slice()
+    }
+}
+
+impl<P> UpdateSlice for Shared<P>
+where
+    P: Deref + Clone,
+    P::Target: SharedSlice + crate::lib::std::fmt::Debug,
+{
+    #[inline(always)]
+    fn update_slice(self, inner: Self::Slice) -> Self {
+        inner
+    }
+}
@@ -6,13 +6,16 @@ use crate::stream::Compare;
 use crate::stream::CompareResult;
 use crate::stream::FindSlice;
 use crate::stream::Location;
+use crate::stream::LookBehind;
 use crate::stream::Offset;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::stream::Recover;
+use crate::stream::RecursionDepth;
 use crate::stream::SliceLen;
 use crate::stream::Stream;
 use crate::stream::StreamIsPartial;
+use crate::stream::StreamPreview;
 use crate::stream::UpdateSlice;
 
 /// Thread global state through your parsers
@@ -84,6 +87,16 @@ impl<I: crate::lib::std::fmt::Display, S> crate::lib::std::fmt::Display for Stat
     }
 }
 
+impl<I: StreamPreview, S> StreamPreview for Stateful<I, S> {
+    fn fmt_preview(
+        &self,
+        max_len: usize,
+        f: &mut crate::lib::std::fmt::Formatter<'_>,
+    ) -> crate::lib::std::fmt::Result {
+        self.input.fmt_preview(max_len, f)
+    }
+}
+
 impl<I, S> SliceLen for Stateful<I, S>
 where
     I: SliceLen,
@@ -156,6 +169,27 @@ impl<I: Stream, S: crate::lib::std::fmt::Debug> Stream for Stateful<I, S> {
     }
 }
 
+impl<I, S> LookBehind for Stateful<I, S>
+where
+    I: LookBehind,
+    S: crate::lib::std::fmt::Debug,
+{
+    #[inline(always)]
+    fn peek_previous_token(&self) -> Option<Self::Token> {
+        self.input.peek_previous_token()
+    }
+}
+
+impl<I, S> RecursionDepth for Stateful<I, S>
+where
+    S: RecursionDepth,
+{
+    #[inline(always)]
+    fn recursion_depth(&mut self) -> &mut usize {
+        self.state.recursion_depth()
+    }
+}
+
 impl<I, S> Location for Stateful<I, S>
 where
     I: Location,
@@ -150,6 +150,68 @@ fn test_custom_slice() {
     assert_eq!(offset, 2);
 }
 
+#[test]
+fn test_custom_slice_parsers() {
+    use crate::token::{any, one_of};
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    enum Token {
+        Value(i64),
+        Plus,
+        OpenParen,
+        CloseParen,
+    }
+
+    let tokens = [
+        Token::OpenParen,
+        Token::Value(1),
+        Token::Plus,
+        Token::Value(2),
+        Token::CloseParen,
+    ];
+    let input = &tokens[..];
+
+    assert_eq!(
+        any::<_, ErrMode<InputError<_>>>.parse_peek(input),
+        Ok((&tokens[1..], Token::OpenParen))
+    );
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>(Token::OpenParen).parse_peek(input),
+        Ok((&tokens[1..], &tokens[..1]))
+    );
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>([Token::OpenParen, Token::Value(1)])
+            .parse_peek(input),
+        Ok((&tokens[2..], &tokens[..2]))
+    );
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>(&[Token::OpenParen, Token::Plus][..])
+            .parse_peek(input),
+        Err(Backtrack(InputError::at(input)))
+    );
+    assert_eq!(
+        one_of::<_, _, ErrMode<InputError<_>>>(|t: Token| matches!(t, Token::Value(_)))
+            .parse_peek(&tokens[1..]),
+        Ok((&tokens[2..], Token::Value(1)))
+    );
+    assert_eq!(
+        one_of::<_, _, ErrMode<InputError<_>>>(|t: Token| matches!(t, Token::Value(_)))
+            .parse_peek(input),
+        Err(Backtrack(InputError::at(input)))
+    );
+
+    let partial = Partial::new(&tokens[..1]);
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>([Token::OpenParen, Token::Value(1)])
+            .parse_peek(partial),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>(Token::Plus).parse_peek(Partial::new(&tokens[..0])),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+}
+
 #[test]
 fn test_literal_support_char() {
     assert_eq!(
@@ -230,6 +292,188 @@ fn test_literal_support_char() {
     );
 }
 
+#[test]
+fn test_literal_single_token() {
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>('(').parse_peek(Partial::new("(1)")),
+        Ok((Partial::new("1)"), "("))
+    );
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>('(').parse_peek(Partial::new("")),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>("(").parse_peek(Partial::new(&b""[..])),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>('(').parse_peek(""),
+        Err(Backtrack(InputError::at("")))
+    );
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>("(").parse_peek(&b"1)"[..]),
+        Err(Backtrack(InputError::at(&b"1)"[..])))
+    );
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>(AsciiCaseless('x')).parse_peek(&b"X1"[..]),
+        Ok((&b"1"[..], &b"X"[..]))
+    );
+}
+
+#[test]
+fn test_find_slice_overlapping_needles() {
+    // Earliest start wins, even when a later needle is shorter
+    assert_eq!("xabc".find_slice(("bc", "abc")), Some(1..4));
+    assert_eq!("xabc".find_slice(("c", "bc", "abc")), Some(1..4));
+    // Ties go to the first needle listed
+    assert_eq!("x\r\n".find_slice(("\r", "\r\n")), Some(1..2));
+    assert_eq!("x\r\n".find_slice(("\r\n", "\r")), Some(1..3));
+    // A needle's prefix overlapping a later match
+    assert_eq!("aab".find_slice(("ab", "aa")), Some(0..2));
+    assert_eq!("aab".find_slice(("ab", "b")), Some(1..3));
+    assert_eq!(
+        (&b"zzab"[..]).find_slice((&b"b"[..], &b"ab"[..], &b"zab"[..])),
+        Some(1..4)
+    );
+    assert_eq!("xyz".find_slice(("ab", "bc")), None);
+    assert_eq!("xyz".find_slice(('a', 'b', 'z')), Some(2..3));
+}
+
+#[cfg(feature = "std")]
+proptest! {
+  #[test]
+  #[cfg_attr(miri, ignore)]  // See https://github.com/AltSysrq/proptest/issues/253
+  fn compare_single_token(input in proptest::collection::vec(any::<u8>(), 0..4), c in any::<char>()) {
+        compare_single_token_inner(&input, c);
+  }
+}
+
+#[cfg(feature = "std")]
+fn compare_single_token_inner(input: &[u8], c: char) {
+    // Reference behavior of the general multi-token comparison
+    fn expected(input: &[u8], literal: &[u8]) -> CompareResult {
+        if literal.iter().zip(input).any(|(a, b)| a != b) {
+            CompareResult::Error
+        } else if input.len() < literal.len() {
+            CompareResult::Incomplete
+        } else {
+            CompareResult::Ok(literal.len())
+        }
+    }
+
+    let mut buffer = [0; 4];
+    let encoded = c.encode_utf8(&mut buffer).as_bytes();
+    assert_eq!(input.compare(c), expected(input, encoded));
+    assert_eq!(input.compare(&encoded[..1]), expected(input, &encoded[..1]));
+    if let Ok(input) = core::str::from_utf8(input) {
+        assert_eq!(input.compare(c), expected(input.as_bytes(), encoded));
+    }
+}
+
+#[test]
+fn wrapper_nesting_forwards_traits() {
+    fn assert_traits<I>()
+    where
+        I: Stream
+            + StreamIsPartial
+            + Location
+            + Offset
+            + Compare<&'static str>
+            + AsBStr
+            + UpdateSlice
+            + FindSlice<&'static str>,
+    {
+    }
+    fn assert_look_behind<I: LookBehind>() {}
+
+    assert_traits::<LocatingSlice<Partial<&str>>>();
+    assert_traits::<Partial<LocatingSlice<&str>>>();
+    assert_traits::<Stateful<LocatingSlice<Partial<&str>>, ()>>();
+    assert_traits::<Stateful<Partial<LocatingSlice<&str>>, ()>>();
+    assert_traits::<LocatingSlice<Partial<Stateful<&str, ()>>>>();
+    assert_traits::<Partial<LocatingSlice<Stateful<&str, ()>>>>();
+    assert_traits::<LocatingSlice<Stateful<Partial<&str>, ()>>>();
+    assert_traits::<Partial<Stateful<LocatingSlice<&str>, ()>>>();
+    assert_traits::<LocatingPartial<&[u8]>>();
+    assert_traits::<StatefulLocating<&[u8], ()>>();
+    assert_traits::<StatefulLocatingPartial<&BStr, ()>>();
+    assert_traits::<CaseInsensitive<LocatingSlice<Partial<&str>>>>();
+    assert_traits::<Stateful<CaseInsensitive<LocatingPartial<&[u8]>>, ()>>();
+
+    assert_look_behind::<LocatingSlice<Partial<&str>>>();
+    assert_look_behind::<Partial<LocatingSlice<&str>>>();
+    assert_look_behind::<Stateful<LocatingSlice<Partial<&str>>, ()>>();
+    assert_look_behind::<Stateful<Partial<LocatingSlice<&str>>, ()>>();
+    assert_look_behind::<Partial<Stateful<LocatingSlice<&str>, ()>>>();
+    assert_look_behind::<LocatingPartial<&[u8]>>();
+    assert_look_behind::<StatefulLocating<&Bytes, ()>>();
+    assert_look_behind::<StatefulLocatingPartial<&BStr, ()>>();
+    assert_look_behind::<CaseInsensitive<LocatingSlice<Partial<&str>>>>();
+}
+
+#[test]
+fn wrapper_nesting_behavior() {
+    fn word<I>(i: &mut I) -> ModalResult<crate::lib::std::ops::Range<usize>>
+    where
+        I: Stream<Token = char> + StreamIsPartial + Location + Compare<char>,
+    {
+        (crate::ascii::alpha1, ' ').span().parse_next(i)
+    }
+
+    let mut input = LocatingSlice::new(Partial::new("hello world"));
+    let _ = literal::<_, _, ErrMode<InputError<_>>>("hel").parse_next(&mut input);
+    assert_eq!(input.peek_previous_token(), Some('l'));
+    assert_eq!(word.parse_next(&mut input), Ok(3..6));
+    assert_eq!(
+        word.parse_next(&mut input),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+
+    let mut input = Partial::new(LocatingSlice::new("hello world"));
+    assert_eq!(word.parse_next(&mut input), Ok(0..6));
+    assert_eq!(input.peek_previous_token(), Some(' '));
+    assert_eq!(
+        word.parse_next(&mut input),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+
+    let mut input = Stateful {
+        input: LocatingSlice::new(Partial::new("hello world")),
+        state: 0usize,
+    };
+    input.state += 1;
+    assert_eq!(word.parse_next(&mut input), Ok(0..6));
+    assert_eq!(input.state, 1);
+}
+
+#[test]
+fn wrapper_input_conversion() {
+    let mut input = LocatingSlice::new(Partial::new("hello world"));
+    let _ = literal::<_, _, ErrMode<InputError<_>>>("hello ").parse_next(&mut input);
+
+    let mut input: LocatingSlice<Partial<&BStr>> = input.into();
+    assert_eq!(input.current_token_start(), 6);
+    assert!(input.is_partial());
+    assert_eq!(
+        literal::<_, _, ErrMode<InputError<_>>>("wor")
+            .span()
+            .parse_next(&mut input),
+        Ok(6..9)
+    );
+
+    let mut input: LocatingSlice<Partial<&[u8]>> = input.into();
+    let _ = input.complete();
+    let input = input.map_input(|i| i.map_input(Bytes::new));
+    assert_eq!(input.current_token_start(), 9);
+    assert!(!input.is_partial());
+    assert_eq!(**input, Bytes::new(b"ld"));
+
+    let input: Partial<&Bytes> = Partial::new(&b"abc"[..]).into();
+    assert!(input.is_partial());
+    let input: Partial<&[u8]> = input.into();
+    assert_eq!(*input, &b"abc"[..]);
+}
+
 #[test]
 fn tokenslice_location() {
     #[derive(Clone, Debug)]
@@ -273,3 +517,325 @@ fn tokenslice_location() {
 
     assert_eq!(input.current_token_start(), 29);
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn shared_str_parsers() {
+    use crate::ascii::{alpha1, dec_uint};
+    use crate::token::{take_until, take_while};
+    use std::sync::Arc;
+
+    let source: Arc<str> = Arc::from("key=42;rest");
+    let mut input = Shared::new(source.clone());
+
+    let start = input.checkpoint();
+    let key = take_while::<_, _, ErrMode<InputError<_>>>(1.., AsChar::is_alpha)
+        .parse_next(&mut input)
+        .unwrap();
+    assert_eq!(&*key, "key");
+    assert_eq!(key.span(), 0..3);
+    assert!(Arc::ptr_eq(key.source(), &source));
+
+    input.reset(&start);
+    let (key, _, value) = (
+        alpha1::<_, ErrMode<InputError<_>>>,
+        '=',
+        dec_uint::<_, u32, _>,
+    )
+        .parse_next(&mut input)
+        .unwrap();
+    assert_eq!(&*key, "key");
+    assert_eq!(value, 42);
+
+    let _ = literal::<_, _, ErrMode<InputError<_>>>(';')
+        .parse_next(&mut input)
+        .unwrap();
+    let rest = take_until::<_, _, ErrMode<InputError<_>>>(0.., "t")
+        .parse_next(&mut input)
+        .unwrap();
+    assert_eq!(&*rest, "res");
+    assert_eq!(rest.span(), 7..10);
+    assert_eq!(input.offset_from(&Shared::new(source)), 10);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn shared_bytes_error_owns_input() {
+    use crate::error::ParseError;
+    use std::rc::Rc;
+
+    type Input = Shared<Rc<[u8]>>;
+
+    fn parse(bytes: Rc<[u8]>) -> Result<Input, ParseError<Input, InputError<Input>>> {
+        literal::<_, _, InputError<_>>(&b"ab"[..]).parse(Shared::new(bytes))
+    }
+
+    assert_eq!(&*parse(Rc::from(&b"ab"[..])).unwrap(), &b"ab"[..]);
+
+    let err = parse(Rc::from(&b"ax"[..])).unwrap_err();
+    assert_eq!(err.offset(), 0);
+    assert_eq!(&**err.input(), &b"ax"[..]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn case_insensitive_keywords() {
+    use crate::ascii::{alpha1, space0, space1};
+    use crate::combinator::{alt, preceded, separated, terminated};
+    use crate::token::take_while;
+
+    type Input<'i> = CaseInsensitive<LocatingSlice<&'i str>>;
+
+    fn identifier<'i>(i: &mut Input<'i>) -> ModalResult<&'i str> {
+        take_while(1.., |c: char| c.is_ascii_alphanumeric() || c == '_').parse_next(i)
+    }
+
+    fn select<'i>(i: &mut Input<'i>) -> ModalResult<(Vec<&'i str>, &'i str)> {
+        (
+            preceded(
+                ("select", space1),
+                separated(1.., identifier, (space0, ',', space0)),
+            ),
+            preceded((space1, "from", space1), terminated(identifier, ';')),
+        )
+            .parse_next(i)
+    }
+
+    for query in [
+        "select Name, user_id from Users;",
+        "SELECT Name, user_id FROM Users;",
+        "SeLeCt Name, user_id fRoM Users;",
+    ] {
+        assert_eq!(
+            select.parse(CaseInsensitive::new(LocatingSlice::new(query))),
+            Ok((vec!["Name", "user_id"], "Users"))
+        );
+    }
+
+    let mut input = CaseInsensitive::new(LocatingSlice::new("TRUE false"));
+    assert_eq!(
+        alt::<_, _, ErrMode<InputError<_>>, _>(("true", "false")).parse_next(&mut input),
+        Ok("TRUE")
+    );
+    assert_eq!(input.current_token_start(), 4);
+    assert_eq!(
+        preceded(' ', alpha1::<_, ErrMode<InputError<_>>>).parse_next(&mut input),
+        Ok("false")
+    );
+
+    // Only ASCII is folded
+    assert!(literal::<_, _, ErrMode<InputError<_>>>("é")
+        .parse_peek(CaseInsensitive::new("É"))
+        .is_err());
+
+    let mut input = CaseInsensitive::new(Partial::new("SEL"));
+    assert!(matches!(
+        literal::<_, _, ErrMode<InputError<_>>>("select").parse_next(&mut input),
+        Err(ErrMode::Incomplete(_))
+    ));
+}
+
+#[test]
+fn range_from_other_ints() {
+    assert_eq!(Range::from(5u64), Range::from(5usize));
+    assert_eq!(Range::from(1u8..4), Range::from(1usize..4));
+    assert_eq!(Range::from(2i32..), Range::from(2usize..));
+    assert_eq!(Range::from(..3u32), Range::from(..3usize));
+    assert_eq!(Range::from(1i64..=3), Range::from(1usize..=3));
+    assert_eq!(Range::from(..=3u16), Range::from(..=3usize));
+
+    // Exclusive bounds are converted before being made inclusive
+    assert_eq!(Range::from(0u64..1), Range::from(0usize..=0));
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn range_from_u64_above_u32() {
+    let large = u64::from(u32::MAX) + 1;
+    assert_eq!(Range::from(large), Range::from(large as usize));
+    assert_eq!(Range::from(0..large), Range::from(0..large as usize));
+
+    // Too many repetitions for the input is an error, not a panic
+    let res: ModalResult<(&str, usize)> = crate::combinator::repeat(large, 'a').parse_peek("aaa");
+    assert!(res.is_err());
+}
+
+#[test]
+#[cfg(target_pointer_width = "32")]
+#[should_panic(expected = "range bound `4294967296` is not a valid `usize`")]
+fn range_from_u64_above_u32() {
+    let _ = Range::from(u64::from(u32::MAX) + 1);
+}
+
+#[test]
+#[should_panic(
+    expected = "range bound `340282366920938463463374607431768211455` is not a valid `usize`"
+)]
+fn range_from_overflow() {
+    let _ = Range::from(0..u128::MAX);
+}
+
+#[test]
+#[should_panic(expected = "range bound `-1` is not a valid `usize`")]
+fn range_from_negative() {
+    let _ = Range::from(-1i32..);
+}
+
+#[test]
+fn preview_wrappers() {
+    use snapbox::prelude::*;
+    use snapbox::str;
+
+    fn preview<I: StreamPreview>(input: &I) -> String {
+        format!("{}\n", Preview::new(input).max_len(8))
+    }
+
+    let mut actual = String::new();
+    actual.push_str(&preview(&"hello"));
+    actual.push_str(&preview(&"hello \"wörld\"\n"));
+    actual.push_str(&preview(&&b"GET /\r\n"[..]));
+    actual.push_str(&preview(&&b"\x00\x01\xfe\xffabcdef"[..]));
+    actual.push_str(&preview(&BStr::new(b"caf\xc3\xa9 au lait")));
+    actual.push_str(&preview(&Bytes::new(b"\x00\x01\xfe\xffabcdef")));
+
+    let mut input = Partial::new(&b"GET /index.html HTTP/1.1\r\n"[..]);
+    actual.push_str(&preview(&input));
+    let _ = input.complete();
+    actual.push_str(&preview(&input));
+
+    let mut input = LocatingSlice::new("hello world");
+    input.next_slice(6);
+    actual.push_str(&preview(&input));
+
+    let mut input = Partial::new(LocatingSlice::new(&b"GET /index.html HTTP/1.1\r\n"[..]));
+    input.next_slice(4);
+    actual.push_str(&preview(&input));
+
+    let mut input = LocatingSlice::new(Partial::new("hello world"));
+    input.next_slice(6);
+    actual.push_str(&preview(&input));
+
+    let mut input = Stateful {
+        input: LocatingSlice::new(Partial::new(BStr::new("hello world"))),
+        state: 0usize,
+    };
+    input.next_slice(6);
+    actual.push_str(&preview(&input));
+
+    let err = InputError::at(Partial::new(LocatingSlice::new(&[0xff; 1_000_000][..])));
+    actual.push_str(&format!("{}\n", err.preview()));
+
+    snapbox::assert_data_eq!(actual, str![[r#"
+"hello"
+"hello \"w"… (15 bytes)
+b"GET /\r\n"
+b"\x00\x01\xfe\xffabcd"… (10 bytes)
+"caf\xc3\xa9 au"… (13 bytes)
+0001FEFF61626364… (10 bytes)
+b"GET /ind"… (26 bytes) (partial)
+b"GET /ind"… (26 bytes)
+"world" at offset 6
+b"/index.h"… (22 bytes) at offset 4 (partial)
+"world" (partial) at offset 6
+"world" (partial) at offset 6
+b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff"… (1000000 bytes) at offset 0 (partial)
+
+"#]].raw());
+}
+
+#[test]
+#[cfg(feature = "bytes")]
+fn shared_bytes_crate_frames() {
+    use crate::binary::{be_u16, length_take};
+    use crate::combinator::repeat;
+
+    type Input = Shared<::bytes::Bytes>;
+
+    fn frame(input: &mut Input) -> ModalResult<::bytes::Bytes> {
+        length_take(be_u16)
+            .map(Shared::into_bytes)
+            .parse_next(input)
+    }
+
+    fn frames(buffer: ::bytes::Bytes) -> Vec<::bytes::Bytes> {
+        repeat(0.., frame).parse(Shared::new(buffer)).unwrap()
+    }
+
+    let buffer = ::bytes::Bytes::from(b"\x00\x05hello\x00\x00\x00\x05world".to_vec());
+    let start = buffer.as_ptr();
+    let payloads = frames(buffer);
+    // The payloads outlive the parse and the original handle, without copying
+    assert_eq!(payloads, [&b"hello"[..], &b""[..], &b"world"[..]]);
+    assert_eq!(payloads[0].as_ptr(), start.wrapping_add(2));
+    assert_eq!(payloads[2].as_ptr(), start.wrapping_add(11));
+    assert_eq!(
+        ::bytes::Bytes::from(Shared::new(payloads[2].clone())),
+        "world"
+    );
+
+    let mut input = Partial::new(Shared::new(::bytes::Bytes::from_static(b"\x00\x05hel")));
+    assert_eq!(
+        length_take::<_, _, ErrMode<InputError<_>>, _>(be_u16).parse_next(&mut input),
+        Err(ErrMode::Incomplete(Needed::new(2)))
+    );
+}
+
+const DIGITS: ByteTable = ByteTable::from_range(b'0'..=b'9');
+const IDENT: ByteTable = ByteTable::new(b"_")
+    .with_range(b'a'..=b'z')
+    .with_range(b'A'..=b'Z')
+    .union(DIGITS);
+// Built at compile time
+const _: () = assert!(IDENT.contains(b'_') && IDENT.contains(b'7') && !IDENT.contains(b'-'));
+const _: () = assert!(!IDENT.complement().contains(b'a') && IDENT.complement().contains(0xFF));
+const _: () = assert!(
+    IDENT.intersection(DIGITS).contains(b'0') && !IDENT.intersection(DIGITS).contains(b'a')
+);
+
+#[test]
+fn byte_table_matches_closure() {
+    let ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    for byte in 0..=u8::MAX {
+        assert_eq!(IDENT.contains_token(byte), ident(byte), "{byte}");
+        assert_eq!(IDENT.contains_token(&byte), ident(byte), "{byte}");
+        assert_eq!(
+            IDENT.complement().contains_token(byte),
+            !ident(byte),
+            "{byte}"
+        );
+    }
+    for c in ['a', 'Z', '0', '_', '-', ' ', 'é', 'ÿ', '東'] {
+        let expected = c.is_ascii_alphanumeric() || c == '_';
+        assert_eq!(IDENT.contains_token(c), expected, "{c}");
+        assert_eq!(IDENT.contains_token(&c), expected, "{c}");
+    }
+
+    for input in ["foo_1 bar", "_", "élan", "a-b", "", "x\u{ff}"] {
+        let mut table = crate::token::take_while::<_, _, InputError<_>>(0.., IDENT);
+        let mut closure = crate::token::take_while::<_, _, InputError<_>>(0.., |c: char| {
+            c.is_ascii_alphanumeric() || c == '_'
+        });
+        assert_eq!(
+            table.parse_peek(input),
+            closure.parse_peek(input),
+            "{input:?}"
+        );
+
+        let bytes = input.as_bytes();
+        let mut table = crate::token::take_while::<_, _, InputError<_>>(0.., IDENT);
+        let mut closure = crate::token::take_while::<_, _, InputError<_>>(0.., ident);
+        assert_eq!(
+            table.parse_peek(bytes),
+            closure.parse_peek(bytes),
+            "{input:?}"
+        );
+    }
+}
+
+#[test]
+fn byte_table_debug() {
+    assert_eq!(format!("{:?}", ByteTable::EMPTY), "{}");
+    assert_eq!(format!("{IDENT:?}"), "{48..=57, 65..=90, 95, 97..=122}");
+    assert_eq!(format!("{:?}", ByteTable::new(&[0, 255])), "{0, 255}");
+    assert_eq!(format!("{:?}", ByteTable::EMPTY.complement()), "{0..=255}");
+}
@@ -0,0 +1,243 @@
+//! Assertions for testing and fuzzing parsers
+//!
+//! These check invariants every [`Parser`] is expected to uphold, panicking with a description of
+//! the violation, so they can be called from unit tests, `proptest` strategies, or fuzz targets.
+//!
+//! # Example
+//!
+//! A [cargo-fuzz](https://github.com/rust-fuzz/cargo-fuzz) target:
+//! ```rust
+//! # use winnow::prelude::*;
+//! use winnow::ascii::{alpha1, digit1};
+//! use winnow::stream::{Compare, Stream, StreamIsPartial};
+//! use winnow::testing::{check_invariants, check_partial};
+//!
+//! fn key_value<I>(input: &mut I) -> ModalResult<I::Slice>
+//! where
+//!     I: Stream<Token = char> + StreamIsPartial + Compare<char>,
+//! {
+//!     (alpha1, '=', digit1).take().parse_next(input)
+//! }
+//!
+//! // fuzz_target!(|data: &[u8]| { ... })
+//! fn fuzz(data: &[u8]) {
+//!     if let Ok(s) = std::str::from_utf8(data) {
+//!         let _ = check_invariants(key_value, s);
+//!         let _ = check_partial(key_value, s);
+//!     }
+//! }
+//! # fuzz(b"abc=123");
+//! ```
+
+use crate::error::{Needed, ParserError};
+use crate::lib::std::fmt::Debug;
+use crate::stream::{Offset, Partial, Stream, StreamIsPartial};
+use crate::{Parser, Result};
+
+/// Parse `input`, checking the invariants every [`Parser`] should uphold
+///
+/// This panics if:
+/// - Parsing consumes more than `input`
+/// - `Incomplete` is returned on a complete stream
+/// - Parsing the same input twice gives a different result
+///
+/// Returns the result like [`Parser::parse_peek`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::digit1;
+/// use winnow::error::ContextError;
+/// use winnow::testing::check_invariants;
+///
+/// assert_eq!(check_invariants(digit1::<_, ContextError>, "123abc"), Ok(("abc", "123")));
+/// ```
+#[track_caller]
+pub fn check_invariants<I, O, E, P>(mut parser: P, input: I) -> Result<(I, O), E>
+where
+    P: Parser<I, O, E>,
+    I: Stream + StreamIsPartial + Clone + Debug,
+    O: Debug + PartialEq,
+    E: ParserError<I> + Debug + PartialEq,
+{
+    let start = input.checkpoint();
+    let len = input.eof_offset();
+
+    let mut first = input.clone();
+    let first_res = parser.parse_next(&mut first);
+    let first_consumed = first.offset_from(&start);
+    assert!(
+        first_consumed <= len,
+        "consumed {first_consumed} of {len} tokens\ninput: {input:?}"
+    );
+    if let Err(err) = &first_res {
+        assert!(
+            input.is_partial() || err.needed().is_none(),
+            "`Incomplete` returned on a complete stream: {err:?}\ninput: {input:?}"
+        );
+    }
+
+    let mut second = input.clone();
+    let second_res = parser.parse_next(&mut second);
+    let second_consumed = second.offset_from(&start);
+    assert_eq!(
+        (&first_res, first_consumed),
+        (&second_res, second_consumed),
+        "parsing the same input twice gave different results\ninput: {input:?}"
+    );
+
+    first_res.map(|o| (first, o))
+}
+
+/// Parse every prefix of `input` as [`Partial`], checking the result matches parsing all of
+/// `input` as complete
+///
+/// This simulates the input arriving in chunks, split at every possible point.  A prefix may
+/// report `Incomplete` but, otherwise, it must succeed or fail the same as the complete input,
+/// at the same position and with the same output.
+///
+/// This panics on a mismatch, or if the complete parse reports `Incomplete`.  The error type must
+/// be able to report `Incomplete`, like [`ErrMode`][crate::error::ErrMode].
+///
+/// Returns the result of parsing the complete input, like [`Parser::parse_peek`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::digit1;
+/// use winnow::error::{ContextError, ErrMode};
+/// use winnow::testing::check_partial;
+///
+/// assert_eq!(check_partial(digit1::<_, ErrMode<ContextError>>, "123abc"), Ok(("abc", "123")));
+/// assert_eq!(check_partial(digit1::<_, ErrMode<ContextError>>, "123"), Ok(("", "123")));
+/// ```
+#[track_caller]
+pub fn check_partial<I, O, E, P>(mut parser: P, input: I) -> Result<(I, O), E>
+where
+    P: Parser<Partial<I>, O, E>,
+    I: Stream<Slice = I> + StreamIsPartial + Clone + Debug,
+    O: Debug + PartialEq,
+    E: ParserError<Partial<I>> + Debug,
+{
+    let mut complete = Partial::new(input.clone());
+    let _ = complete.complete();
+    let start = complete.checkpoint();
+    let expected = parser.parse_next(&mut complete);
+    let expected_consumed = Offset::offset_from(&complete, &start);
+    if let Err(err) = &expected {
+        assert!(
+            err.needed().is_none(),
+            "`Incomplete` returned on a complete stream: {err:?}\ninput: {input:?}"
+        );
+    }
+
+    let offsets = input
+        .iter_offsets()
+        .map(|(offset, _)| offset)
+        .chain(core::iter::once(input.eof_offset()));
+    for offset in offsets {
+        let prefix = input.peek_slice(offset);
+        let mut partial = Partial::new(prefix.clone());
+        let start = partial.checkpoint();
+        let actual = parser.parse_next(&mut partial);
+        let actual_consumed = Offset::offset_from(&partial, &start);
+        let matches = match (&actual, &expected) {
+            (Err(err), _) if err.needed().is_some() => true,
+            (Ok(actual), Ok(expected)) => {
+                actual == expected && actual_consumed == expected_consumed
+            }
+            (Err(actual), Err(expected)) => {
+                actual.is_backtrack() == expected.is_backtrack()
+                    && actual_consumed == expected_consumed
+            }
+            _ => false,
+        };
+        assert!(
+            matches,
+            "parsing the first {offset} tokens as partial gave {actual:?} at {actual_consumed}, \
+             expected {expected:?} at {expected_consumed}\n\
+             input: {input:?}\nprefix: {prefix:?}"
+        );
+    }
+
+    expected.map(|o| (complete.into_inner(), o))
+}
+
+/// Parse every prefix of `input` as [`Partial`], checking that providing as much more input as
+/// was [`Needed`] is never more than the parser required
+///
+/// This is for parsers that know how much input they need, like [`literal`][crate::token::literal]
+/// or [`take`][crate::token::take], where a buffering reader can rely on reading exactly
+/// [`Needed::Size`] more bytes.  Parsers that return [`Needed::Unknown`] are not checked.
+///
+/// Each prefix that is `Incomplete` is extended by the needed amount of `input`, rounded up to the
+/// next token, like a buffering reader would, until it is no longer `Incomplete`.  A parser may
+/// need more than once, like [`length_take`][crate::binary::length_take] reading its length and
+/// then the data.  Prefixes that need more than what is left of `input` are skipped.  The error
+/// type must be able to report `Incomplete`, like [`ErrMode`][crate::error::ErrMode].
+///
+/// This panics if stopping a token short of what was needed is enough to succeed.
+///
+/// Returns the result of parsing the complete input, like [`Parser::parse_peek`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::crlf;
+/// use winnow::error::{ContextError, ErrMode};
+/// use winnow::testing::check_needed;
+///
+/// assert_eq!(check_needed(crlf::<_, ErrMode<ContextError>>, "\r\n"), Ok(("", "\r\n")));
+/// ```
+#[track_caller]
+pub fn check_needed<I, O, E, P>(mut parser: P, input: I) -> Result<(I, O), E>
+where
+    P: Parser<Partial<I>, O, E>,
+    I: Stream<Slice = I> + StreamIsPartial + Clone + Debug,
+    E: ParserError<Partial<I>> + Debug,
+{
+    let offsets = input
+        .iter_offsets()
+        .map(|(offset, _)| offset)
+        .chain(core::iter::once(input.eof_offset()))
+        .collect::<crate::lib::std::vec::Vec<_>>();
+    let needed_at = |parser: &mut P, end: usize| {
+        parser
+            .parse_peek(Partial::new(input.peek_slice(end)))
+            .err()
+            .and_then(|err| err.needed())
+    };
+    for &offset in &offsets {
+        let mut current = offset;
+        while let Some(Needed::Size(needed)) = needed_at(&mut parser, current) {
+            let needed = needed.get();
+            let end = match offsets.iter().position(|&end| current + needed <= end) {
+                Some(end) => end,
+                None => break,
+            };
+            let short = offsets[end - 1];
+            if current < short {
+                assert!(
+                    parser
+                        .parse_peek(Partial::new(input.peek_slice(short)))
+                        .is_err(),
+                    "parsing the first {current} tokens as partial needed {needed} more \
+                     but the first {short} tokens were enough\n\
+                     input: {input:?}\nprefix: {:?}\nshort: {:?}",
+                    input.peek_slice(offset),
+                    input.peek_slice(short),
+                );
+            }
+            current = offsets[end];
+        }
+    }
+
+    let mut complete = Partial::new(input);
+    let _ = complete.complete();
+    parser
+        .parse_next(&mut complete)
+        .map(|o| (complete.into_inner(), o))
+}
@@ -3,14 +3,16 @@
 #[cfg(test)]
 mod tests;
 
+use crate::combinator::impls::EmptyHint;
 use crate::combinator::trace;
 use crate::combinator::DisplayDebug;
 use crate::error::Needed;
 use crate::error::ParserError;
 use crate::lib::std::result::Result::Ok;
+use crate::stream::AsChar;
 use crate::stream::Range;
 use crate::stream::{Compare, CompareResult, ContainsToken, FindSlice, Stream};
-use crate::stream::{StreamIsPartial, ToUsize};
+use crate::stream::{SliceLen, StreamIsPartial, ToUsize};
 use crate::Parser;
 use crate::Result;
 
@@ -134,7 +136,7 @@ where
 /// assert_eq!(parser.parse_peek(Partial::new("Hello, World!")), Ok((Partial::new(", World!"), "Hello")));
 /// assert!(parser.parse_peek(Partial::new("Something")).is_err());
 /// assert!(parser.parse_peek(Partial::new("S")).is_err());
-/// assert_eq!(parser.parse_peek(Partial::new("H")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// assert_eq!(parser.parse_peek(Partial::new("H")), Err(ErrMode::Incomplete(Needed::new(4))));
 /// ```
 ///
 /// ```rust
@@ -168,9 +170,33 @@ where
     trace(DisplayDebug(literal.clone()), move |i: &mut Input| {
         let t = literal.clone();
         if <Input as StreamIsPartial>::is_partial_supported() {
-            literal_::<_, _, _, true>(i, t)
+            literal_::<_, _, _, true>(i, t, None)
         } else {
-            literal_::<_, _, _, false>(i, t)
+            literal_::<_, _, _, false>(i, t, None)
+        }
+    })
+}
+
+/// [`literal`] for literals of a known length, reporting how much of the literal is still
+/// [`Needed`] on a partial stream
+///
+/// Used by the [`Parser`] impls for `u8`, `char`, `&str`, etc.
+#[inline(always)]
+pub(crate) fn sized_literal<Literal, Input, Error>(
+    literal: Literal,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<Literal>,
+    Literal: SliceLen + Clone + crate::lib::std::fmt::Debug,
+    Error: ParserError<Input>,
+{
+    trace(DisplayDebug(literal.clone()), move |i: &mut Input| {
+        let t = literal.clone();
+        let len = t.slice_len();
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            literal_::<_, _, _, true>(i, t, Some(len))
+        } else {
+            literal_::<_, _, _, false>(i, t, Some(len))
         }
     })
 }
@@ -178,6 +204,7 @@ where
 fn literal_<T, I, Error: ParserError<I>, const PARTIAL: bool>(
     i: &mut I,
     t: T,
+    t_len: Option<usize>,
 ) -> Result<<I as Stream>::Slice, Error>
 where
     I: StreamIsPartial,
@@ -187,7 +214,12 @@ where
     match i.compare(t) {
         CompareResult::Ok(len) => Ok(i.next_slice(len)),
         CompareResult::Incomplete if PARTIAL && i.is_partial() => {
-            Err(ParserError::incomplete(i, Needed::Unknown))
+            // `Incomplete` means the input is a prefix of the literal
+            let needed = match t_len {
+                Some(t_len) => Needed::new(t_len.saturating_sub(i.eof_offset())),
+                None => Needed::Unknown,
+            };
+            Err(ParserError::incomplete(i, needed))
         }
         CompareResult::Incomplete | CompareResult::Error => Err(ParserError::from_input(i)),
     }
@@ -201,9 +233,13 @@ where
 /// only) for
 /// - `u8`
 /// - `char`
+/// - arrays of `u8` or `char`
 ///
 /// </div>
 ///
+/// When the set is a list of literal tokens, like `'a'` or `['a', 'b']`, they are recorded as
+/// expected through [`ParserError::add_expected`] on failure.
+///
 /// *Complete version*: Will return an error if there's not enough input data.
 ///
 /// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
@@ -266,10 +302,11 @@ where
     Set: ContainsToken<<Input as Stream>::Token>,
     Error: ParserError<Input>,
 {
-    trace(
-        "one_of",
-        any.verify(move |t: &<Input as Stream>::Token| set.contains_token(t.clone())),
-    )
+    trace("one_of", move |input: &mut Input| {
+        any.verify(|t: &<Input as Stream>::Token| set.contains_token(t.clone()))
+            .parse_next(input)
+            .map_err(|e: Error| set.fold_expected(e, |e, expected| e.add_expected(input, expected)))
+    })
 }
 
 /// Recognize a token that does not match a [set of tokens][ContainsToken]
@@ -488,7 +525,7 @@ where
         start_inclusive,
         end_inclusive,
     } = occurrences.into();
-    trace("take_while", move |i: &mut Input| {
+    let traced = trace("take_while", move |i: &mut Input| {
         match (start_inclusive, end_inclusive) {
             (0, None) => {
                 if <Input as StreamIsPartial>::is_partial_supported() {
@@ -513,7 +550,8 @@ where
                 }
             }
         }
-    })
+    });
+    EmptyHint::new(traced, start_inclusive == 0)
 }
 
 fn take_till0<P, I: StreamIsPartial + Stream, E: ParserError<I>, const PARTIAL: bool>(
@@ -591,11 +629,7 @@ where
         if final_count == n {
             Ok(input.finish())
         } else {
-            let needed = if m > input.eof_offset() {
-                m - input.eof_offset()
-            } else {
-                1
-            };
+            let needed = if m > final_count { m - final_count } else { 1 };
             Err(ParserError::incomplete(input, Needed::new(needed)))
         }
     } else {
@@ -679,7 +713,7 @@ where
         start_inclusive,
         end_inclusive,
     } = occurrences.into();
-    trace("take_till", move |i: &mut Input| {
+    let traced = trace("take_till", move |i: &mut Input| {
         match (start_inclusive, end_inclusive) {
             (0, None) => {
                 if <Input as StreamIsPartial>::is_partial_supported() {
@@ -704,9 +738,145 @@ where
                 }
             }
         }
+    });
+    EmptyHint::new(traced, start_inclusive == 0)
+}
+
+/// Recognize the longest input slice (if any) whose tokens are in the [set of tokens][ContainsToken],
+/// with `occurrences` counted in bytes, rather than tokens.
+///
+/// For `&[u8]`, this is the same as [`take_while`]. For `&str`, the length of the slice is
+/// measured in UTF-8 bytes while the set is still checked against each `char`.  A `char` that
+/// would extend the slice past the maximum length is not taken, so the slice never ends in the
+/// middle of a `char`.
+///
+/// It will return an `ErrMode::Backtrack(_)` if the set of tokens wasn't met or is out
+/// of range (m <= len <= n), including when the only way to reach `m` bytes would be to split a
+/// `char`.
+///
+/// *[Partial version][crate::_topic::partial]* will return a `ErrMode::Incomplete(Needed::new(M))`
+/// if a member of the set of tokens reaches the end of the input, where M is the number of bytes
+/// still missing to reach `m`, or `1` once `m` bytes have been taken.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] with `0..` or `1..` [ranges][Range]:
+/// ```rust
+/// # use std::ops::RangeFrom;
+/// # use winnow::prelude::*;
+/// # use winnow::stream::ContainsToken;
+/// # use winnow::error::ContextError;
+/// pub fn take_bytes_while<'i>(occurrences: RangeFrom<usize>, set: impl ContainsToken<char>) -> impl Parser<&'i str, &'i str, ContextError>
+/// # {
+/// #     winnow::token::take_bytes_while(occurrences, set)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::token::take_bytes_while;
+///
+/// fn field<'i>(s: &mut &'i str) -> ModalResult<&'i str> {
+///   take_bytes_while(4, |c: char| c != ' ').parse_next(s)
+/// }
+///
+/// assert_eq!(field.parse_peek("abcdef"), Ok(("ef", "abcd")));
+/// assert_eq!(field.parse_peek("éèfg"), Ok(("fg", "éè")));
+/// // `é` would end at byte 5
+/// assert!(field.parse_peek("abcé").is_err());
+/// assert!(field.parse_peek("ab cd").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::token::take_bytes_while;
+///
+/// fn field<'i>(s: &mut Partial<&'i str>) -> ModalResult<&'i str> {
+///   take_bytes_while(4, |c: char| c != ' ').parse_next(s)
+/// }
+///
+/// assert_eq!(field.parse_peek(Partial::new("éèfg")), Ok((Partial::new("fg"), "éè")));
+/// assert_eq!(field.parse_peek(Partial::new("éè")), Ok((Partial::new(""), "éè")));
+/// assert_eq!(field.parse_peek(Partial::new("é")), Err(ErrMode::Incomplete(Needed::new(2))));
+/// assert!(field.parse_peek(Partial::new("abcé")).is_err());
+/// ```
+#[inline(always)]
+pub fn take_bytes_while<Set, Input, Error>(
+    occurrences: impl Into<Range>,
+    set: Set,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar + Clone,
+    Set: ContainsToken<<Input as Stream>::Token>,
+    Error: ParserError<Input>,
+{
+    let Range {
+        start_inclusive,
+        end_inclusive,
+    } = occurrences.into();
+    trace("take_bytes_while", move |i: &mut Input| {
+        let end = end_inclusive.unwrap_or(usize::MAX);
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            take_bytes_while_m_n::<_, _, _, true>(i, start_inclusive, end, |c| {
+                set.contains_token(c)
+            })
+        } else {
+            take_bytes_while_m_n::<_, _, _, false>(i, start_inclusive, end, |c| {
+                set.contains_token(c)
+            })
+        }
     })
 }
 
+fn take_bytes_while_m_n<P, I, Error: ParserError<I>, const PARTIAL: bool>(
+    input: &mut I,
+    m: usize,
+    n: usize,
+    predicate: P,
+) -> Result<<I as Stream>::Slice, Error>
+where
+    I: StreamIsPartial,
+    I: Stream,
+    I::Token: AsChar + Clone,
+    P: Fn(I::Token) -> bool,
+{
+    if n < m {
+        return Err(ParserError::assert(
+            input,
+            "`occurrences` should be ascending, rather than descending",
+        ));
+    }
+
+    let mut end = None;
+    for (offset, token) in input.iter_offsets() {
+        if !predicate(token.clone()) || n - offset < token.len() {
+            end = Some(offset);
+            break;
+        }
+    }
+    let offset = match end {
+        Some(offset) => offset,
+        None => {
+            let offset = input.eof_offset();
+            if PARTIAL && input.is_partial() && offset < n {
+                let needed = if m > offset { m - offset } else { 1 };
+                return Err(ParserError::incomplete(input, Needed::new(needed)));
+            }
+            offset
+        }
+    };
+    if offset < m {
+        Err(ParserError::from_input(input))
+    } else {
+        Ok(input.next_slice(offset))
+    }
+}
+
 /// Recognize an input slice containing the first N input elements (I[..N]).
 ///
 /// *Complete version*: It will return `Err(ErrMode::Backtrack(_))` if the input is shorter than the argument.
@@ -774,8 +944,8 @@ where
 ///
 /// assert_eq!(take6.parse_peek(Partial::new("1234567")), Ok((Partial::new("7"), "123456")));
 /// assert_eq!(take6.parse_peek(Partial::new("things")), Ok((Partial::new(""), "things")));
-/// // `Unknown` as we don't know the number of bytes that `count` corresponds to
-/// assert_eq!(take6.parse_peek(Partial::new("short")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// // At least one byte, as we don't know the number of bytes that `count` corresponds to
+/// assert_eq!(take6.parse_peek(Partial::new("short")), Err(ErrMode::Incomplete(Needed::new(1))));
 /// ```
 #[inline(always)]
 pub fn take<UsizeLike, Input, Error>(
@@ -1021,6 +1191,368 @@ where
     }
 }
 
+/// Recognize the input slice up to the first occurrence of a [literal] that isn't escaped.
+///
+/// A token in the `escape` [set][ContainsToken] escapes the token after it, so `\"` doesn't end
+/// a `"`-terminated string while `\\"` does.  Escapes are only skipped, not validated; to also
+/// decode them, see [`escaped`][crate::ascii::escaped] or [`take_escaped`][crate::ascii::take_escaped].
+///
+/// Feature `simd` will enable the use of [`memchr`](https://docs.rs/memchr/latest/memchr/) for
+/// finding candidates.
+///
+/// It doesn't consume the literal.
+///
+/// *Complete version*: It will return `Err(ErrMode::Backtrack(_))`
+/// if no unescaped literal was met.
+///
+/// *[Partial version][crate::_topic::partial]*: will return a `ErrMode::Incomplete(Needed::Unknown)`
+/// if the input doesn't contain an unescaped literal, including when it only contains escaped ones.
+///
+/// See also
+/// - [`take_until`] when the literal can't be escaped
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] with `0..` or `1..` [ranges][Range]:
+/// ```rust
+/// # use std::ops::RangeFrom;
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// pub fn take_until_unescaped(occurrences: RangeFrom<usize>, escape: char, literal: &str) -> impl Parser<&str, &str, ContextError>
+/// # {
+/// #     winnow::token::take_until_unescaped(occurrences, escape, literal)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::combinator::delimited;
+/// use winnow::token::take_until_unescaped;
+///
+/// fn string<'i>(s: &mut &'i str) -> ModalResult<&'i str> {
+///   delimited('"', take_until_unescaped(0.., '\\', "\""), '"').parse_next(s)
+/// }
+///
+/// assert_eq!(string.parse_peek(r#""say \"hi\"" rest"#), Ok((" rest", r#"say \"hi\""#)));
+/// assert_eq!(string.parse_peek(r#""C:\\" rest"#), Ok((" rest", r#"C:\\"#)));
+/// assert_eq!(string.parse_peek(r#""""#), Ok(("", "")));
+/// assert!(string.parse_peek(r#""unterminated \""#).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::token::take_until_unescaped;
+///
+/// fn contents<'i>(s: &mut Partial<&'i str>) -> ModalResult<&'i str> {
+///   take_until_unescaped(1.., '\\', "\"").parse_next(s)
+/// }
+///
+/// assert_eq!(contents.parse_peek(Partial::new(r#"a\"b" rest"#)), Ok((Partial::new(r#"" rest"#), r#"a\"b"#)));
+/// assert_eq!(contents.parse_peek(Partial::new(r#"a\"b"#)), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// assert_eq!(contents.parse_peek(Partial::new(r#"a\"#)), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// assert!(contents.parse_peek(Partial::new(r#"" rest"#)).is_err());
+/// ```
+#[inline(always)]
+pub fn take_until_unescaped<Escape, Literal, Input, Error>(
+    occurrences: impl Into<Range>,
+    escape: Escape,
+    literal: Literal,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream + FindSlice<Literal>,
+    Escape: ContainsToken<<Input as Stream>::Token>,
+    Literal: Clone,
+    Error: ParserError<Input>,
+{
+    let Range {
+        start_inclusive,
+        end_inclusive,
+    } = occurrences.into();
+    trace("take_until_unescaped", move |i: &mut Input| {
+        let end = end_inclusive.unwrap_or(usize::MAX);
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            take_until_unescaped_::<_, _, _, _, true>(i, start_inclusive, end, &escape, &literal)
+        } else {
+            take_until_unescaped_::<_, _, _, _, false>(i, start_inclusive, end, &escape, &literal)
+        }
+    })
+}
+
+fn take_until_unescaped_<E, T, I, Error: ParserError<I>, const PARTIAL: bool>(
+    i: &mut I,
+    start: usize,
+    end: usize,
+    escape: &E,
+    t: &T,
+) -> Result<<I as Stream>::Slice, Error>
+where
+    I: StreamIsPartial,
+    I: Stream + FindSlice<T>,
+    E: ContainsToken<<I as Stream>::Token>,
+    T: Clone,
+{
+    if end < start {
+        return Err(ParserError::assert(
+            i,
+            "`occurrences` should be ascending, rather than descending",
+        ));
+    }
+
+    let checkpoint = i.checkpoint();
+    let mut escaped = false;
+    let found = loop {
+        let Some(range) = i.find_slice(t.clone()) else {
+            i.reset(&checkpoint);
+            if PARTIAL && i.is_partial() {
+                return Err(ParserError::incomplete(i, Needed::Unknown));
+            } else {
+                return Err(ParserError::from_input(i));
+            }
+        };
+        for (_, token) in i
+            .iter_offsets()
+            .take_while(|(offset, _)| *offset < range.start)
+        {
+            escaped = !escaped && escape.contains_token(token);
+        }
+        let _ = i.next_slice(range.start);
+        if !escaped {
+            break i.offset_from(&checkpoint);
+        }
+        // Skip the escaped token and look for the next candidate after it
+        let _ = i.next_token();
+        escaped = false;
+    };
+    i.reset(&checkpoint);
+
+    let start_offset = i.offset_at(start);
+    let end_offset = i.offset_at(end).unwrap_or_else(|_err| i.eof_offset());
+    if start_offset.map(|s| found < s).unwrap_or(true) || end_offset < found {
+        return Err(ParserError::from_input(i));
+    }
+    Ok(i.next_slice(found))
+}
+
+/// Recognize the input slice up to the earliest of several needles, returning it along with the
+/// needle that was found.
+///
+/// Unlike [`take_until`], this also consumes the needle.  When several needles match at the same
+/// offset, the first one listed wins.  See [`FindSlice`] for the supported needles and for
+/// implementing it for your own [`Stream`].
+///
+/// *Complete version*: It will return `Err(ErrMode::Backtrack(_))`
+/// if none of the needles were met.
+///
+/// *[Partial version][crate::_topic::partial]*: will return a `ErrMode::Incomplete(Needed::Unknown)`
+/// if the input doesn't contain any of the needles.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] with three needles:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// pub fn take_until_first<'i>(needles: (&'static str, &'static str, &'static str)) -> impl Parser<&'i str, (&'i str, &'i str), ContextError>
+/// # {
+/// #     winnow::token::take_until_first(needles)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::token::take_until_first;
+///
+/// fn line<'i>(s: &mut &'i str) -> ModalResult<(&'i str, &'i str)> {
+///   take_until_first(("\r\n", "\n")).parse_next(s)
+/// }
+///
+/// assert_eq!(line.parse_peek("hello\r\nworld"), Ok(("world", ("hello", "\r\n"))));
+/// assert_eq!(line.parse_peek("hello\nworld\r\n"), Ok(("world\r\n", ("hello", "\n"))));
+/// assert!(line.parse_peek("hello").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::token::take_until_first;
+///
+/// fn line<'i>(s: &mut Partial<&'i str>) -> ModalResult<(&'i str, &'i str)> {
+///   take_until_first(("\r\n", "\n")).parse_next(s)
+/// }
+///
+/// assert_eq!(line.parse_peek(Partial::new("hello\nworld")), Ok((Partial::new("world"), ("hello", "\n"))));
+/// assert_eq!(line.parse_peek(Partial::new("hello")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// ```
+#[inline(always)]
+pub fn take_until_first<Needles, Input, Error>(
+    needles: Needles,
+) -> impl Parser<Input, (<Input as Stream>::Slice, <Input as Stream>::Slice), Error>
+where
+    Input: StreamIsPartial + Stream + FindSlice<Needles>,
+    Needles: Clone,
+    Error: ParserError<Input>,
+{
+    trace("take_until_first", move |i: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            take_until_first_::<_, _, _, true>(i, needles.clone())
+        } else {
+            take_until_first_::<_, _, _, false>(i, needles.clone())
+        }
+    })
+}
+
+fn take_until_first_<T, I, Error: ParserError<I>, const PARTIAL: bool>(
+    i: &mut I,
+    t: T,
+) -> Result<(<I as Stream>::Slice, <I as Stream>::Slice), Error>
+where
+    I: StreamIsPartial,
+    I: Stream + FindSlice<T>,
+{
+    match i.find_slice(t) {
+        Some(range) => {
+            let prefix = i.next_slice(range.start);
+            let needle = i.next_slice(range.end - range.start);
+            Ok((prefix, needle))
+        }
+        None if PARTIAL && i.is_partial() => Err(ParserError::incomplete(i, Needed::Unknown)),
+        None => Err(ParserError::from_input(i)),
+    }
+}
+
+/// Recognize a [`literal`] prefix followed by tokens up to and including a member of a
+/// [set of terminators][ContainsToken], returning the whole matched slice.
+///
+/// This covers framing like ANSI escape sequences (`"\x1b["` up to a final byte in `0x40..=0x7E`)
+/// or comment-like constructs.  At most `max_len` tokens are allowed between the prefix and the
+/// terminator, bounding how much input a missing terminator can consume.
+///
+/// On failure, the terminators are recorded as expected through [`ParserError::add_expected`].
+///
+/// *Complete version*: Will return an error if the prefix doesn't match, if the input ends before a
+/// terminator is found, or if there are more than `max_len` tokens before the terminator.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))`
+/// if the input ends before a terminator is found and `max_len` hasn't been exceeded.
+///
+/// See also
+/// - [`take_till`] for only the interior
+/// - [`delimited`][crate::combinator::delimited] for arbitrary parsers as the prefix, interior, and terminator
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&[u8]` [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::stream::ContainsToken;
+/// # use winnow::error::ContextError;
+/// pub fn prefixed_until<'i>(prefix: &'static [u8], terminators: impl ContainsToken<u8>, max_len: usize) -> impl Parser<&'i [u8], &'i [u8], ContextError>
+/// # {
+/// #     winnow::token::prefixed_until(prefix, terminators, max_len)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::token::prefixed_until;
+///
+/// fn csi<'i>(s: &mut &'i [u8]) -> ModalResult<&'i [u8]> {
+///   prefixed_until(b"\x1b[", 0x40..=0x7E, 16).parse_next(s)
+/// }
+///
+/// assert_eq!(csi.parse_peek(b"\x1b[1;31mred"), Ok((&b"red"[..], &b"\x1b[1;31m"[..])));
+/// assert_eq!(csi.parse_peek(b"\x1b[Kline"), Ok((&b"line"[..], &b"\x1b[K"[..])));
+/// assert!(csi.parse_peek(b"plain").is_err());
+/// assert!(csi.parse_peek(b"\x1b[1;31").is_err());
+/// assert!(csi.parse_peek(b"\x1b[00000000000000000m").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::token::prefixed_until;
+///
+/// fn csi<'i>(s: &mut Partial<&'i [u8]>) -> ModalResult<&'i [u8]> {
+///   prefixed_until(b"\x1b[", 0x40..=0x7E, 16).parse_next(s)
+/// }
+///
+/// assert_eq!(csi.parse_peek(Partial::new(b"\x1b[1mbold")), Ok((Partial::new(&b"bold"[..]), &b"\x1b[1m"[..])));
+/// assert_eq!(csi.parse_peek(Partial::new(b"\x1b[1;3")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// assert_eq!(csi.parse_peek(Partial::new(b"\x1b")), Err(ErrMode::Incomplete(Needed::Unknown)));
+/// ```
+#[inline(always)]
+pub fn prefixed_until<Literal, Set, Input, Error>(
+    prefix: Literal,
+    terminators: Set,
+    max_len: usize,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<Literal>,
+    Literal: Clone + crate::lib::std::fmt::Debug,
+    Set: ContainsToken<<Input as Stream>::Token>,
+    Error: ParserError<Input>,
+{
+    trace("prefixed_until", move |i: &mut Input| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            prefixed_until_::<_, _, _, _, true>(i, prefix.clone(), &terminators, max_len)
+        } else {
+            prefixed_until_::<_, _, _, _, false>(i, prefix.clone(), &terminators, max_len)
+        }
+    })
+}
+
+fn prefixed_until_<T, S, I, Error: ParserError<I>, const PARTIAL: bool>(
+    i: &mut I,
+    prefix: T,
+    terminators: &S,
+    max_len: usize,
+) -> Result<<I as Stream>::Slice, Error>
+where
+    I: StreamIsPartial,
+    I: Stream + Compare<T>,
+    T: crate::lib::std::fmt::Debug,
+    S: ContainsToken<<I as Stream>::Token>,
+{
+    let start = i.checkpoint();
+    literal_::<_, _, Error, PARTIAL>(i, prefix, None)?;
+
+    let mut terminator = None;
+    for (processed, (offset, token)) in i.iter_offsets().enumerate() {
+        if terminators.contains_token(token) {
+            terminator = Some(offset);
+            break;
+        } else if processed == max_len {
+            let _ = i.next_slice(offset);
+            let e = ParserError::from_input(i);
+            return Err(terminators.fold_expected(e, |e, expected| e.add_expected(i, expected)));
+        }
+    }
+    let Some(offset) = terminator else {
+        if PARTIAL && i.is_partial() {
+            i.reset(&start);
+            return Err(ParserError::incomplete(i, Needed::new(1)));
+        }
+        let _ = i.finish();
+        let e = ParserError::from_input(i);
+        return Err(terminators.fold_expected(e, |e, expected| e.add_expected(i, expected)));
+    };
+    let _ = i.next_slice(offset);
+    let _ = i.next_token();
+    let len = i.offset_from(&start);
+    i.reset(&start);
+    Ok(i.next_slice(len))
+}
+
 /// Return the remaining input.
 ///
 /// # Effective Signature
@@ -201,6 +201,436 @@ Ok(
     );
 }
 
+#[test]
+fn complete_take_until_unescaped() {
+    fn contents<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+        take_until_unescaped(0.., '\\', "\"").parse_next(i)
+    }
+    fn comment<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i str> {
+        take_until_unescaped(1..=8, b'\\', "*/")
+            .try_map(core::str::from_utf8)
+            .parse_next(i)
+    }
+    assert_parse!(
+        contents.parse_peek(r#"a\"b"c"#),
+        str![[r#"
+Ok(
+    (
+        "\"c",
+        "a\\\"b",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    // A doubled escape escapes itself, so the literal after it is a terminator
+    assert_parse!(
+        contents.parse_peek(r#"a\\"b"c"#),
+        str![[r#"
+Ok(
+    (
+        "\"b\"c",
+        "a\\\\",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        contents.parse_peek(r#"a\\\"b"c"#),
+        str![[r#"
+Ok(
+    (
+        "\"c",
+        "a\\\\\\\"b",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        contents.parse_peek(r#""b"#),
+        str![[r#"
+Ok(
+    (
+        "\"b",
+        "",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        contents.parse_peek(r#"a\""#),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "a\\\"",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        contents.parse_peek(r#"a\"#),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "a\\",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        contents.parse_peek(""),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        comment.parse_peek(b"a\\*/ */"),
+        str![[r#"
+Ok(
+    (
+        [
+            42,
+            47,
+        ],
+        "a\\*/ ",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        comment.parse_peek(b"\\\\*/ */"),
+        str![[r#"
+Ok(
+    (
+        [
+            42,
+            47,
+            32,
+            42,
+            47,
+        ],
+        "\\\\",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        comment.parse_peek(b"*/"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                42,
+                47,
+            ],
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        comment.parse_peek(b"0123456789*/"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                48,
+                49,
+                50,
+                51,
+                52,
+                53,
+                54,
+                55,
+                56,
+                57,
+                42,
+                47,
+            ],
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn complete_take_until_first() {
+    fn terminator<'i>(i: &mut &'i str) -> TestResult<&'i str, (&'i str, &'i str)> {
+        take_until_first(("bc", "abc", ";")).parse_next(i)
+    }
+    assert_parse!(
+        terminator.parse_peek("xabc;"),
+        str![[r#"
+Ok(
+    (
+        ";",
+        (
+            "x",
+            "abc",
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        terminator.parse_peek("x;abc"),
+        str![[r#"
+Ok(
+    (
+        "abc",
+        (
+            "x",
+            ";",
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        terminator.parse_peek("xbc"),
+        str![[r#"
+Ok(
+    (
+        "",
+        (
+            "x",
+            "bc",
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        terminator.parse_peek("xyz"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "xyz",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn complete_prefixed_until() {
+    fn osc<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+        prefixed_until("\x1b]", ['\x07', '\x1b'], 8).parse_next(i)
+    }
+    fn csi<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+        prefixed_until(b"\x1b[", 0x40..=0x7E, 3).parse_next(i)
+    }
+    assert_parse!(
+        osc.parse_peek("\x1b]0;title\x07rest"),
+        str![[r#"
+Ok(
+    (
+        "rest",
+        "\u{1b}]0;title\u{7}",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    // A nested sequence ends the outer one at its `ESC`
+    assert_parse!(
+        osc.parse_peek("\x1b]0;a\x1b[1m\x07"),
+        str![[r#"
+Ok(
+    (
+        "[1m\u{7}",
+        "\u{1b}]0;a\u{1b}",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        csi.parse_peek(b"\x1b[?25hrest"),
+        str![[r#"
+Ok(
+    (
+        [
+            114,
+            101,
+            115,
+            116,
+        ],
+        [
+            27,
+            91,
+            63,
+            50,
+            53,
+            104,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        csi.parse_peek(b"\x1b[m"),
+        str![[r#"
+Ok(
+    (
+        [],
+        [
+            27,
+            91,
+            109,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    // Exactly `max_len` tokens between the prefix and the terminator
+    assert_parse!(
+        csi.parse_peek(b"\x1b[1;2H"),
+        str![[r#"
+Ok(
+    (
+        [],
+        [
+            27,
+            91,
+            49,
+            59,
+            50,
+            72,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        csi.parse_peek(b"\x1b[1;23H"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                51,
+                72,
+            ],
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        csi.parse_peek(b"\x1b[1;"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [],
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        csi.parse_peek(b"\x1b(B"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                27,
+                40,
+                66,
+            ],
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn complete_prefixed_until_expected() {
+    fn comment<'i>(i: &mut &'i str) -> ModalResult<&'i str> {
+        prefixed_until("<!", '>', 8).parse_next(i)
+    }
+    snapbox::assert_data_eq!(
+        comment.parse("<!doctype html>").unwrap_err().to_string(),
+        str![[r#"
+<!doctype html>
+          ^
+expected `>`
+"#]]
+        .raw()
+    );
+    snapbox::assert_data_eq!(
+        comment.parse("<!doc").unwrap_err().to_string(),
+        str![[r#"
+<!doc
+     ^
+expected `>`
+"#]]
+        .raw()
+    );
+}
+
 #[test]
 fn complete_literal_case_insensitive() {
     fn caseless_bytes<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
@@ -757,6 +1187,61 @@ Ok(
     );
 }
 
+#[test]
+fn char_array_str() {
+    fn f<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, char> {
+        ['t', 'f'].parse_next(i)
+    }
+
+    assert_parse!(
+        f.parse_peek(Partial::new("none")),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: "none",
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        f.parse_peek(Partial::new("false")),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: "alse",
+            partial: true,
+        },
+        'f',
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        f.parse_peek(Partial::new("")),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
 #[test]
 fn partial_none_of_test() {
     fn f<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, u8> {
@@ -809,6 +1294,55 @@ Ok(
     );
 }
 
+#[test]
+fn caseless_set_test() {
+    fn bytes<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], u8> {
+        one_of(Caseless(['x', 'q'])).parse_next(i)
+    }
+    assert_eq!(bytes.parse_peek(b"Xy"), Ok((&b"y"[..], b'X')));
+    assert_eq!(bytes.parse_peek(b"qy"), Ok((&b"y"[..], b'q')));
+    assert!(bytes.parse_peek(b"y").is_err());
+
+    fn hex<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+        (
+            literal(Caseless("0x")),
+            take_while(1.., Caseless((b'a'..=b'f', b'0'..=b'9'))),
+        )
+            .map(|(_, digits)| digits)
+            .parse_next(i)
+    }
+    assert_eq!(
+        hex.parse_peek(b"0XdeadBEEFg"),
+        Ok((&b"g"[..], &b"deadBEEF"[..]))
+    );
+
+    // Non-ASCII bytes are compared verbatim
+    fn latin1<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], u8> {
+        one_of(Caseless([0xE9_u8])).parse_next(i)
+    }
+    assert_eq!(latin1.parse_peek(b"\xE9"), Ok((&b""[..], 0xE9)));
+    assert!(latin1.parse_peek(b"\xC9").is_err());
+
+    fn chars<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+        take_while(1.., Caseless(('i', 'v', 'x'))).parse_next(i)
+    }
+    assert_eq!(chars.parse_peek("XiV."), Ok((".", "XiV")));
+    assert!(chars.parse_peek("L").is_err());
+
+    fn not_a<'i>(i: &mut &'i str) -> TestResult<&'i str, char> {
+        none_of(Caseless('a')).parse_next(i)
+    }
+    assert_eq!(not_a.parse_peek("b"), Ok(("", 'b')));
+    assert!(not_a.parse_peek("A").is_err());
+
+    // Unicode case folding is not supported
+    fn unicode<'i>(i: &mut &'i str) -> TestResult<&'i str, char> {
+        one_of(Caseless(['é'])).parse_next(i)
+    }
+    assert_eq!(unicode.parse_peek("é"), Ok(("", 'é')));
+    assert!(unicode.parse_peek("É").is_err());
+}
+
 #[test]
 fn partial_is_a() {
     fn a_or_b<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
@@ -1035,6 +1569,237 @@ Err(
     );
 }
 
+#[test]
+fn partial_take_until_unescaped() {
+    fn contents<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, &'i str> {
+        take_until_unescaped(0.., '\\', "\"").parse_next(i)
+    }
+    assert_parse!(
+        contents.parse_peek(Partial::new(r#"a\"b"c"#)),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: "\"c",
+            partial: true,
+        },
+        "a\\\"b",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        contents.parse_peek(Partial::new(r#"a\\"b"#)),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: "\"b",
+            partial: true,
+        },
+        "a\\\\",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        contents.parse_peek(Partial::new(r#""b"#)),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: "\"b",
+            partial: true,
+        },
+        "",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    // Only escaped occurrences so far
+    assert_parse!(
+        contents.parse_peek(Partial::new(r#"a\"b\""#)),
+        str![[r#"
+Err(
+    Incomplete(
+        Unknown,
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    // The escape is the last token, so the next one will be escaped
+    assert_parse!(
+        contents.parse_peek(Partial::new(r#"a\"#)),
+        str![[r#"
+Err(
+    Incomplete(
+        Unknown,
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        contents.parse_peek(Partial::new("")),
+        str![[r#"
+Err(
+    Incomplete(
+        Unknown,
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn partial_take_until_first() {
+    fn terminator<'i>(
+        i: &mut Partial<&'i str>,
+    ) -> TestResult<Partial<&'i str>, (&'i str, &'i str)> {
+        take_until_first(("\r\n", "\n")).parse_next(i)
+    }
+    assert_parse!(
+        terminator.parse_peek(Partial::new("a\r\nb\n")),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: "b\n",
+            partial: true,
+        },
+        (
+            "a",
+            "\r\n",
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        terminator.parse_peek(Partial::new("a\nb\r\n")),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: "b\r\n",
+            partial: true,
+        },
+        (
+            "a",
+            "\n",
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        terminator.parse_peek(Partial::new("a\r")),
+        str![[r#"
+Err(
+    Incomplete(
+        Unknown,
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn partial_prefixed_until() {
+    fn csi<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        prefixed_until(b"\x1b[", 0x40..=0x7E, 3).parse_next(i)
+    }
+    assert_parse!(
+        csi.parse_peek(Partial::new(b"\x1b[1mbold")),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                98,
+                111,
+                108,
+                100,
+            ],
+            partial: true,
+        },
+        [
+            27,
+            91,
+            49,
+            109,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        csi.parse_peek(Partial::new(b"\x1b")),
+        str![[r#"
+Err(
+    Incomplete(
+        Unknown,
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        csi.parse_peek(Partial::new(b"\x1b[1;")),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    // Running past `max_len` fails without waiting for more input
+    assert_parse!(
+        csi.parse_peek(Partial::new(b"\x1b[1;23")),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: [
+                    51,
+                ],
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
 #[test]
 fn partial_take_until_incomplete() {
     fn y<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
@@ -1814,6 +2579,108 @@ Err(
     );
 }
 
+#[test]
+fn complete_take_while_not_utf8_range() {
+    fn f<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+        take_while(1.., crate::stream::not(('α'..='ω', ' '))).parse_next(i)
+    }
+
+    assert_parse!(
+        f.parse_peek("ΑΒΓ αβγ"),
+        str![[r#"
+Ok(
+    (
+        " αβγ",
+        "ΑΒΓ",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        f.parse_peek("αβγ"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "αβγ",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn complete_take_while_byte_range() {
+    fn f<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+        take_while(1.., (b'0'..=b'9', b'a'..=b'f')).parse_next(i)
+    }
+    fn g<'i>(i: &mut &'i [u8]) -> TestResult<&'i [u8], &'i [u8]> {
+        take_while(1.., crate::stream::not(b'0'..=b'9')).parse_next(i)
+    }
+
+    assert_parse!(
+        f.parse_peek(&b"09af\xff"[..]),
+        str![[r#"
+Ok(
+    (
+        [
+            255,
+        ],
+        [
+            48,
+            57,
+            97,
+            102,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        g.parse_peek(&b"\xffz09"[..]),
+        str![[r#"
+Ok(
+    (
+        [
+            48,
+            57,
+        ],
+        [
+            255,
+            122,
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        g.parse_peek(&b"09"[..]),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: [
+                48,
+                57,
+            ],
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
 #[test]
 fn partial_take_while_utf8() {
     fn f<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, &'i str> {
@@ -2063,7 +2930,9 @@ fn partial_take_utf8() {
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            3,
+        ),
     ),
 )
 
@@ -2075,7 +2944,9 @@ Err(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            1,
+        ),
     ),
 )
 
@@ -2087,7 +2958,9 @@ Err(
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            2,
+        ),
     ),
 )
 
@@ -2319,6 +3192,182 @@ Ok(
     );
 }
 
+#[test]
+fn complete_take_bytes_while_utf8() {
+    fn parser<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+        take_bytes_while(2, |c: char| c.is_alphabetic()).parse_next(i)
+    }
+    assert_parse!(
+        parser.parse_peek("abc"),
+        str![[r#"
+Ok(
+    (
+        "c",
+        "ab",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        parser.parse_peek("øn"),
+        str![[r#"
+Ok(
+    (
+        "n",
+        "ø",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        parser.parse_peek("aø"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "aø",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        parser.parse_peek("a"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "a",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn complete_take_bytes_while_utf8_range() {
+    fn parser<'i>(i: &mut &'i str) -> TestResult<&'i str, &'i str> {
+        take_bytes_while(1..=5, |c: char| c != '!').parse_next(i)
+    }
+    assert_parse!(
+        parser.parse_peek("😃😃"),
+        str![[r#"
+Ok(
+    (
+        "😃",
+        "😃",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        parser.parse_peek("a😃!"),
+        str![[r#"
+Ok(
+    (
+        "!",
+        "a😃",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        parser.parse_peek("!"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "!",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn partial_take_bytes_while_utf8() {
+    fn parser<'i>(i: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, &'i str> {
+        take_bytes_while(2, |c: char| c.is_alphabetic()).parse_next(i)
+    }
+    assert_parse!(
+        parser.parse_peek(Partial::new("øn")),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: "n",
+            partial: true,
+        },
+        "ø",
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        parser.parse_peek(Partial::new("aø")),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: "aø",
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        parser.parse_peek(Partial::new("a")),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        parser.parse_peek(Partial::new("")),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            2,
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn partial_take_take_while0() {
@@ -2704,6 +3753,39 @@ Ok(
     );
 }
 
+#[test]
+#[cfg(feature = "std")]
+fn partial_needed() {
+    use crate::error::{ContextError, Needed};
+    use crate::testing::check_needed;
+
+    let _ = check_needed::<_, _, ErrMode<ContextError>, _>("Hello".void(), "Hello");
+    let _ = check_needed::<_, _, ErrMode<ContextError>, _>(Caseless("hello").void(), "HeLlo");
+    let _ = check_needed::<_, _, ErrMode<ContextError>, _>('é'.void(), "é");
+    let _ = check_needed::<_, _, ErrMode<ContextError>, _>(b"Hello".void(), &b"Hello"[..]);
+    let _ = check_needed(take::<_, _, ErrMode<ContextError>>(3usize), &b"abc"[..]);
+    let _ = check_needed(take::<_, _, ErrMode<ContextError>>(3usize), "abc");
+
+    // Everything that is known to be missing is needed at once
+    assert_eq!(
+        "Hello".parse_peek(Partial::new("He")),
+        Err(ErrMode::<ContextError>::Incomplete(Needed::new(3)))
+    );
+    assert_eq!(
+        'é'.parse_peek(Partial::new("")),
+        Err(ErrMode::<ContextError>::Incomplete(Needed::new(2)))
+    );
+    assert_eq!(
+        take::<_, _, ErrMode<ContextError>>(3usize).parse_peek(Partial::new("a")),
+        Err(ErrMode::Incomplete(Needed::new(2)))
+    );
+
+    assert_eq!(
+        take_till::<_, _, ErrMode<ContextError>>(3.., ',').parse_peek(Partial::new("aé")),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+}
+
 #[test]
 fn rest_on_slices() {
     let input: &[u8] = &b"Hello, world!"[..];
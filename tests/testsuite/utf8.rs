@@ -149,7 +149,9 @@ fn take_incomplete_str() {
         str![[r#"
 Err(
     Incomplete(
-        Unknown,
+        Size(
+            3,
+        ),
     ),
 )
 